@@ -5,8 +5,12 @@
 //! - Timing functions (ease, linear, cubic-bezier, steps)
 //! - Animation properties (duration, delay, iteration, direction, fill-mode)
 //! - Animation engine for computing animated values
+//! - Timelines for driving animations from sources other than wall-clock time
 
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::rc::Rc;
 
 // ============================================================================
 // Basic Enums
@@ -189,6 +193,64 @@ pub trait AnimationEngine {
 // ============================================================================
 
 impl TimingFunction {
+    /// Parse a timing function from CSS text
+    ///
+    /// Accepts the easing keywords (including `step-start` and `step-end`),
+    /// `cubic-bezier(x1, y1, x2, y2)` and `steps(n[, start|end])`.
+    ///
+    /// # Returns
+    /// The parsed timing function, or `None` if the input is invalid
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+
+        match input {
+            "ease" => return Some(TimingFunction::Ease),
+            "linear" => return Some(TimingFunction::Linear),
+            "ease-in" => return Some(TimingFunction::EaseIn),
+            "ease-out" => return Some(TimingFunction::EaseOut),
+            "ease-in-out" => return Some(TimingFunction::EaseInOut),
+            "step-start" => return Some(TimingFunction::Steps(1, StepPosition::Start)),
+            "step-end" => return Some(TimingFunction::Steps(1, StepPosition::End)),
+            _ => {}
+        }
+
+        if let Some(args) = input
+            .strip_prefix("cubic-bezier(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let values: Vec<f32> = args
+                .split(',')
+                .map(|s| s.trim().parse::<f32>().ok().filter(|v| v.is_finite()))
+                .collect::<Option<_>>()?;
+            if values.len() != 4
+                || !(0.0..=1.0).contains(&values[0])
+                || !(0.0..=1.0).contains(&values[2])
+            {
+                return None;
+            }
+            return Some(TimingFunction::CubicBezier(
+                values[0], values[1], values[2], values[3],
+            ));
+        }
+
+        if let Some(args) = input
+            .strip_prefix("steps(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let parts: Vec<&str> = args.split(',').map(|s| s.trim()).collect();
+            let count = parts[0].parse::<i32>().ok().filter(|count| *count > 0)?;
+            let position = match parts.get(1..) {
+                Some([]) => StepPosition::End,
+                Some(["start"]) => StepPosition::Start,
+                Some(["end"]) => StepPosition::End,
+                _ => return None,
+            };
+            return Some(TimingFunction::Steps(count, position));
+        }
+
+        None
+    }
+
     /// Apply timing function to linear progress (0.0 to 1.0)
     ///
     /// # Arguments
@@ -316,6 +378,87 @@ pub fn find_surrounding_keyframes(
     Some((before, after, local_progress))
 }
 
+// ============================================================================
+// Timelines
+// ============================================================================
+
+/// Time source that drives animations
+///
+/// Animations added with [`AnimationEngine::add_animation`] follow the
+/// document timeline, the timestamp passed to [`AnimationEngine::tick`].
+/// Animations attached to a named timeline with
+/// [`BasicAnimationEngine::add_animation_on_timeline`] read their time from
+/// that timeline instead.
+pub trait Timeline: Debug {
+    /// Current time of the timeline in milliseconds
+    fn current_time(&self) -> f64;
+}
+
+/// Timeline driven by the scroll position of a scroll container
+///
+/// Scroll progress through `scroll_range` is reported as time through
+/// `duration_ms`, so an animation whose delay plus duration matches
+/// `duration_ms` runs from start to end as the container scrolls from top
+/// to bottom. The host keeps a shared handle and updates the offset as the
+/// container scrolls.
+///
+/// # Examples
+/// ```
+/// use css_animations::{ScrollTimeline, Timeline};
+///
+/// let timeline = ScrollTimeline::new(2000.0, 1000.0);
+/// timeline.set_scroll_offset(500.0);
+/// assert_eq!(timeline.current_time(), 250.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScrollTimeline {
+    scroll_offset: Cell<f64>,
+    scroll_range: f64,
+    duration_ms: f64,
+}
+
+impl ScrollTimeline {
+    /// Create a scroll timeline
+    ///
+    /// # Arguments
+    /// * `scroll_range` - Maximum scroll offset of the container
+    /// * `duration_ms` - Time reported when fully scrolled
+    pub fn new(scroll_range: f64, duration_ms: f64) -> Self {
+        Self {
+            scroll_offset: Cell::new(0.0),
+            scroll_range,
+            duration_ms,
+        }
+    }
+
+    /// Update the current scroll offset
+    pub fn set_scroll_offset(&self, scroll_offset: f64) {
+        self.scroll_offset.set(scroll_offset);
+    }
+
+    /// Get the current scroll offset
+    pub fn scroll_offset(&self) -> f64 {
+        self.scroll_offset.get()
+    }
+
+    /// Scroll progress in `0.0..=1.0`
+    ///
+    /// A container that cannot scroll has no progress.
+    pub fn progress(&self) -> f64 {
+        if self.scroll_range > 0.0 {
+            (self.scroll_offset.get() / self.scroll_range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Timeline for ScrollTimeline {
+    fn current_time(&self) -> f64 {
+        self.progress() * self.duration_ms
+    }
+}
+
 // ============================================================================
 // Basic Animation Engine Implementation
 // ============================================================================
@@ -326,6 +469,10 @@ struct AnimationState {
     element_id: ElementId,
     animation: Animation,
     start_time: f64,
+    /// Keeps animating even when reduced motion is requested
+    motion_exempt: bool,
+    /// Named timeline driving the animation, `None` for the document timeline
+    timeline: Option<String>,
 }
 
 /// Basic animation engine implementation
@@ -333,6 +480,10 @@ struct AnimationState {
 pub struct BasicAnimationEngine {
     animations: Vec<AnimationState>,
     keyframes_registry: HashMap<String, Keyframes>,
+    timelines: HashMap<String, Rc<dyn Timeline>>,
+    reduced_motion: bool,
+    /// Timestamp at which every animation was paused by [`BasicAnimationEngine::pause_all`]
+    paused_at: Option<f64>,
 }
 
 impl BasicAnimationEngine {
@@ -341,6 +492,73 @@ impl BasicAnimationEngine {
         Self {
             animations: Vec::new(),
             keyframes_registry: HashMap::new(),
+            timelines: HashMap::new(),
+            reduced_motion: false,
+            paused_at: None,
+        }
+    }
+
+    /// Pause every animation, e.g. when the page moves to the background
+    ///
+    /// Ticks while paused report the values at `timestamp_ms`. Pausing an
+    /// already paused engine keeps the original pause time. Animations on
+    /// named timelines keep following their timeline.
+    ///
+    /// # Arguments
+    /// * `timestamp_ms` - Current timestamp in milliseconds
+    pub fn pause_all(&mut self, timestamp_ms: f64) {
+        self.paused_at.get_or_insert(timestamp_ms);
+    }
+
+    /// Resume every animation after [`pause_all`](Self::pause_all)
+    ///
+    /// Start times are shifted by the time spent paused so animations
+    /// continue from where they stopped instead of jumping ahead.
+    ///
+    /// # Arguments
+    /// * `timestamp_ms` - Current timestamp in milliseconds
+    pub fn resume_all(&mut self, timestamp_ms: f64) {
+        if let Some(paused_at) = self.paused_at.take() {
+            let paused_duration = (timestamp_ms - paused_at).max(0.0);
+            for state in &mut self.animations {
+                if state.timeline.is_none() {
+                    state.start_time += paused_duration;
+                }
+            }
+        }
+    }
+
+    /// Check whether all animations are paused
+    pub fn is_globally_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Honor a `prefers-reduced-motion: reduce` user preference
+    ///
+    /// While enabled, running animations jump straight to the value at the
+    /// end of their active duration instead of interpolating. Delay and
+    /// fill-mode behavior are unchanged, as are animations marked with
+    /// [`set_motion_exempt`](Self::set_motion_exempt).
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    /// Check whether reduced motion is enabled
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Opt an animation on an element out of reduced motion
+    ///
+    /// # Arguments
+    /// * `element_id` - Element with the animation
+    /// * `animation_name` - Name of the animation
+    /// * `exempt` - Whether the animation keeps interpolating under reduced motion
+    pub fn set_motion_exempt(&mut self, element_id: ElementId, animation_name: &str, exempt: bool) {
+        for state in &mut self.animations {
+            if state.element_id == element_id && state.animation.name == animation_name {
+                state.motion_exempt = exempt;
+            }
         }
     }
 
@@ -358,7 +576,51 @@ impl BasicAnimationEngine {
         self.keyframes_registry.get(name)
     }
 
-    /// Calculate animation progress at given timestamp
+    /// Register a named timeline, replacing any timeline with the same name
+    ///
+    /// # Arguments
+    /// * `name` - Name animations refer to the timeline by
+    /// * `timeline` - Shared handle to the time source
+    pub fn register_timeline(&mut self, name: &str, timeline: Rc<dyn Timeline>) {
+        self.timelines.insert(name.to_string(), timeline);
+    }
+
+    /// Add an animation driven by a named timeline
+    ///
+    /// The animation starts at time zero of the timeline. Until a timeline
+    /// with that name is registered, the animation produces no updates.
+    ///
+    /// # Arguments
+    /// * `element_id` - Element to animate
+    /// * `animation` - Animation definition
+    /// * `timeline` - Name of the timeline
+    pub fn add_animation_on_timeline(
+        &mut self,
+        element_id: ElementId,
+        animation: Animation,
+        timeline: &str,
+    ) {
+        self.add_animation(element_id, animation);
+        if let Some(state) = self.animations.last_mut() {
+            state.timeline = Some(timeline.to_string());
+        }
+    }
+
+    /// Resolve the current time of an animation's timeline
+    ///
+    /// Returns `None` if the animation names an unregistered timeline.
+    fn timeline_time(&self, state: &AnimationState, timestamp_ms: f64) -> Option<f64> {
+        match &state.timeline {
+            Some(name) => self
+                .timelines
+                .get(name)
+                .map(|timeline| timeline.current_time()),
+            // Time stands still while the whole engine is paused
+            None => Some(self.paused_at.unwrap_or(timestamp_ms)),
+        }
+    }
+
+    /// Calculate animation progress at given timeline time
     fn calculate_progress(&self, state: &AnimationState, timestamp_ms: f64) -> Option<f32> {
         if state.animation.play_state == PlayState::Paused {
             return None;
@@ -399,42 +661,70 @@ impl BasicAnimationEngine {
             return None;
         }
 
+        // Reduced motion skips the interpolation and holds the end state
+        if self.reduced_motion && !state.motion_exempt {
+            return Some(end_progress(&state.animation));
+        }
+
         // Get progress within current iteration
+        let iteration = raw_progress.floor() as i64;
         let iteration_progress = (raw_progress % 1.0) as f32;
 
-        // Apply direction
-        let directed_progress = match state.animation.direction {
-            AnimationDirection::Normal => iteration_progress,
-            AnimationDirection::Reverse => 1.0 - iteration_progress,
-            AnimationDirection::Alternate => {
-                let iteration = raw_progress.floor() as i32;
-                if iteration % 2 == 0 {
-                    iteration_progress
-                } else {
-                    1.0 - iteration_progress
-                }
+        Some(apply_direction(
+            state.animation.direction,
+            iteration,
+            iteration_progress,
+        ))
+    }
+}
+
+/// Map progress within an iteration onto the keyframe timeline
+fn apply_direction(direction: AnimationDirection, iteration: i64, progress: f32) -> f32 {
+    match direction {
+        AnimationDirection::Normal => progress,
+        AnimationDirection::Reverse => 1.0 - progress,
+        AnimationDirection::Alternate => {
+            if iteration % 2 == 0 {
+                progress
+            } else {
+                1.0 - progress
             }
-            AnimationDirection::AlternateReverse => {
-                let iteration = raw_progress.floor() as i32;
-                if iteration % 2 == 0 {
-                    1.0 - iteration_progress
-                } else {
-                    iteration_progress
-                }
+        }
+        AnimationDirection::AlternateReverse => {
+            if iteration % 2 == 0 {
+                1.0 - progress
+            } else {
+                progress
             }
-        };
-
-        Some(directed_progress)
+        }
     }
 }
 
+/// Keyframe progress at the end of an animation's active duration
+///
+/// Infinite animations have no end, so the end of the first iteration is used.
+fn end_progress(animation: &Animation) -> f32 {
+    let (iteration, progress) = match animation.iteration_count {
+        IterationCount::Count(count) if count > 0.0 => {
+            let iteration = count.ceil() - 1.0;
+            (iteration as i64, count - iteration)
+        }
+        IterationCount::Count(_) => (0, 0.0),
+        IterationCount::Infinite => (0, 1.0),
+    };
+    apply_direction(animation.direction, iteration, progress)
+}
+
 impl AnimationEngine for BasicAnimationEngine {
     fn tick(&mut self, timestamp_ms: f64) -> Vec<AnimationUpdate> {
         let mut updates = Vec::new();
 
         for state in &self.animations {
             // Calculate current progress
-            let progress = match self.calculate_progress(state, timestamp_ms) {
+            let progress = match self
+                .timeline_time(state, timestamp_ms)
+                .and_then(|time| self.calculate_progress(state, time))
+            {
                 Some(p) => p,
                 None => continue,
             };
@@ -501,6 +791,8 @@ impl AnimationEngine for BasicAnimationEngine {
             element_id,
             animation,
             start_time: 0.0,
+            motion_exempt: false,
+            timeline: None,
         });
     }
 
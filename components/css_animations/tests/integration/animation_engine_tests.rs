@@ -300,3 +300,221 @@ fn test_engine_multiple_animations() {
     assert!(properties.contains(&"opacity"));
     assert!(properties.contains(&"transform"));
 }
+
+fn three_step_keyframes() -> Keyframes {
+    let keyframe = |offset: f32, opacity: &str| {
+        let mut properties = HashMap::new();
+        properties.insert("opacity".to_string(), opacity.to_string());
+        Keyframe { offset, properties }
+    };
+
+    Keyframes {
+        name: "fade".to_string(),
+        keyframes: vec![keyframe(0.0, "0"), keyframe(0.5, "0.5"), keyframe(1.0, "1")],
+    }
+}
+
+fn fade_animation(direction: AnimationDirection) -> Animation {
+    Animation {
+        name: "fade".to_string(),
+        duration: 1.0,
+        timing_function: TimingFunction::Linear,
+        delay: 0.0,
+        iteration_count: IterationCount::Count(1.0),
+        direction,
+        fill_mode: FillMode::None,
+        play_state: PlayState::Running,
+    }
+}
+
+#[test]
+fn test_engine_reduced_motion_jumps_to_end_value() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+
+    // Without reduced motion, 25% progress sits between 0% and 50%
+    let updates = engine.tick(250.0);
+    assert_eq!(updates[0].value, "0.5");
+
+    engine.set_reduced_motion(true);
+    assert!(engine.reduced_motion());
+
+    let updates = engine.tick(250.0);
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].value, "1");
+}
+
+#[test]
+fn test_engine_reduced_motion_respects_direction() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.set_reduced_motion(true);
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Reverse));
+
+    // A reversed animation ends on its first keyframe
+    let updates = engine.tick(250.0);
+    assert_eq!(updates[0].value, "0");
+
+    // Two alternating iterations also end back at the start
+    let mut animation = fade_animation(AnimationDirection::Alternate);
+    animation.iteration_count = IterationCount::Count(2.0);
+    engine.add_animation(1, animation);
+    let updates = engine.tick(250.0);
+    assert_eq!(updates[0].value, "0");
+}
+
+#[test]
+fn test_engine_reduced_motion_keeps_fill_behavior() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.set_reduced_motion(true);
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+
+    // The end value is only held for the active duration without a fill mode
+    assert_eq!(engine.tick(500.0)[0].value, "1");
+    assert!(engine.tick(1500.0).is_empty());
+}
+
+#[test]
+fn test_engine_reduced_motion_exempt_animation_interpolates() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.set_reduced_motion(true);
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+    engine.set_motion_exempt(1, "fade", true);
+
+    let updates = engine.tick(250.0);
+    assert_eq!(updates[0].value, "0.5");
+
+    engine.set_motion_exempt(1, "fade", false);
+    let updates = engine.tick(250.0);
+    assert_eq!(updates[0].value, "1");
+}
+
+#[test]
+fn test_engine_pause_all_freezes_progress() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+
+    engine.pause_all(250.0);
+    assert!(engine.is_globally_paused());
+
+    // Time advancing while paused does not move the animation
+    let updates = engine.tick(900.0);
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].value, "0.5");
+}
+
+#[test]
+fn test_engine_resume_all_continues_progress() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+
+    // Pause at 40% progress, stay paused for 10 seconds
+    engine.pause_all(400.0);
+    engine.resume_all(10_400.0);
+    assert!(!engine.is_globally_paused());
+
+    // 50ms after resuming is 45% progress, not finished
+    assert_eq!(engine.tick(10_450.0)[0].value, "0.5");
+    // 100ms later the animation has crossed its 50% keyframe
+    assert_eq!(engine.tick(10_550.0)[0].value, "1");
+    // The one second duration ends 600ms after resuming
+    assert_eq!(engine.tick(10_999.0).len(), 1);
+    assert!(engine.tick(11_000.0).is_empty());
+}
+
+#[test]
+fn test_engine_pause_all_keeps_first_pause_time() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation(1, fade_animation(AnimationDirection::Normal));
+
+    engine.pause_all(200.0);
+    engine.pause_all(800.0);
+    engine.resume_all(1_200.0);
+
+    // Resumed at 20% progress, so 1,400ms is 40%
+    assert_eq!(engine.tick(1_400.0)[0].value, "0.5");
+}
+
+/// Timeline whose time is set directly by the test
+#[derive(Debug, Default)]
+struct MockTimeline {
+    time: std::cell::Cell<f64>,
+}
+
+impl Timeline for MockTimeline {
+    fn current_time(&self) -> f64 {
+        self.time.get()
+    }
+}
+
+#[test]
+fn test_engine_named_timeline_drives_animation() {
+    let timeline = std::rc::Rc::new(MockTimeline::default());
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.register_timeline("mock", timeline.clone());
+    engine.add_animation_on_timeline(1, fade_animation(AnimationDirection::Normal), "mock");
+
+    // The tick timestamp is ignored; only the timeline's time matters
+    assert_eq!(engine.tick(900.0)[0].value, "0");
+
+    // Jump ahead, then scrub backwards
+    timeline.time.set(700.0);
+    assert_eq!(engine.tick(0.0)[0].value, "1");
+    timeline.time.set(200.0);
+    assert_eq!(engine.tick(0.0)[0].value, "0.5");
+    timeline.time.set(0.0);
+    assert_eq!(engine.tick(0.0)[0].value, "0");
+
+    // Past the end of the animation there is nothing to apply
+    timeline.time.set(5_000.0);
+    assert!(engine.tick(0.0).is_empty());
+}
+
+#[test]
+fn test_engine_named_timeline_independent_of_pause_all() {
+    let timeline = std::rc::Rc::new(MockTimeline::default());
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.register_timeline("mock", timeline.clone());
+    engine.add_animation_on_timeline(1, fade_animation(AnimationDirection::Normal), "mock");
+
+    engine.pause_all(0.0);
+    timeline.time.set(700.0);
+    assert_eq!(engine.tick(0.0)[0].value, "1");
+}
+
+#[test]
+fn test_engine_unregistered_timeline_produces_no_updates() {
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.add_animation_on_timeline(1, fade_animation(AnimationDirection::Normal), "missing");
+
+    assert!(engine.tick(250.0).is_empty());
+}
+
+#[test]
+fn test_engine_scroll_timeline_reports_progress_as_time() {
+    let scroller = std::rc::Rc::new(ScrollTimeline::new(2_000.0, 1_000.0));
+    let mut engine = BasicAnimationEngine::new();
+    engine.register_keyframes(three_step_keyframes());
+    engine.register_timeline("scroller", scroller.clone());
+    engine.add_animation_on_timeline(1, fade_animation(AnimationDirection::Normal), "scroller");
+
+    // A quarter of the way down is 25% progress
+    scroller.set_scroll_offset(500.0);
+    assert_eq!(scroller.progress(), 0.25);
+    assert_eq!(engine.tick(0.0)[0].value, "0.5");
+
+    // Overscroll is clamped to the end of the range
+    scroller.set_scroll_offset(-100.0);
+    assert_eq!(scroller.current_time(), 0.0);
+    scroller.set_scroll_offset(1_600.0);
+    assert_eq!(engine.tick(0.0)[0].value, "1");
+}
@@ -93,6 +93,76 @@ fn test_steps_start() {
 // Interpolation Tests
 // ============================================================================
 
+#[test]
+fn test_parse_timing_function_keywords() {
+    assert_eq!(TimingFunction::parse("ease"), Some(TimingFunction::Ease));
+    assert_eq!(
+        TimingFunction::parse("linear"),
+        Some(TimingFunction::Linear)
+    );
+    assert_eq!(
+        TimingFunction::parse("ease-in-out"),
+        Some(TimingFunction::EaseInOut)
+    );
+    assert_eq!(TimingFunction::parse("bounce"), None);
+}
+
+#[test]
+fn test_parse_timing_function_functions() {
+    assert_eq!(
+        TimingFunction::parse("cubic-bezier(0.4, 0, 0.2, 1)"),
+        Some(TimingFunction::CubicBezier(0.4, 0.0, 0.2, 1.0))
+    );
+    assert_eq!(
+        TimingFunction::parse("steps(4, start)"),
+        Some(TimingFunction::Steps(4, StepPosition::Start))
+    );
+    assert_eq!(
+        TimingFunction::parse("steps(4)"),
+        Some(TimingFunction::Steps(4, StepPosition::End))
+    );
+    assert_eq!(TimingFunction::parse("steps(0)"), None);
+    assert_eq!(TimingFunction::parse("cubic-bezier(2, 0, 0.2, 1)"), None);
+}
+
+#[test]
+fn test_parse_cubic_bezier_rejects_non_finite() {
+    assert_eq!(
+        TimingFunction::parse("cubic-bezier(0.4, NaN, 0.2, 1)"),
+        None
+    );
+    assert_eq!(
+        TimingFunction::parse("cubic-bezier(0.4, 0, 0.2, inf)"),
+        None
+    );
+    assert_eq!(
+        TimingFunction::parse("cubic-bezier(0.4, 1e40, 0.2, 1)"),
+        None
+    );
+}
+
+#[test]
+fn test_parse_step_start() {
+    let step_start = TimingFunction::parse("step-start").unwrap();
+    assert_eq!(step_start, TimingFunction::Steps(1, StepPosition::Start));
+
+    // Jumps to the end value immediately
+    assert_eq!(step_start.apply(0.0), 1.0);
+    assert_eq!(step_start.apply(0.5), 1.0);
+    assert_eq!(step_start.apply(1.0), 1.0);
+}
+
+#[test]
+fn test_parse_step_end() {
+    let step_end = TimingFunction::parse("step-end").unwrap();
+    assert_eq!(step_end, TimingFunction::Steps(1, StepPosition::End));
+
+    // Holds the start value until the very end
+    assert_eq!(step_end.apply(0.0), 0.0);
+    assert_eq!(step_end.apply(0.99), 0.0);
+    assert_eq!(step_end.apply(1.0), 1.0);
+}
+
 #[test]
 fn test_interpolate_f32_at_start() {
     let result = interpolate_f32(0.0, 100.0, 0.0);
@@ -27,6 +27,7 @@ pub use css_types::Specificity;
 // Re-export public types and functions from our modules
 pub use resolver::CascadeResolver;
 pub use types::{
-    ApplicableRule, CascadeResult, ComputedValues, Origin, PropertyId, PropertyValue, Selector,
-    StyleRule,
+    initial_keyword_of, initial_value, initial_value_of, ApplicableRule, CascadeLevel,
+    CascadeResult, ComputedValues, InitialValue, Origin, PropertyId, PropertyValue, Selector,
+    StyleRule, INITIAL_VALUES,
 };
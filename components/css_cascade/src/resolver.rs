@@ -1,7 +1,9 @@
 use crate::types::{
-    ApplicableRule, CascadeResult, ComputedValues, PropertyId, PropertyValue, Selector,
+    ApplicableRule, CascadeLevel, CascadeResult, ComputedValues, PropertyId, PropertyValue,
+    Selector,
 };
 use css_types::Specificity;
+use std::collections::HashMap;
 
 /// CSS cascade resolver
 pub struct CascadeResolver;
@@ -20,6 +22,11 @@ impl CascadeResolver {
     /// 3. Within same specificity, sort by source order
     /// 4. Apply !important rules with reversed origin priority
     /// 5. Merge all properties, later rules override earlier ones for same property
+    ///
+    /// Steps 1-4 are a single sort by [`CascadeLevel`]. A `revert-layer`
+    /// declaration takes the value cascaded from earlier layers or origins
+    /// instead; with no such value the keyword is kept, to be resolved by
+    /// [`resolve_css_wide_keywords`](Self::resolve_css_wide_keywords).
     pub fn resolve(&self, rules: &[ApplicableRule]) -> CascadeResult {
         let mut result = CascadeResult::new();
 
@@ -27,9 +34,8 @@ impl CascadeResolver {
             return result;
         }
 
-        // Separate normal and important declarations
-        let mut normal_rules: Vec<_> = Vec::new();
-        let mut important_rules: Vec<_> = Vec::new();
+        // Group each rule's declarations by importance, tagged with their cascade level
+        let mut levels: Vec<(CascadeLevel, Vec<(PropertyId, PropertyValue)>)> = Vec::new();
 
         for applicable_rule in rules {
             let mut normal_decls = Vec::new();
@@ -46,78 +52,104 @@ impl CascadeResolver {
                 }
             }
 
-            if !normal_decls.is_empty() {
-                normal_rules.push((
-                    applicable_rule.origin,
-                    applicable_rule.specificity,
-                    applicable_rule.source_order,
-                    normal_decls,
-                ));
+            for (important, declarations) in [(false, normal_decls), (true, important_decls)] {
+                if !declarations.is_empty() {
+                    let level = CascadeLevel::new(
+                        applicable_rule.origin,
+                        important,
+                        applicable_rule.specificity,
+                        applicable_rule.source_order,
+                    )
+                    .with_layer(applicable_rule.layer);
+                    levels.push((level, declarations));
+                }
             }
+        }
 
-            if !important_decls.is_empty() {
-                important_rules.push((
-                    applicable_rule.origin,
-                    applicable_rule.specificity,
-                    applicable_rule.source_order,
-                    important_decls,
-                ));
+        // Apply in ascending cascade order, so later (higher) levels override earlier ones
+        levels.sort_by_key(|(level, _)| *level);
+
+        // Values applied so far for each property, for rolling back `revert-layer`
+        let mut applied: HashMap<PropertyId, Vec<(CascadeLevel, PropertyValue)>> = HashMap::new();
+
+        for (level, declarations) in levels {
+            for (prop_id, prop_value) in declarations {
+                let history = applied.entry(prop_id).or_default();
+                let reverted = match prop_value.as_css_wide_keyword() {
+                    Some(PropertyValue::RevertLayer) => history
+                        .iter()
+                        .rev()
+                        .find(|(earlier, _)| !earlier.same_layer(&level))
+                        .map(|(_, value)| value.clone()),
+                    _ => None,
+                };
+                let value = reverted.unwrap_or_else(|| {
+                    if level.important() {
+                        PropertyValue::Important(Box::new(prop_value))
+                    } else {
+                        prop_value
+                    }
+                });
+                history.push((level, value.clone()));
+                result.properties.insert(prop_id, value);
             }
         }
 
-        // Sort normal rules by cascade order
-        normal_rules.sort_by(|a, b| {
-            // Compare origin first
-            match a.0.cmp(&b.0) {
-                std::cmp::Ordering::Equal => {
-                    // Then specificity
-                    match a.1.cmp(&b.1) {
-                        std::cmp::Ordering::Equal => {
-                            // Finally source order
-                            a.2.cmp(&b.2)
-                        }
-                        other => other,
-                    }
-                }
+        result
+    }
+
+    /// Resolve CSS-wide keywords in a cascade result into computed values
+    ///
+    /// For each cascaded declaration:
+    /// - `inherit` takes the parent's computed value
+    /// - `initial` takes the property's initial value
+    /// - `unset` behaves as `inherit` for inherited properties and as
+    ///   `initial` otherwise
+    /// - `revert` behaves as `unset`, since per-origin cascade results are
+    ///   not retained; so does `revert-layer` when no earlier layer gave the
+    ///   property a value (see [`resolve`](Self::resolve))
+    ///
+    /// Inheriting from a missing parent (or a parent without the property)
+    /// falls back to the initial value. `!important` wrappers are dropped.
+    pub fn resolve_css_wide_keywords(
+        result: &CascadeResult,
+        parent: Option<&ComputedValues>,
+    ) -> ComputedValues {
+        let mut computed = ComputedValues::new();
+
+        for (&prop_id, value) in &result.properties {
+            let value = match value {
+                PropertyValue::Important(inner) => inner.as_ref(),
                 other => other,
-            }
-        });
-
-        // Sort important rules (reversed origin priority)
-        important_rules.sort_by(|a, b| {
-            // Compare origin first (REVERSED for !important)
-            match b.0.cmp(&a.0) {
-                std::cmp::Ordering::Equal => {
-                    // Then specificity
-                    match a.1.cmp(&b.1) {
-                        std::cmp::Ordering::Equal => {
-                            // Finally source order
-                            a.2.cmp(&b.2)
-                        }
-                        other => other,
+            };
+
+            let resolved = match value.as_css_wide_keyword() {
+                Some(PropertyValue::Inherit) => Self::inherited_value(prop_id, parent),
+                Some(PropertyValue::Initial) => prop_id.initial_value(),
+                Some(PropertyValue::Unset)
+                | Some(PropertyValue::Revert)
+                | Some(PropertyValue::RevertLayer) => {
+                    if prop_id.is_inherited() {
+                        Self::inherited_value(prop_id, parent)
+                    } else {
+                        prop_id.initial_value()
                     }
                 }
-                other => other,
-            }
-        });
+                _ => value.clone(),
+            };
 
-        // Apply normal rules (in order, so later rules override earlier ones)
-        for (_origin, _specificity, _source_order, declarations) in normal_rules {
-            for (prop_id, prop_value) in declarations {
-                result.properties.insert(prop_id, prop_value);
-            }
+            computed.set(prop_id, resolved);
         }
 
-        // Apply important rules (these override normal rules)
-        for (_origin, _specificity, _source_order, declarations) in important_rules {
-            for (prop_id, prop_value) in declarations {
-                result
-                    .properties
-                    .insert(prop_id, PropertyValue::Important(Box::new(prop_value)));
-            }
-        }
+        computed
+    }
 
-        result
+    /// Parent's computed value for a property, or its initial value
+    fn inherited_value(prop_id: PropertyId, parent: Option<&ComputedValues>) -> PropertyValue {
+        parent
+            .and_then(|parent| parent.get(&prop_id))
+            .cloned()
+            .unwrap_or_else(|| prop_id.initial_value())
     }
 
     /// Calculate selector specificity
@@ -186,6 +218,8 @@ impl CascadeResolver {
     /// - margin
     /// - padding
     /// - border
+    /// - border-color
+    /// - background-color
     /// - width
     /// - height
     /// - display
@@ -221,6 +255,8 @@ impl CascadeResolver {
             PropertyId::Margin,
             PropertyId::Padding,
             PropertyId::Border,
+            PropertyId::BorderColor,
+            PropertyId::BackgroundColor,
             PropertyId::Width,
             PropertyId::Height,
             PropertyId::Display,
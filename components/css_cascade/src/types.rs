@@ -3,6 +3,8 @@ use std::collections::HashMap;
 // Re-export Specificity from css_types
 pub use css_types::Specificity;
 
+use css_types::{BorderStyle, Color, ColorValue, CssValue, KeywordEnum, Length, LengthUnit};
+
 /// Rule origin for cascade ordering
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Origin {
@@ -38,11 +40,284 @@ pub enum PropertyId {
     Margin,
     Padding,
     Border,
+    BorderColor,
+    BackgroundColor,
     Width,
     Height,
     Display,
 }
 
+impl PropertyId {
+    /// Whether the property is inherited by default
+    pub fn is_inherited(&self) -> bool {
+        matches!(
+            self,
+            PropertyId::Color
+                | PropertyId::FontSize
+                | PropertyId::FontFamily
+                | PropertyId::LineHeight
+                | PropertyId::TextAlign
+        )
+    }
+
+    /// The property's CSS name
+    pub fn name(&self) -> &'static str {
+        match self {
+            PropertyId::Color => "color",
+            PropertyId::FontSize => "font-size",
+            PropertyId::FontFamily => "font-family",
+            PropertyId::LineHeight => "line-height",
+            PropertyId::TextAlign => "text-align",
+            PropertyId::Margin => "margin",
+            PropertyId::Padding => "padding",
+            PropertyId::Border => "border",
+            PropertyId::BorderColor => "border-color",
+            PropertyId::BackgroundColor => "background-color",
+            PropertyId::Width => "width",
+            PropertyId::Height => "height",
+            PropertyId::Display => "display",
+        }
+    }
+
+    /// The property's initial value
+    pub fn initial_value(&self) -> PropertyValue {
+        self.initial().to_property_value()
+    }
+
+    /// The property's typed initial value from [`INITIAL_VALUES`]
+    pub fn initial(&self) -> InitialValue {
+        // Constant lookups, so a property missing from the table fails the build
+        macro_rules! entry {
+            ($name:literal) => {{
+                const VALUE: InitialValue = initial_value_of($name);
+                VALUE
+            }};
+        }
+
+        match self {
+            PropertyId::Color => entry!("color"),
+            PropertyId::FontSize => entry!("font-size"),
+            PropertyId::FontFamily => entry!("font-family"),
+            PropertyId::LineHeight => entry!("line-height"),
+            PropertyId::TextAlign => entry!("text-align"),
+            PropertyId::Margin => entry!("margin"),
+            PropertyId::Padding => entry!("padding"),
+            PropertyId::Border => entry!("border"),
+            PropertyId::BorderColor => entry!("border-color"),
+            PropertyId::BackgroundColor => entry!("background-color"),
+            PropertyId::Width => entry!("width"),
+            PropertyId::Height => entry!("height"),
+            PropertyId::Display => entry!("display"),
+        }
+    }
+}
+
+/// Typed initial value of a property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitialValue {
+    /// A keyword such as `auto` or `inline`
+    Keyword(&'static str),
+    /// A length
+    Length(Length),
+    /// A number
+    Number(f32),
+    /// A color, which may be `currentColor`
+    Color(ColorValue),
+    /// A list of font families
+    FontFamily(&'static [&'static str]),
+    /// The `border` shorthand
+    Border {
+        width: Length,
+        style: BorderStyle,
+        color: ColorValue,
+    },
+}
+
+impl InitialValue {
+    /// Convert to the cascade's property value representation
+    pub fn to_property_value(&self) -> PropertyValue {
+        match *self {
+            InitialValue::Keyword(keyword) => PropertyValue::Keyword(keyword.to_string()),
+            InitialValue::Length(length) => PropertyValue::Length(
+                f64::from(length.value()),
+                length.unit().to_str().to_string(),
+            ),
+            InitialValue::Number(value) => PropertyValue::Number(f64::from(value)),
+            InitialValue::Color(color) => PropertyValue::Keyword(color.serialize()),
+            InitialValue::FontFamily(families) => {
+                PropertyValue::FontFamily(families.iter().map(|f| f.to_string()).collect())
+            }
+            InitialValue::Border {
+                width,
+                style,
+                color,
+            } => PropertyValue::Border {
+                width: f64::from(width.value()),
+                style: style.to_keyword().to_string(),
+                color: color.serialize(),
+            },
+        }
+    }
+}
+
+const AUTO: InitialValue = InitialValue::Keyword("auto");
+const ZERO: InitialValue = InitialValue::Length(Length::new(0.0, LengthUnit::Px));
+const CURRENT_COLOR: InitialValue = InitialValue::Color(ColorValue::CurrentColor);
+
+/// Initial values of the supported properties, keyed by CSS name
+pub const INITIAL_VALUES: &[(&str, InitialValue)] = &[
+    (
+        "background-color",
+        InitialValue::Color(ColorValue::Rgba(Color::rgba(0, 0, 0, 0.0))),
+    ),
+    (
+        "border",
+        InitialValue::Border {
+            width: Length::new(3.0, LengthUnit::Px),
+            style: BorderStyle::None,
+            color: ColorValue::CurrentColor,
+        },
+    ),
+    ("border-color", CURRENT_COLOR),
+    ("bottom", AUTO),
+    (
+        "color",
+        InitialValue::Color(ColorValue::Rgba(Color::rgb(0, 0, 0))),
+    ),
+    ("display", InitialValue::Keyword("inline")),
+    ("font-family", InitialValue::FontFamily(&["serif"])),
+    // `medium`
+    (
+        "font-size",
+        InitialValue::Length(Length::new(16.0, LengthUnit::Px)),
+    ),
+    ("height", AUTO),
+    ("left", AUTO),
+    ("line-height", InitialValue::Keyword("normal")),
+    ("margin", ZERO),
+    ("margin-bottom", ZERO),
+    ("margin-left", ZERO),
+    ("margin-right", ZERO),
+    ("margin-top", ZERO),
+    ("opacity", InitialValue::Number(1.0)),
+    ("padding", ZERO),
+    ("padding-bottom", ZERO),
+    ("padding-left", ZERO),
+    ("padding-right", ZERO),
+    ("padding-top", ZERO),
+    ("position", InitialValue::Keyword("static")),
+    ("right", AUTO),
+    ("text-align", InitialValue::Keyword("start")),
+    ("top", AUTO),
+    ("transform", InitialValue::Keyword("none")),
+    ("width", AUTO),
+    ("will-change", AUTO),
+    ("z-index", AUTO),
+];
+
+/// Look up the initial value of a property by its CSS name
+///
+/// The name is matched ASCII case-insensitively against [`INITIAL_VALUES`].
+/// Returns `None` for properties not in the table.
+///
+/// # Examples
+/// ```
+/// use css_cascade::{initial_value, PropertyValue};
+///
+/// assert_eq!(
+///     initial_value("display"),
+///     Some(PropertyValue::Keyword("inline".to_string()))
+/// );
+/// assert_eq!(
+///     initial_value("margin-top"),
+///     Some(PropertyValue::Length(0.0, "px".to_string()))
+/// );
+/// assert_eq!(initial_value("no-such-property"), None);
+/// ```
+pub fn initial_value(property: &str) -> Option<PropertyValue> {
+    INITIAL_VALUES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(property))
+        .map(|(_, value)| value.to_property_value())
+}
+
+/// Look up a typed initial value by exact CSS name, for constant contexts
+///
+/// Use it to initialise a `const`, so a name missing from
+/// [`INITIAL_VALUES`] is a compile error instead of a runtime failure.
+///
+/// # Examples
+/// ```
+/// use css_cascade::{initial_value_of, InitialValue};
+///
+/// const DISPLAY: InitialValue = initial_value_of("display");
+/// assert_eq!(DISPLAY, InitialValue::Keyword("inline"));
+/// ```
+///
+/// # Panics
+/// Panics if `property` is not in the table.
+pub const fn initial_value_of(property: &str) -> InitialValue {
+    let mut i = 0;
+    while i < INITIAL_VALUES.len() {
+        if names_equal(INITIAL_VALUES[i].0, property) {
+            return INITIAL_VALUES[i].1;
+        }
+        i += 1;
+    }
+    panic!("property missing from INITIAL_VALUES")
+}
+
+/// Map a keyword initial value onto a keyword enum, for constant contexts
+///
+/// `keywords` is the enum's [`KeywordEnum::KEYWORDS`] table. As with
+/// [`initial_value_of`], use it to initialise a `const`.
+///
+/// # Examples
+/// ```
+/// use css_cascade::initial_keyword_of;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Display { Block, Inline }
+///
+/// const DISPLAY: Display =
+///     initial_keyword_of("display", &[("block", Display::Block), ("inline", Display::Inline)]);
+/// assert_eq!(DISPLAY, Display::Inline);
+/// ```
+///
+/// # Panics
+/// Panics if `property` is not in the table, its initial value is not a
+/// keyword, or the keyword is not in `keywords`.
+pub const fn initial_keyword_of<T: Copy>(property: &str, keywords: &[(&str, T)]) -> T {
+    let keyword = match initial_value_of(property) {
+        InitialValue::Keyword(keyword) => keyword,
+        _ => panic!("initial value is not a keyword"),
+    };
+    let mut i = 0;
+    while i < keywords.len() {
+        if names_equal(keywords[i].0, keyword) {
+            return keywords[i].1;
+        }
+        i += 1;
+    }
+    panic!("initial keyword is not a value of this type")
+}
+
+/// Byte-wise string equality usable in `const fn`
+const fn names_equal(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Property value
 #[derive(Debug, Clone, PartialEq)]
 pub enum PropertyValue {
@@ -56,7 +331,42 @@ pub enum PropertyValue {
         color: String,
     },
     Important(Box<PropertyValue>),
+    /// CSS-wide `inherit` keyword
     Inherit,
+    /// CSS-wide `initial` keyword
+    Initial,
+    /// CSS-wide `unset` keyword
+    Unset,
+    /// CSS-wide `revert` keyword
+    Revert,
+    /// CSS-wide `revert-layer` keyword
+    RevertLayer,
+}
+
+impl PropertyValue {
+    /// Return the CSS-wide keyword this value represents, if any
+    ///
+    /// Recognizes both the dedicated variants and keyword values spelled
+    /// `inherit`, `initial`, `unset`, `revert`, or `revert-layer`
+    /// (case-insensitive).
+    pub fn as_css_wide_keyword(&self) -> Option<PropertyValue> {
+        match self {
+            PropertyValue::Inherit
+            | PropertyValue::Initial
+            | PropertyValue::Unset
+            | PropertyValue::Revert
+            | PropertyValue::RevertLayer => Some(self.clone()),
+            PropertyValue::Keyword(keyword) => match keyword.to_ascii_lowercase().as_str() {
+                "inherit" => Some(PropertyValue::Inherit),
+                "initial" => Some(PropertyValue::Initial),
+                "unset" => Some(PropertyValue::Unset),
+                "revert" => Some(PropertyValue::Revert),
+                "revert-layer" => Some(PropertyValue::RevertLayer),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 /// Style rule with declarations
@@ -72,6 +382,128 @@ pub struct ApplicableRule {
     pub specificity: Specificity,
     pub origin: Origin,
     pub source_order: usize,
+    /// Position of the rule's cascade layer in declaration order, or `None`
+    /// for unlayered rules
+    pub layer: Option<u32>,
+}
+
+/// Position of a declaration in the cascade
+///
+/// Orders declarations by, in decreasing priority: origin and importance,
+/// cascade layer, specificity, then source order. Normal declarations rank
+/// user-agent < user < author; `!important` declarations rank above all
+/// normal ones with the origin order reversed (author < user < user-agent).
+/// Within an origin, normal declarations in later layers beat earlier
+/// layers and unlayered declarations beat all layers; for `!important`
+/// declarations the layer order is reversed. Sorting ascending therefore
+/// puts the winning declaration last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CascadeLevel {
+    origin: Origin,
+    important: bool,
+    layer: Option<u32>,
+    specificity: Specificity,
+    source_order: usize,
+}
+
+impl CascadeLevel {
+    /// Create a cascade level for a declaration
+    pub fn new(
+        origin: Origin,
+        important: bool,
+        specificity: Specificity,
+        source_order: usize,
+    ) -> Self {
+        Self {
+            origin,
+            important,
+            layer: None,
+            specificity,
+            source_order,
+        }
+    }
+
+    /// Builder: place the declaration in a cascade layer (`None` is unlayered)
+    pub fn with_layer(mut self, layer: Option<u32>) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Get the origin
+    pub fn origin(&self) -> Origin {
+        self.origin
+    }
+
+    /// Whether the declaration is `!important`
+    pub fn important(&self) -> bool {
+        self.important
+    }
+
+    /// Get the cascade layer ordinal, or `None` if unlayered
+    pub fn layer(&self) -> Option<u32> {
+        self.layer
+    }
+
+    /// Whether both declarations are in the same origin, importance, and layer
+    ///
+    /// `revert-layer` rolls back past every declaration that shares its
+    /// layer in this sense.
+    pub fn same_layer(&self, other: &CascadeLevel) -> bool {
+        self.origin == other.origin
+            && self.important == other.important
+            && self.layer == other.layer
+    }
+
+    /// Get the specificity
+    pub fn specificity(&self) -> Specificity {
+        self.specificity
+    }
+
+    /// Get the source order
+    pub fn source_order(&self) -> usize {
+        self.source_order
+    }
+
+    /// Rank of the origin/importance pair (0 = lowest precedence)
+    fn origin_rank(&self) -> u8 {
+        let origin = match self.origin {
+            Origin::UserAgent => 0,
+            Origin::User => 1,
+            Origin::Author => 2,
+        };
+        if self.important {
+            5 - origin
+        } else {
+            origin
+        }
+    }
+
+    /// Rank of the cascade layer within an origin (0 = lowest precedence)
+    fn layer_rank(&self) -> u64 {
+        // Unlayered declarations act as a final, implicit layer
+        let position = self.layer.map_or(u64::from(u32::MAX) + 1, u64::from);
+        if self.important {
+            u64::from(u32::MAX) + 1 - position
+        } else {
+            position
+        }
+    }
+}
+
+impl PartialOrd for CascadeLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CascadeLevel {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.origin_rank()
+            .cmp(&other.origin_rank())
+            .then_with(|| self.layer_rank().cmp(&other.layer_rank()))
+            .then_with(|| self.specificity.cmp(&other.specificity))
+            .then_with(|| self.source_order.cmp(&other.source_order))
+    }
 }
 
 /// Result of cascade resolution
@@ -0,0 +1,98 @@
+use css_cascade::{CascadeLevel, Origin, Specificity};
+
+fn level(origin: Origin, important: bool) -> CascadeLevel {
+    CascadeLevel::new(origin, important, Specificity::new(0, 1, 0), 0)
+}
+
+#[test]
+fn test_cascade_level_origin_and_importance_precedence() {
+    // Ascending precedence per CSS Cascade
+    let expected = vec![
+        level(Origin::UserAgent, false),
+        level(Origin::User, false),
+        level(Origin::Author, false),
+        level(Origin::Author, true),
+        level(Origin::User, true),
+        level(Origin::UserAgent, true),
+    ];
+
+    let mut shuffled = vec![
+        expected[4], expected[0], expected[5], expected[2], expected[3], expected[1],
+    ];
+    shuffled.sort_by_key(|level| *level);
+
+    assert_eq!(shuffled, expected);
+}
+
+#[test]
+fn test_important_user_agent_beats_important_author() {
+    assert!(level(Origin::UserAgent, true) > level(Origin::Author, true));
+}
+
+#[test]
+fn test_important_beats_higher_specificity() {
+    let normal_id = CascadeLevel::new(Origin::Author, false, Specificity::new(1, 0, 0), 5);
+    let important_type = CascadeLevel::new(Origin::UserAgent, true, Specificity::new(0, 0, 1), 0);
+    assert!(important_type > normal_id);
+}
+
+#[test]
+fn test_origin_beats_specificity() {
+    let user_agent_id = CascadeLevel::new(Origin::UserAgent, false, Specificity::new(1, 0, 0), 9);
+    let author_type = CascadeLevel::new(Origin::Author, false, Specificity::new(0, 0, 1), 0);
+    assert!(author_type > user_agent_id);
+}
+
+#[test]
+fn test_specificity_then_source_order() {
+    let low = CascadeLevel::new(Origin::Author, false, Specificity::new(0, 1, 0), 9);
+    let high = CascadeLevel::new(Origin::Author, false, Specificity::new(0, 2, 0), 0);
+    assert!(high > low);
+
+    let earlier = CascadeLevel::new(Origin::Author, false, Specificity::new(0, 1, 0), 1);
+    let later = CascadeLevel::new(Origin::Author, false, Specificity::new(0, 1, 0), 2);
+    assert!(later > earlier);
+}
+
+#[test]
+fn test_later_layer_beats_earlier_layer() {
+    let earlier = level(Origin::Author, false).with_layer(Some(0));
+    let later = level(Origin::Author, false).with_layer(Some(1));
+    let unlayered = level(Origin::Author, false);
+    assert!(later > earlier);
+    assert!(unlayered > later);
+    assert_eq!(later.layer(), Some(1));
+}
+
+#[test]
+fn test_important_layer_order_is_reversed() {
+    let earlier = level(Origin::Author, true).with_layer(Some(0));
+    let later = level(Origin::Author, true).with_layer(Some(1));
+    let unlayered = level(Origin::Author, true);
+    assert!(earlier > later);
+    assert!(later > unlayered);
+}
+
+#[test]
+fn test_layer_beats_specificity_but_not_origin() {
+    let earlier_id =
+        CascadeLevel::new(Origin::Author, false, Specificity::new(1, 0, 0), 9).with_layer(Some(0));
+    let later_type =
+        CascadeLevel::new(Origin::Author, false, Specificity::new(0, 0, 1), 0).with_layer(Some(1));
+    assert!(later_type > earlier_id);
+
+    let user_unlayered = level(Origin::User, false);
+    assert!(earlier_id > user_unlayered);
+    assert!(level(Origin::Author, true).with_layer(Some(0)) > later_type);
+}
+
+#[test]
+fn test_same_layer() {
+    let layered = level(Origin::Author, false).with_layer(Some(0));
+    let other = CascadeLevel::new(Origin::Author, false, Specificity::new(1, 0, 0), 5);
+    assert!(layered.same_layer(&other.with_layer(Some(0))));
+    assert!(!layered.same_layer(&level(Origin::Author, false).with_layer(Some(1))));
+    assert!(!layered.same_layer(&level(Origin::Author, true).with_layer(Some(0))));
+    assert!(!layered.same_layer(&level(Origin::User, false).with_layer(Some(0))));
+    assert!(!layered.same_layer(&level(Origin::Author, false)));
+}
@@ -21,6 +21,7 @@ fn test_applicable_rule_creation() {
         specificity: Specificity::new(0, 1, 0),
         origin: Origin::Author,
         source_order: 0,
+        layer: None,
     };
     assert_eq!(applicable.specificity, Specificity::new(0, 1, 0));
     assert_eq!(applicable.origin, Origin::Author);
@@ -45,6 +46,7 @@ fn test_cascade_single_rule() {
         specificity: Specificity::new(0, 1, 0),
         origin: Origin::Author,
         source_order: 0,
+        layer: None,
     }];
 
     let result = resolver.resolve(&applicable_rules);
@@ -77,12 +79,14 @@ fn test_cascade_specificity_override() {
             specificity: Specificity::new(0, 1, 0), // .class
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         },
         ApplicableRule {
             rule: rule2,
             specificity: Specificity::new(1, 0, 0), // #id
             origin: Origin::Author,
             source_order: 1,
+            layer: None,
         },
     ];
 
@@ -117,12 +121,14 @@ fn test_cascade_source_order() {
             specificity: Specificity::new(0, 1, 0),
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         },
         ApplicableRule {
             rule: rule2,
             specificity: Specificity::new(0, 1, 0),
             origin: Origin::Author,
             source_order: 1, // Later in source order
+            layer: None,
         },
     ];
 
@@ -158,12 +164,14 @@ fn test_cascade_origin_override() {
             specificity: Specificity::new(1, 0, 0), // Higher specificity
             origin: Origin::UserAgent,
             source_order: 0,
+            layer: None,
         },
         ApplicableRule {
             rule: rule2,
             specificity: Specificity::new(0, 1, 0), // Lower specificity
             origin: Origin::Author,
             source_order: 1,
+            layer: None,
         },
     ];
 
@@ -199,12 +207,14 @@ fn test_cascade_important_flag() {
             specificity: Specificity::new(1, 0, 0), // Higher specificity
             origin: Origin::Author,
             source_order: 1,
+            layer: None,
         },
         ApplicableRule {
             rule: rule2,
             specificity: Specificity::new(0, 1, 0), // Lower specificity
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         },
     ];
 
@@ -252,12 +262,14 @@ fn test_cascade_merge_multiple_properties() {
             specificity: Specificity::new(0, 1, 0),
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         },
         ApplicableRule {
             rule: rule2,
             specificity: Specificity::new(0, 1, 1),
             origin: Origin::Author,
             source_order: 1,
+            layer: None,
         },
     ];
 
@@ -286,3 +298,142 @@ fn test_empty_rules() {
     let result = resolver.resolve(&[]);
     assert!(result.properties.is_empty());
 }
+
+#[test]
+fn test_cascade_later_layer_beats_earlier_layer() {
+    let resolver = CascadeResolver::new();
+
+    // Equal specificity; the earlier layer's rule comes later in source
+    let applicable_rules = vec![
+        ApplicableRule {
+            rule: StyleRule {
+                declarations: vec![(
+                    PropertyId::Color,
+                    PropertyValue::Keyword("blue".to_string()),
+                )],
+            },
+            specificity: Specificity::new(0, 1, 0),
+            origin: Origin::Author,
+            source_order: 0,
+            layer: Some(1),
+        },
+        ApplicableRule {
+            rule: StyleRule {
+                declarations: vec![(PropertyId::Color, PropertyValue::Keyword("red".to_string()))],
+            },
+            specificity: Specificity::new(0, 1, 0),
+            origin: Origin::Author,
+            source_order: 1,
+            layer: Some(0),
+        },
+    ];
+
+    let result = resolver.resolve(&applicable_rules);
+
+    assert_eq!(
+        result.properties.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("blue".to_string()))
+    );
+}
+
+#[test]
+fn test_cascade_unlayered_beats_layered() {
+    let resolver = CascadeResolver::new();
+
+    let applicable_rules = vec![
+        ApplicableRule {
+            rule: StyleRule {
+                declarations: vec![(PropertyId::Color, PropertyValue::Keyword("red".to_string()))],
+            },
+            specificity: Specificity::new(0, 1, 0),
+            origin: Origin::Author,
+            source_order: 0,
+            layer: None,
+        },
+        ApplicableRule {
+            rule: StyleRule {
+                declarations: vec![(
+                    PropertyId::Color,
+                    PropertyValue::Keyword("blue".to_string()),
+                )],
+            },
+            specificity: Specificity::new(1, 0, 0),
+            origin: Origin::Author,
+            source_order: 1,
+            layer: Some(0),
+        },
+    ];
+
+    let result = resolver.resolve(&applicable_rules);
+
+    assert_eq!(
+        result.properties.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+}
+
+fn layered_color(color: &str, specificity: Specificity, layer: Option<u32>) -> ApplicableRule {
+    ApplicableRule {
+        rule: StyleRule {
+            declarations: vec![(PropertyId::Color, PropertyValue::Keyword(color.to_string()))],
+        },
+        specificity,
+        origin: Origin::Author,
+        source_order: 0,
+        layer,
+    }
+}
+
+#[test]
+fn test_cascade_revert_layer_rolls_back_to_earlier_layer() {
+    let resolver = CascadeResolver::new();
+
+    // The red declaration shares the reverting layer, so it is skipped too
+    let applicable_rules = vec![
+        layered_color("blue", Specificity::new(0, 1, 0), Some(0)),
+        layered_color("red", Specificity::new(0, 0, 1), Some(1)),
+        layered_color("revert-layer", Specificity::new(1, 0, 0), Some(1)),
+    ];
+
+    let result = resolver.resolve(&applicable_rules);
+
+    assert_eq!(
+        result.properties.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("blue".to_string()))
+    );
+}
+
+#[test]
+fn test_cascade_revert_layer_from_unlayered_rolls_back_to_layers() {
+    let resolver = CascadeResolver::new();
+
+    let applicable_rules = vec![
+        layered_color("blue", Specificity::new(0, 1, 0), Some(0)),
+        layered_color("green", Specificity::new(0, 1, 0), Some(1)),
+        layered_color("revert-layer", Specificity::new(0, 1, 0), None),
+    ];
+
+    let result = resolver.resolve(&applicable_rules);
+
+    assert_eq!(
+        result.properties.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("green".to_string()))
+    );
+}
+
+#[test]
+fn test_cascade_revert_layer_without_earlier_layer_is_kept() {
+    let resolver = CascadeResolver::new();
+
+    let applicable_rules = vec![
+        layered_color("red", Specificity::new(0, 0, 1), Some(0)),
+        layered_color("revert-layer", Specificity::new(0, 1, 0), Some(0)),
+    ];
+
+    let result = resolver.resolve(&applicable_rules);
+
+    assert_eq!(
+        result.properties.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("revert-layer".to_string()))
+    );
+}
@@ -0,0 +1,152 @@
+use css_cascade::{
+    ApplicableRule, CascadeResolver, ComputedValues, Origin, PropertyId, PropertyValue,
+    Specificity, StyleRule,
+};
+
+fn author_rule(declarations: Vec<(PropertyId, PropertyValue)>) -> ApplicableRule {
+    ApplicableRule {
+        rule: StyleRule { declarations },
+        specificity: Specificity::new(0, 1, 0),
+        origin: Origin::Author,
+        source_order: 0,
+        layer: None,
+    }
+}
+
+fn red_parent() -> ComputedValues {
+    let mut parent = ComputedValues::new();
+    parent.set(PropertyId::Color, PropertyValue::Keyword("red".to_string()));
+    parent.set(PropertyId::Width, PropertyValue::Length(200.0, "px".to_string()));
+    parent
+}
+
+#[test]
+fn test_color_inherit_pulls_parent_color() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Color,
+        PropertyValue::Inherit,
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+}
+
+#[test]
+fn test_color_initial_resets_to_default() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Color,
+        PropertyValue::Initial,
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyId::Color.initial_value())
+    );
+}
+
+#[test]
+fn test_keyword_spelling_is_recognized() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Color,
+        PropertyValue::Keyword("INHERIT".to_string()),
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+}
+
+#[test]
+fn test_unset_inherits_inherited_and_resets_others() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![
+        (PropertyId::Color, PropertyValue::Unset),
+        (PropertyId::Width, PropertyValue::Unset),
+    ])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+    assert_eq!(
+        computed.get(&PropertyId::Width),
+        Some(&PropertyValue::Keyword("auto".to_string()))
+    );
+}
+
+#[test]
+fn test_inherit_without_parent_uses_initial() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Color,
+        PropertyValue::Inherit,
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, None);
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyId::Color.initial_value())
+    );
+}
+
+#[test]
+fn test_important_inherit_is_resolved() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Color,
+        PropertyValue::Important(Box::new(PropertyValue::Inherit)),
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+}
+
+#[test]
+fn test_regular_values_pass_through() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![(
+        PropertyId::Display,
+        PropertyValue::Keyword("block".to_string()),
+    )])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, None);
+    assert_eq!(
+        computed.get(&PropertyId::Display),
+        Some(&PropertyValue::Keyword("block".to_string()))
+    );
+}
+
+#[test]
+fn test_revert_layer_without_earlier_layer_behaves_as_unset() {
+    let resolver = CascadeResolver::new();
+    let result = resolver.resolve(&[author_rule(vec![
+        (
+            PropertyId::Color,
+            PropertyValue::Keyword("revert-layer".to_string()),
+        ),
+        (PropertyId::Width, PropertyValue::RevertLayer),
+    ])]);
+
+    let computed = CascadeResolver::resolve_css_wide_keywords(&result, Some(&red_parent()));
+    assert_eq!(
+        computed.get(&PropertyId::Color),
+        Some(&PropertyValue::Keyword("red".to_string()))
+    );
+    assert_eq!(
+        computed.get(&PropertyId::Width),
+        Some(&PropertyValue::Keyword("auto".to_string()))
+    );
+}
@@ -0,0 +1,85 @@
+use css_cascade::{
+    initial_value, initial_value_of, InitialValue, PropertyId, PropertyValue, INITIAL_VALUES,
+};
+use css_types::{Color, ColorValue, Length, LengthUnit};
+
+fn keyword(value: &str) -> PropertyValue {
+    PropertyValue::Keyword(value.to_string())
+}
+
+#[test]
+fn test_initial_values_spot_check() {
+    assert_eq!(initial_value("display"), Some(keyword("inline")));
+    assert_eq!(initial_value("color"), Some(keyword("rgb(0, 0, 0)")));
+    assert_eq!(initial_value("position"), Some(keyword("static")));
+    assert_eq!(initial_value("width"), Some(keyword("auto")));
+    assert_eq!(
+        initial_value("margin-left"),
+        Some(PropertyValue::Length(0.0, "px".to_string()))
+    );
+    assert_eq!(initial_value("opacity"), Some(PropertyValue::Number(1.0)));
+    assert_eq!(
+        initial_value("font-family"),
+        Some(PropertyValue::FontFamily(vec!["serif".to_string()]))
+    );
+    assert_eq!(
+        initial_value("border"),
+        Some(PropertyValue::Border {
+            width: 3.0,
+            style: "none".to_string(),
+            color: "currentcolor".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_initial_value_lookup_is_case_insensitive() {
+    assert_eq!(
+        initial_value("Background-Color"),
+        Some(keyword("rgba(0, 0, 0, 0)"))
+    );
+    assert_eq!(initial_value("float-ish"), None);
+}
+
+#[test]
+fn test_every_property_id_has_table_entry() {
+    let ids = [
+        PropertyId::Color,
+        PropertyId::FontSize,
+        PropertyId::FontFamily,
+        PropertyId::LineHeight,
+        PropertyId::TextAlign,
+        PropertyId::Margin,
+        PropertyId::Padding,
+        PropertyId::Border,
+        PropertyId::BorderColor,
+        PropertyId::BackgroundColor,
+        PropertyId::Width,
+        PropertyId::Height,
+        PropertyId::Display,
+    ];
+    for id in ids {
+        assert!(INITIAL_VALUES.iter().any(|(name, _)| *name == id.name()));
+        assert_eq!(Some(id.initial_value()), initial_value(id.name()));
+    }
+}
+
+#[test]
+fn test_initial_values_are_typed() {
+    assert_eq!(
+        initial_value_of("color"),
+        InitialValue::Color(ColorValue::Rgba(Color::rgb(0, 0, 0)))
+    );
+    assert_eq!(
+        initial_value_of("font-size"),
+        InitialValue::Length(Length::new(16.0, LengthUnit::Px))
+    );
+    assert_eq!(
+        PropertyId::BorderColor.initial(),
+        InitialValue::Color(ColorValue::CurrentColor)
+    );
+    assert_eq!(
+        PropertyId::FontSize.initial_value(),
+        PropertyValue::Length(16.0, "px".to_string())
+    );
+}
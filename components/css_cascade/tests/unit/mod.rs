@@ -1,3 +1,6 @@
+mod cascade_level_tests;
 mod cascade_tests;
+mod css_wide_keyword_tests;
 mod inheritance_tests;
+mod initial_value_tests;
 mod specificity_tests;
@@ -21,7 +21,7 @@
 //! let calc = parse_calc_expression("calc(100% - 20px)").unwrap();
 //! ```
 
-use css_types::{CssError, Length, LengthUnit};
+use css_types::{parse_finite_f32, split_function, CssError, CssValue, Length, LengthUnit};
 
 // ============================================================================
 // Custom Property Types
@@ -145,14 +145,42 @@ impl CalcValue {
             CalcValue::Length(length) => match length.unit() {
                 LengthUnit::Px => length.value(),
                 LengthUnit::Em => length.value() * context.font_size,
-                LengthUnit::Rem => length.value() * context.font_size, // Simplified
+                LengthUnit::Rem => length.value() * context.root_font_size,
                 LengthUnit::Percent => length.value() * context.viewport_width / 100.0,
                 LengthUnit::Vw => length.value() * context.viewport_width / 100.0,
-                LengthUnit::Vh => length.value() * context.viewport_width / 100.0, // Simplified
+                LengthUnit::Vh => length.value() * context.viewport_height / 100.0,
+                LengthUnit::Vmin => {
+                    length.value() * context.viewport_width.min(context.viewport_height) / 100.0
+                }
+                LengthUnit::Vmax => {
+                    length.value() * context.viewport_width.max(context.viewport_height) / 100.0
+                }
+                unit @ (LengthUnit::Pt | LengthUnit::Cm | LengthUnit::Mm | LengthUnit::In) => {
+                    length.value() * unit.px_per_unit().unwrap_or(1.0)
+                }
             },
             CalcValue::Percentage(pct) => pct * context.viewport_width / 100.0,
         }
     }
+
+    /// Serialize the value as it would appear inside a calc() expression
+    ///
+    /// # Examples
+    /// ```
+    /// use css_custom_properties::CalcValue;
+    /// use css_types::{Length, LengthUnit};
+    ///
+    /// assert_eq!(CalcValue::Percentage(50.0).to_css_string(), "50%");
+    /// assert_eq!(CalcValue::Length(Length::new(1.5, LengthUnit::Rem)).to_css_string(), "1.5rem");
+    /// assert_eq!(CalcValue::Number(2.0).to_css_string(), "2");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        match self {
+            CalcValue::Number(n) => n.to_string(),
+            CalcValue::Length(length) => length.serialize(),
+            CalcValue::Percentage(pct) => format!("{}%", pct),
+        }
+    }
 }
 
 /// Calc expression tree
@@ -204,6 +232,53 @@ impl CalcExpression {
             }
         }
     }
+
+    /// Serialize the expression back to `calc(...)` notation
+    ///
+    /// Parentheses are only emitted where precedence requires them: around a
+    /// sum or difference that is the right-hand side of a subtraction, or the
+    /// operand of a multiplication or division. The numeric factor of a
+    /// multiplication is always written on the right.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_custom_properties::parse_calc_expression;
+    ///
+    /// let expr = parse_calc_expression("calc(100% - 20px)").unwrap();
+    /// assert_eq!(expr.to_css_string(), "calc(100% - 20px)");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        format!("calc({})", self.serialize_operand())
+    }
+
+    /// Serialize the expression without the surrounding `calc()`
+    fn serialize_operand(&self) -> String {
+        match self {
+            CalcExpression::Value(val) => val.to_css_string(),
+            CalcExpression::Add(left, right) => {
+                format!("{} + {}", left.serialize_operand(), right.serialize_operand())
+            }
+            CalcExpression::Subtract(left, right) => {
+                format!("{} - {}", left.serialize_operand(), right.serialize_grouped())
+            }
+            CalcExpression::Multiply(expr, multiplier) => {
+                format!("{} * {}", expr.serialize_grouped(), multiplier)
+            }
+            CalcExpression::Divide(expr, divisor) => {
+                format!("{} / {}", expr.serialize_grouped(), divisor)
+            }
+        }
+    }
+
+    /// Serialize the expression, wrapping sums and differences in parentheses
+    fn serialize_grouped(&self) -> String {
+        match self {
+            CalcExpression::Add(..) | CalcExpression::Subtract(..) => {
+                format!("({})", self.serialize_operand())
+            }
+            _ => self.serialize_operand(),
+        }
+    }
 }
 
 /// Context for evaluating calc() expressions
@@ -211,25 +286,57 @@ impl CalcExpression {
 pub struct CalcContext {
     /// Viewport width in pixels
     pub viewport_width: f32,
-    /// Font size in pixels
+    /// Viewport height in pixels (used for `vh`)
+    pub viewport_height: f32,
+    /// Font size in pixels (used for `em`)
     pub font_size: f32,
+    /// Root element font size in pixels (used for `rem`)
+    pub root_font_size: f32,
 }
 
 impl CalcContext {
     /// Create a new calc context
     ///
+    /// The viewport height defaults to the viewport width and the root font
+    /// size defaults to `font_size`, so `vh` and `rem` evaluate exactly as
+    /// `vw` and `em` do. Use [`CalcContext::with_dimensions`] when those
+    /// values differ.
+    ///
     /// # Examples
     /// ```
     /// use css_custom_properties::CalcContext;
     ///
     /// let context = CalcContext::new(1920.0, 16.0);
     /// assert_eq!(context.viewport_width, 1920.0);
+    /// assert_eq!(context.viewport_height, 1920.0);
     /// assert_eq!(context.font_size, 16.0);
+    /// assert_eq!(context.root_font_size, 16.0);
     /// ```
     pub fn new(viewport_width: f32, font_size: f32) -> Self {
+        Self::with_dimensions(viewport_width, viewport_width, font_size, font_size)
+    }
+
+    /// Create a calc context with every resolution input specified
+    ///
+    /// # Examples
+    /// ```
+    /// use css_custom_properties::CalcContext;
+    ///
+    /// let context = CalcContext::with_dimensions(1920.0, 1080.0, 20.0, 16.0);
+    /// assert_eq!(context.viewport_height, 1080.0);
+    /// assert_eq!(context.root_font_size, 16.0);
+    /// ```
+    pub fn with_dimensions(
+        viewport_width: f32,
+        viewport_height: f32,
+        font_size: f32,
+        root_font_size: f32,
+    ) -> Self {
         Self {
             viewport_width,
+            viewport_height,
             font_size,
+            root_font_size,
         }
     }
 }
@@ -292,6 +399,19 @@ pub fn parse_custom_property(input: &str) -> Result<CustomProperty, CssError> {
     Ok(CustomProperty::new(name, value, true))
 }
 
+/// Default limit on parenthesis nesting inside calc() and var()
+///
+/// Deeper input is rejected before parsing so that pathological values
+/// cannot exhaust the stack.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 32;
+
+/// Limit on the number of operators in a single calc() expression
+///
+/// Each operator adds a level to the parsed [`CalcExpression`] tree, which is
+/// evaluated, serialized and dropped recursively, so long flat chains such as
+/// `calc(1px + 1px + ...)` are rejected rather than built.
+pub const MAX_CALC_OPERATIONS: usize = 1024;
+
 /// Parse a var() reference (e.g., "var(--color, red)")
 ///
 /// # Examples
@@ -304,26 +424,30 @@ pub fn parse_custom_property(input: &str) -> Result<CustomProperty, CssError> {
 /// ```
 ///
 /// # Errors
-/// Returns an error if the input is not a valid var() reference
+/// Returns an error if the input is not a valid var() reference, or if its
+/// fallback nests deeper than [`DEFAULT_MAX_NESTING_DEPTH`]
 pub fn parse_var_reference(input: &str) -> Result<VariableReference, CssError> {
-    let input = input.trim();
-
-    // Check for var( prefix
-    if !input.starts_with("var(") {
-        return Err(CssError::ParseError(
-            "Variable reference must start with var(".to_string(),
-        ));
-    }
-
-    // Check for closing paren
-    if !input.ends_with(')') {
-        return Err(CssError::ParseError(
-            "Variable reference must end with )".to_string(),
-        ));
-    }
+    parse_var_reference_with_max_depth(input, DEFAULT_MAX_NESTING_DEPTH)
+}
 
-    // Extract content between var( and )
-    let content = &input[4..input.len() - 1];
+/// Parse a var() reference, limiting nesting in its fallback to `max_depth`
+///
+/// # Examples
+/// ```
+/// use css_custom_properties::parse_var_reference_with_max_depth;
+///
+/// assert!(parse_var_reference_with_max_depth("var(--a, var(--b))", 1).is_ok());
+/// assert!(parse_var_reference_with_max_depth("var(--a, var(--b, var(--c)))", 1).is_err());
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is not a valid var()
+/// reference or its fallback nests deeper than `max_depth`
+pub fn parse_var_reference_with_max_depth(
+    input: &str,
+    max_depth: usize,
+) -> Result<VariableReference, CssError> {
+    let content = expect_function(input, "var")?;
 
     // Split by comma to separate name and fallback
     let parts: Vec<&str> = content.splitn(2, ',').collect();
@@ -332,12 +456,28 @@ pub fn parse_var_reference(input: &str) -> Result<VariableReference, CssError> {
 
     if parts.len() > 1 {
         let fallback = parts[1].trim();
+        check_nesting_depth(fallback, max_depth, "var()")?;
         Ok(VariableReference::with_fallback(name, fallback))
     } else {
         Ok(VariableReference::new(name))
     }
 }
 
+/// Get the arguments of a call to the function `name`
+///
+/// Malformed calls, including names containing whitespace, report the
+/// error from [`split_function`].
+fn expect_function<'a>(input: &'a str, name: &str) -> Result<&'a str, CssError> {
+    let (found, arguments) = split_function(input)?;
+    if !found.eq_ignore_ascii_case(name) {
+        return Err(CssError::ParseError(format!(
+            "Expected {}(), found {}()",
+            name, found
+        )));
+    }
+    Ok(arguments)
+}
+
 /// Parse a calc() expression (e.g., "calc(100% - 20px)")
 ///
 /// # Examples
@@ -348,102 +488,218 @@ pub fn parse_var_reference(input: &str) -> Result<VariableReference, CssError> {
 /// ```
 ///
 /// # Errors
-/// Returns an error if the input is not a valid calc() expression
+/// Returns an error if the input is not a valid calc() expression, or if it
+/// nests parentheses deeper than [`DEFAULT_MAX_NESTING_DEPTH`]
 pub fn parse_calc_expression(input: &str) -> Result<CalcExpression, CssError> {
-    let input = input.trim();
+    parse_calc_expression_with_max_depth(input, DEFAULT_MAX_NESTING_DEPTH)
+}
 
-    // Check for calc( prefix
-    if !input.starts_with("calc(") {
-        return Err(CssError::ParseError(
-            "Calc expression must start with calc(".to_string(),
-        ));
-    }
+/// Parse a calc() expression, limiting parenthesis nesting to `max_depth`
+///
+/// # Examples
+/// ```
+/// use css_custom_properties::parse_calc_expression_with_max_depth;
+///
+/// assert!(parse_calc_expression_with_max_depth("calc((1px + 2px) * 2)", 1).is_ok());
+/// assert!(parse_calc_expression_with_max_depth("calc(((1px)))", 1).is_err());
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is not a valid calc()
+/// expression or nests deeper than `max_depth`
+pub fn parse_calc_expression_with_max_depth(
+    input: &str,
+    max_depth: usize,
+) -> Result<CalcExpression, CssError> {
+    let content = expect_function(input, "calc")?;
+    check_nesting_depth(content, max_depth, "calc()")?;
+
+    parse_calc_content(content, &mut 0)
+}
 
-    // Check for closing paren
-    if !input.ends_with(')') {
-        return Err(CssError::ParseError(
-            "Calc expression must end with )".to_string(),
-        ));
+/// Reject content whose parentheses nest deeper than `max_depth`
+fn check_nesting_depth(content: &str, max_depth: usize, function: &str) -> Result<(), CssError> {
+    let mut depth = 0usize;
+    for ch in content.chars() {
+        match ch {
+            '(' => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(CssError::ParseError(format!(
+                        "{} nesting exceeds the maximum depth of {}",
+                        function, max_depth
+                    )));
+                }
+            }
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
     }
-
-    // Extract content between calc( and )
-    let content = &input[5..input.len() - 1].trim();
-
-    parse_calc_content(content)
+    Ok(())
 }
 
 /// Parse the content inside calc()
-fn parse_calc_content(content: &str) -> Result<CalcExpression, CssError> {
-    let content = content.trim();
-
-    // Handle nested parentheses
-    if content.starts_with('(') && content.ends_with(')') {
-        // Remove outer parens and parse recursively
-        let inner = &content[1..content.len() - 1];
-        return parse_calc_content(inner);
+///
+/// Chains of operators are folded left to right rather than parsed
+/// recursively, so only parenthesized groups (already bounded by the nesting
+/// check) recurse. `operations` counts the operators parsed so far and is
+/// capped at [`MAX_CALC_OPERATIONS`].
+fn parse_calc_content(content: &str, operations: &mut usize) -> Result<CalcExpression, CssError> {
+    let mut content = content.trim();
+
+    // Strip parentheses, but only when the outer pair encloses the whole
+    // content (so "(a) + (b)" is not mistaken for a single group)
+    while is_wrapped_in_parens(content) {
+        content = content[1..content.len() - 1].trim();
     }
 
-    // Try to find operators (division has highest precedence, then multiplication, then +/-)
-    // For simplicity, we'll parse from left to right
+    // Sums and differences have the lowest precedence, so the content is
+    // split into terms first and "a - b - c" associates to the left
+    let terms = split_operators(content, &['+', '-']);
+    let mut expr = parse_calc_product(terms[0].1, operations)?;
+    for &(operator, term) in &terms[1..] {
+        count_operation(operations)?;
+        let right = parse_calc_product(term, operations)?;
+        expr = if operator == '+' {
+            CalcExpression::Add(Box::new(expr), Box::new(right))
+        } else {
+            CalcExpression::Subtract(Box::new(expr), Box::new(right))
+        };
+    }
+    Ok(expr)
+}
 
-    // Look for + or - (lowest precedence)
-    if let Some(pos) = find_operator(content, &['+', '-']) {
-        let left = parse_calc_content(content[..pos].trim())?;
-        let right = parse_calc_content(content[pos + 1..].trim())?;
+/// Parse a term of a calc() sum: factors joined by `*` and `/`
+///
+/// One side of each multiplication must be a plain number, and so must the
+/// right side of each division. A number on the left is only accepted for
+/// the first factor.
+fn parse_calc_product(content: &str, operations: &mut usize) -> Result<CalcExpression, CssError> {
+    let factors = split_operators(content, &['*', '/']);
+    let (_, first) = factors[0];
+    if factors.len() == 1 {
+        return parse_calc_factor(first, operations);
+    }
 
-        return Ok(if content.chars().nth(pos) == Some('+') {
-            CalcExpression::Add(Box::new(left), Box::new(right))
+    let mut expr: Option<CalcExpression> = None;
+    for &(operator, factor) in &factors[1..] {
+        count_operation(operations)?;
+        let (operand, num) = if let Some(num) = parse_calc_number(factor)? {
+            let operand = match expr.take() {
+                Some(operand) => operand,
+                None => parse_calc_factor(first, operations)?,
+            };
+            (operand, num)
+        } else if operator == '/' {
+            // Dividing by a length or percentage has no pixel value
+            return Err(CssError::ParseError(format!(
+                "Invalid calc divisor: {}",
+                factor
+            )));
+        } else if let (None, Some(num)) = (&expr, parse_calc_number(first)?) {
+            (parse_calc_factor(factor, operations)?, num)
         } else {
-            CalcExpression::Subtract(Box::new(left), Box::new(right))
+            return Err(CssError::ParseError(format!(
+                "Invalid calc value: {}",
+                content.trim()
+            )));
+        };
+        expr = Some(if operator == '*' {
+            CalcExpression::Multiply(Box::new(operand), num)
+        } else {
+            CalcExpression::Divide(Box::new(operand), num)
         });
     }
+    Ok(expr.expect("a product has at least one operator"))
+}
 
-    // Look for * or /
-    if let Some(pos) = find_operator(content, &['*', '/']) {
-        let left_str = content[..pos].trim();
-        let right_str = content[pos + 1..].trim();
+/// Parse a single operand: a parenthesized group or a simple value
+fn parse_calc_factor(content: &str, operations: &mut usize) -> Result<CalcExpression, CssError> {
+    let content = content.trim();
+    if is_wrapped_in_parens(content) {
+        return parse_calc_content(content, operations);
+    }
+    parse_calc_value(content).map(CalcExpression::Value)
+}
 
-        // For * and /, one side should be a value and the other a number
-        let is_multiply = content.chars().nth(pos) == Some('*');
+/// Count one more operator, rejecting expressions with too many
+fn count_operation(operations: &mut usize) -> Result<(), CssError> {
+    *operations += 1;
+    if *operations > MAX_CALC_OPERATIONS {
+        return Err(CssError::ParseError(format!(
+            "calc() has more than {} operators",
+            MAX_CALC_OPERATIONS
+        )));
+    }
+    Ok(())
+}
 
-        // Try to parse right side as number first
-        if let Ok(num) = right_str.parse::<f32>() {
-            let expr = parse_calc_content(left_str)?;
-            return Ok(if is_multiply {
-                CalcExpression::Multiply(Box::new(expr), num)
-            } else {
-                CalcExpression::Divide(Box::new(expr), num)
-            });
+/// Split the content at its top-level operators (not inside parentheses)
+///
+/// Each part is paired with the operator before it; the first part is paired
+/// with the first of `operators`. A `+` or `-` that starts the content or
+/// directly follows another operator is a sign, not an operator, and is not
+/// split on.
+fn split_operators<'a>(content: &'a str, operators: &[char]) -> Vec<(char, &'a str)> {
+    let mut parts = Vec::new();
+    let mut paren_depth = 0;
+    let mut previous: Option<char> = None;
+    let mut operator = operators[0];
+    let mut start = 0;
+    for (i, ch) in content.char_indices() {
+        match ch {
+            '(' => paren_depth += 1,
+            ')' => paren_depth -= 1,
+            _ if paren_depth == 0 && operators.contains(&ch) => {
+                let is_sign = matches!(previous, None | Some('+' | '-' | '*' | '/' | '('));
+                if !is_sign {
+                    parts.push((operator, content[start..i].trim()));
+                    operator = ch;
+                    start = i + ch.len_utf8();
+                }
+            }
+            _ => {}
         }
-
-        // Try to parse left side as number
-        if let Ok(num) = left_str.parse::<f32>() {
-            let expr = parse_calc_content(right_str)?;
-            return Ok(if is_multiply {
-                CalcExpression::Multiply(Box::new(expr), num)
-            } else {
-                // For division, we need to flip this
-                CalcExpression::Divide(Box::new(expr), num)
-            });
+        if !ch.is_whitespace() {
+            previous = Some(ch);
         }
     }
-
-    // Try to parse as a simple value
-    parse_calc_value(content).map(CalcExpression::Value)
+    parts.push((operator, content[start..].trim()));
+    parts
 }
 
-/// Find the position of an operator at the top level (not inside parentheses)
-fn find_operator(content: &str, operators: &[char]) -> Option<usize> {
+/// Check whether the content is a single parenthesized group
+fn is_wrapped_in_parens(content: &str) -> bool {
+    if !content.starts_with('(') || !content.ends_with(')') {
+        return false;
+    }
     let mut paren_depth = 0;
-    for (i, ch) in content.chars().enumerate() {
+    for (i, ch) in content.char_indices() {
         match ch {
             '(' => paren_depth += 1,
-            ')' => paren_depth -= 1,
-            _ if paren_depth == 0 && operators.contains(&ch) => return Some(i),
+            ')' => {
+                paren_depth -= 1;
+                // The opening paren closed before the end of the content
+                if paren_depth == 0 && i != content.len() - 1 {
+                    return false;
+                }
+            }
             _ => {}
         }
     }
-    None
+    true
+}
+
+/// Parse a plain number inside calc()
+///
+/// Returns `Ok(None)` when the content is not a number, and an error when it
+/// is a non-finite one.
+fn parse_calc_number(content: &str) -> Result<Option<f32>, CssError> {
+    match parse_finite_f32(content) {
+        Ok(num) => Ok(Some(num)),
+        Err(e @ CssError::InvalidValue(_)) => Err(e),
+        Err(_) => Ok(None),
+    }
 }
 
 /// Parse a calc value (number, length, or percentage)
@@ -452,19 +708,22 @@ fn parse_calc_value(content: &str) -> Result<CalcValue, CssError> {
 
     // Try to parse as percentage
     if let Some(num_str) = content.strip_suffix('%') {
-        let value = num_str
-            .parse::<f32>()
-            .map_err(|_| CssError::ParseError("Invalid percentage value".to_string()))?;
+        let value = parse_finite_f32(num_str).map_err(|e| match e {
+            CssError::InvalidValue(_) => e,
+            _ => CssError::ParseError("Invalid percentage value".to_string()),
+        })?;
         return Ok(CalcValue::Percentage(value));
     }
 
     // Try to parse as length
-    if let Ok(length) = css_types::CssValue::parse(content) {
-        return Ok(CalcValue::Length(length));
+    match Length::parse(content) {
+        Ok(length) => return Ok(CalcValue::Length(length)),
+        Err(e @ CssError::InvalidValue(_)) => return Err(e),
+        Err(_) => {}
     }
 
     // Try to parse as plain number
-    if let Ok(num) = content.parse::<f32>() {
+    if let Some(num) = parse_calc_number(content)? {
         return Ok(CalcValue::Number(num));
     }
 
@@ -1,5 +1,5 @@
 use css_custom_properties::*;
-use css_types::{Length, LengthUnit};
+use css_types::{CssError, Length, LengthUnit};
 
 #[test]
 fn test_parse_calc_simple_addition() {
@@ -13,6 +13,21 @@ fn test_parse_calc_simple_subtraction() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_parse_calc_space_before_paren() {
+    assert!(parse_calc_expression("calc (100% - 20px)").is_ok());
+    assert!(parse_calc_expression("calc(10px) + calc(20px)").is_err());
+}
+
+#[test]
+fn test_parse_calc_rejects_whitespace_in_name() {
+    match parse_calc_expression("cal c(100% - 20px)") {
+        Err(CssError::ParseError(message)) => assert!(message.contains("whitespace")),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+    assert!(parse_calc_expression("var(--width)").is_err());
+}
+
 #[test]
 fn test_parse_calc_multiplication() {
     let result = parse_calc_expression("calc(10px * 2)");
@@ -153,3 +168,183 @@ fn test_evaluate_calc_percentage() {
     let result = expr.evaluate(&context);
     assert!((result - 60.0).abs() < 0.01);
 }
+
+#[test]
+fn test_evaluate_calc_em_vs_rem() {
+    // font_size=20, root_font_size=16: 2em = 40px, 2rem = 32px
+    let context = CalcContext::with_dimensions(1000.0, 500.0, 20.0, 16.0);
+    let em = CalcExpression::Value(CalcValue::Length(Length::new(2.0, LengthUnit::Em)));
+    let rem = CalcExpression::Value(CalcValue::Length(Length::new(2.0, LengthUnit::Rem)));
+
+    assert!((em.evaluate(&context) - 40.0).abs() < 0.01);
+    assert!((rem.evaluate(&context) - 32.0).abs() < 0.01);
+}
+
+#[test]
+fn test_evaluate_calc_vw_vs_vh() {
+    // viewport 1000x500: 10vw = 100px, 10vh = 50px
+    let context = CalcContext::with_dimensions(1000.0, 500.0, 20.0, 16.0);
+    let vw = CalcExpression::Value(CalcValue::Length(Length::new(10.0, LengthUnit::Vw)));
+    let vh = CalcExpression::Value(CalcValue::Length(Length::new(10.0, LengthUnit::Vh)));
+
+    assert!((vw.evaluate(&context) - 100.0).abs() < 0.01);
+    assert!((vh.evaluate(&context) - 50.0).abs() < 0.01);
+}
+
+#[test]
+fn test_calc_context_new_defaults() {
+    let context = CalcContext::new(800.0, 18.0);
+    assert_eq!(context.viewport_height, 800.0);
+    assert_eq!(context.root_font_size, 18.0);
+}
+
+#[test]
+fn test_calc_to_css_string_subtraction() {
+    let expr = parse_calc_expression("calc(100% - 20px)").unwrap();
+    assert_eq!(expr.to_css_string(), "calc(100% - 20px)");
+}
+
+#[test]
+fn test_calc_to_css_string_grouped_multiplication_round_trip() {
+    let expr = parse_calc_expression("calc(2 * (10px + 5px))").unwrap();
+    let css = expr.to_css_string();
+    assert_eq!(css, "calc((10px + 5px) * 2)");
+    assert_eq!(parse_calc_expression(&css).unwrap(), expr);
+}
+
+#[test]
+fn test_calc_to_css_string_left_associative_chain() {
+    // No parentheses are needed for a left-to-right chain
+    let expr = parse_calc_expression("calc(100% - 20px - 1em + 2vh)").unwrap();
+    let css = expr.to_css_string();
+    assert_eq!(css, "calc(100% - 20px - 1em + 2vh)");
+    assert_eq!(parse_calc_expression(&css).unwrap(), expr);
+}
+
+#[test]
+fn test_calc_to_css_string_grouped_subtrahend() {
+    let expr = parse_calc_expression("calc(100% - (20px + 1rem))").unwrap();
+    let css = expr.to_css_string();
+    assert_eq!(css, "calc(100% - (20px + 1rem))");
+    assert_eq!(parse_calc_expression(&css).unwrap(), expr);
+}
+
+#[test]
+fn test_calc_to_css_string_division_round_trip() {
+    let expr = parse_calc_expression("calc((100% - 20px) / 2)").unwrap();
+    let css = expr.to_css_string();
+    assert_eq!(css, "calc((100% - 20px) / 2)");
+    assert_eq!(parse_calc_expression(&css).unwrap(), expr);
+}
+
+#[test]
+fn test_parse_calc_rejects_non_finite_numbers() {
+    for input in [
+        "calc(NaN)",
+        "calc(inf)",
+        "calc(1e40)",
+        "calc(10px * NaN)",
+        "calc(inf * 10px)",
+        "calc(10px / 1e40)",
+        "calc(NaN% + 10px)",
+    ] {
+        assert!(
+            matches!(
+                parse_calc_expression(input),
+                Err(css_types::CssError::InvalidValue(_))
+            ),
+            "{} should be rejected",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_calc_rejects_overflowing_length() {
+    let input = format!("calc(1{}px + 10px)", "0".repeat(40));
+    assert!(matches!(
+        parse_calc_expression(&input),
+        Err(css_types::CssError::InvalidValue(_))
+    ));
+    assert!(parse_calc_expression("calc(1e40px + 10px)").is_err());
+}
+
+fn nested_calc(depth: usize) -> String {
+    format!("calc({}10px{})", "(".repeat(depth), ")".repeat(depth))
+}
+
+#[test]
+fn test_parse_calc_nesting_at_limit_succeeds() {
+    let input = nested_calc(DEFAULT_MAX_NESTING_DEPTH);
+    let expr = parse_calc_expression(&input).unwrap();
+    assert_eq!(
+        expr,
+        CalcExpression::Value(CalcValue::Length(Length::new(10.0, LengthUnit::Px)))
+    );
+}
+
+#[test]
+fn test_parse_calc_nesting_beyond_limit_errors() {
+    let input = nested_calc(DEFAULT_MAX_NESTING_DEPTH + 1);
+    assert!(matches!(
+        parse_calc_expression(&input),
+        Err(CssError::ParseError(_))
+    ));
+
+    // Far beyond the limit fails without recursing
+    assert!(parse_calc_expression(&nested_calc(100_000)).is_err());
+}
+
+#[test]
+fn test_parse_calc_custom_max_depth() {
+    assert!(parse_calc_expression_with_max_depth(&nested_calc(4), 4).is_ok());
+    assert!(parse_calc_expression_with_max_depth(&nested_calc(5), 4).is_err());
+    assert!(parse_calc_expression_with_max_depth("calc(10px + 2px)", 0).is_ok());
+}
+
+#[test]
+fn test_parse_calc_long_operator_chain() {
+    // Chains fold left to right: ((1px + 1px) + 1px) + ...
+    let terms = vec!["1px"; MAX_CALC_OPERATIONS + 1].join(" + ");
+    let expr = parse_calc_expression(&format!("calc({})", terms)).unwrap();
+    let context = CalcContext::new(100.0, 16.0);
+    assert!((expr.evaluate(&context) - (MAX_CALC_OPERATIONS + 1) as f32).abs() < 0.01);
+
+    let mixed = parse_calc_expression("calc(10px - 2px - 3px * 2 / 3)").unwrap();
+    assert!((mixed.evaluate(&context) - 6.0).abs() < 0.01);
+}
+
+#[test]
+fn test_parse_calc_operator_chain_beyond_limit_errors() {
+    let terms = vec!["1px"; MAX_CALC_OPERATIONS + 2].join(" + ");
+    assert!(matches!(
+        parse_calc_expression(&format!("calc({})", terms)),
+        Err(CssError::ParseError(_))
+    ));
+
+    // A very long flat chain fails without overflowing the stack
+    let terms = vec!["1px"; 100_000].join(" + ");
+    assert!(parse_calc_expression(&format!("calc({})", terms)).is_err());
+    let factors = vec!["2"; 100_000].join(" * ");
+    assert!(parse_calc_expression(&format!("calc(1px * {})", factors)).is_err());
+}
+
+#[test]
+fn test_parse_calc_number_on_left() {
+    // A number may lead a multiplication...
+    let expr = parse_calc_expression("calc(2 * 10px)").unwrap();
+    assert_eq!(expr.to_css_string(), "calc(10px * 2)");
+
+    // ...but dividing by a length or percentage is invalid
+    for input in [
+        "calc(2 / 10px)",
+        "calc(2 / 50%)",
+        "calc(1px + 2 / (1px + 2px))",
+    ] {
+        assert!(
+            matches!(parse_calc_expression(input), Err(CssError::ParseError(_))),
+            "{} should be rejected",
+            input
+        );
+    }
+}
@@ -27,6 +27,13 @@ fn test_parse_var_reference_with_whitespace() {
     assert_eq!(var_ref.fallback(), Some("20px"));
 }
 
+#[test]
+fn test_parse_var_reference_space_before_paren() {
+    let var_ref = parse_var_reference("var (--margin, 20px)").unwrap();
+    assert_eq!(var_ref.name(), "--margin");
+    assert_eq!(var_ref.fallback(), Some("20px"));
+}
+
 #[test]
 fn test_parse_var_reference_nested_fallback() {
     // Fallback can contain another var()
@@ -50,6 +57,10 @@ fn test_parse_var_reference_invalid_syntax() {
     // Missing closing paren
     let result = parse_var_reference("var(--color");
     assert!(result.is_err());
+
+    // Whitespace inside the function name
+    let result = parse_var_reference("v ar(--color)");
+    assert!(result.is_err());
 }
 
 #[test]
@@ -62,3 +73,35 @@ fn test_variable_reference_creation() {
     assert_eq!(var_ref.name(), "--size");
     assert_eq!(var_ref.fallback(), Some("10px"));
 }
+
+fn nested_var(depth: usize) -> String {
+    let mut value = "red".to_string();
+    for level in (0..=depth).rev() {
+        value = format!("var(--v{}, {})", level, value);
+    }
+    value
+}
+
+#[test]
+fn test_parse_var_reference_nesting_limit() {
+    // The outer var() plus DEFAULT_MAX_NESTING_DEPTH nested fallbacks
+    let at_limit = nested_var(DEFAULT_MAX_NESTING_DEPTH);
+    let var_ref = parse_var_reference(&at_limit).unwrap();
+    assert_eq!(var_ref.name(), "--v0");
+
+    let beyond_limit = nested_var(DEFAULT_MAX_NESTING_DEPTH + 1);
+    assert!(matches!(
+        parse_var_reference(&beyond_limit),
+        Err(css_types::CssError::ParseError(_))
+    ));
+
+    // Far beyond the limit fails without recursing
+    assert!(parse_var_reference(&nested_var(100_000)).is_err());
+}
+
+#[test]
+fn test_parse_var_reference_custom_max_depth() {
+    assert!(parse_var_reference_with_max_depth("var(--a, var(--b))", 1).is_ok());
+    assert!(parse_var_reference_with_max_depth("var(--a, var(--b, var(--c)))", 1).is_err());
+    assert!(parse_var_reference_with_max_depth("var(--a, red)", 0).is_ok());
+}
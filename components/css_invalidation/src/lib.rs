@@ -44,6 +44,7 @@ pub struct DomTree {
     parent_map: HashMap<ElementId, ElementId>,
     children_map: HashMap<ElementId, Vec<ElementId>>,
     class_map: HashMap<ElementId, HashSet<String>>,
+    tag_map: HashMap<ElementId, String>,
 }
 
 impl DomTree {
@@ -54,6 +55,7 @@ impl DomTree {
             parent_map: HashMap::new(),
             children_map: HashMap::new(),
             class_map: HashMap::new(),
+            tag_map: HashMap::new(),
         }
     }
 
@@ -66,6 +68,30 @@ impl DomTree {
         tree
     }
 
+    /// Build a DOM tree from a nested element description
+    ///
+    /// Every element in `spec` is added to the tree along with its tag name,
+    /// classes, and parent/child links. Children keep their declaration order.
+    pub fn from_nested(spec: &NestedElement) -> Self {
+        let mut tree = Self::new();
+        tree.insert_nested(spec, None);
+        tree
+    }
+
+    fn insert_nested(&mut self, spec: &NestedElement, parent: Option<ElementId>) {
+        self.add_element(spec.id);
+        self.set_tag(spec.id, &spec.tag);
+        for class in &spec.classes {
+            self.add_class(spec.id, class);
+        }
+        if let Some(parent) = parent {
+            self.set_parent(spec.id, parent);
+        }
+        for child in &spec.children {
+            self.insert_nested(child, Some(spec.id));
+        }
+    }
+
     /// Add an element to the tree
     pub fn add_element(&mut self, element: ElementId) {
         self.elements.insert(element);
@@ -85,6 +111,56 @@ impl DomTree {
             .insert(class.to_string());
     }
 
+    /// Set the tag name of an element
+    pub fn set_tag(&mut self, element: ElementId, tag: &str) {
+        self.tag_map.insert(element, tag.to_string());
+    }
+
+    /// Get the tag name of an element, if one was set
+    pub fn tag_name(&self, element: ElementId) -> Option<&str> {
+        self.tag_map.get(&element).map(String::as_str)
+    }
+
+    /// Get the parent of an element
+    pub fn parent(&self, element: ElementId) -> Option<ElementId> {
+        self.parent_map.get(&element).copied()
+    }
+
+    /// Get the children of an element in document order
+    pub fn children(&self, element: ElementId) -> &[ElementId] {
+        self.children_map
+            .get(&element)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Get the position of an element among its parent's children
+    ///
+    /// Returns `None` for elements without a parent.
+    pub fn index_in_parent(&self, element: ElementId) -> Option<usize> {
+        let parent = self.parent(element)?;
+        self.children(parent)
+            .iter()
+            .position(|child| *child == element)
+    }
+
+    /// Get the sibling immediately before an element
+    pub fn previous_sibling(&self, element: ElementId) -> Option<ElementId> {
+        let index = self.index_in_parent(element)?;
+        let parent = self.parent(element)?;
+        index
+            .checked_sub(1)
+            .map(|previous| self.children(parent)[previous])
+    }
+
+    /// Get all siblings after an element, nearest first
+    pub fn next_siblings(&self, element: ElementId) -> Vec<ElementId> {
+        match (self.parent(element), self.index_in_parent(element)) {
+            (Some(parent), Some(index)) => self.children(parent)[index + 1..].to_vec(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Get all elements in the tree
     pub fn elements(&self) -> &HashSet<ElementId> {
         &self.elements
@@ -137,6 +213,63 @@ impl Default for DomTree {
     }
 }
 
+// ============================================================================
+// NestedElement
+// ============================================================================
+
+/// Nested description of an element and its subtree, used to build a [`DomTree`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedElement {
+    id: ElementId,
+    tag: String,
+    classes: Vec<String>,
+    children: Vec<NestedElement>,
+}
+
+impl NestedElement {
+    /// Create a new element description with no classes or children
+    pub fn new(id: ElementId, tag: &str) -> Self {
+        Self {
+            id,
+            tag: tag.to_string(),
+            classes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Add a class to the element
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.classes.push(class.to_string());
+        self
+    }
+
+    /// Append a child element
+    pub fn with_child(mut self, child: NestedElement) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Get the element ID
+    pub fn id(&self) -> ElementId {
+        self.id
+    }
+
+    /// Get the tag name
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Get the classes
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Get the children
+    pub fn children(&self) -> &[NestedElement] {
+        &self.children
+    }
+}
+
 // ============================================================================
 // InvalidationType
 // ============================================================================
@@ -173,6 +306,8 @@ pub enum InvalidationScope {
     Element(ElementId),
     /// An element and all its descendants
     Subtree(ElementId),
+    /// A change to the named attribute on a specific element
+    Attribute(ElementId, String),
 }
 
 // ============================================================================
@@ -285,6 +420,8 @@ pub struct InvalidationTracker {
     dirty_elements: HashSet<ElementId>,
     dirty_subtrees: HashSet<ElementId>,
     pending_invalidations: Vec<Invalidation>,
+    attribute_dependencies: HashMap<String, HashSet<String>>,
+    deferred_elements: Vec<(ElementId, InvalidationType)>,
 }
 
 impl InvalidationTracker {
@@ -294,6 +431,8 @@ impl InvalidationTracker {
             dirty_elements: HashSet::new(),
             dirty_subtrees: HashSet::new(),
             pending_invalidations: Vec::new(),
+            attribute_dependencies: HashMap::new(),
+            deferred_elements: Vec::new(),
         }
     }
 
@@ -321,6 +460,103 @@ impl InvalidationTracker {
     pub fn add_pending_invalidation(&mut self, invalidation: Invalidation) {
         self.pending_invalidations.push(invalidation);
     }
+
+    /// Register an active selector so attribute changes it depends on
+    /// invalidate the elements it matches
+    pub fn register_selector(&mut self, selector: &str) {
+        for attribute in selector_attribute_dependencies(selector) {
+            self.attribute_dependencies
+                .entry(attribute)
+                .or_default()
+                .insert(selector.to_string());
+        }
+    }
+
+    /// Get the registered selectors that reference an attribute
+    pub fn selectors_depending_on(&self, attribute: &str) -> Vec<&str> {
+        let mut selectors: Vec<&str> = self
+            .attribute_dependencies
+            .get(&attribute.to_ascii_lowercase())
+            .map(|selectors| selectors.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        selectors.sort_unstable();
+        selectors
+    }
+
+    /// Compute the elements affected by an attribute change on `element`
+    ///
+    /// The element itself is always affected. Elements matched by any
+    /// registered selector that references the attribute are affected too.
+    pub fn affected_by_attribute_change(
+        &self,
+        element: ElementId,
+        attribute: &str,
+        dom: &DomTree,
+    ) -> HashSet<ElementId> {
+        let mut result = HashSet::new();
+        result.insert(element);
+        for selector in self.selectors_depending_on(attribute) {
+            result.extend(dom.get_elements_by_selector(selector));
+        }
+        result
+    }
+
+    /// Process pending invalidations, marking at most `max_elements` dirty
+    ///
+    /// Elements deferred by an earlier call are handled first, then the
+    /// elements affected by each pending invalidation in timestamp order.
+    /// Elements beyond the budget are re-queued for the next call, and the
+    /// returned flag is `true` while any remain, so restyling can be spread
+    /// across frames. The returned set lists the invalidations consumed by
+    /// this call and the elements marked dirty.
+    pub fn process_invalidations_budgeted(
+        &mut self,
+        dom: &DomTree,
+        max_elements: usize,
+    ) -> (InvalidationSet, bool) {
+        // Sort invalidations by timestamp
+        self.pending_invalidations.sort_by_key(|inv| inv.timestamp);
+
+        let mut result = InvalidationSet::new();
+        let mut work = std::mem::take(&mut self.deferred_elements);
+
+        let invalidations: Vec<_> = self.pending_invalidations.drain(..).collect();
+        for invalidation in invalidations {
+            // Compute affected elements, consulting selector dependencies for
+            // attribute changes
+            let mut affected: Vec<ElementId> = match invalidation.scope() {
+                InvalidationScope::Attribute(element, attribute) => {
+                    self.affected_by_attribute_change(*element, attribute, dom)
+                }
+                _ => compute_affected_elements(&invalidation, dom),
+            }
+            .into_iter()
+            .collect();
+            // Document order is unknown; id order keeps batches deterministic
+            affected.sort_unstable_by_key(ElementId::value);
+
+            let invalidation_type = *invalidation.invalidation_type();
+            work.extend(
+                affected
+                    .into_iter()
+                    .map(|element| (element, invalidation_type)),
+            );
+            result.add_invalidation(invalidation);
+        }
+
+        for (element, invalidation_type) in work {
+            let counted = result.affected_elements().contains(&element);
+            if !counted && result.affected_elements().len() >= max_elements {
+                self.deferred_elements.push((element, invalidation_type));
+                continue;
+            }
+            self.mark_dirty(element, invalidation_type);
+            result.add_affected_element(element);
+        }
+
+        let more_work = !self.deferred_elements.is_empty();
+        (result, more_work)
+    }
 }
 
 impl Default for InvalidationTracker {
@@ -361,36 +597,7 @@ impl InvalidationEngine for InvalidationTracker {
     }
 
     fn process_invalidations(&mut self, dom: &DomTree) -> InvalidationSet {
-        // Sort invalidations by timestamp
-        self.pending_invalidations.sort_by_key(|inv| inv.timestamp);
-
-        let mut result = InvalidationSet::new();
-        let mut all_affected = HashSet::new();
-
-        // Collect invalidations to process (to avoid borrow checker issues)
-        let invalidations: Vec<_> = self.pending_invalidations.drain(..).collect();
-
-        // Process each invalidation
-        for invalidation in invalidations {
-            // Compute affected elements
-            let affected = compute_affected_elements(&invalidation, dom);
-
-            // Mark elements as dirty
-            for element in &affected {
-                self.mark_dirty(*element, *invalidation.invalidation_type());
-            }
-
-            // Add to result
-            all_affected.extend(affected);
-            result.add_invalidation(invalidation);
-        }
-
-        // Set affected elements in result
-        for element in all_affected {
-            result.add_affected_element(element);
-        }
-
-        result
+        self.process_invalidations_budgeted(dom, usize::MAX).0
     }
 
     fn mark_dirty(&mut self, element_id: ElementId, invalidation_type: InvalidationType) {
@@ -438,7 +645,49 @@ pub fn compute_affected_elements(invalidation: &Invalidation, dom: &DomTree) ->
             // Elements matching the selector are affected
             dom.get_elements_by_selector(selector)
         }
+        InvalidationScope::Attribute(element_id, _) => {
+            // Without selector dependency information only the element itself
+            // is known to be affected; see
+            // `InvalidationTracker::affected_by_attribute_change`
+            let mut result = HashSet::new();
+            result.insert(*element_id);
+            result
+        }
+    }
+}
+
+/// Collect the attribute names a selector depends on
+///
+/// Class selectors depend on `class`, ID selectors on `id`, and attribute
+/// selectors (`[name]`, `[name=value]`, ...) on the named attribute.
+pub fn selector_attribute_dependencies(selector: &str) -> HashSet<String> {
+    let mut result = HashSet::new();
+    let mut chars = selector.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '.' => {
+                result.insert("class".to_string());
+            }
+            '#' => {
+                result.insert("id".to_string());
+            }
+            '[' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || "]=~|^$*".contains(next) {
+                        break;
+                    }
+                    name.push(next.to_ascii_lowercase());
+                    chars.next();
+                }
+                if !name.is_empty() {
+                    result.insert(name);
+                }
+            }
+            _ => {}
+        }
     }
+    result
 }
 
 /// Determine if a subtree invalidation is needed
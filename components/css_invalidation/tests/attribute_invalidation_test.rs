@@ -0,0 +1,85 @@
+//! Tests for attribute-change invalidation driven by selector dependencies
+
+use css_invalidation::{
+    selector_attribute_dependencies, DomTree, ElementId, Invalidation, InvalidationEngine,
+    InvalidationScope, InvalidationTracker, InvalidationType, NestedElement,
+};
+use std::collections::HashSet;
+
+/// Build `div#1 > (span#2.foo, span#3.foo, span#4)`
+fn spec() -> NestedElement {
+    NestedElement::new(ElementId::new(1), "div")
+        .with_child(NestedElement::new(ElementId::new(2), "span").with_class("foo"))
+        .with_child(NestedElement::new(ElementId::new(3), "span").with_class("foo"))
+        .with_child(NestedElement::new(ElementId::new(4), "span"))
+}
+
+fn attribute_change(element: u64, attribute: &str) -> Invalidation {
+    Invalidation::new(
+        InvalidationType::Attribute,
+        InvalidationScope::Attribute(ElementId::new(element), attribute.to_string()),
+        1000,
+    )
+}
+
+// ============================================================================
+// Selector Dependency Tests
+// ============================================================================
+
+#[test]
+fn test_selector_attribute_dependencies() {
+    let deps = selector_attribute_dependencies(".foo");
+    assert_eq!(deps, ["class".to_string()].into_iter().collect());
+
+    let deps = selector_attribute_dependencies("a#main[href^=\"http\"][DATA-x]");
+    let expected: HashSet<String> = ["id", "href", "data-x"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(deps, expected);
+
+    assert!(selector_attribute_dependencies("div > span").is_empty());
+}
+
+#[test]
+fn test_register_selector_tracks_dependents() {
+    let mut tracker = InvalidationTracker::new();
+    tracker.register_selector(".foo");
+    tracker.register_selector("[title]");
+
+    assert_eq!(tracker.selectors_depending_on("class"), vec![".foo"]);
+    assert_eq!(tracker.selectors_depending_on("title"), vec!["[title]"]);
+    assert!(tracker.selectors_depending_on("lang").is_empty());
+}
+
+// ============================================================================
+// Attribute Invalidation Tests
+// ============================================================================
+
+#[test]
+fn test_class_change_invalidates_elements_matched_by_class_selector() {
+    let dom = DomTree::from_nested(&spec());
+    let mut tracker = InvalidationTracker::new();
+    tracker.register_selector(".foo");
+
+    tracker.invalidate(attribute_change(4, "class"));
+    let result = tracker.process_invalidations(&dom);
+
+    let expected: HashSet<_> = [2, 3, 4].into_iter().map(ElementId::new).collect();
+    assert_eq!(result.affected_elements(), &expected);
+    assert!(!tracker.is_dirty(ElementId::new(1)));
+}
+
+#[test]
+fn test_unreferenced_attribute_change_invalidates_only_element() {
+    let dom = DomTree::from_nested(&spec());
+    let mut tracker = InvalidationTracker::new();
+    tracker.register_selector(".foo");
+
+    tracker.invalidate(attribute_change(2, "title"));
+    let result = tracker.process_invalidations(&dom);
+
+    let expected: HashSet<_> = [ElementId::new(2)].into_iter().collect();
+    assert_eq!(result.affected_elements(), &expected);
+    assert!(!tracker.is_dirty(ElementId::new(3)));
+}
@@ -0,0 +1,119 @@
+//! Tests for DomTree construction and navigation
+
+use css_invalidation::{DomTree, ElementId, NestedElement};
+use std::collections::HashSet;
+
+// ============================================================================
+// from_nested Tests
+// ============================================================================
+
+/// Build `html > body.page > (div.card.featured, div.card)`
+fn three_level_spec() -> NestedElement {
+    NestedElement::new(ElementId::new(1), "html").with_child(
+        NestedElement::new(ElementId::new(2), "body")
+            .with_class("page")
+            .with_child(
+                NestedElement::new(ElementId::new(3), "div")
+                    .with_class("card")
+                    .with_class("featured"),
+            )
+            .with_child(NestedElement::new(ElementId::new(4), "div").with_class("card")),
+    )
+}
+
+#[test]
+fn test_from_nested_adds_all_elements() {
+    let dom = DomTree::from_nested(&three_level_spec());
+    assert_eq!(dom.elements().len(), 4);
+}
+
+#[test]
+fn test_from_nested_wires_descendants() {
+    let dom = DomTree::from_nested(&three_level_spec());
+
+    let all: HashSet<_> = (1..=4).map(ElementId::new).collect();
+    assert_eq!(dom.get_descendants(ElementId::new(1)), all);
+
+    let body: HashSet<_> = (2..=4).map(ElementId::new).collect();
+    assert_eq!(dom.get_descendants(ElementId::new(2)), body);
+
+    assert_eq!(dom.parent(ElementId::new(3)), Some(ElementId::new(2)));
+    assert_eq!(dom.parent(ElementId::new(1)), None);
+    assert_eq!(
+        dom.children(ElementId::new(2)),
+        &[ElementId::new(3), ElementId::new(4)]
+    );
+}
+
+#[test]
+fn test_from_nested_class_lookup() {
+    let dom = DomTree::from_nested(&three_level_spec());
+
+    let cards: HashSet<_> = [ElementId::new(3), ElementId::new(4)].into_iter().collect();
+    assert_eq!(dom.get_elements_by_selector(".card"), cards);
+
+    let featured: HashSet<_> = [ElementId::new(3)].into_iter().collect();
+    assert_eq!(dom.get_elements_by_selector(".featured"), featured);
+
+    assert!(dom.has_class(ElementId::new(2), "page"));
+    assert!(!dom.has_class(ElementId::new(1), "page"));
+}
+
+#[test]
+fn test_from_nested_records_tags() {
+    let dom = DomTree::from_nested(&three_level_spec());
+    assert_eq!(dom.tag_name(ElementId::new(1)), Some("html"));
+    assert_eq!(dom.tag_name(ElementId::new(4)), Some("div"));
+    assert_eq!(dom.tag_name(ElementId::new(99)), None);
+}
+
+// ============================================================================
+// Sibling Navigation Tests
+// ============================================================================
+
+/// Build `ul > (li#2, li#3, li#4)`
+fn list_spec() -> NestedElement {
+    NestedElement::new(ElementId::new(1), "ul")
+        .with_child(NestedElement::new(ElementId::new(2), "li"))
+        .with_child(NestedElement::new(ElementId::new(3), "li"))
+        .with_child(NestedElement::new(ElementId::new(4), "li"))
+}
+
+#[test]
+fn test_index_in_parent() {
+    let dom = DomTree::from_nested(&list_spec());
+    assert_eq!(dom.index_in_parent(ElementId::new(2)), Some(0));
+    assert_eq!(dom.index_in_parent(ElementId::new(3)), Some(1));
+    assert_eq!(dom.index_in_parent(ElementId::new(4)), Some(2));
+    assert_eq!(dom.index_in_parent(ElementId::new(1)), None);
+}
+
+#[test]
+fn test_previous_sibling() {
+    let dom = DomTree::from_nested(&list_spec());
+    assert_eq!(dom.previous_sibling(ElementId::new(2)), None);
+    assert_eq!(
+        dom.previous_sibling(ElementId::new(3)),
+        Some(ElementId::new(2))
+    );
+    assert_eq!(
+        dom.previous_sibling(ElementId::new(4)),
+        Some(ElementId::new(3))
+    );
+    assert_eq!(dom.previous_sibling(ElementId::new(1)), None);
+}
+
+#[test]
+fn test_next_siblings() {
+    let dom = DomTree::from_nested(&list_spec());
+    assert_eq!(
+        dom.next_siblings(ElementId::new(2)),
+        vec![ElementId::new(3), ElementId::new(4)]
+    );
+    assert_eq!(
+        dom.next_siblings(ElementId::new(3)),
+        vec![ElementId::new(4)]
+    );
+    assert!(dom.next_siblings(ElementId::new(4)).is_empty());
+    assert!(dom.next_siblings(ElementId::new(1)).is_empty());
+}
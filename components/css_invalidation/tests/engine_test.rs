@@ -4,6 +4,7 @@ use css_invalidation::{
     compute_affected_elements, should_invalidate_subtree, DomTree, ElementId, Invalidation,
     InvalidationEngine, InvalidationScope, InvalidationTracker, InvalidationType,
 };
+use std::collections::HashSet;
 
 // ============================================================================
 // Helper Functions Tests
@@ -356,3 +357,82 @@ fn test_dirty_flag_clearing_after_restyle() {
     tracker.clear_dirty(element_id);
     assert!(!tracker.is_dirty(element_id));
 }
+
+// ============================================================================
+// Budgeted Processing Tests
+// ============================================================================
+
+fn dom_with_elements(count: u64) -> DomTree {
+    DomTree::with_elements((1..=count).map(ElementId::new).collect())
+}
+
+#[test]
+fn test_budgeted_processing_splits_large_invalidation() {
+    let dom = dom_with_elements(10);
+    let mut tracker = InvalidationTracker::new();
+    tracker.invalidate(Invalidation::new(
+        InvalidationType::Full,
+        InvalidationScope::Global,
+        1000,
+    ));
+
+    let (first, more) = tracker.process_invalidations_budgeted(&dom, 6);
+    assert!(more);
+    assert_eq!(first.affected_elements().len(), 6);
+    assert_eq!(first.invalidations().len(), 1);
+    assert!(tracker.pending_invalidations().is_empty());
+
+    let (second, more) = tracker.process_invalidations_budgeted(&dom, 6);
+    assert!(!more);
+    assert_eq!(second.affected_elements().len(), 4);
+    assert!(second.invalidations().is_empty());
+
+    // Together the two batches cover every element exactly once
+    assert!(first
+        .affected_elements()
+        .is_disjoint(second.affected_elements()));
+    let covered: HashSet<ElementId> = first
+        .affected_elements()
+        .union(second.affected_elements())
+        .copied()
+        .collect();
+    assert_eq!(&covered, dom.elements());
+    assert!(dom.elements().iter().all(|id| tracker.is_dirty(*id)));
+}
+
+#[test]
+fn test_budgeted_processing_within_budget_finishes() {
+    let dom = dom_with_elements(3);
+    let mut tracker = InvalidationTracker::new();
+    tracker.invalidate(Invalidation::new(
+        InvalidationType::Element,
+        InvalidationScope::Element(ElementId::new(2)),
+        1000,
+    ));
+
+    let (result, more) = tracker.process_invalidations_budgeted(&dom, 5);
+    assert!(!more);
+    assert_eq!(result.affected_elements().len(), 1);
+    assert!(tracker.is_dirty(ElementId::new(2)));
+}
+
+#[test]
+fn test_unbudgeted_processing_flushes_deferred_elements() {
+    let dom = dom_with_elements(4);
+    let mut tracker = InvalidationTracker::new();
+    tracker.invalidate(Invalidation::new(
+        InvalidationType::Full,
+        InvalidationScope::Global,
+        1000,
+    ));
+
+    let (first, more) = tracker.process_invalidations_budgeted(&dom, 1);
+    assert!(more);
+    assert_eq!(first.affected_elements().len(), 1);
+
+    let rest = tracker.process_invalidations(&dom);
+    assert_eq!(rest.affected_elements().len(), 3);
+    let (empty, more) = tracker.process_invalidations_budgeted(&dom, 1);
+    assert!(!more);
+    assert!(empty.affected_elements().is_empty());
+}
@@ -3,90 +3,23 @@
 //! This crate provides box model computation for CSS elements including:
 //! - Box model structures (content, padding, border, margin)
 //! - Box sizing modes (content-box, border-box)
-//! - Display property values
+//! - Display and overflow property values
 //! - Box model calculation trait and implementation
+//! - Aspect-ratio sizing
+//! - Replaced element fitting (`object-fit`, `object-position`)
 
-use css_stylist_core::ComputedValues;
-use css_types::{Length, LengthUnit};
+use css_parser_values::AspectRatio;
+use css_stylist_core::{ComputedValues, Position};
+use css_types::{CssError, KeywordEnum, Length, LengthUnit};
 
 // ============================================================================
 // Core Types
 // ============================================================================
 
 /// Rectangle dimensions
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Rect {
-    x: f32,
-    y: f32,
-    width: f32,
-    height: f32,
-}
-
-impl Rect {
-    /// Create a new rectangle
-    ///
-    /// # Arguments
-    /// * `x` - X coordinate
-    /// * `y` - Y coordinate
-    /// * `width` - Width
-    /// * `height` - Height
-    ///
-    /// # Examples
-    /// ```
-    /// use css_layout_box_model::Rect;
-    ///
-    /// let rect = Rect::new(10.0, 20.0, 100.0, 50.0);
-    /// assert_eq!(rect.width(), 100.0);
-    /// ```
-    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
-        Self {
-            x,
-            y,
-            width,
-            height,
-        }
-    }
-
-    /// Get the x coordinate
-    pub fn x(&self) -> f32 {
-        self.x
-    }
-
-    /// Get the y coordinate
-    pub fn y(&self) -> f32 {
-        self.y
-    }
-
-    /// Get the width
-    pub fn width(&self) -> f32 {
-        self.width
-    }
-
-    /// Get the height
-    pub fn height(&self) -> f32 {
-        self.height
-    }
-
-    /// Calculate the area of the rectangle
-    pub fn area(&self) -> f32 {
-        self.width * self.height
-    }
-
-    /// Check if a point is contained within the rectangle
-    ///
-    /// # Arguments
-    /// * `x` - X coordinate of the point
-    /// * `y` - Y coordinate of the point
-    pub fn contains(&self, x: f32, y: f32) -> bool {
-        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
-    }
-}
-
-impl Default for Rect {
-    fn default() -> Self {
-        Self::new(0.0, 0.0, 0.0, 0.0)
-    }
-}
+///
+/// Re-exported from `css_types`, which holds the canonical definition.
+pub use css_types::Rect;
 
 /// Sizes for all four edges (top, right, bottom, left)
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -204,6 +137,45 @@ pub enum Display {
     Table,
 }
 
+/// Overflow property values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Content may be painted outside the box
+    #[default]
+    Visible,
+    /// Content is clipped, with no scrolling
+    Hidden,
+    /// Content is clipped, and the box is not a scroll container
+    Clip,
+    /// Content is clipped and scrollbars are always shown
+    Scroll,
+    /// Content is clipped and scrollbars are shown when needed
+    Auto,
+}
+
+impl KeywordEnum for Overflow {
+    const KIND: &'static str = "overflow";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("visible", Overflow::Visible),
+        ("hidden", Overflow::Hidden),
+        ("clip", Overflow::Clip),
+        ("scroll", Overflow::Scroll),
+        ("auto", Overflow::Auto),
+    ];
+}
+
+impl Overflow {
+    /// Parse an overflow keyword (case-insensitive)
+    pub fn parse(input: &str) -> Result<Self, CssError> {
+        Self::from_keyword(input)
+    }
+
+    /// Check whether content outside the box is clipped
+    pub fn clips(&self) -> bool {
+        *self != Overflow::Visible
+    }
+}
+
 /// Computed box model for an element
 #[derive(Debug, Clone, PartialEq)]
 pub struct BoxModel {
@@ -300,10 +272,10 @@ impl BoxModel {
     /// ```
     pub fn padding_box(&self) -> Rect {
         Rect::new(
-            self.content.x - self.padding.left,
-            self.content.y - self.padding.top,
-            self.content.width + self.padding.horizontal(),
-            self.content.height + self.padding.vertical(),
+            self.content.x() - self.padding.left,
+            self.content.y() - self.padding.top,
+            self.content.width() + self.padding.horizontal(),
+            self.content.height() + self.padding.vertical(),
         )
     }
 
@@ -326,10 +298,10 @@ impl BoxModel {
     pub fn border_box(&self) -> Rect {
         let padding_box = self.padding_box();
         Rect::new(
-            padding_box.x - self.border.left,
-            padding_box.y - self.border.top,
-            padding_box.width + self.border.horizontal(),
-            padding_box.height + self.border.vertical(),
+            padding_box.x() - self.border.left,
+            padding_box.y() - self.border.top,
+            padding_box.width() + self.border.horizontal(),
+            padding_box.height() + self.border.vertical(),
         )
     }
 
@@ -352,12 +324,36 @@ impl BoxModel {
     pub fn margin_box(&self) -> Rect {
         let border_box = self.border_box();
         Rect::new(
-            border_box.x - self.margin.left,
-            border_box.y - self.margin.top,
-            border_box.width + self.margin.horizontal(),
-            border_box.height + self.margin.vertical(),
+            border_box.x() - self.margin.left,
+            border_box.y() - self.margin.top,
+            border_box.width() + self.margin.horizontal(),
+            border_box.height() + self.margin.vertical(),
         )
     }
+
+    /// Calculate the rectangle descendants are clipped to
+    ///
+    /// Every overflow value other than `visible` clips to the padding box.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_layout_box_model::{BoxModel, BoxSizing, EdgeSizes, Overflow, Rect};
+    ///
+    /// let content = Rect::new(0.0, 0.0, 200.0, 100.0);
+    /// let box_model = BoxModel::new(
+    ///     content,
+    ///     EdgeSizes::uniform(10.0),
+    ///     EdgeSizes::uniform(2.0),
+    ///     EdgeSizes::uniform(5.0),
+    ///     BoxSizing::ContentBox,
+    /// );
+    ///
+    /// assert_eq!(box_model.clip_rect(Overflow::Hidden), Some(box_model.padding_box()));
+    /// assert_eq!(box_model.clip_rect(Overflow::Visible), None);
+    /// ```
+    pub fn clip_rect(&self, overflow: Overflow) -> Option<Rect> {
+        overflow.clips().then(|| self.padding_box())
+    }
 }
 
 // ============================================================================
@@ -451,6 +447,11 @@ pub fn compute_margin(style: &ComputedValues, containing_block_width: f32) -> Ed
 
 /// Compute content box dimensions
 ///
+/// An `auto` width on an in-flow block-level box (`display: block` or
+/// `flex`) fills the containing block, less the box's horizontal margins,
+/// padding and border. Other `auto` sizes depend on content and resolve to
+/// 0 here.
+///
 /// # Arguments
 /// * `style` - Computed style values
 /// * `containing_block` - Containing block rectangle
@@ -462,8 +463,8 @@ pub fn compute_margin(style: &ComputedValues, containing_block_width: f32) -> Ed
 /// use css_types::{Length, LengthUnit};
 ///
 /// let mut style = ComputedValues::default();
-/// style.width = Length::new(200.0, LengthUnit::Px);
-/// style.height = Length::new(100.0, LengthUnit::Px);
+/// style.width = Some(Length::new(200.0, LengthUnit::Px));
+/// style.height = Some(Length::new(100.0, LengthUnit::Px));
 ///
 /// let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
 /// let content = compute_content_box(&style, &containing_block);
@@ -472,10 +473,134 @@ pub fn compute_margin(style: &ComputedValues, containing_block_width: f32) -> Ed
 /// assert_eq!(content.height(), 100.0);
 /// ```
 pub fn compute_content_box(style: &ComputedValues, containing_block: &Rect) -> Rect {
-    let width = resolve_length(&style.width, containing_block.width);
-    let height = resolve_length(&style.height, containing_block.height);
+    let width = match &style.width {
+        Some(width) => resolve_length(width, containing_block.width()),
+        None => resolve_auto_width(style, containing_block.width()),
+    };
+    let height = style.height.as_ref().map_or(0.0, |height| {
+        resolve_length(height, containing_block.height())
+    });
+
+    Rect::new(containing_block.x(), containing_block.y(), width, height)
+}
+
+/// Compute the offset applied by the `position` and inset properties
+///
+/// The result depends on the positioning scheme:
+/// - `relative`: the shift from the box's normal-flow position. `left` wins
+///   over `right` and `top` over `bottom` when both are set.
+/// - `absolute` and `fixed`: the origin of the margin box, in the same
+///   coordinate space as `containing_block` (the viewport for `fixed`).
+///   A box with only `right`/`bottom` set is anchored to that edge; with
+///   both insets `auto` on an axis it falls back to the containing block
+///   edge, since the static position is not known here.
+/// - `static` and `sticky`: `(0.0, 0.0)`. Sticky offsets depend on the
+///   scroll position and are applied at scroll time.
+///
+/// Horizontal insets resolve percentages against the containing block
+/// width, vertical insets against its height.
+///
+/// # Examples
+/// ```
+/// use css_layout_box_model::{compute_positioned_offset, Rect};
+/// use css_stylist_core::{ComputedValues, Position};
+/// use css_types::{Length, LengthUnit};
+///
+/// let mut style = ComputedValues::default();
+/// style.position = Position::Relative;
+/// style.top = Some(Length::new(10.0, LengthUnit::Px));
+///
+/// let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// assert_eq!(compute_positioned_offset(&style, &containing_block), (0.0, 10.0));
+/// ```
+pub fn compute_positioned_offset(style: &ComputedValues, containing_block: &Rect) -> (f32, f32) {
+    let inset = |length: &Option<Length>, reference: f32| {
+        length
+            .as_ref()
+            .map(|length| resolve_length(length, reference))
+    };
+    let left = inset(&style.left, containing_block.width());
+    let right = inset(&style.right, containing_block.width());
+    let top = inset(&style.top, containing_block.height());
+    let bottom = inset(&style.bottom, containing_block.height());
+
+    match style.position {
+        Position::Static | Position::Sticky => (0.0, 0.0),
+        Position::Relative => (
+            left.or(right.map(|right| -right)).unwrap_or(0.0),
+            top.or(bottom.map(|bottom| -bottom)).unwrap_or(0.0),
+        ),
+        Position::Absolute | Position::Fixed => {
+            let margin_box = DefaultBoxModelCalculator
+                .compute_box_model(style, containing_block)
+                .margin_box();
+
+            let x = match (left, right) {
+                (Some(left), _) => containing_block.x() + left,
+                (None, Some(right)) => {
+                    containing_block.x() + containing_block.width() - right - margin_box.width()
+                }
+                (None, None) => containing_block.x(),
+            };
+            let y = match (top, bottom) {
+                (Some(top), _) => containing_block.y() + top,
+                (None, Some(bottom)) => {
+                    containing_block.y() + containing_block.height() - bottom - margin_box.height()
+                }
+                (None, None) => containing_block.y(),
+            };
+            (x, y)
+        }
+    }
+}
 
-    Rect::new(containing_block.x, containing_block.y, width, height)
+/// Derive a missing box dimension from the other one and an aspect ratio
+///
+/// If exactly one of `width` and `height` is known and the aspect ratio
+/// has a usable ratio, the other is computed from it. Otherwise both are
+/// returned unchanged. `auto` without a ratio only applies to replaced
+/// elements with a natural ratio, which are not handled here.
+///
+/// # Arguments
+/// * `width` - Definite width, if any
+/// * `height` - Definite height, if any
+/// * `aspect_ratio` - Value of the `aspect-ratio` property
+///
+/// # Examples
+/// ```
+/// use css_layout_box_model::apply_aspect_ratio;
+/// use css_parser_values::parse_aspect_ratio;
+///
+/// let ratio = parse_aspect_ratio("16 / 9").unwrap();
+/// assert_eq!(apply_aspect_ratio(Some(320.0), None, &ratio), (Some(320.0), Some(180.0)));
+/// ```
+pub fn apply_aspect_ratio(
+    width: Option<f32>,
+    height: Option<f32>,
+    aspect_ratio: &AspectRatio,
+) -> (Option<f32>, Option<f32>) {
+    match (width, height, aspect_ratio.ratio()) {
+        (Some(width), None, Some(ratio)) => (Some(width), Some(width / ratio)),
+        (None, Some(height), Some(ratio)) => (Some(height * ratio), Some(height)),
+        _ => (width, height),
+    }
+}
+
+/// Resolve `width: auto` for a box in a containing block of the given width
+fn resolve_auto_width(style: &ComputedValues, containing_block_width: f32) -> f32 {
+    let in_flow = !matches!(style.position, Position::Absolute | Position::Fixed);
+    let block_level = matches!(
+        style.display,
+        css_stylist_core::Display::Block | css_stylist_core::Display::Flex
+    );
+    if !(in_flow && block_level) {
+        return 0.0;
+    }
+
+    let used = compute_margin(style, containing_block_width).horizontal()
+        + compute_padding(style, containing_block_width).horizontal()
+        + compute_border(style).horizontal();
+    (containing_block_width - used).max(0.0)
 }
 
 /// Resolve a length value to pixels
@@ -497,6 +622,135 @@ fn resolve_length(length: &Length, reference_value: f32) -> f32 {
     }
 }
 
+// ============================================================================
+// Replaced Element Fitting
+// ============================================================================
+
+/// Value of the `object-fit` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObjectFit {
+    /// Stretch to fill the box, ignoring the aspect ratio
+    #[default]
+    Fill,
+    /// Scale to fit entirely within the box, letterboxing if needed
+    Contain,
+    /// Scale to cover the whole box, cropping if needed
+    Cover,
+    /// Keep the intrinsic size
+    None,
+    /// Like `contain`, but never scale up
+    ScaleDown,
+}
+
+impl KeywordEnum for ObjectFit {
+    const KIND: &'static str = "object-fit";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("fill", ObjectFit::Fill),
+        ("contain", ObjectFit::Contain),
+        ("cover", ObjectFit::Cover),
+        ("none", ObjectFit::None),
+        ("scale-down", ObjectFit::ScaleDown),
+    ];
+}
+
+/// Value of the `object-position` property
+///
+/// Each offset is a pixel length from the box's left/top edge, or a
+/// percentage of the space left over once the object is sized (so `50%`
+/// centers it and `100%` aligns it to the right/bottom edge).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectPosition {
+    x: Length,
+    y: Length,
+}
+
+impl ObjectPosition {
+    /// Create an object position
+    ///
+    /// # Arguments
+    /// * `x` - Horizontal offset
+    /// * `y` - Vertical offset
+    pub fn new(x: Length, y: Length) -> Self {
+        Self { x, y }
+    }
+
+    /// Get the horizontal offset
+    pub fn x(&self) -> Length {
+        self.x
+    }
+
+    /// Get the vertical offset
+    pub fn y(&self) -> Length {
+        self.y
+    }
+}
+
+impl Default for ObjectPosition {
+    /// `50% 50%`, centering the object
+    fn default() -> Self {
+        Self::new(
+            Length::new(50.0, LengthUnit::Percent),
+            Length::new(50.0, LengthUnit::Percent),
+        )
+    }
+}
+
+/// Compute the rectangle a replaced element's content is drawn into
+///
+/// The result may extend past `container` (for `cover` and `none`); the
+/// caller clips it to the content box. Objects without a positive intrinsic
+/// size are drawn at their intrinsic size for every fit except `fill`.
+///
+/// # Arguments
+/// * `container` - Content box of the replaced element
+/// * `intrinsic` - Intrinsic `(width, height)` of the content
+/// * `fit` - Value of `object-fit`
+/// * `position` - Value of `object-position`
+///
+/// # Examples
+/// ```
+/// use css_layout_box_model::{compute_object_fit, ObjectFit, ObjectPosition, Rect};
+///
+/// let container = Rect::new(0.0, 0.0, 100.0, 100.0);
+/// let drawn = compute_object_fit(container, (200.0, 100.0), ObjectFit::Contain, ObjectPosition::default());
+/// assert_eq!(drawn, Rect::new(0.0, 25.0, 100.0, 50.0));
+/// ```
+pub fn compute_object_fit(
+    container: Rect,
+    intrinsic: (f32, f32),
+    fit: ObjectFit,
+    position: ObjectPosition,
+) -> Rect {
+    let (intrinsic_width, intrinsic_height) = intrinsic;
+    let has_ratio = intrinsic_width > 0.0 && intrinsic_height > 0.0;
+    let contain_scale =
+        || (container.width() / intrinsic_width).min(container.height() / intrinsic_height);
+
+    let scale = match fit {
+        ObjectFit::Fill => None,
+        _ if !has_ratio => Some(1.0),
+        ObjectFit::Contain => Some(contain_scale()),
+        ObjectFit::Cover => {
+            Some((container.width() / intrinsic_width).max(container.height() / intrinsic_height))
+        }
+        ObjectFit::None => Some(1.0),
+        ObjectFit::ScaleDown => Some(contain_scale().min(1.0)),
+    };
+
+    let Some(scale) = scale else {
+        return container;
+    };
+
+    let width = intrinsic_width * scale;
+    let height = intrinsic_height * scale;
+    Rect::new(
+        container.x() + resolve_length(&position.x(), container.width() - width),
+        container.y() + resolve_length(&position.y(), container.height() - height),
+        width,
+        height,
+    )
+}
+
 // ============================================================================
 // Box Model Calculator Trait
 // ============================================================================
@@ -543,8 +797,8 @@ pub trait BoxModelCalculator {
 ///
 /// let calculator = DefaultBoxModelCalculator;
 /// let mut style = ComputedValues::default();
-/// style.width = Length::new(200.0, LengthUnit::Px);
-/// style.height = Length::new(100.0, LengthUnit::Px);
+/// style.width = Some(Length::new(200.0, LengthUnit::Px));
+/// style.height = Some(Length::new(100.0, LengthUnit::Px));
 ///
 /// let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
 /// let box_model = calculator.compute_box_model(&style, &containing_block);
@@ -556,9 +810,9 @@ pub struct DefaultBoxModelCalculator;
 impl BoxModelCalculator for DefaultBoxModelCalculator {
     fn compute_box_model(&self, style: &ComputedValues, containing_block: &Rect) -> BoxModel {
         let content = compute_content_box(style, containing_block);
-        let padding = compute_padding(style, containing_block.width);
+        let padding = compute_padding(style, containing_block.width());
         let border = compute_border(style);
-        let margin = compute_margin(style, containing_block.width);
+        let margin = compute_margin(style, containing_block.width());
 
         BoxModel::new(content, padding, border, margin, BoxSizing::ContentBox)
     }
@@ -1,11 +1,13 @@
 //! Unit tests for box model computation functions
 
 use css_layout_box_model::{
-    compute_border, compute_content_box, compute_margin, compute_padding, BoxModelCalculator,
-    DefaultBoxModelCalculator, Rect,
+    apply_aspect_ratio, compute_border, compute_content_box, compute_margin, compute_object_fit,
+    compute_padding, compute_positioned_offset, BoxModelCalculator, DefaultBoxModelCalculator,
+    ObjectFit, ObjectPosition, Rect,
 };
-use css_stylist_core::ComputedValues;
-use css_types::{Length, LengthUnit};
+use css_parser_values::parse_aspect_ratio;
+use css_stylist_core::{ComputedValues, Display, Position};
+use css_types::{KeywordEnum, Length, LengthUnit};
 
 #[test]
 fn test_compute_padding_all_pixels() {
@@ -93,8 +95,8 @@ fn test_compute_margin_with_percentages() {
 #[test]
 fn test_compute_content_box_with_explicit_dimensions() {
     let mut style = ComputedValues::default();
-    style.width = Length::new(200.0, LengthUnit::Px);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(200.0, LengthUnit::Px));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
 
     let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
     let content = compute_content_box(&style, &containing_block);
@@ -106,8 +108,8 @@ fn test_compute_content_box_with_explicit_dimensions() {
 #[test]
 fn test_compute_content_box_with_percentage_width() {
     let mut style = ComputedValues::default();
-    style.width = Length::new(50.0, LengthUnit::Percent);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(50.0, LengthUnit::Percent));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
 
     let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
     let content = compute_content_box(&style, &containing_block);
@@ -116,6 +118,57 @@ fn test_compute_content_box_with_percentage_width() {
     assert_eq!(content.height(), 100.0);
 }
 
+#[test]
+fn test_auto_width_block_fills_container_minus_margins() {
+    let style = ComputedValues {
+        display: Display::Block,
+        margin_left: Length::new(10.0, LengthUnit::Px),
+        margin_right: Length::new(10.0, LengthUnit::Px),
+        ..ComputedValues::default()
+    };
+
+    let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+    let box_model = DefaultBoxModelCalculator.compute_box_model(&style, &containing_block);
+
+    assert_eq!(box_model.content().width(), 780.0);
+    assert_eq!(box_model.margin_box().width(), 800.0);
+}
+
+#[test]
+fn test_auto_width_block_accounts_for_padding() {
+    let style = ComputedValues {
+        display: Display::Block,
+        margin_left: Length::new(10.0, LengthUnit::Px),
+        margin_right: Length::new(10.0, LengthUnit::Px),
+        padding_left: Length::new(5.0, LengthUnit::Percent),
+        padding_right: Length::new(20.0, LengthUnit::Px),
+        ..ComputedValues::default()
+    };
+
+    let content = compute_content_box(&style, &Rect::new(0.0, 0.0, 800.0, 600.0));
+
+    assert_eq!(content.width(), 720.0); // 800 - 20 margin - 40 - 20 padding
+    assert_eq!(content.height(), 0.0);
+}
+
+#[test]
+fn test_auto_width_inline_and_absolute_boxes_do_not_fill() {
+    let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+
+    let inline = ComputedValues::default();
+    assert_eq!(compute_content_box(&inline, &containing_block).width(), 0.0);
+
+    let absolute = ComputedValues {
+        display: Display::Block,
+        position: Position::Absolute,
+        ..ComputedValues::default()
+    };
+    assert_eq!(
+        compute_content_box(&absolute, &containing_block).width(),
+        0.0
+    );
+}
+
 #[test]
 fn test_box_model_calculator_resolve_width() {
     let calculator = DefaultBoxModelCalculator;
@@ -161,8 +214,8 @@ fn test_box_model_calculator_compute_full_box_model() {
     let calculator = DefaultBoxModelCalculator;
     let mut style = ComputedValues::default();
 
-    style.width = Length::new(200.0, LengthUnit::Px);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(200.0, LengthUnit::Px));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
     style.padding_top = Length::new(10.0, LengthUnit::Px);
     style.padding_right = Length::new(10.0, LengthUnit::Px);
     style.padding_bottom = Length::new(10.0, LengthUnit::Px);
@@ -180,3 +233,212 @@ fn test_box_model_calculator_compute_full_box_model() {
     assert_eq!(box_model.padding().top(), 10.0);
     assert_eq!(box_model.margin().top(), 5.0);
 }
+
+fn px(value: f32) -> Option<Length> {
+    Some(Length::new(value, LengthUnit::Px))
+}
+
+#[test]
+fn test_positioned_offset_relative_shift() {
+    let style = ComputedValues {
+        position: Position::Relative,
+        top: px(10.0),
+        left: px(20.0),
+        ..ComputedValues::default()
+    };
+
+    let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+    assert_eq!(
+        compute_positioned_offset(&style, &containing_block),
+        (20.0, 10.0)
+    );
+}
+
+#[test]
+fn test_positioned_offset_relative_right_bottom_shift_back() {
+    let style = ComputedValues {
+        position: Position::Relative,
+        right: Some(Length::new(10.0, LengthUnit::Percent)),
+        bottom: px(5.0),
+        ..ComputedValues::default()
+    };
+
+    let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+    assert_eq!(
+        compute_positioned_offset(&style, &containing_block),
+        (-80.0, -5.0)
+    );
+}
+
+#[test]
+fn test_positioned_offset_absolute_anchored_right_bottom() {
+    let style = ComputedValues {
+        position: Position::Absolute,
+        width: Some(Length::new(100.0, LengthUnit::Px)),
+        height: Some(Length::new(50.0, LengthUnit::Px)),
+        margin_right: Length::new(4.0, LengthUnit::Px),
+        right: px(10.0),
+        bottom: px(20.0),
+        ..ComputedValues::default()
+    };
+
+    // Margin box is 104x50, its right edge sits 10px from the block's edge
+    let containing_block = Rect::new(50.0, 100.0, 800.0, 600.0);
+    assert_eq!(
+        compute_positioned_offset(&style, &containing_block),
+        (50.0 + 800.0 - 10.0 - 104.0, 100.0 + 600.0 - 20.0 - 50.0)
+    );
+}
+
+#[test]
+fn test_positioned_offset_absolute_left_wins_over_right() {
+    let style = ComputedValues {
+        position: Position::Absolute,
+        left: px(30.0),
+        right: px(10.0),
+        ..ComputedValues::default()
+    };
+
+    let containing_block = Rect::new(50.0, 100.0, 800.0, 600.0);
+    assert_eq!(
+        compute_positioned_offset(&style, &containing_block),
+        (80.0, 100.0)
+    );
+}
+
+#[test]
+fn test_positioned_offset_static_and_sticky_ignore_insets() {
+    let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
+
+    for position in [Position::Static, Position::Sticky] {
+        let style = ComputedValues {
+            position,
+            top: px(10.0),
+            left: px(20.0),
+            ..ComputedValues::default()
+        };
+        assert_eq!(
+            compute_positioned_offset(&style, &containing_block),
+            (0.0, 0.0)
+        );
+    }
+}
+
+#[test]
+fn test_apply_aspect_ratio_derives_height_from_width() {
+    let ratio = parse_aspect_ratio("16 / 9").unwrap();
+
+    assert_eq!(
+        apply_aspect_ratio(Some(1600.0), None, &ratio),
+        (Some(1600.0), Some(900.0))
+    );
+}
+
+#[test]
+fn test_apply_aspect_ratio_derives_width_from_height() {
+    let ratio = parse_aspect_ratio("auto 4/3").unwrap();
+
+    assert_eq!(
+        apply_aspect_ratio(None, Some(300.0), &ratio),
+        (Some(400.0), Some(300.0))
+    );
+}
+
+#[test]
+fn test_apply_aspect_ratio_keeps_definite_or_unknown_sizes() {
+    let ratio = parse_aspect_ratio("1").unwrap();
+
+    assert_eq!(
+        apply_aspect_ratio(Some(10.0), Some(20.0), &ratio),
+        (Some(10.0), Some(20.0))
+    );
+    assert_eq!(apply_aspect_ratio(None, None, &ratio), (None, None));
+    assert_eq!(
+        apply_aspect_ratio(Some(10.0), None, &parse_aspect_ratio("auto").unwrap()),
+        (Some(10.0), None)
+    );
+}
+
+#[test]
+fn test_object_fit_cover_crops_wide_image() {
+    let container = Rect::new(0.0, 0.0, 100.0, 100.0);
+    let drawn = compute_object_fit(
+        container,
+        (200.0, 100.0),
+        ObjectFit::Cover,
+        ObjectPosition::default(),
+    );
+
+    // Scaled to the container height, centered, overflowing left and right
+    assert_eq!(drawn, Rect::new(-50.0, 0.0, 200.0, 100.0));
+}
+
+#[test]
+fn test_object_fit_contain_letterboxes_wide_image() {
+    let container = Rect::new(10.0, 10.0, 100.0, 100.0);
+    let drawn = compute_object_fit(
+        container,
+        (200.0, 100.0),
+        ObjectFit::Contain,
+        ObjectPosition::default(),
+    );
+
+    assert_eq!(drawn, Rect::new(10.0, 35.0, 100.0, 50.0));
+}
+
+#[test]
+fn test_object_fit_fill_none_and_scale_down() {
+    let container = Rect::new(0.0, 0.0, 100.0, 100.0);
+    let center = ObjectPosition::default();
+
+    assert_eq!(
+        compute_object_fit(container, (200.0, 100.0), ObjectFit::Fill, center),
+        container
+    );
+    assert_eq!(
+        compute_object_fit(container, (40.0, 20.0), ObjectFit::None, center),
+        Rect::new(30.0, 40.0, 40.0, 20.0)
+    );
+    // Small images keep their size, large ones scale down like contain
+    assert_eq!(
+        compute_object_fit(container, (40.0, 20.0), ObjectFit::ScaleDown, center),
+        Rect::new(30.0, 40.0, 40.0, 20.0)
+    );
+    assert_eq!(
+        compute_object_fit(container, (200.0, 100.0), ObjectFit::ScaleDown, center),
+        Rect::new(0.0, 25.0, 100.0, 50.0)
+    );
+}
+
+#[test]
+fn test_object_position_offsets() {
+    let container = Rect::new(0.0, 0.0, 100.0, 100.0);
+    let top_right = ObjectPosition::new(
+        Length::new(100.0, LengthUnit::Percent),
+        Length::new(0.0, LengthUnit::Px),
+    );
+
+    assert_eq!(
+        compute_object_fit(container, (200.0, 100.0), ObjectFit::Contain, top_right),
+        Rect::new(0.0, 0.0, 100.0, 50.0)
+    );
+
+    let pixel = ObjectPosition::new(
+        Length::new(5.0, LengthUnit::Px),
+        Length::new(10.0, LengthUnit::Px),
+    );
+    assert_eq!(
+        compute_object_fit(container, (20.0, 20.0), ObjectFit::None, pixel),
+        Rect::new(5.0, 10.0, 20.0, 20.0)
+    );
+}
+
+#[test]
+fn test_object_fit_keywords() {
+    assert_eq!(
+        ObjectFit::from_keyword("scale-down"),
+        Ok(ObjectFit::ScaleDown)
+    );
+    assert_eq!(ObjectFit::Cover.to_keyword(), "cover");
+    assert_eq!(ObjectFit::default(), ObjectFit::Fill);
+}
@@ -16,8 +16,8 @@ fn test_content_box_sizing_complete_workflow() {
     let mut style = ComputedValues::default();
 
     // Set dimensions
-    style.width = Length::new(300.0, LengthUnit::Px);
-    style.height = Length::new(200.0, LengthUnit::Px);
+    style.width = Some(Length::new(300.0, LengthUnit::Px));
+    style.height = Some(Length::new(200.0, LengthUnit::Px));
 
     // Set padding
     style.padding_top = Length::new(20.0, LengthUnit::Px);
@@ -67,8 +67,8 @@ fn test_percentage_based_dimensions() {
     let mut style = ComputedValues::default();
 
     // 50% width of containing block
-    style.width = Length::new(50.0, LengthUnit::Percent);
-    style.height = Length::new(200.0, LengthUnit::Px);
+    style.width = Some(Length::new(50.0, LengthUnit::Percent));
+    style.height = Some(Length::new(200.0, LengthUnit::Px));
 
     // Percentage padding (relative to containing block width)
     style.padding_top = Length::new(5.0, LengthUnit::Percent);
@@ -106,8 +106,8 @@ fn test_mixed_units() {
     let calculator = DefaultBoxModelCalculator;
     let mut style = ComputedValues::default();
 
-    style.width = Length::new(400.0, LengthUnit::Px);
-    style.height = Length::new(25.0, LengthUnit::Percent); // 25% of 800 = 200
+    style.width = Some(Length::new(400.0, LengthUnit::Px));
+    style.height = Some(Length::new(25.0, LengthUnit::Percent)); // 25% of 800 = 200
 
     style.padding_top = Length::new(10.0, LengthUnit::Px);
     style.padding_right = Length::new(5.0, LengthUnit::Percent); // 5% of 1000 = 50
@@ -154,8 +154,8 @@ fn test_asymmetric_padding_and_margins() {
     let calculator = DefaultBoxModelCalculator;
     let mut style = ComputedValues::default();
 
-    style.width = Length::new(200.0, LengthUnit::Px);
-    style.height = Length::new(150.0, LengthUnit::Px);
+    style.width = Some(Length::new(200.0, LengthUnit::Px));
+    style.height = Some(Length::new(150.0, LengthUnit::Px));
 
     // Asymmetric padding
     style.padding_top = Length::new(5.0, LengthUnit::Px);
@@ -195,8 +195,8 @@ fn test_large_dimensions() {
     let calculator = DefaultBoxModelCalculator;
     let mut style = ComputedValues::default();
 
-    style.width = Length::new(10000.0, LengthUnit::Px);
-    style.height = Length::new(5000.0, LengthUnit::Px);
+    style.width = Some(Length::new(10000.0, LengthUnit::Px));
+    style.height = Some(Length::new(5000.0, LengthUnit::Px));
 
     style.padding_top = Length::new(100.0, LengthUnit::Px);
     style.padding_right = Length::new(100.0, LengthUnit::Px);
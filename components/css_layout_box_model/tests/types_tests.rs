@@ -1,6 +1,6 @@
 //! Unit tests for box model types
 
-use css_layout_box_model::{BoxModel, BoxSizing, Display, EdgeSizes, Rect};
+use css_layout_box_model::{BoxModel, BoxSizing, Display, EdgeSizes, Overflow, Rect};
 
 #[test]
 fn test_rect_new() {
@@ -34,6 +34,13 @@ fn test_rect_contains_point() {
     assert!(!rect.contains(50.0, 10.0));
 }
 
+#[test]
+fn test_rect_is_canonical_css_types_rect() {
+    let rect: css_types::Rect = Rect::new(10.0, 20.0, 100.0, 50.0);
+    assert_eq!(rect.area(), 5000.0);
+    assert!(rect.contains(110.0, 70.0));
+}
+
 #[test]
 fn test_edge_sizes_new() {
     let edges = EdgeSizes::new(10.0, 20.0, 30.0, 40.0);
@@ -159,3 +166,46 @@ fn test_box_model_margin_box() {
     assert_eq!(margin_box.width(), 234.0);
     assert_eq!(margin_box.height(), 134.0);
 }
+
+#[test]
+fn test_box_model_clip_rect_hidden_is_padding_box() {
+    let content = Rect::new(20.0, 20.0, 200.0, 100.0);
+    let padding = EdgeSizes::new(5.0, 10.0, 15.0, 20.0);
+    let border = EdgeSizes::uniform(2.0);
+    let margin = EdgeSizes::uniform(5.0);
+
+    let box_model = BoxModel::new(content, padding, border, margin, BoxSizing::ContentBox);
+
+    let expected = Rect::new(0.0, 15.0, 230.0, 120.0);
+    assert_eq!(box_model.padding_box(), expected);
+    for overflow in [
+        Overflow::Hidden,
+        Overflow::Clip,
+        Overflow::Scroll,
+        Overflow::Auto,
+    ] {
+        assert_eq!(box_model.clip_rect(overflow), Some(expected));
+    }
+}
+
+#[test]
+fn test_box_model_clip_rect_visible_is_none() {
+    let content = Rect::new(0.0, 0.0, 200.0, 100.0);
+    let box_model = BoxModel::new(
+        content,
+        EdgeSizes::uniform(10.0),
+        EdgeSizes::uniform(2.0),
+        EdgeSizes::uniform(5.0),
+        BoxSizing::ContentBox,
+    );
+
+    assert_eq!(box_model.clip_rect(Overflow::Visible), None);
+}
+
+#[test]
+fn test_overflow_parse() {
+    assert_eq!(Overflow::parse("hidden"), Ok(Overflow::Hidden));
+    assert_eq!(Overflow::parse(" SCROLL "), Ok(Overflow::Scroll));
+    assert!(Overflow::parse("overlay").is_err());
+    assert_eq!(Overflow::default(), Overflow::Visible);
+}
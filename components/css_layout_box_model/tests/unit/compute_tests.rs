@@ -90,8 +90,8 @@ fn test_compute_margin_with_percentages() {
 #[test]
 fn test_compute_content_box_with_explicit_dimensions() {
     let mut style = ComputedValues::default();
-    style.width = Length::new(200.0, LengthUnit::Px);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(200.0, LengthUnit::Px));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
 
     let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
     let content = compute_content_box(&style, &containing_block);
@@ -103,8 +103,8 @@ fn test_compute_content_box_with_explicit_dimensions() {
 #[test]
 fn test_compute_content_box_with_percentage_width() {
     let mut style = ComputedValues::default();
-    style.width = Length::new(50.0, LengthUnit::Percent);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(50.0, LengthUnit::Percent));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
 
     let containing_block = Rect::new(0.0, 0.0, 800.0, 600.0);
     let content = compute_content_box(&style, &containing_block);
@@ -158,8 +158,8 @@ fn test_box_model_calculator_compute_full_box_model() {
     let calculator = DefaultBoxModelCalculator;
     let mut style = ComputedValues::default();
 
-    style.width = Length::new(200.0, LengthUnit::Px);
-    style.height = Length::new(100.0, LengthUnit::Px);
+    style.width = Some(Length::new(200.0, LengthUnit::Px));
+    style.height = Some(Length::new(100.0, LengthUnit::Px));
     style.padding_top = Length::new(10.0, LengthUnit::Px);
     style.padding_right = Length::new(10.0, LengthUnit::Px);
     style.padding_bottom = Length::new(10.0, LengthUnit::Px);
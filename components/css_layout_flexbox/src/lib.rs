@@ -5,8 +5,9 @@
 //! - Flex item properties (flex-grow, flex-shrink, flex-basis, align-self, order)
 //! - Flexbox layout algorithm implementing CSS Flexbox specification
 //! - Gap properties support (gap, row-gap, column-gap)
+//! - Parsing of the `flex` shorthand
 
-use css_types::Length;
+use css_types::{parse_finite_f32, snap_span, CssError, CssValue, Gap, Length, LengthUnit, Rect};
 
 // ============================================================================
 // Core Enums
@@ -187,6 +188,27 @@ impl FlexContainer {
         self
     }
 
+    /// Set the gap from a parsed `gap` value
+    ///
+    /// `normal` leaves the gap unset, so it resolves to 0 in the
+    /// `effective_*_gap` getters. Absolute lengths are converted to pixels.
+    ///
+    /// # Errors
+    /// Returns `CssError::InvalidValue` for a length that needs a font size,
+    /// viewport or percentage basis, which the container does not know.
+    pub fn with_gap_value(mut self, gap: Gap) -> Result<Self, CssError> {
+        self.gap = match gap {
+            Gap::Normal => None,
+            Gap::Length(length) => Some(length.to_px().ok_or_else(|| {
+                CssError::InvalidValue(format!(
+                    "Gap must be an absolute length: {}",
+                    gap.serialize()
+                ))
+            })?),
+        };
+        Ok(self)
+    }
+
     /// Set the row gap
     pub fn with_row_gap(mut self, row_gap: f32) -> Self {
         self.row_gap = Some(row_gap);
@@ -240,11 +262,16 @@ impl FlexContainer {
     }
 
     /// Get the effective row gap (row_gap or gap or 0)
+    ///
+    /// An unset gap is `normal`, which is 0 for flex containers (unlike
+    /// multi-column layout, where it is 1em).
     pub fn effective_row_gap(&self) -> f32 {
         self.row_gap.or(self.gap).unwrap_or(0.0)
     }
 
     /// Get the effective column gap (column_gap or gap or 0)
+    ///
+    /// An unset gap is `normal`, which is 0 for flex containers.
     pub fn effective_column_gap(&self) -> f32 {
         self.column_gap.or(self.gap).unwrap_or(0.0)
     }
@@ -256,6 +283,139 @@ impl Default for FlexContainer {
     }
 }
 
+// ============================================================================
+// Flex Basis
+// ============================================================================
+
+/// Flex basis
+///
+/// Defines the initial main size of a flex item before free space is
+/// distributed.
+///
+/// # Examples
+/// ```
+/// use css_layout_flexbox::FlexBasis;
+/// use css_types::{Length, LengthUnit};
+///
+/// assert_eq!(FlexBasis::parse("content").unwrap(), FlexBasis::Content);
+/// assert_eq!(
+///     FlexBasis::parse("25%").unwrap(),
+///     FlexBasis::Length(Length::new(25.0, LengthUnit::Percent))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FlexBasis {
+    /// Use the item's main-size property (width or height)
+    #[default]
+    Auto,
+    /// Size based on the item's max-content size
+    Content,
+    /// Explicit length or percentage of the container's main size
+    Length(Length),
+}
+
+impl FlexBasis {
+    /// Parse a `flex-basis` value (`auto`, `content`, or a non-negative length)
+    pub fn parse(input: &str) -> Result<Self, CssError> {
+        match input.trim() {
+            "auto" => Ok(FlexBasis::Auto),
+            "content" => Ok(FlexBasis::Content),
+            other => {
+                let length = Length::parse(other)?;
+                if length.value() < 0.0 {
+                    return Err(CssError::InvalidValue(format!(
+                        "flex-basis cannot be negative: {}",
+                        other
+                    )));
+                }
+                Ok(FlexBasis::Length(length))
+            }
+        }
+    }
+}
+
+/// Parse the `flex` shorthand into (flex-grow, flex-shrink, flex-basis)
+///
+/// Supports the `none`, `auto` and `initial` keywords and the
+/// `<flex-grow> <flex-shrink>? || <flex-basis>` grammar. As in CSS, an
+/// omitted basis is `0px` when a grow factor is given, and omitted grow and
+/// shrink factors default to 1.
+///
+/// # Examples
+/// ```
+/// use css_layout_flexbox::{parse_flex_shorthand, FlexBasis};
+/// use css_types::{Length, LengthUnit};
+///
+/// assert_eq!(parse_flex_shorthand("none").unwrap(), (0.0, 0.0, FlexBasis::Auto));
+/// assert_eq!(
+///     parse_flex_shorthand("2").unwrap(),
+///     (2.0, 1.0, FlexBasis::Length(Length::new(0.0, LengthUnit::Px)))
+/// );
+/// assert_eq!(parse_flex_shorthand("content").unwrap(), (1.0, 1.0, FlexBasis::Content));
+/// ```
+pub fn parse_flex_shorthand(input: &str) -> Result<(f32, f32, FlexBasis), CssError> {
+    match input.trim() {
+        "none" => return Ok((0.0, 0.0, FlexBasis::Auto)),
+        "auto" => return Ok((1.0, 1.0, FlexBasis::Auto)),
+        "initial" => return Ok((0.0, 1.0, FlexBasis::Auto)),
+        _ => {}
+    }
+
+    let mut grow = None;
+    let mut shrink = None;
+    let mut basis = None;
+    let mut previous_was_number = false;
+
+    for token in input.split_whitespace() {
+        let number = match parse_finite_f32(token) {
+            Ok(number) => Some(number),
+            Err(e @ CssError::InvalidValue(_)) => return Err(e),
+            Err(_) => None,
+        };
+        if let Some(number) = number {
+            if number < 0.0 {
+                return Err(CssError::InvalidValue(format!(
+                    "flex factors cannot be negative: {}",
+                    token
+                )));
+            }
+            if grow.is_none() {
+                grow = Some(number);
+            } else if shrink.is_none() && previous_was_number {
+                shrink = Some(number);
+            } else if number == 0.0 && shrink.is_some() && basis.is_none() {
+                // A unitless zero after both factors is the basis
+                basis = Some(FlexBasis::Length(Length::new(0.0, LengthUnit::Px)));
+            } else {
+                return Err(CssError::ParseError(format!(
+                    "Unexpected flex factor: {}",
+                    token
+                )));
+            }
+            previous_was_number = true;
+        } else {
+            if basis.is_some() {
+                return Err(CssError::ParseError(format!(
+                    "Duplicate flex-basis: {}",
+                    token
+                )));
+            }
+            basis = Some(FlexBasis::parse(token)?);
+            previous_was_number = false;
+        }
+    }
+
+    match (grow, basis) {
+        (None, None) => Err(CssError::ParseError("Empty flex shorthand".to_string())),
+        (None, Some(basis)) => Ok((1.0, 1.0, basis)),
+        (Some(grow), basis) => Ok((
+            grow,
+            shrink.unwrap_or(1.0),
+            basis.unwrap_or(FlexBasis::Length(Length::new(0.0, LengthUnit::Px))),
+        )),
+    }
+}
+
 // ============================================================================
 // Flex Item
 // ============================================================================
@@ -280,7 +440,8 @@ pub struct FlexItem {
     height: f32,
     flex_grow: f32,
     flex_shrink: f32,
-    flex_basis: Option<Length>,
+    flex_basis: FlexBasis,
+    max_content_size: Option<(f32, f32)>,
     align_self: Option<AlignItems>,
     order: i32,
 }
@@ -304,7 +465,8 @@ impl FlexItem {
             height,
             flex_grow: 0.0,
             flex_shrink: 1.0,
-            flex_basis: None,
+            flex_basis: FlexBasis::Auto,
+            max_content_size: None,
             align_self: None,
             order: 0,
         }
@@ -323,8 +485,14 @@ impl FlexItem {
     }
 
     /// Set the flex-basis
-    pub fn with_flex_basis(mut self, flex_basis: Length) -> Self {
-        self.flex_basis = Some(flex_basis);
+    pub fn with_flex_basis(mut self, flex_basis: FlexBasis) -> Self {
+        self.flex_basis = flex_basis;
+        self
+    }
+
+    /// Set the max-content size used by `flex-basis: content`
+    pub fn with_max_content_size(mut self, width: f32, height: f32) -> Self {
+        self.max_content_size = Some((width, height));
         self
     }
 
@@ -361,10 +529,17 @@ impl FlexItem {
     }
 
     /// Get the flex-basis
-    pub fn flex_basis(&self) -> Option<Length> {
+    pub fn flex_basis(&self) -> FlexBasis {
         self.flex_basis
     }
 
+    /// Get the max-content size (width, height)
+    ///
+    /// Falls back to the item's width and height when no content size was set.
+    pub fn max_content_size(&self) -> (f32, f32) {
+        self.max_content_size.unwrap_or((self.width, self.height))
+    }
+
     /// Get the align-self property
     pub fn align_self(&self) -> Option<AlignItems> {
         self.align_self
@@ -461,6 +636,49 @@ impl FlexLayout {
     pub fn container_size(&self) -> (f32, f32) {
         self.container_size
     }
+
+    /// Get the union of all item boxes
+    ///
+    /// Unlike the container size this includes items that overflow the
+    /// container, so it gives the scrollable extent of a scroll container.
+    /// A layout without items has empty bounds at the origin.
+    pub fn content_bounds(&self) -> Rect {
+        Rect::union_all(
+            self.items
+                .iter()
+                .map(|item| Rect::new(item.x, item.y, item.width, item.height)),
+        )
+    }
+}
+
+// ============================================================================
+// Pixel Snapping
+// ============================================================================
+
+/// Snap a flex layout to the device-pixel grid
+///
+/// Item edges are rounded to the nearest device pixel and sizes are derived
+/// from the rounded edges, so items that shared an edge before snapping still
+/// share it afterwards. A non-positive or non-finite ratio leaves the layout
+/// unchanged.
+///
+/// # Arguments
+/// * `layout` - Layout to snap in place
+/// * `device_pixel_ratio` - Device pixels per CSS pixel
+pub fn snap_layout(layout: &mut FlexLayout, device_pixel_ratio: f32) {
+    if !device_pixel_ratio.is_finite() || device_pixel_ratio <= 0.0 {
+        return;
+    }
+
+    for item in &mut layout.items {
+        (item.x, item.width) = snap_span(item.x, item.width, device_pixel_ratio);
+        (item.y, item.height) = snap_span(item.y, item.height, device_pixel_ratio);
+    }
+    let (width, height) = layout.container_size;
+    layout.container_size = (
+        snap_span(0.0, width, device_pixel_ratio).1,
+        snap_span(0.0, height, device_pixel_ratio).1,
+    );
 }
 
 // ============================================================================
@@ -473,6 +691,12 @@ impl FlexLayout {
 pub trait FlexLayoutEngine {
     /// Compute layout for flex container and items
     ///
+    /// Items are placed in order-modified document order: ascending `order`,
+    /// with items of equal `order` keeping their position in `items`. Reverse
+    /// directions then mirror that sequence along the main axis, so the
+    /// first item in order-modified order sits at the main-end edge.
+    /// Layouts are returned in the order of `items`.
+    ///
     /// # Arguments
     /// * `container` - Flex container properties
     /// * `items` - Slice of flex items to layout
@@ -507,6 +731,96 @@ pub trait FlexLayoutEngine {
 /// ```
 pub struct DefaultFlexLayoutEngine;
 
+impl DefaultFlexLayoutEngine {
+    /// Compute the intrinsic main size of a flex container
+    ///
+    /// Returns `(min-content, max-content)`. The max-content size lays every
+    /// item out on a single line, summing item sizes and gaps. The min-content
+    /// size is the largest single item when wrapping is allowed, and equals
+    /// the max-content size for `nowrap` containers.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_layout_flexbox::{DefaultFlexLayoutEngine, FlexContainer, FlexItem, FlexWrap};
+    ///
+    /// let engine = DefaultFlexLayoutEngine;
+    /// let container = FlexContainer::new().with_wrap(FlexWrap::Wrap).with_gap(10.0);
+    /// let items = vec![FlexItem::new(50.0, 20.0), FlexItem::new(80.0, 20.0)];
+    /// assert_eq!(engine.intrinsic_main_size(&container, &items), (80.0, 140.0));
+    /// ```
+    pub fn intrinsic_main_size(&self, container: &FlexContainer, items: &[FlexItem]) -> (f32, f32) {
+        if items.is_empty() {
+            return (0.0, 0.0);
+        }
+
+        let is_row = matches!(
+            container.direction(),
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        let gap = if is_row {
+            container.effective_column_gap()
+        } else {
+            container.effective_row_gap()
+        };
+
+        let contributions: Vec<f32> = items
+            .iter()
+            .map(|item| intrinsic_contribution(item, is_row))
+            .collect();
+
+        let max_content =
+            contributions.iter().sum::<f32>() + gap * (contributions.len() - 1) as f32;
+        let min_content = if container.wrap() == FlexWrap::NoWrap {
+            max_content
+        } else {
+            contributions.iter().copied().fold(0.0, f32::max)
+        };
+
+        (min_content, max_content)
+    }
+
+    /// Compute the main-axis free space of a flex container
+    ///
+    /// This is the space left after resolving every item's flex base size
+    /// and subtracting the gaps, before `flex-grow` or `flex-shrink` is
+    /// applied. Positive values are distributed to growing items, negative
+    /// values are taken from shrinking items.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_layout_flexbox::{DefaultFlexLayoutEngine, FlexContainer, FlexItem};
+    ///
+    /// let engine = DefaultFlexLayoutEngine;
+    /// let items = vec![FlexItem::new(100.0, 50.0); 3];
+    /// assert_eq!(
+    ///     engine.compute_free_space(&FlexContainer::new(), &items, (400.0, 200.0)),
+    ///     100.0
+    /// );
+    /// ```
+    pub fn compute_free_space(
+        &self,
+        container: &FlexContainer,
+        items: &[FlexItem],
+        available_space: (f32, f32),
+    ) -> f32 {
+        let is_row = matches!(
+            container.direction(),
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        let (main_size, gap) = if is_row {
+            (available_space.0, container.effective_column_gap())
+        } else {
+            (available_space.1, container.effective_row_gap())
+        };
+
+        let base_sizes: Vec<f32> = items
+            .iter()
+            .map(|item| flex_base_size(item, is_row, main_size))
+            .collect();
+        free_space(&base_sizes, main_size, gap)
+    }
+}
+
 impl FlexLayoutEngine for DefaultFlexLayoutEngine {
     fn compute_flex_layout(
         &self,
@@ -518,7 +832,9 @@ impl FlexLayoutEngine for DefaultFlexLayoutEngine {
             return FlexLayout::new(vec![], available_space);
         }
 
-        // Sort items by order property
+        // Order-modified document order; the sort is stable, so items with
+        // equal `order` keep their source order. Reverse directions are
+        // handled by mirroring positions below, not by reversing this list.
         let mut indexed_items: Vec<(usize, &FlexItem)> = items.iter().enumerate().collect();
         indexed_items.sort_by_key(|(_, item)| item.order());
 
@@ -620,28 +936,19 @@ fn compute_flex_sizes(
     align_items: AlignItems,
     cross_size: f32,
 ) -> Vec<(usize, ComputedFlexItem)> {
-    let total_gaps = if indexed_items.len() > 1 {
-        gap * (indexed_items.len() - 1) as f32
-    } else {
-        0.0
-    };
-
     // Calculate initial sizes
-    let mut total_main_size = 0.0;
     let mut item_main_sizes: Vec<f32> = Vec::new();
     let mut total_grow = 0.0;
     let mut total_shrink_weight = 0.0;
 
     for (_, item) in indexed_items.iter() {
-        let item_main_size = if is_row { item.width() } else { item.height() };
-        item_main_sizes.push(item_main_size);
-        total_main_size += item_main_size;
+        let base_size = flex_base_size(item, is_row, main_size);
+        item_main_sizes.push(base_size);
         total_grow += item.flex_grow();
-        total_shrink_weight += item.flex_shrink();
+        total_shrink_weight += item.flex_shrink() * base_size;
     }
 
-    let available_main = main_size - total_gaps;
-    let free_space = available_main - total_main_size;
+    let free_space = free_space(&item_main_sizes, main_size, gap);
 
     // Apply flex grow or shrink
     if free_space > 0.0 && total_grow > 0.0 {
@@ -653,12 +960,48 @@ fn compute_flex_sizes(
             }
         }
     } else if free_space < 0.0 && total_shrink_weight > 0.0 {
-        // Shrink items
-        let shrink_space = -free_space;
-        for (i, (_, item)) in indexed_items.iter().enumerate() {
-            if item.flex_shrink() > 0.0 {
-                let shrink_amount = shrink_space * (item.flex_shrink() / total_shrink_weight);
-                item_main_sizes[i] = (item_main_sizes[i] - shrink_amount).max(0.0);
+        // Shrink items by their scaled shrink factor (flex-shrink times base
+        // size). An item that would shrink below zero is frozen at zero and
+        // the overflow is redistributed among the others.
+        let base_sizes = item_main_sizes.clone();
+        let mut frozen: Vec<bool> = indexed_items
+            .iter()
+            .map(|(_, item)| item.flex_shrink() <= 0.0)
+            .collect();
+
+        loop {
+            let overflow = -self::free_space(&item_main_sizes, main_size, gap);
+            let scaled_total: f32 = indexed_items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !frozen[*i])
+                .map(|(i, (_, item))| item.flex_shrink() * base_sizes[i])
+                .sum();
+            if overflow <= 0.0 || scaled_total <= 0.0 {
+                break;
+            }
+
+            let mut changed = false;
+            for (i, (_, item)) in indexed_items.iter().enumerate() {
+                if frozen[i] {
+                    continue;
+                }
+                let scaled = item.flex_shrink() * base_sizes[i];
+                let target = item_main_sizes[i] - overflow * (scaled / scaled_total);
+                if target < 0.0 {
+                    item_main_sizes[i] = 0.0;
+                    frozen[i] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                for (i, (_, item)) in indexed_items.iter().enumerate() {
+                    if !frozen[i] {
+                        let scaled = item.flex_shrink() * base_sizes[i];
+                        item_main_sizes[i] -= overflow * (scaled / scaled_total);
+                    }
+                }
+                break;
             }
         }
     }
@@ -694,6 +1037,68 @@ fn compute_flex_sizes(
     computed_items
 }
 
+/// Main-axis space left after placing items at their base sizes with gaps
+fn free_space(base_sizes: &[f32], main_size: f32, gap: f32) -> f32 {
+    let total_gaps = gap * base_sizes.len().saturating_sub(1) as f32;
+    main_size - total_gaps - base_sizes.iter().sum::<f32>()
+}
+
+/// Compute the flex base size of an item from its flex-basis
+///
+/// Percentages resolve against the container's main size. Units that need
+/// font or viewport context are not yet supported and fall back to `auto`.
+fn flex_base_size(item: &FlexItem, is_row: bool, container_main_size: f32) -> f32 {
+    let main_size_property = if is_row { item.width() } else { item.height() };
+
+    match item.flex_basis() {
+        FlexBasis::Auto => main_size_property,
+        FlexBasis::Content => {
+            let (width, height) = item.max_content_size();
+            if is_row {
+                width
+            } else {
+                height
+            }
+        }
+        FlexBasis::Length(length) => match length.unit() {
+            LengthUnit::Px => length.value(),
+            LengthUnit::Percent => (length.value() / 100.0) * container_main_size,
+            _ => main_size_property,
+        },
+    }
+}
+
+/// Compute an item's contribution to its container's intrinsic main size
+///
+/// Percentage bases cannot resolve against a container that is being sized,
+/// so they fall back to the main-size property like `auto`.
+fn intrinsic_contribution(item: &FlexItem, is_row: bool) -> f32 {
+    match item.flex_basis() {
+        FlexBasis::Length(length) if length.unit() == LengthUnit::Px => length.value(),
+        FlexBasis::Content => {
+            let (width, height) = item.max_content_size();
+            if is_row {
+                width
+            } else {
+                height
+            }
+        }
+        _ => {
+            if is_row {
+                item.width()
+            } else {
+                item.height()
+            }
+        }
+    }
+}
+
+/// Distribute free main-axis space according to justify-content
+///
+/// Items arrive positioned from the start with `gap` between them, so gaps
+/// act as minimum spacing and distributed space is added on top of them.
+/// When items overflow, `space-between` falls back to `flex-start` and
+/// `space-around`/`space-evenly` fall back to `center`.
 fn apply_justify_content(
     items: &mut [(usize, ComputedFlexItem)],
     justify: JustifyContent,
@@ -712,6 +1117,14 @@ fn apply_justify_content(
     };
     let free_space = main_size - total_item_size - total_gaps;
 
+    let justify = match justify {
+        JustifyContent::SpaceBetween if free_space < 0.0 => JustifyContent::FlexStart,
+        JustifyContent::SpaceAround | JustifyContent::SpaceEvenly if free_space < 0.0 => {
+            JustifyContent::Center
+        }
+        _ => justify,
+    };
+
     match justify {
         JustifyContent::FlexStart => {
             // Items are already positioned from start
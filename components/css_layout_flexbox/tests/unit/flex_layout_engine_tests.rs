@@ -1,6 +1,7 @@
 //! Unit tests for flexbox layout engine
 
 use css_layout_flexbox::*;
+use css_types::Rect;
 
 // ============================================================================
 // Basic Layout Tests
@@ -197,6 +198,70 @@ fn test_justify_content_space_evenly() {
     assert_eq!(item_layouts[2].x(), 350.0); // 200 + 100 + 50
 }
 
+#[test]
+fn test_justify_content_space_between_adds_to_gap() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new()
+        .with_justify_content(JustifyContent::SpaceBetween)
+        .with_gap(10.0);
+    let items = vec![
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(100.0, 50.0),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (500.0, 200.0));
+
+    let item_layouts = layout.items();
+    // Free space = 500 - 300 - 2 * 10 = 180, so 90 is added to each gap
+    for pair in item_layouts.windows(2) {
+        let spacing = pair[1].x() - (pair[0].x() + pair[0].width());
+        assert_eq!(spacing, 10.0 + 90.0);
+    }
+    assert_eq!(item_layouts[2].x() + item_layouts[2].width(), 500.0);
+}
+
+#[test]
+fn test_justify_content_space_around_adds_to_gap() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new()
+        .with_justify_content(JustifyContent::SpaceAround)
+        .with_gap(20.0);
+    let items = vec![FlexItem::new(100.0, 50.0), FlexItem::new(100.0, 50.0)];
+
+    let layout = engine.compute_flex_layout(&container, &items, (420.0, 200.0));
+
+    let item_layouts = layout.items();
+    // Free space = 420 - 200 - 20 = 200, 50 on each side of each item
+    assert_eq!(item_layouts[0].x(), 50.0);
+    assert_eq!(item_layouts[1].x(), 270.0); // 150 + 20 gap + 100
+}
+
+#[test]
+fn test_justify_content_space_distribution_on_overflow() {
+    let engine = DefaultFlexLayoutEngine;
+    let items = vec![
+        FlexItem::new(100.0, 50.0).with_flex_shrink(0.0),
+        FlexItem::new(100.0, 50.0).with_flex_shrink(0.0),
+    ];
+
+    // Space-between falls back to flex-start and keeps the gap
+    let container = FlexContainer::new()
+        .with_justify_content(JustifyContent::SpaceBetween)
+        .with_gap(10.0);
+    let layout = engine.compute_flex_layout(&container, &items, (150.0, 200.0));
+    assert_eq!(layout.items()[0].x(), 0.0);
+    assert_eq!(layout.items()[1].x(), 110.0);
+
+    // Space-evenly falls back to center: overflow of 60 split on both sides
+    let container = FlexContainer::new()
+        .with_justify_content(JustifyContent::SpaceEvenly)
+        .with_gap(10.0);
+    let layout = engine.compute_flex_layout(&container, &items, (150.0, 200.0));
+    assert_eq!(layout.items()[0].x(), -30.0);
+    assert_eq!(layout.items()[1].x(), 80.0);
+}
+
 // ============================================================================
 // Align Items Tests
 // ============================================================================
@@ -311,13 +376,246 @@ fn test_flex_shrink() {
 
     let item_layouts = layout.items();
     // Overflow = 400 - 300 = 100
-    // Total shrink weight = 1 + 2 = 3
+    // Scaled shrink factors = 1 * 200 : 2 * 200 = 1 : 2
     // Item 0 shrinks by 100 * 1/3 = 33.33... -> 166.67
     // Item 1 shrinks by 100 * 2/3 = 66.67... -> 133.33
     assert!((item_layouts[0].width() - 166.67).abs() < 0.1);
     assert!((item_layouts[1].width() - 133.33).abs() < 0.1);
 }
 
+#[test]
+fn test_flex_shrink_zero_keeps_size() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new();
+    let items = vec![
+        FlexItem::new(200.0, 50.0).with_flex_shrink(0.0),
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(300.0, 50.0),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 200.0));
+
+    let item_layouts = layout.items();
+    // Overflow = 600 - 400 = 200, absorbed 100 : 300 by the shrinkable items
+    assert_eq!(item_layouts[0].width(), 200.0);
+    assert!((item_layouts[1].width() - 50.0).abs() < 0.01);
+    assert!((item_layouts[2].width() - 150.0).abs() < 0.01);
+    assert!((item_layouts[2].x() + item_layouts[2].width() - 400.0).abs() < 0.01);
+}
+
+#[test]
+fn test_flex_shrink_freezes_items_at_zero() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new();
+    let items = vec![
+        FlexItem::new(200.0, 50.0).with_flex_shrink(0.0),
+        FlexItem::new(100.0, 50.0).with_flex_shrink(10.0),
+        FlexItem::new(100.0, 50.0),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (250.0, 200.0));
+
+    let item_layouts = layout.items();
+    // Item 1 would shrink past zero, so item 2 absorbs the rest
+    assert_eq!(item_layouts[0].width(), 200.0);
+    assert_eq!(item_layouts[1].width(), 0.0);
+    assert!((item_layouts[2].width() - 50.0).abs() < 0.01);
+}
+
+// ============================================================================
+// Flex Basis Tests
+// ============================================================================
+
+#[test]
+fn test_flex_basis_content_sizes_from_max_content() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_align_items(AlignItems::FlexStart);
+    let items = vec![
+        FlexItem::new(100.0, 50.0)
+            .with_max_content_size(180.0, 40.0)
+            .with_flex_basis(FlexBasis::Content),
+        FlexItem::new(100.0, 50.0)
+            .with_max_content_size(180.0, 40.0)
+            .with_flex_basis(FlexBasis::Auto),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 200.0));
+
+    // content uses the max-content width, auto falls back to the width property
+    assert_eq!(layout.items()[0].width(), 180.0);
+    assert_eq!(layout.items()[1].width(), 100.0);
+    assert_eq!(layout.items()[1].x(), 180.0);
+}
+
+#[test]
+fn test_flex_basis_content_column_uses_content_height() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new()
+        .with_direction(FlexDirection::Column)
+        .with_align_items(AlignItems::FlexStart);
+    let items = vec![FlexItem::new(100.0, 50.0)
+        .with_max_content_size(180.0, 75.0)
+        .with_flex_basis(FlexBasis::Content)];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 400.0));
+
+    assert_eq!(layout.items()[0].height(), 75.0);
+}
+
+#[test]
+fn test_flex_basis_length_overrides_width() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_align_items(AlignItems::FlexStart);
+    let (grow, shrink, basis) = parse_flex_shorthand("0 1 40%").unwrap();
+    let items = vec![FlexItem::new(100.0, 50.0)
+        .with_flex_grow(grow)
+        .with_flex_shrink(shrink)
+        .with_flex_basis(basis)];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 200.0));
+
+    // 40% of the 400px container
+    assert_eq!(layout.items()[0].width(), 160.0);
+
+    let items = vec![
+        FlexItem::new(100.0, 50.0).with_flex_basis(FlexBasis::Length(css_types::Length::new(
+            150.0,
+            css_types::LengthUnit::Px,
+        ))),
+    ];
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 200.0));
+
+    assert_eq!(layout.items()[0].width(), 150.0);
+}
+
+// ============================================================================
+// Intrinsic Size Tests
+// ============================================================================
+
+#[test]
+fn test_intrinsic_main_size_wrap() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new()
+        .with_wrap(FlexWrap::Wrap)
+        .with_column_gap(10.0);
+    let items = vec![
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(60.0, 50.0).with_flex_basis(FlexBasis::Content),
+        FlexItem::new(80.0, 50.0),
+    ];
+
+    let (min_content, max_content) = engine.intrinsic_main_size(&container, &items);
+
+    // Largest single item, and 100 + 60 + 80 plus two 10px gaps
+    assert_eq!(min_content, 100.0);
+    assert_eq!(max_content, 260.0);
+}
+
+#[test]
+fn test_intrinsic_main_size_nowrap_is_single_line() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_gap(5.0);
+    let items = vec![
+        FlexItem::new(40.0, 10.0),
+        FlexItem::new(50.0, 10.0).with_max_content_size(120.0, 10.0),
+        FlexItem::new(30.0, 10.0),
+    ];
+
+    let intrinsic = engine.intrinsic_main_size(&container, &items);
+
+    assert_eq!(intrinsic, (130.0, 130.0));
+
+    // Sizing from content picks up the item's max-content width
+    let items = vec![
+        FlexItem::new(40.0, 10.0),
+        FlexItem::new(50.0, 10.0)
+            .with_max_content_size(120.0, 10.0)
+            .with_flex_basis(FlexBasis::Content),
+        FlexItem::new(30.0, 10.0),
+    ];
+    assert_eq!(
+        engine.intrinsic_main_size(&container, &items),
+        (200.0, 200.0)
+    );
+}
+
+#[test]
+fn test_intrinsic_main_size_column_uses_heights() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new()
+        .with_direction(FlexDirection::Column)
+        .with_wrap(FlexWrap::Wrap)
+        .with_row_gap(4.0);
+    let items = vec![
+        FlexItem::new(100.0, 20.0),
+        FlexItem::new(100.0, 30.0),
+        FlexItem::new(100.0, 25.0),
+    ];
+
+    assert_eq!(engine.intrinsic_main_size(&container, &items), (30.0, 83.0));
+    assert_eq!(engine.intrinsic_main_size(&container, &[]), (0.0, 0.0));
+}
+
+// ============================================================================
+// Free Space Tests
+// ============================================================================
+
+#[test]
+fn test_free_space_three_items_in_row() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new();
+    let items = vec![
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(100.0, 50.0),
+        FlexItem::new(100.0, 50.0),
+    ];
+
+    assert_eq!(
+        engine.compute_free_space(&container, &items, (400.0, 200.0)),
+        100.0
+    );
+}
+
+#[test]
+fn test_free_space_is_before_grow_and_shrink() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_gap(10.0);
+    let items = vec![
+        FlexItem::new(100.0, 50.0).with_flex_grow(1.0),
+        FlexItem::new(0.0, 50.0).with_flex_basis(FlexBasis::Length(css_types::Length::new(
+            50.0,
+            css_types::LengthUnit::Percent,
+        ))),
+    ];
+
+    // 400 - 100 - 200 (50% basis) - 10 gap; growing does not consume it
+    assert_eq!(
+        engine.compute_free_space(&container, &items, (400.0, 200.0)),
+        90.0
+    );
+    // Overflowing items report negative free space
+    assert_eq!(
+        engine.compute_free_space(&container, &items, (200.0, 200.0)),
+        -10.0
+    );
+}
+
+#[test]
+fn test_free_space_column_uses_height() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_direction(FlexDirection::Column);
+    let items = vec![FlexItem::new(100.0, 30.0), FlexItem::new(100.0, 20.0)];
+
+    assert_eq!(
+        engine.compute_free_space(&container, &items, (400.0, 200.0)),
+        150.0
+    );
+    assert_eq!(
+        engine.compute_free_space(&container, &[], (400.0, 200.0)),
+        200.0
+    );
+}
+
 // ============================================================================
 // Order Tests
 // ============================================================================
@@ -347,6 +645,77 @@ fn test_item_order() {
     assert_eq!(item_layouts[2].x(), 0.0);
 }
 
+fn order_test_container(direction: FlexDirection) -> FlexContainer {
+    FlexContainer::new()
+        .with_direction(direction)
+        .with_align_items(AlignItems::FlexStart)
+}
+
+fn x_positions(layout: &FlexLayout) -> Vec<f32> {
+    layout.items().iter().map(|item| item.x()).collect()
+}
+
+#[test]
+fn test_equal_order_keeps_source_order() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = order_test_container(FlexDirection::Row);
+    let items = vec![
+        FlexItem::new(10.0, 10.0).with_order(1),
+        FlexItem::new(20.0, 10.0).with_order(1),
+        FlexItem::new(30.0, 10.0).with_order(0),
+        FlexItem::new(40.0, 10.0).with_order(1),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 100.0));
+
+    // Visual order: item 2, then items 0, 1, 3 in source order
+    assert_eq!(x_positions(&layout), vec![30.0, 40.0, 0.0, 60.0]);
+}
+
+#[test]
+fn test_negative_order_sorts_before_zero() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = order_test_container(FlexDirection::Row);
+    let items = vec![
+        FlexItem::new(10.0, 10.0),
+        FlexItem::new(20.0, 10.0).with_order(-1),
+        FlexItem::new(30.0, 10.0).with_order(-5),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (400.0, 100.0));
+
+    // Visual order: item 2 (-5), item 1 (-1), item 0 (0)
+    assert_eq!(x_positions(&layout), vec![50.0, 30.0, 0.0]);
+}
+
+#[test]
+fn test_order_with_row_reverse() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = order_test_container(FlexDirection::RowReverse);
+    let items = vec![
+        FlexItem::new(10.0, 10.0).with_order(1),
+        FlexItem::new(20.0, 10.0),
+        FlexItem::new(30.0, 10.0),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (100.0, 100.0));
+
+    // Order-modified order is items 1, 2, 0, laid out from the right edge
+    assert_eq!(x_positions(&layout), vec![40.0, 80.0, 50.0]);
+}
+
+#[test]
+fn test_column_reverse_equal_order_keeps_source_order_from_bottom() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = order_test_container(FlexDirection::ColumnReverse);
+    let items = vec![FlexItem::new(10.0, 10.0), FlexItem::new(10.0, 20.0)];
+
+    let layout = engine.compute_flex_layout(&container, &items, (100.0, 100.0));
+
+    assert_eq!(layout.items()[0].y(), 90.0);
+    assert_eq!(layout.items()[1].y(), 70.0);
+}
+
 // ============================================================================
 // Reverse Direction Tests
 // ============================================================================
@@ -420,3 +789,91 @@ fn test_single_item() {
     assert_eq!(item_layouts[0].width(), 100.0);
     assert_eq!(item_layouts[0].height(), 50.0);
 }
+
+// ============================================================================
+// Pixel Snapping Tests
+// ============================================================================
+
+fn thirds_layout() -> FlexLayout {
+    let third = 100.0 / 3.0;
+    FlexLayout::new(
+        vec![
+            FlexItemLayout::new(0.0, 0.25, third, 10.3),
+            FlexItemLayout::new(third, 0.25, third, 10.3),
+            FlexItemLayout::new(2.0 * third, 0.25, third, 10.3),
+        ],
+        (100.0, 10.55),
+    )
+}
+
+fn assert_gap_free(layout: &FlexLayout) {
+    for pair in layout.items().windows(2) {
+        assert_eq!(pair[0].x() + pair[0].width(), pair[1].x());
+    }
+    let last = layout.items().last().unwrap();
+    assert_eq!(last.x() + last.width(), 100.0);
+}
+
+#[test]
+fn test_snap_layout_dpr_1_keeps_items_adjacent() {
+    let mut layout = thirds_layout();
+    snap_layout(&mut layout, 1.0);
+
+    assert_gap_free(&layout);
+    for item in layout.items() {
+        assert_eq!(item.x().fract(), 0.0);
+        assert_eq!(item.width().fract(), 0.0);
+        assert_eq!(item.y(), 0.0);
+        assert_eq!(item.height(), 11.0);
+    }
+    assert_eq!(layout.container_size(), (100.0, 11.0));
+}
+
+#[test]
+fn test_snap_layout_dpr_2_keeps_items_adjacent() {
+    let mut layout = thirds_layout();
+    snap_layout(&mut layout, 2.0);
+
+    assert_gap_free(&layout);
+    for item in layout.items() {
+        assert_eq!((item.x() * 2.0).fract(), 0.0);
+        assert_eq!((item.width() * 2.0).fract(), 0.0);
+        assert_eq!(item.y(), 0.5);
+        assert_eq!(item.height(), 10.0);
+    }
+    assert_eq!(layout.container_size(), (100.0, 10.5));
+}
+
+#[test]
+fn test_snap_layout_ignores_invalid_ratio() {
+    let mut layout = thirds_layout();
+    snap_layout(&mut layout, 0.0);
+    snap_layout(&mut layout, f32::NAN);
+
+    assert_eq!(layout, thirds_layout());
+}
+
+// ============================================================================
+// Content Bounds Tests
+// ============================================================================
+
+#[test]
+fn test_content_bounds_include_overflowing_items() {
+    let engine = DefaultFlexLayoutEngine;
+    let container = FlexContainer::new().with_align_items(AlignItems::FlexStart);
+    let items = vec![
+        FlexItem::new(150.0, 50.0).with_flex_shrink(0.0),
+        FlexItem::new(100.0, 250.0).with_flex_shrink(0.0),
+    ];
+
+    let layout = engine.compute_flex_layout(&container, &items, (200.0, 100.0));
+
+    assert_eq!(layout.container_size(), (200.0, 100.0));
+    assert_eq!(layout.content_bounds(), Rect::new(0.0, 0.0, 250.0, 250.0));
+}
+
+#[test]
+fn test_content_bounds_of_empty_layout() {
+    let layout = FlexLayout::new(Vec::new(), (200.0, 100.0));
+    assert_eq!(layout.content_bounds(), Rect::default());
+}
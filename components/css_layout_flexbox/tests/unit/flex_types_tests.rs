@@ -1,7 +1,7 @@
 //! Unit tests for flexbox type definitions
 
 use css_layout_flexbox::*;
-use css_types::{Length, LengthUnit};
+use css_types::{CssError, Length, LengthUnit};
 
 // ============================================================================
 // FlexDirection Tests
@@ -188,6 +188,34 @@ fn test_flex_container_effective_gaps() {
     assert_eq!(container.effective_column_gap(), 0.0);
 }
 
+#[test]
+fn test_flex_container_normal_gap_is_zero() {
+    let normal = css_types::parse_gap("normal").unwrap();
+    let container = FlexContainer::new().with_gap_value(normal).unwrap();
+    assert_eq!(container.gap(), None);
+    assert_eq!(container.effective_row_gap(), 0.0);
+    assert_eq!(container.effective_column_gap(), 0.0);
+
+    let container = FlexContainer::new()
+        .with_gap_value(css_types::parse_gap("12px").unwrap())
+        .unwrap();
+    assert_eq!(container.effective_column_gap(), 12.0);
+}
+
+#[test]
+fn test_flex_container_gap_value_keeps_units() {
+    let container = FlexContainer::new()
+        .with_gap_value(css_types::parse_gap("1in").unwrap())
+        .unwrap();
+    assert_eq!(container.effective_column_gap(), 96.0);
+
+    let relative = css_types::parse_gap("2em").unwrap();
+    assert!(matches!(
+        FlexContainer::new().with_gap_value(relative),
+        Err(CssError::InvalidValue(_))
+    ));
+}
+
 // ============================================================================
 // FlexItem Tests
 // ============================================================================
@@ -199,7 +227,7 @@ fn test_flex_item_creation() {
     assert_eq!(item.height(), 50.0);
     assert_eq!(item.flex_grow(), 0.0);
     assert_eq!(item.flex_shrink(), 1.0);
-    assert_eq!(item.flex_basis(), None);
+    assert_eq!(item.flex_basis(), FlexBasis::Auto);
     assert_eq!(item.align_self(), None);
     assert_eq!(item.order(), 0);
 }
@@ -218,9 +246,18 @@ fn test_flex_item_with_flex_shrink() {
 
 #[test]
 fn test_flex_item_with_flex_basis() {
-    let basis = Length::new(200.0, LengthUnit::Px);
+    let basis = FlexBasis::Length(Length::new(200.0, LengthUnit::Px));
     let item = FlexItem::new(100.0, 50.0).with_flex_basis(basis);
-    assert_eq!(item.flex_basis(), Some(basis));
+    assert_eq!(item.flex_basis(), basis);
+}
+
+#[test]
+fn test_flex_item_max_content_size_defaults_to_dimensions() {
+    let item = FlexItem::new(100.0, 50.0);
+    assert_eq!(item.max_content_size(), (100.0, 50.0));
+
+    let item = item.with_max_content_size(180.0, 20.0);
+    assert_eq!(item.max_content_size(), (180.0, 20.0));
 }
 
 #[test]
@@ -235,6 +272,79 @@ fn test_flex_item_with_order() {
     assert_eq!(item.order(), 5);
 }
 
+// ============================================================================
+// FlexBasis Tests
+// ============================================================================
+
+#[test]
+fn test_flex_basis_parse() {
+    assert_eq!(FlexBasis::parse("auto").unwrap(), FlexBasis::Auto);
+    assert_eq!(FlexBasis::parse("content").unwrap(), FlexBasis::Content);
+    assert_eq!(
+        FlexBasis::parse("120px").unwrap(),
+        FlexBasis::Length(Length::new(120.0, LengthUnit::Px))
+    );
+    assert!(FlexBasis::parse("-10px").is_err());
+    assert!(FlexBasis::parse("fill").is_err());
+}
+
+#[test]
+fn test_parse_flex_shorthand_keywords() {
+    assert_eq!(
+        parse_flex_shorthand("none").unwrap(),
+        (0.0, 0.0, FlexBasis::Auto)
+    );
+    assert_eq!(
+        parse_flex_shorthand("auto").unwrap(),
+        (1.0, 1.0, FlexBasis::Auto)
+    );
+    assert_eq!(
+        parse_flex_shorthand("initial").unwrap(),
+        (0.0, 1.0, FlexBasis::Auto)
+    );
+}
+
+#[test]
+fn test_parse_flex_shorthand_forms() {
+    let zero = FlexBasis::Length(Length::new(0.0, LengthUnit::Px));
+    assert_eq!(parse_flex_shorthand("2").unwrap(), (2.0, 1.0, zero));
+    assert_eq!(parse_flex_shorthand("2 3").unwrap(), (2.0, 3.0, zero));
+    assert_eq!(
+        parse_flex_shorthand("1 0 content").unwrap(),
+        (1.0, 0.0, FlexBasis::Content)
+    );
+    assert_eq!(
+        parse_flex_shorthand("50%").unwrap(),
+        (
+            1.0,
+            1.0,
+            FlexBasis::Length(Length::new(50.0, LengthUnit::Percent))
+        )
+    );
+    assert_eq!(
+        parse_flex_shorthand("content 2").unwrap(),
+        (2.0, 1.0, FlexBasis::Content)
+    );
+}
+
+#[test]
+fn test_parse_flex_shorthand_unitless_zero_basis() {
+    let zero = FlexBasis::Length(Length::new(0.0, LengthUnit::Px));
+    assert_eq!(parse_flex_shorthand("1 1 0").unwrap(), (1.0, 1.0, zero));
+    assert_eq!(parse_flex_shorthand("2 0 0").unwrap(), (2.0, 0.0, zero));
+    assert!(parse_flex_shorthand("1 1 0 0").is_err());
+    assert!(parse_flex_shorthand("1 10px 0").is_err());
+}
+
+#[test]
+fn test_parse_flex_shorthand_invalid() {
+    assert!(parse_flex_shorthand("").is_err());
+    assert!(parse_flex_shorthand("1 2 3").is_err());
+    assert!(parse_flex_shorthand("1 10px 2").is_err());
+    assert!(parse_flex_shorthand("10px 20px").is_err());
+    assert!(parse_flex_shorthand("-1").is_err());
+}
+
 // ============================================================================
 // FlexItemLayout Tests
 // ============================================================================
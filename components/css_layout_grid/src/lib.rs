@@ -2,11 +2,13 @@
 //!
 //! This module provides CSS Grid layout computation including:
 //! - Grid container properties (template rows/columns, auto-flow, gaps)
-//! - Grid item placement (explicit and auto-placement)
-//! - Track sizing with fr units
+//! - Grid item placement (explicit, by named area, and auto-placement)
+//! - `grid-template-areas` parsing
+//! - Track sizing with fr units, content-sized (auto/min/max-content),
+//!   `minmax()` and `fit-content()` tracks, and track list parsing
 //! - Grid layout engine trait and implementation
 
-use css_types::{Length, LengthUnit};
+use css_types::{parse_finite_f32, snap_span, CssError, CssValue, Gap, Length, LengthUnit, Rect};
 
 // ============================================================================
 // Grid Auto Flow
@@ -43,6 +45,230 @@ pub enum TrackSizing {
     MaxContent,
     /// Auto sizing
     Auto,
+    /// `minmax(min, max)`: at least `min`, growing toward `max`
+    ///
+    /// The minimum is never flexible; a flexible maximum makes the track
+    /// take part in the fr distribution.
+    MinMax(Box<TrackSizing>, Box<TrackSizing>),
+    /// `fit-content(limit)`: content-sized like `auto`, but no larger than
+    /// `limit` unless the min-content size is
+    FitContent(Length),
+}
+
+/// Inputs needed to turn a track's lengths into pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackResolutionContext {
+    available_size: f32,
+}
+
+impl TrackResolutionContext {
+    /// Create a context for tracks laid out in `available_size` pixels
+    pub fn new(available_size: f32) -> Self {
+        Self { available_size }
+    }
+
+    /// Space along the track axis; the basis for percentage tracks
+    pub fn available_size(&self) -> f32 {
+        self.available_size
+    }
+
+    /// Resolve a length to pixels
+    ///
+    /// Absolute units convert directly and percentages resolve against the
+    /// available size. Font- and viewport-relative units are not supported
+    /// and give `None`.
+    fn resolve_length(&self, length: &Length) -> Option<f32> {
+        match length.unit() {
+            LengthUnit::Percent => Some(length.value() / 100.0 * self.available_size),
+            unit => unit.px_per_unit().map(|px| length.value() * px),
+        }
+    }
+}
+
+impl TrackSizing {
+    /// Resolve this track to a size in pixels
+    ///
+    /// `fr_value` is the size of one `fr` and `content_size` the size of the
+    /// track's content; content-sized tracks take `content_size`, capped at
+    /// the limit for `fit-content()`. A `minmax()` track takes its maximum,
+    /// floored at its minimum. Lengths in unsupported units resolve to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_layout_grid::{TrackResolutionContext, TrackSizing};
+    /// use css_types::{Length, LengthUnit};
+    ///
+    /// let ctx = TrackResolutionContext::new(400.0);
+    /// assert_eq!(TrackSizing::Flexible(2.0).resolve(&ctx, 50.0, 0.0), 100.0);
+    /// let quarter = TrackSizing::Fixed(Length::new(25.0, LengthUnit::Percent));
+    /// assert_eq!(quarter.resolve(&ctx, 0.0, 0.0), 100.0);
+    /// ```
+    pub fn resolve(&self, ctx: &TrackResolutionContext, fr_value: f32, content_size: f32) -> f32 {
+        match self {
+            TrackSizing::Fixed(length) => ctx.resolve_length(length).unwrap_or(0.0),
+            TrackSizing::Flexible(fr) => fr * fr_value,
+            TrackSizing::MinContent | TrackSizing::MaxContent | TrackSizing::Auto => content_size,
+            TrackSizing::FitContent(limit) => match ctx.resolve_length(limit) {
+                Some(limit) => content_size.min(limit),
+                None => content_size,
+            },
+            TrackSizing::MinMax(min, max) => {
+                let min = min.resolve(ctx, fr_value, content_size);
+                max.resolve(ctx, fr_value, content_size).max(min)
+            }
+        }
+    }
+}
+
+/// Parse a `grid-template-rows` / `grid-template-columns` track list
+///
+/// Supports lengths, `<n>fr`, `auto`, `min-content`, `max-content`,
+/// `minmax(min, max)` and `fit-content(limit)`. `none` is an empty track
+/// list.
+///
+/// # Examples
+/// ```
+/// use css_layout_grid::{parse_track_list, TrackSizing};
+///
+/// let tracks = parse_track_list("100px minmax(100px, 1fr) auto").unwrap();
+/// assert_eq!(tracks.len(), 3);
+/// assert!(matches!(tracks[1], TrackSizing::MinMax(_, _)));
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` for unrecognized or malformed tracks and
+/// `CssError::InvalidValue` for negative sizes or limits, or a flexible
+/// `minmax()` minimum.
+pub fn parse_track_list(input: &str) -> Result<Vec<TrackSizing>, CssError> {
+    let input = input.trim();
+    if input == "none" {
+        return Ok(Vec::new());
+    }
+
+    let tracks = split_track_list(input)
+        .into_iter()
+        .map(parse_track_size)
+        .collect::<Result<Vec<_>, _>>()?;
+    if tracks.is_empty() {
+        return Err(CssError::ParseError("Empty track list".to_string()));
+    }
+    Ok(tracks)
+}
+
+/// Parse a single track size
+fn parse_track_size(input: &str) -> Result<TrackSizing, CssError> {
+    match input {
+        "auto" => return Ok(TrackSizing::Auto),
+        "min-content" => return Ok(TrackSizing::MinContent),
+        "max-content" => return Ok(TrackSizing::MaxContent),
+        _ => {}
+    }
+
+    if let Some(arguments) = input
+        .strip_prefix("minmax(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let (min, max) = arguments.split_once(',').ok_or_else(|| {
+            CssError::ParseError(format!("minmax() requires two arguments: {}", input))
+        })?;
+        let min = parse_track_size(min.trim())?;
+        let max = parse_track_size(max.trim())?;
+        return match (&min, &max) {
+            (TrackSizing::MinMax(_, _) | TrackSizing::FitContent(_), _)
+            | (_, TrackSizing::MinMax(_, _) | TrackSizing::FitContent(_)) => Err(
+                CssError::ParseError(format!("minmax() arguments cannot be functions: {}", input)),
+            ),
+            (TrackSizing::Flexible(_), _) => Err(CssError::InvalidValue(format!(
+                "minmax() minimum cannot be flexible: {}",
+                input
+            ))),
+            _ => Ok(TrackSizing::MinMax(Box::new(min), Box::new(max))),
+        };
+    }
+
+    if let Some(limit) = input
+        .strip_prefix("fit-content(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let limit = Length::parse(limit)?;
+        if limit.value() < 0.0 {
+            return Err(CssError::InvalidValue(format!(
+                "fit-content() limit cannot be negative: {}",
+                input
+            )));
+        }
+        return Ok(TrackSizing::FitContent(limit));
+    }
+
+    if let Some(number) = input.strip_suffix("fr") {
+        let fr = parse_finite_f32(number)?;
+        if fr < 0.0 {
+            return Err(CssError::InvalidValue(format!(
+                "Flexible track size cannot be negative: {}",
+                input
+            )));
+        }
+        return Ok(TrackSizing::Flexible(fr));
+    }
+
+    let length = Length::parse(input)?;
+    if length.value() < 0.0 {
+        return Err(CssError::InvalidValue(format!(
+            "Track size cannot be negative: {}",
+            input
+        )));
+    }
+    Ok(TrackSizing::Fixed(length))
+}
+
+/// Split a track list on whitespace outside parentheses
+fn split_track_list(input: &str) -> Vec<&str> {
+    let mut tracks = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+
+    for (i, ch) in input.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if ch.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    tracks.push(&input[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        tracks.push(&input[s..]);
+    }
+
+    tracks
+}
+
+// ============================================================================
+// Content Alignment
+// ============================================================================
+
+/// Alignment of the grid tracks within the container
+/// (`justify-content` / `align-content`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentAlignment {
+    /// Tracks packed at the start of the container
+    #[default]
+    Start,
+    /// Tracks packed at the end of the container
+    End,
+    /// Tracks centered in the container
+    Center,
+    /// Leftover space between tracks, first/last flush with the edges
+    SpaceBetween,
+    /// Equal space around each track (half-size at the edges)
+    SpaceAround,
+    /// Equal space between tracks and at the edges
+    SpaceEvenly,
 }
 
 // ============================================================================
@@ -61,6 +287,243 @@ pub enum GridLine {
     Span(i32),
 }
 
+// ============================================================================
+// Grid Template Areas
+// ============================================================================
+
+/// Rectangular region covered by a named grid area
+///
+/// Lines are 1-indexed like [`GridLine::LineNumber`]; the end lines are
+/// exclusive, so an area covering only the first cell spans lines 1 to 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridArea {
+    row_start: usize,
+    row_end: usize,
+    column_start: usize,
+    column_end: usize,
+}
+
+impl GridArea {
+    /// Create a grid area from its bounding lines
+    pub fn new(row_start: usize, row_end: usize, column_start: usize, column_end: usize) -> Self {
+        Self {
+            row_start,
+            row_end,
+            column_start,
+            column_end,
+        }
+    }
+
+    /// Get the first row line
+    pub fn row_start(&self) -> usize {
+        self.row_start
+    }
+
+    /// Get the row line after the last row
+    pub fn row_end(&self) -> usize {
+        self.row_end
+    }
+
+    /// Get the first column line
+    pub fn column_start(&self) -> usize {
+        self.column_start
+    }
+
+    /// Get the column line after the last column
+    pub fn column_end(&self) -> usize {
+        self.column_end
+    }
+
+    /// Number of rows the area spans
+    pub fn row_span(&self) -> usize {
+        self.row_end - self.row_start
+    }
+
+    /// Number of columns the area spans
+    pub fn column_span(&self) -> usize {
+        self.column_end - self.column_start
+    }
+}
+
+/// Named areas declared by `grid-template-areas`
+///
+/// Areas are kept in row-major order of their top-left cell.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GridAreas {
+    row_count: usize,
+    column_count: usize,
+    areas: Vec<(String, GridArea)>,
+}
+
+impl GridAreas {
+    /// Get the number of rows in the template
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get the number of columns in the template
+    pub fn column_count(&self) -> usize {
+        self.column_count
+    }
+
+    /// Check whether no areas are declared
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+
+    /// Look up an area by name
+    pub fn get(&self, name: &str) -> Option<GridArea> {
+        self.areas
+            .iter()
+            .find(|(area_name, _)| area_name == name)
+            .map(|(_, area)| *area)
+    }
+
+    /// Iterate over the areas in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, GridArea)> {
+        self.areas.iter().map(|(name, area)| (name.as_str(), *area))
+    }
+
+    /// Resolve an implicit named row line (`<area>-start` or `<area>-end`)
+    pub fn row_line(&self, line_name: &str) -> Option<usize> {
+        self.named_line(line_name, |area| (area.row_start, area.row_end))
+    }
+
+    /// Resolve an implicit named column line (`<area>-start` or `<area>-end`)
+    pub fn column_line(&self, line_name: &str) -> Option<usize> {
+        self.named_line(line_name, |area| (area.column_start, area.column_end))
+    }
+
+    fn named_line(
+        &self,
+        line_name: &str,
+        lines: impl Fn(&GridArea) -> (usize, usize),
+    ) -> Option<usize> {
+        if let Some(name) = line_name.strip_suffix("-start") {
+            self.get(name).map(|area| lines(&area).0)
+        } else if let Some(name) = line_name.strip_suffix("-end") {
+            self.get(name).map(|area| lines(&area).1)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse the `grid-template-areas` property
+///
+/// Each quoted string is one row of whitespace-separated cell names. A cell
+/// of one or more `.` characters is a null cell that belongs to no area.
+/// `none` declares no areas.
+///
+/// # Examples
+/// ```
+/// use css_layout_grid::parse_grid_template_areas;
+///
+/// let areas = parse_grid_template_areas(r#""header header" "nav main""#).unwrap();
+/// let header = areas.get("header").unwrap();
+/// assert_eq!((header.row_span(), header.column_span()), (1, 2));
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` for malformed strings or rows of unequal
+/// length, and `CssError::InvalidValue` if an area is not a single rectangle.
+pub fn parse_grid_template_areas(input: &str) -> Result<GridAreas, CssError> {
+    let input = input.trim();
+    if input == "none" {
+        return Ok(GridAreas::default());
+    }
+
+    let rows = split_area_strings(input)?;
+    let column_count = rows[0].len();
+    if let Some(row) = rows.iter().find(|row| row.len() != column_count) {
+        return Err(CssError::ParseError(format!(
+            "Grid template area rows must have {} columns, found {}",
+            column_count,
+            row.len()
+        )));
+    }
+
+    // Bounding box and cell count of every named area, in row-major order
+    let mut bounds: Vec<(String, GridArea, usize)> = Vec::new();
+    for (row, cells) in rows.iter().enumerate() {
+        for (column, cell) in cells.iter().enumerate() {
+            if cell.chars().all(|c| c == '.') {
+                continue;
+            }
+            match bounds.iter_mut().find(|(name, _, _)| name == cell) {
+                Some((_, area, count)) => {
+                    area.row_end = area.row_end.max(row + 2);
+                    area.column_start = area.column_start.min(column + 1);
+                    area.column_end = area.column_end.max(column + 2);
+                    *count += 1;
+                }
+                None => bounds.push((
+                    cell.clone(),
+                    GridArea::new(row + 1, row + 2, column + 1, column + 2),
+                    1,
+                )),
+            }
+        }
+    }
+
+    for (name, area, count) in &bounds {
+        if area.row_span() * area.column_span() != *count {
+            return Err(CssError::InvalidValue(format!(
+                "Grid area '{}' is not a rectangle",
+                name
+            )));
+        }
+    }
+
+    Ok(GridAreas {
+        row_count: rows.len(),
+        column_count,
+        areas: bounds
+            .into_iter()
+            .map(|(name, area, _)| (name, area))
+            .collect(),
+    })
+}
+
+/// Split the quoted rows of `grid-template-areas` into cell names
+fn split_area_strings(input: &str) -> Result<Vec<Vec<String>>, CssError> {
+    let mut rows = Vec::new();
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'');
+        let Some(quote) = quote else {
+            return Err(CssError::ParseError(format!(
+                "Expected a quoted grid area row: {}",
+                rest
+            )));
+        };
+        let end = rest[1..]
+            .find(quote)
+            .ok_or_else(|| CssError::ParseError("Unterminated grid area string".to_string()))?;
+
+        let cells: Vec<String> = rest[1..end + 1]
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        if cells.is_empty() {
+            return Err(CssError::ParseError(
+                "Grid area row must not be empty".to_string(),
+            ));
+        }
+        rows.push(cells);
+        rest = rest[end + 2..].trim_start();
+    }
+
+    if rows.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty grid-template-areas value".to_string(),
+        ));
+    }
+
+    Ok(rows)
+}
+
 // ============================================================================
 // Grid Container
 // ============================================================================
@@ -76,6 +539,9 @@ pub struct GridContainer {
     gap: Option<f32>,
     row_gap: Option<f32>,
     column_gap: Option<f32>,
+    justify_content: ContentAlignment,
+    align_content: ContentAlignment,
+    template_areas: GridAreas,
 }
 
 impl GridContainer {
@@ -90,9 +556,99 @@ impl GridContainer {
             gap: None,
             row_gap: None,
             column_gap: None,
+            justify_content: ContentAlignment::default(),
+            align_content: ContentAlignment::default(),
+            template_areas: GridAreas::default(),
         }
     }
 
+    /// Set the template rows
+    pub fn with_template_rows(mut self, rows: Vec<TrackSizing>) -> Self {
+        self.template_rows = rows;
+        self
+    }
+
+    /// Set the template columns
+    pub fn with_template_columns(mut self, columns: Vec<TrackSizing>) -> Self {
+        self.template_columns = columns;
+        self
+    }
+
+    /// Set the auto rows
+    pub fn with_auto_rows(mut self, rows: Vec<TrackSizing>) -> Self {
+        self.auto_rows = rows;
+        self
+    }
+
+    /// Set the auto columns
+    pub fn with_auto_columns(mut self, columns: Vec<TrackSizing>) -> Self {
+        self.auto_columns = columns;
+        self
+    }
+
+    /// Set the auto flow
+    pub fn with_auto_flow(mut self, flow: GridAutoFlow) -> Self {
+        self.auto_flow = flow;
+        self
+    }
+
+    /// Set the gap (shorthand for row-gap and column-gap)
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    /// Set the gap from a parsed `gap` value
+    ///
+    /// `normal` leaves the gap unset, so it resolves to 0 in the
+    /// `effective_*_gap` getters. Absolute lengths are converted to pixels.
+    ///
+    /// # Errors
+    /// Returns `CssError::InvalidValue` for a length that needs a font size,
+    /// viewport or percentage basis, which the container does not know.
+    pub fn with_gap_value(mut self, gap: Gap) -> Result<Self, CssError> {
+        self.gap = match gap {
+            Gap::Normal => None,
+            Gap::Length(length) => Some(length.to_px().ok_or_else(|| {
+                CssError::InvalidValue(format!(
+                    "Gap must be an absolute length: {}",
+                    gap.serialize()
+                ))
+            })?),
+        };
+        Ok(self)
+    }
+
+    /// Set the row gap
+    pub fn with_row_gap(mut self, row_gap: f32) -> Self {
+        self.row_gap = Some(row_gap);
+        self
+    }
+
+    /// Set the column gap
+    pub fn with_column_gap(mut self, column_gap: f32) -> Self {
+        self.column_gap = Some(column_gap);
+        self
+    }
+
+    /// Set the justify-content alignment
+    pub fn with_justify_content(mut self, justify_content: ContentAlignment) -> Self {
+        self.justify_content = justify_content;
+        self
+    }
+
+    /// Set the align-content alignment
+    pub fn with_align_content(mut self, align_content: ContentAlignment) -> Self {
+        self.align_content = align_content;
+        self
+    }
+
+    /// Set the template areas
+    pub fn with_template_areas(mut self, areas: GridAreas) -> Self {
+        self.template_areas = areas;
+        self
+    }
+
     /// Get template rows
     pub fn template_rows(&self) -> &[TrackSizing] {
         &self.template_rows
@@ -173,12 +729,47 @@ impl GridContainer {
         self.column_gap = gap;
     }
 
+    /// Get justify-content (column track alignment)
+    pub fn justify_content(&self) -> ContentAlignment {
+        self.justify_content
+    }
+
+    /// Set justify-content
+    pub fn set_justify_content(&mut self, alignment: ContentAlignment) {
+        self.justify_content = alignment;
+    }
+
+    /// Get align-content (row track alignment)
+    pub fn align_content(&self) -> ContentAlignment {
+        self.align_content
+    }
+
+    /// Set align-content
+    pub fn set_align_content(&mut self, alignment: ContentAlignment) {
+        self.align_content = alignment;
+    }
+
+    /// Get template areas
+    pub fn template_areas(&self) -> &GridAreas {
+        &self.template_areas
+    }
+
+    /// Set template areas
+    pub fn set_template_areas(&mut self, areas: GridAreas) {
+        self.template_areas = areas;
+    }
+
     /// Get effective row gap (row_gap if set, otherwise gap)
+    ///
+    /// An unset gap is `normal`, which is 0 for grid containers (unlike
+    /// multi-column layout, where it is 1em).
     pub fn effective_row_gap(&self) -> f32 {
         self.row_gap.or(self.gap).unwrap_or(0.0)
     }
 
     /// Get effective column gap (column_gap if set, otherwise gap)
+    ///
+    /// An unset gap is `normal`, which is 0 for grid containers.
     pub fn effective_column_gap(&self) -> f32 {
         self.column_gap.or(self.gap).unwrap_or(0.0)
     }
@@ -201,6 +792,7 @@ pub struct GridItem {
     row_end: GridLine,
     column_start: GridLine,
     column_end: GridLine,
+    area: Option<String>,
 }
 
 impl GridItem {
@@ -211,6 +803,7 @@ impl GridItem {
             row_end: GridLine::Auto,
             column_start: GridLine::Auto,
             column_end: GridLine::Auto,
+            area: None,
         }
     }
 
@@ -253,6 +846,20 @@ impl GridItem {
     pub fn set_column_end(&mut self, line: GridLine) {
         self.column_end = line;
     }
+
+    /// Get the named area the item is placed in
+    pub fn area(&self) -> Option<&str> {
+        self.area.as_deref()
+    }
+
+    /// Place the item in a named area (`grid-area: <name>`)
+    ///
+    /// The name is looked up in the container's template areas during
+    /// layout and takes precedence over the line placement. Unknown names
+    /// fall back to the line placement.
+    pub fn set_area(&mut self, name: Option<&str>) {
+        self.area = name.map(str::to_string);
+    }
 }
 
 impl Default for GridItem {
@@ -261,6 +868,36 @@ impl Default for GridItem {
     }
 }
 
+/// Intrinsic content sizes of a grid item
+///
+/// Used to size `auto`, `min-content` and `max-content` tracks from the
+/// items placed in them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ItemContentSize {
+    min_content: (f32, f32),
+    max_content: (f32, f32),
+}
+
+impl ItemContentSize {
+    /// Create content sizes from (width, height) pairs
+    pub fn new(min_content: (f32, f32), max_content: (f32, f32)) -> Self {
+        Self {
+            min_content,
+            max_content,
+        }
+    }
+
+    /// Get min-content size (width, height)
+    pub fn min_content(&self) -> (f32, f32) {
+        self.min_content
+    }
+
+    /// Get max-content size (width, height)
+    pub fn max_content(&self) -> (f32, f32) {
+        self.max_content
+    }
+}
+
 // ============================================================================
 // Grid Layout Results
 // ============================================================================
@@ -349,6 +986,7 @@ impl GridItemLayout {
 pub struct GridLayout {
     items: Vec<GridItemLayout>,
     container_size: (f32, f32),
+    unplaced: Vec<usize>,
 }
 
 impl GridLayout {
@@ -357,18 +995,77 @@ impl GridLayout {
         Self {
             items,
             container_size,
+            unplaced: Vec::new(),
         }
     }
 
+    /// Builder: record the input indices of items that could not be placed
+    pub fn with_unplaced(mut self, unplaced: Vec<usize>) -> Self {
+        self.unplaced = unplaced;
+        self
+    }
+
     /// Get item layouts
+    ///
+    /// Only placed items are included; see [`GridLayout::unplaced`].
     pub fn items(&self) -> &[GridItemLayout] {
         &self.items
     }
 
+    /// Get the indices (into the input items) of items that were dropped
+    ///
+    /// Items are dropped when they start outside the explicit grid or when
+    /// their explicit placement overlaps an earlier explicitly placed item.
+    pub fn unplaced(&self) -> &[usize] {
+        &self.unplaced
+    }
+
     /// Get container size (width, height)
     pub fn container_size(&self) -> (f32, f32) {
         self.container_size
     }
+
+    /// Get the union of all item boxes
+    ///
+    /// Unlike the container size this includes items that overflow the
+    /// container, so it gives the scrollable extent of a scroll container.
+    /// A layout without items has empty bounds at the origin.
+    pub fn content_bounds(&self) -> Rect {
+        Rect::union_all(
+            self.items
+                .iter()
+                .map(|item| Rect::new(item.x, item.y, item.width, item.height)),
+        )
+    }
+}
+
+// ============================================================================
+// Pixel Snapping
+// ============================================================================
+
+/// Snap a grid layout to the device-pixel grid
+///
+/// Item edges are rounded to the nearest device pixel and sizes are derived
+/// from the rounded edges, so items in adjacent tracks stay flush. A
+/// non-positive or non-finite ratio leaves the layout unchanged.
+///
+/// # Arguments
+/// * `layout` - Layout to snap in place
+/// * `device_pixel_ratio` - Device pixels per CSS pixel
+pub fn snap_grid_layout(layout: &mut GridLayout, device_pixel_ratio: f32) {
+    if !device_pixel_ratio.is_finite() || device_pixel_ratio <= 0.0 {
+        return;
+    }
+
+    for item in &mut layout.items {
+        (item.x, item.width) = snap_span(item.x, item.width, device_pixel_ratio);
+        (item.y, item.height) = snap_span(item.y, item.height, device_pixel_ratio);
+    }
+    let (width, height) = layout.container_size;
+    layout.container_size = (
+        snap_span(0.0, width, device_pixel_ratio).1,
+        snap_span(0.0, height, device_pixel_ratio).1,
+    );
 }
 
 // ============================================================================
@@ -417,92 +1114,439 @@ pub trait GridLayoutEngine {
 /// Basic implementation of grid layout engine
 pub struct BasicGridLayoutEngine;
 
+/// Resolved grid area of a placed item (0-based track indices)
+#[derive(Debug, Clone, Copy)]
+struct ItemPlacement {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+/// Content size an item contributes to the tracks it spans
+#[derive(Debug, Clone, Copy)]
+struct TrackContribution {
+    start: usize,
+    span: usize,
+    min_content: f32,
+    max_content: f32,
+}
+
 impl BasicGridLayoutEngine {
     /// Create a new basic grid layout engine
     pub fn new() -> Self {
         Self
     }
 
-    /// Resolve a single track size to pixels
-    fn resolve_single_track(&self, track: &TrackSizing, _available_size: f32) -> Option<f32> {
+    /// Compute layout, sizing intrinsic tracks from item content sizes
+    ///
+    /// `content_sizes[i]` holds the content sizes of `items[i]`; items without
+    /// an entry contribute nothing. `auto` and `max-content` tracks grow to the
+    /// largest max-content contribution of their items, `min-content` tracks
+    /// to the largest min-content contribution. Items spanning several
+    /// intrinsic tracks distribute any extra size evenly between them.
+    pub fn compute_grid_layout_with_content_sizes(
+        &self,
+        container: &GridContainer,
+        items: &[GridItem],
+        content_sizes: &[ItemContentSize],
+        available_space: (f32, f32),
+    ) -> GridLayout {
+        let (width, height) = available_space;
+        let row_count = container.template_rows().len();
+        let col_count = container.template_columns().len();
+
+        let placements = self.place_items(items, row_count, col_count, container);
+
+        let contributions = |axis_is_row: bool| -> Vec<TrackContribution> {
+            placements
+                .iter()
+                .enumerate()
+                .filter_map(|(index, placement)| {
+                    let placement = placement.as_ref()?;
+                    let sizes = content_sizes.get(index)?;
+                    Some(if axis_is_row {
+                        TrackContribution {
+                            start: placement.row,
+                            span: placement.row_span,
+                            min_content: sizes.min_content().1,
+                            max_content: sizes.max_content().1,
+                        }
+                    } else {
+                        TrackContribution {
+                            start: placement.column,
+                            span: placement.column_span,
+                            min_content: sizes.min_content().0,
+                            max_content: sizes.max_content().0,
+                        }
+                    })
+                })
+                .collect()
+        };
+
+        // Resolve track sizes
+        let column_sizes = self.resolve_tracks(
+            container.template_columns(),
+            width,
+            container.effective_column_gap(),
+            &contributions(false),
+        );
+
+        let row_sizes = self.resolve_tracks(
+            container.template_rows(),
+            height,
+            container.effective_row_gap(),
+            &contributions(true),
+        );
+
+        let column_positions = track_positions(
+            &column_sizes,
+            container.effective_column_gap(),
+            width,
+            container.justify_content(),
+        );
+        let row_positions = track_positions(
+            &row_sizes,
+            container.effective_row_gap(),
+            height,
+            container.align_content(),
+        );
+
+        let item_layouts = self.layout_items(
+            &placements,
+            (&row_positions, &row_sizes),
+            (&column_positions, &column_sizes),
+        );
+
+        let unplaced = placements
+            .iter()
+            .enumerate()
+            .filter(|(_, placement)| placement.is_none())
+            .map(|(index, _)| index)
+            .collect();
+
+        GridLayout::new(item_layouts, available_space).with_unplaced(unplaced)
+    }
+
+    /// Sizing function that sets a track's base size (the min of `minmax()`)
+    fn min_sizing(track: &TrackSizing) -> &TrackSizing {
         match track {
-            TrackSizing::Fixed(length) => {
-                // For now, only handle px units
-                if length.unit() == LengthUnit::Px {
-                    Some(length.value())
-                } else {
-                    None
-                }
-            }
+            TrackSizing::MinMax(min, _) => min,
+            other => other,
+        }
+    }
+
+    /// Sizing function that limits a track's growth (the max of `minmax()`)
+    fn max_sizing(track: &TrackSizing) -> &TrackSizing {
+        match track {
+            TrackSizing::MinMax(_, max) => max,
+            other => other,
+        }
+    }
+
+    /// Flex factor of a track whose (maximum) size is in fr units
+    fn flex_factor(track: &TrackSizing) -> Option<f32> {
+        match Self::max_sizing(track) {
+            TrackSizing::Flexible(fr) => Some(*fr),
             _ => None,
         }
     }
 
-    /// Calculate total fr units in tracks
-    fn total_fr_units(&self, tracks: &[TrackSizing]) -> f32 {
-        tracks
+    /// Check whether a track is sized from its content
+    fn is_intrinsic(track: &TrackSizing) -> bool {
+        matches!(
+            track,
+            TrackSizing::Auto
+                | TrackSizing::MinContent
+                | TrackSizing::MaxContent
+                | TrackSizing::FitContent(_)
+        )
+    }
+
+    /// Size a track list, growing intrinsic tracks to fit item contributions
+    ///
+    /// Tracks start at their base size (the `minmax()` minimum for minmax
+    /// tracks), then `minmax()` tracks with a non-flexible maximum grow
+    /// evenly toward it, and finally flexible tracks share what is left.
+    /// A flexible track never shrinks below its base size.
+    fn resolve_tracks(
+        &self,
+        tracks: &[TrackSizing],
+        available_size: f32,
+        gap: f32,
+        contributions: &[TrackContribution],
+    ) -> Vec<f32> {
+        if tracks.is_empty() {
+            return Vec::new();
+        }
+
+        let ctx = TrackResolutionContext::new(available_size);
+
+        // Non-flexible base sizes; fixed tracks in unsupported units stay 0
+        let mut sizes: Vec<f32> = tracks
             .iter()
-            .map(|t| match t {
-                TrackSizing::Flexible(fr) => *fr,
-                _ => 0.0,
+            .map(|track| Self::min_sizing(track).resolve(&ctx, 0.0, 0.0))
+            .collect();
+
+        let contribution_for = |track: &TrackSizing, contribution: &TrackContribution| match track {
+            TrackSizing::MinContent => contribution.min_content,
+            // An auto minimum only floors the track at its min-content size
+            TrackSizing::MinMax(min, _)
+                if matches!(**min, TrackSizing::Auto | TrackSizing::MinContent) =>
+            {
+                contribution.min_content
+            }
+            // min(max-content, max(min-content, limit)); a limit in an
+            // unsupported unit does not clamp
+            TrackSizing::FitContent(limit) => match ctx.resolve_length(limit) {
+                Some(limit) => contribution
+                    .max_content
+                    .min(contribution.min_content.max(limit)),
+                None => contribution.max_content,
+            },
+            _ => contribution.max_content,
+        };
+
+        // Items spanning a single intrinsic track size it directly
+        for contribution in contributions.iter().filter(|c| c.span == 1) {
+            if let Some(track) = tracks.get(contribution.start) {
+                if Self::is_intrinsic(Self::min_sizing(track)) {
+                    let size = &mut sizes[contribution.start];
+                    *size = size.max(contribution_for(track, contribution));
+                }
+            }
+        }
+
+        // Spanning items distribute what the spanned tracks are still
+        // missing across their intrinsic tracks, smallest spans first.
+        // Items crossing a flexible track are left to the fr distribution.
+        let mut spanning: Vec<&TrackContribution> =
+            contributions.iter().filter(|c| c.span > 1).collect();
+        spanning.sort_by_key(|c| c.span);
+
+        for contribution in spanning {
+            let end = (contribution.start + contribution.span).min(tracks.len());
+            if contribution.start >= end {
+                continue;
+            }
+            let spanned = &tracks[contribution.start..end];
+            if spanned.iter().any(|t| Self::flex_factor(t).is_some()) {
+                continue;
+            }
+
+            let intrinsic: Vec<usize> = (contribution.start..end)
+                .filter(|&i| Self::is_intrinsic(Self::min_sizing(&tracks[i])))
+                .collect();
+            if intrinsic.is_empty() {
+                continue;
+            }
+
+            let needed = intrinsic
+                .iter()
+                .map(|&i| contribution_for(&tracks[i], contribution))
+                .fold(0.0, f32::max);
+            let current = sizes[contribution.start..end].iter().sum::<f32>()
+                + (end - contribution.start - 1) as f32 * gap;
+            let extra = needed - current;
+
+            if extra > 0.0 {
+                let share = extra / intrinsic.len() as f32;
+                for i in intrinsic {
+                    sizes[i] += share;
+                }
+            }
+        }
+
+        let gap_count = tracks.len().saturating_sub(1);
+        let total_gap = (gap_count as f32) * gap;
+
+        // minmax() tracks with a non-flexible maximum grow toward it
+        let content_of = |index: usize, size: fn(&TrackContribution) -> f32| {
+            contributions
+                .iter()
+                .filter(|c| c.span == 1 && c.start == index)
+                .map(size)
+                .fold(0.0, f32::max)
+        };
+        let limits: Vec<Option<f32>> = tracks
+            .iter()
+            .enumerate()
+            .map(|(index, track)| {
+                let TrackSizing::MinMax(_, max) = track else {
+                    return None;
+                };
+                let limit = match max.as_ref() {
+                    TrackSizing::Flexible(_) => return None,
+                    TrackSizing::MinContent => content_of(index, |c| c.min_content),
+                    TrackSizing::Auto | TrackSizing::MaxContent => {
+                        content_of(index, |c| c.max_content)
+                    }
+                    TrackSizing::Fixed(length) => ctx.resolve_length(length)?,
+                    _ => return None,
+                };
+                Some(limit.max(sizes[index]))
             })
-            .sum()
-    }
+            .collect();
+
+        let mut free = available_size - total_gap - sizes.iter().sum::<f32>();
+        // Each pass either spends all free space or caps at least one track
+        for _ in 0..tracks.len() {
+            let growable: Vec<usize> = (0..tracks.len())
+                .filter(|&i| limits[i].is_some_and(|limit| limit > sizes[i]))
+                .collect();
+            if growable.is_empty() || free <= 0.0 {
+                break;
+            }
+            let share = free / growable.len() as f32;
+            for i in growable {
+                let grow = share.min(limits[i].unwrap_or(0.0) - sizes[i]);
+                sizes[i] += grow;
+                free -= grow;
+            }
+        }
 
-    /// Calculate fixed space used by non-flexible tracks
-    fn calculate_fixed_space(&self, tracks: &[TrackSizing]) -> f32 {
-        tracks
+        // Flexible tracks share whatever space is left. A track whose share
+        // would fall below its base size keeps the base size instead and the
+        // rest is redistributed among the others.
+        let flexible: Vec<(usize, f32)> = tracks
             .iter()
-            .filter_map(|t| self.resolve_single_track(t, 0.0))
-            .sum()
+            .enumerate()
+            .filter_map(|(index, track)| Self::flex_factor(track).map(|fr| (index, fr)))
+            .collect();
+        let inflexible_space: f32 = (0..tracks.len())
+            .filter(|&i| Self::flex_factor(&tracks[i]).is_none())
+            .map(|i| sizes[i])
+            .sum();
+        let mut frozen = vec![false; tracks.len()];
+
+        loop {
+            let frozen_space: f32 = flexible
+                .iter()
+                .filter(|(i, _)| frozen[*i])
+                .map(|(i, _)| sizes[*i])
+                .sum();
+            let total_fr: f32 = flexible
+                .iter()
+                .filter(|(i, _)| !frozen[*i])
+                .map(|(_, fr)| fr)
+                .sum();
+            let remaining_space =
+                (available_size - total_gap - inflexible_space - frozen_space).max(0.0);
+            let fr_value = if total_fr > 0.0 {
+                remaining_space / total_fr
+            } else {
+                0.0
+            };
+
+            let mut changed = false;
+            for &(i, fr) in &flexible {
+                if !frozen[i] && fr * fr_value < sizes[i] {
+                    frozen[i] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                for &(i, _) in &flexible {
+                    if !frozen[i] {
+                        sizes[i] = tracks[i].resolve(&ctx, fr_value, 0.0);
+                    }
+                }
+                break;
+            }
+        }
+
+        sizes
+    }
+
+    /// Number of tracks an item spans along one axis
+    fn span_of(start: GridLine, end: GridLine) -> usize {
+        match (start, end) {
+            (_, GridLine::Span(n)) | (GridLine::Span(n), _) => n.max(1) as usize,
+            (GridLine::LineNumber(s), GridLine::LineNumber(e)) if e > s => (e - s) as usize,
+            _ => 1,
+        }
     }
 
-    /// Place items using auto-placement algorithm
-    fn auto_place_items(
+    /// Assign each item a grid area using the auto-placement algorithm
+    ///
+    /// Items that fall outside the explicit grid, or whose explicit placement
+    /// overlaps an earlier explicitly placed item, are left unplaced (`None`).
+    fn place_items(
         &self,
         items: &[GridItem],
-        row_sizes: &[f32],
-        column_sizes: &[f32],
+        row_count: usize,
+        col_count: usize,
         container: &GridContainer,
-    ) -> Vec<GridItemLayout> {
-        let mut layouts = Vec::new();
+    ) -> Vec<Option<ItemPlacement>> {
+        let mut placements = Vec::with_capacity(items.len());
         let mut cursor_row = 0;
         let mut cursor_col = 0;
 
-        let row_count = row_sizes.len();
-        let col_count = column_sizes.len();
-
         if row_count == 0 || col_count == 0 {
-            return layouts;
+            placements.resize(items.len(), None);
+            return placements;
         }
 
-        let row_gap = container.effective_row_gap();
-        let col_gap = container.effective_column_gap();
+        // Cells claimed by explicitly placed items, row-major
+        let mut occupied = vec![false; row_count * col_count];
 
         for item in items {
+            let area = item
+                .area()
+                .and_then(|name| container.template_areas().get(name));
+            let (row_span, column_span) = match area {
+                Some(area) => (area.row_span(), area.column_span()),
+                None => (
+                    Self::span_of(item.row_start, item.row_end),
+                    Self::span_of(item.column_start, item.column_end),
+                ),
+            };
+
+            let explicit = area.is_some()
+                || matches!(
+                    (item.row_start, item.column_start),
+                    (GridLine::LineNumber(_), GridLine::LineNumber(_))
+                );
+
             // Determine placement
-            let (row, col) = match (item.row_start, item.column_start) {
-                (GridLine::LineNumber(r), GridLine::LineNumber(c)) => {
+            let (row, col) = match (area, item.row_start, item.column_start) {
+                (Some(area), _, _) => (area.row_start() - 1, area.column_start() - 1),
+                (None, GridLine::LineNumber(r), GridLine::LineNumber(c)) => {
                     // Explicit placement (convert 1-based to 0-based)
                     let row_idx = if r > 0 { (r - 1) as usize } else { 0 };
                     let col_idx = if c > 0 { (c - 1) as usize } else { 0 };
                     (row_idx, col_idx)
                 }
                 _ => {
-                    // Auto placement
+                    // Auto placement; wrap early if the span doesn't fit
+                    match container.auto_flow {
+                        GridAutoFlow::Row | GridAutoFlow::RowDense => {
+                            if cursor_col > 0 && cursor_col + column_span > col_count {
+                                cursor_col = 0;
+                                cursor_row += 1;
+                            }
+                        }
+                        GridAutoFlow::Column | GridAutoFlow::ColumnDense => {
+                            if cursor_row > 0 && cursor_row + row_span > row_count {
+                                cursor_row = 0;
+                                cursor_col += 1;
+                            }
+                        }
+                    }
+
                     let placement = (cursor_row, cursor_col);
 
                     // Advance cursor based on auto-flow
                     match container.auto_flow {
                         GridAutoFlow::Row | GridAutoFlow::RowDense => {
-                            cursor_col += 1;
+                            cursor_col += column_span;
                             if cursor_col >= col_count {
                                 cursor_col = 0;
                                 cursor_row += 1;
                             }
                         }
                         GridAutoFlow::Column | GridAutoFlow::ColumnDense => {
-                            cursor_row += 1;
+                            cursor_row += row_span;
                             if cursor_row >= row_count {
                                 cursor_row = 0;
                                 cursor_col += 1;
@@ -516,24 +1560,129 @@ impl BasicGridLayoutEngine {
 
             // Ensure within bounds
             if row >= row_count || col >= col_count {
+                placements.push(None);
                 continue;
             }
 
-            // Calculate position
-            let x = column_sizes[..col].iter().sum::<f32>() + (col as f32) * col_gap;
-            let y = row_sizes[..row].iter().sum::<f32>() + (row as f32) * row_gap;
+            let placement = ItemPlacement {
+                row,
+                column: col,
+                row_span: row_span.min(row_count - row),
+                column_span: column_span.min(col_count - col),
+            };
 
-            // Calculate size (for now, single cell)
-            let width = column_sizes[col];
-            let height = row_sizes[row];
+            if explicit {
+                let cells: Vec<usize> = (placement.row..placement.row + placement.row_span)
+                    .flat_map(|r| {
+                        (placement.column..placement.column + placement.column_span)
+                            .map(move |c| r * col_count + c)
+                    })
+                    .collect();
+                if cells.iter().any(|&cell| occupied[cell]) {
+                    placements.push(None);
+                    continue;
+                }
+                for cell in cells {
+                    occupied[cell] = true;
+                }
+            }
 
-            layouts.push(GridItemLayout::new(x, y, width, height, row, col, 1, 1));
+            placements.push(Some(placement));
         }
 
-        layouts
+        placements
+    }
+
+    /// Position placed items within the resolved tracks
+    ///
+    /// Each axis is given as (track start positions, track sizes).
+    fn layout_items(
+        &self,
+        placements: &[Option<ItemPlacement>],
+        rows: (&[f32], &[f32]),
+        columns: (&[f32], &[f32]),
+    ) -> Vec<GridItemLayout> {
+        // Distance from the start of the first spanned track to the end of the last
+        let extent = |(positions, sizes): (&[f32], &[f32]), start: usize, span: usize| {
+            let last = start + span - 1;
+            positions[last] + sizes[last] - positions[start]
+        };
+
+        placements
+            .iter()
+            .flatten()
+            .map(|p| {
+                let (row, col) = (p.row, p.column);
+
+                let x = columns.0[col];
+                let y = rows.0[row];
+                let width = extent(columns, col, p.column_span);
+                let height = extent(rows, row, p.row_span);
+
+                GridItemLayout::new(x, y, width, height, row, col, p.row_span, p.column_span)
+            })
+            .collect()
     }
 }
 
+/// Compute the start position of each track after content alignment
+///
+/// Leftover space (available size minus tracks and gaps) is distributed
+/// according to `alignment`. When the tracks overflow, the `space-*` values
+/// fall back to `start` (space-between) or `center` (space-around/evenly).
+fn track_positions(
+    sizes: &[f32],
+    gap: f32,
+    available_size: f32,
+    alignment: ContentAlignment,
+) -> Vec<f32> {
+    let count = sizes.len();
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let used = sizes.iter().sum::<f32>() + (count - 1) as f32 * gap;
+    let leftover = available_size - used;
+
+    let alignment = match alignment {
+        ContentAlignment::SpaceBetween if leftover < 0.0 => ContentAlignment::Start,
+        ContentAlignment::SpaceAround | ContentAlignment::SpaceEvenly if leftover < 0.0 => {
+            ContentAlignment::Center
+        }
+        other => other,
+    };
+
+    // (offset before the first track, extra space added to every gap)
+    let (offset, extra_gap) = match alignment {
+        ContentAlignment::Start => (0.0, 0.0),
+        ContentAlignment::End => (leftover, 0.0),
+        ContentAlignment::Center => (leftover / 2.0, 0.0),
+        ContentAlignment::SpaceBetween => {
+            if count > 1 {
+                (0.0, leftover / (count - 1) as f32)
+            } else {
+                (0.0, 0.0)
+            }
+        }
+        ContentAlignment::SpaceAround => {
+            let space = leftover / count as f32;
+            (space / 2.0, space)
+        }
+        ContentAlignment::SpaceEvenly => {
+            let space = leftover / (count + 1) as f32;
+            (space, space)
+        }
+    };
+
+    let mut positions = Vec::with_capacity(count);
+    let mut cursor = offset;
+    for size in sizes {
+        positions.push(cursor);
+        cursor += size + gap + extra_gap;
+    }
+    positions
+}
+
 impl Default for BasicGridLayoutEngine {
     fn default() -> Self {
         Self::new()
@@ -547,47 +1696,8 @@ impl GridLayoutEngine for BasicGridLayoutEngine {
         available_size: f32,
         gap: f32,
     ) -> Vec<f32> {
-        if tracks.is_empty() {
-            return Vec::new();
-        }
-
-        // Calculate gap space
-        let gap_count = tracks.len().saturating_sub(1);
-        let total_gap = (gap_count as f32) * gap;
-
-        // Calculate fixed space
-        let fixed_space = self.calculate_fixed_space(tracks);
-
-        // Calculate total fr units
-        let total_fr = self.total_fr_units(tracks);
-
-        // Remaining space for flexible tracks
-        let remaining_space = (available_size - total_gap - fixed_space).max(0.0);
-
-        // Calculate fr unit value
-        let fr_value = if total_fr > 0.0 {
-            remaining_space / total_fr
-        } else {
-            0.0
-        };
-
-        // Resolve each track
-        tracks
-            .iter()
-            .map(|track| match track {
-                TrackSizing::Fixed(length) => {
-                    if length.unit() == LengthUnit::Px {
-                        length.value()
-                    } else {
-                        0.0 // Unsupported unit for now
-                    }
-                }
-                TrackSizing::Flexible(fr) => fr * fr_value,
-                TrackSizing::Auto => 0.0, // TODO: Implement auto sizing
-                TrackSizing::MinContent => 0.0, // TODO: Implement min-content
-                TrackSizing::MaxContent => 0.0, // TODO: Implement max-content
-            })
-            .collect()
+        // Without items, intrinsic tracks have no content and collapse to 0
+        self.resolve_tracks(tracks, available_size, gap, &[])
     }
 
     fn compute_grid_layout(
@@ -596,25 +1706,7 @@ impl GridLayoutEngine for BasicGridLayoutEngine {
         items: &[GridItem],
         available_space: (f32, f32),
     ) -> GridLayout {
-        let (width, height) = available_space;
-
-        // Resolve track sizes
-        let column_sizes = self.resolve_track_sizes(
-            container.template_columns(),
-            width,
-            container.effective_column_gap(),
-        );
-
-        let row_sizes = self.resolve_track_sizes(
-            container.template_rows(),
-            height,
-            container.effective_row_gap(),
-        );
-
-        // Place items
-        let item_layouts = self.auto_place_items(items, &row_sizes, &column_sizes, container);
-
-        GridLayout::new(item_layouts, available_space)
+        self.compute_grid_layout_with_content_sizes(container, items, &[], available_space)
     }
 }
 
@@ -651,4 +1743,35 @@ mod tests {
         assert_eq!(container.effective_row_gap(), 15.0);
         assert_eq!(container.effective_column_gap(), 20.0);
     }
+
+    #[test]
+    fn test_container_normal_gap_is_zero() {
+        let normal = css_types::parse_gap("normal").unwrap();
+        let container = GridContainer::new()
+            .with_gap(10.0)
+            .with_gap_value(normal)
+            .unwrap();
+        assert_eq!(container.gap(), None);
+        assert_eq!(container.effective_row_gap(), 0.0);
+        assert_eq!(container.effective_column_gap(), 0.0);
+
+        let container = GridContainer::new()
+            .with_gap_value(css_types::parse_gap("8px").unwrap())
+            .unwrap();
+        assert_eq!(container.effective_row_gap(), 8.0);
+    }
+
+    #[test]
+    fn test_container_gap_value_rejects_relative_lengths() {
+        let container = GridContainer::new()
+            .with_gap_value(css_types::parse_gap("6pt").unwrap())
+            .unwrap();
+        assert_eq!(container.effective_row_gap(), 8.0);
+
+        let relative = css_types::parse_gap("10%").unwrap();
+        assert!(matches!(
+            GridContainer::new().with_gap_value(relative),
+            Err(CssError::InvalidValue(_))
+        ));
+    }
 }
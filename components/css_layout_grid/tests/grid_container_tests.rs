@@ -1,6 +1,8 @@
 //! Unit tests for GridContainer and GridItem
 
-use css_layout_grid::{GridAutoFlow, GridContainer, GridItem, GridLine, TrackSizing};
+use css_layout_grid::{
+    ContentAlignment, GridAutoFlow, GridContainer, GridItem, GridLine, TrackSizing,
+};
 use css_types::{Length, LengthUnit};
 
 // ============================================================================
@@ -15,6 +17,48 @@ fn test_grid_container_default() {
     assert_eq!(container.template_columns().len(), 0);
     assert_eq!(container.auto_flow(), GridAutoFlow::Row);
     assert_eq!(container.gap(), None);
+    assert_eq!(container.justify_content(), ContentAlignment::Start);
+    assert_eq!(container.align_content(), ContentAlignment::Start);
+}
+
+#[test]
+fn test_grid_container_builder() {
+    let container = GridContainer::new()
+        .with_template_columns(vec![
+            TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px)),
+            TrackSizing::Flexible(1.0),
+        ])
+        .with_template_rows(vec![TrackSizing::Auto])
+        .with_auto_rows(vec![TrackSizing::Flexible(1.0)])
+        .with_auto_flow(GridAutoFlow::ColumnDense)
+        .with_gap(8.0)
+        .with_row_gap(4.0)
+        .with_justify_content(ContentAlignment::SpaceBetween)
+        .with_align_content(ContentAlignment::Center);
+
+    assert_eq!(container.template_columns().len(), 2);
+    assert_eq!(container.template_columns()[1], TrackSizing::Flexible(1.0));
+    assert_eq!(container.template_rows(), &[TrackSizing::Auto]);
+    assert_eq!(container.auto_rows(), &[TrackSizing::Flexible(1.0)]);
+    assert!(container.auto_columns().is_empty());
+    assert_eq!(container.auto_flow(), GridAutoFlow::ColumnDense);
+    assert_eq!(container.gap(), Some(8.0));
+    assert_eq!(container.row_gap(), Some(4.0));
+    assert_eq!(container.column_gap(), None);
+    assert_eq!(container.effective_row_gap(), 4.0);
+    assert_eq!(container.effective_column_gap(), 8.0);
+    assert_eq!(container.justify_content(), ContentAlignment::SpaceBetween);
+    assert_eq!(container.align_content(), ContentAlignment::Center);
+}
+
+#[test]
+fn test_grid_container_content_alignment() {
+    let mut container = GridContainer::new();
+    container.set_justify_content(ContentAlignment::SpaceBetween);
+    container.set_align_content(ContentAlignment::Center);
+
+    assert_eq!(container.justify_content(), ContentAlignment::SpaceBetween);
+    assert_eq!(container.align_content(), ContentAlignment::Center);
 }
 
 #[test]
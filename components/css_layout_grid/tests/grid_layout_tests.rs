@@ -1,10 +1,11 @@
 //! Unit tests for GridLayout computation
 
 use css_layout_grid::{
-    BasicGridLayoutEngine, GridContainer, GridItem, GridItemLayout, GridLayout, GridLayoutEngine,
-    GridLine, TrackSizing,
+    parse_grid_template_areas, parse_track_list, snap_grid_layout, BasicGridLayoutEngine,
+    ContentAlignment, GridAutoFlow, GridContainer, GridItem, GridItemLayout, GridLayout,
+    GridLayoutEngine, GridLine, ItemContentSize, TrackResolutionContext, TrackSizing,
 };
-use css_types::{Length, LengthUnit};
+use css_types::{Length, LengthUnit, Rect};
 
 // ============================================================================
 // GridItemLayout Tests
@@ -180,4 +181,553 @@ fn test_grid_layout_explicit_placement() {
     assert_eq!(layout.items()[0].y(), 100.0);
     assert_eq!(layout.items()[0].row(), 1);
     assert_eq!(layout.items()[0].column(), 1);
+    assert!(layout.unplaced().is_empty());
+}
+
+fn two_by_two_container() -> GridContainer {
+    let track = TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px));
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![track.clone(), track.clone()]);
+    container.set_template_columns(vec![track.clone(), track]);
+    container
+}
+
+fn explicit_item(row: i32, column: i32) -> GridItem {
+    let mut item = GridItem::new();
+    item.set_row_start(GridLine::LineNumber(row));
+    item.set_column_start(GridLine::LineNumber(column));
+    item
+}
+
+#[test]
+fn test_grid_layout_reports_conflicting_explicit_placement() {
+    let engine = BasicGridLayoutEngine::new();
+    let container = two_by_two_container();
+
+    let items = vec![
+        explicit_item(1, 1),
+        explicit_item(2, 2),
+        explicit_item(1, 1),
+    ];
+
+    let layout = engine.compute_grid_layout(&container, &items, (200.0, 200.0));
+
+    assert_eq!(layout.items().len(), 2);
+    assert_eq!(layout.unplaced(), &[2]);
+}
+
+#[test]
+fn test_grid_layout_reports_spanning_conflict_and_out_of_range() {
+    let engine = BasicGridLayoutEngine::new();
+    let container = two_by_two_container();
+
+    let mut wide = explicit_item(1, 1);
+    wide.set_column_end(GridLine::Span(2));
+    let items = vec![
+        wide,
+        explicit_item(1, 2),
+        explicit_item(5, 1),
+        explicit_item(2, 2),
+    ];
+
+    let layout = engine.compute_grid_layout(&container, &items, (200.0, 200.0));
+
+    // The second item collides with the spanning first one, the third
+    // starts outside the explicit grid
+    assert_eq!(layout.unplaced(), &[1, 2]);
+    assert_eq!(layout.items().len(), 2);
+    assert_eq!(
+        (layout.items()[1].row(), layout.items()[1].column()),
+        (1, 1)
+    );
+}
+
+// ============================================================================
+// Content-Sized Track Tests
+// ============================================================================
+
+#[test]
+fn test_auto_tracks_without_content_collapse() {
+    let engine = BasicGridLayoutEngine::new();
+    let tracks = vec![TrackSizing::Auto, TrackSizing::Flexible(1.0)];
+
+    let sizes = engine.resolve_track_sizes(&tracks, 300.0, 0.0);
+
+    assert_eq!(sizes, vec![0.0, 300.0]);
+}
+
+#[test]
+fn test_auto_track_uses_largest_item_content() {
+    let engine = BasicGridLayoutEngine::new();
+
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![TrackSizing::Auto, TrackSizing::Auto]);
+    container.set_template_columns(vec![TrackSizing::Auto, TrackSizing::Flexible(1.0)]);
+    container.set_auto_flow(GridAutoFlow::Column);
+
+    // Both items land in the first (auto) column, one per row
+    let items = vec![GridItem::new(), GridItem::new()];
+    let content_sizes = vec![
+        ItemContentSize::new((40.0, 10.0), (120.0, 20.0)),
+        ItemContentSize::new((60.0, 30.0), (80.0, 50.0)),
+    ];
+
+    let layout = engine.compute_grid_layout_with_content_sizes(
+        &container,
+        &items,
+        &content_sizes,
+        (400.0, 300.0),
+    );
+
+    // Column is as wide as the widest max-content item
+    assert_eq!(layout.items()[0].width(), 120.0);
+    assert_eq!(layout.items()[1].width(), 120.0);
+    // Each auto row fits its own item
+    assert_eq!(layout.items()[0].height(), 20.0);
+    assert_eq!(layout.items()[1].y(), 20.0);
+    assert_eq!(layout.items()[1].height(), 50.0);
+}
+
+#[test]
+fn test_min_and_max_content_tracks() {
+    let engine = BasicGridLayoutEngine::new();
+
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![TrackSizing::Auto]);
+    container.set_template_columns(vec![TrackSizing::MinContent, TrackSizing::MaxContent]);
+
+    let items = vec![GridItem::new(), GridItem::new()];
+    let content_sizes = vec![
+        ItemContentSize::new((40.0, 10.0), (120.0, 20.0)),
+        ItemContentSize::new((60.0, 30.0), (80.0, 50.0)),
+    ];
+
+    let layout = engine.compute_grid_layout_with_content_sizes(
+        &container,
+        &items,
+        &content_sizes,
+        (400.0, 300.0),
+    );
+
+    assert_eq!(layout.items()[0].width(), 40.0);
+    assert_eq!(layout.items()[1].x(), 40.0);
+    assert_eq!(layout.items()[1].width(), 80.0);
+    // The shared auto row fits the taller item
+    assert_eq!(layout.items()[0].height(), 50.0);
+}
+
+#[test]
+fn test_spanning_item_distributes_content_size() {
+    let engine = BasicGridLayoutEngine::new();
+
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![TrackSizing::Auto, TrackSizing::Auto]);
+    container.set_template_columns(vec![TrackSizing::Auto, TrackSizing::Auto]);
+    container.set_gap(Some(10.0));
+
+    // First item spans both columns of row 1; the other two share row 2
+    let mut wide = GridItem::new();
+    wide.set_column_end(GridLine::Span(2));
+    let items = vec![wide, GridItem::new(), GridItem::new()];
+    let content_sizes = vec![
+        ItemContentSize::new((100.0, 20.0), (210.0, 20.0)),
+        ItemContentSize::new((30.0, 20.0), (50.0, 20.0)),
+        ItemContentSize::new((30.0, 20.0), (70.0, 20.0)),
+    ];
+
+    let layout = engine.compute_grid_layout_with_content_sizes(
+        &container,
+        &items,
+        &content_sizes,
+        (400.0, 300.0),
+    );
+
+    // 50 + 70 + 10 gap = 130; the remaining 80px is split between both tracks
+    assert_eq!(layout.items()[0].column_span(), 2);
+    assert_eq!(layout.items()[0].width(), 210.0);
+    assert_eq!(layout.items()[1].width(), 90.0);
+    assert_eq!(layout.items()[2].x(), 100.0);
+    assert_eq!(layout.items()[2].width(), 110.0);
+}
+
+// ============================================================================
+// Content Alignment Tests
+// ============================================================================
+
+fn two_by_one_grid(justify: ContentAlignment) -> GridContainer {
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![TrackSizing::Fixed(Length::new(50.0, LengthUnit::Px))]);
+    container.set_template_columns(vec![
+        TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px)),
+        TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px)),
+    ]);
+    container.set_justify_content(justify);
+    container
+}
+
+#[test]
+fn test_justify_content_center() {
+    let engine = BasicGridLayoutEngine::new();
+    let container = two_by_one_grid(ContentAlignment::Center);
+    let items = vec![GridItem::new(), GridItem::new()];
+
+    let layout = engine.compute_grid_layout(&container, &items, (400.0, 300.0));
+
+    // 200px of leftover space, half before the tracks
+    assert_eq!(layout.items()[0].x(), 100.0);
+    assert_eq!(layout.items()[1].x(), 200.0);
+}
+
+#[test]
+fn test_justify_content_end() {
+    let engine = BasicGridLayoutEngine::new();
+    let container = two_by_one_grid(ContentAlignment::End);
+    let items = vec![GridItem::new(), GridItem::new()];
+
+    let layout = engine.compute_grid_layout(&container, &items, (400.0, 300.0));
+
+    assert_eq!(layout.items()[0].x(), 200.0);
+    assert_eq!(layout.items()[1].x(), 300.0);
+}
+
+#[test]
+fn test_justify_content_space_distribution() {
+    let engine = BasicGridLayoutEngine::new();
+    let items = vec![GridItem::new(), GridItem::new()];
+
+    let layout = engine.compute_grid_layout(
+        &two_by_one_grid(ContentAlignment::SpaceBetween),
+        &items,
+        (400.0, 300.0),
+    );
+    assert_eq!(layout.items()[0].x(), 0.0);
+    assert_eq!(layout.items()[1].x(), 300.0);
+
+    let layout = engine.compute_grid_layout(
+        &two_by_one_grid(ContentAlignment::SpaceAround),
+        &items,
+        (400.0, 300.0),
+    );
+    assert_eq!(layout.items()[0].x(), 50.0);
+    assert_eq!(layout.items()[1].x(), 250.0);
+
+    let layout = engine.compute_grid_layout(
+        &two_by_one_grid(ContentAlignment::SpaceEvenly),
+        &items,
+        (500.0, 300.0),
+    );
+    assert_eq!(layout.items()[0].x(), 100.0);
+    assert_eq!(layout.items()[1].x(), 300.0);
+}
+
+#[test]
+fn test_align_content_center_rows() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = two_by_one_grid(ContentAlignment::Start);
+    container.set_align_content(ContentAlignment::Center);
+    let items = vec![GridItem::new()];
+
+    let layout = engine.compute_grid_layout(&container, &items, (400.0, 300.0));
+
+    assert_eq!(layout.items()[0].y(), 125.0);
+    assert_eq!(layout.items()[0].height(), 50.0);
+}
+
+#[test]
+fn test_space_between_falls_back_to_start_on_overflow() {
+    let engine = BasicGridLayoutEngine::new();
+    let container = two_by_one_grid(ContentAlignment::SpaceBetween);
+    let items = vec![GridItem::new(), GridItem::new()];
+
+    let layout = engine.compute_grid_layout(&container, &items, (150.0, 300.0));
+
+    assert_eq!(layout.items()[0].x(), 0.0);
+    assert_eq!(layout.items()[1].x(), 100.0);
+}
+
+// ============================================================================
+// Named Area Placement Tests
+// ============================================================================
+
+fn px(value: f32) -> TrackSizing {
+    TrackSizing::Fixed(Length::new(value, LengthUnit::Px))
+}
+
+fn area_item(name: &str) -> GridItem {
+    let mut item = GridItem::new();
+    item.set_area(Some(name));
+    item
+}
+
+#[test]
+fn test_place_item_by_area_name() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![px(50.0), px(200.0)]);
+    container.set_template_columns(vec![px(100.0), px(300.0)]);
+    container
+        .set_template_areas(parse_grid_template_areas(r#""header header" "nav main""#).unwrap());
+
+    let items = vec![area_item("main"), area_item("header"), area_item("nav")];
+    let layout = engine.compute_grid_layout(&container, &items, (400.0, 250.0));
+
+    let main = &layout.items()[0];
+    assert_eq!((main.row(), main.column()), (1, 1));
+    assert_eq!((main.x(), main.y()), (100.0, 50.0));
+    assert_eq!((main.width(), main.height()), (300.0, 200.0));
+
+    let header = &layout.items()[1];
+    assert_eq!((header.row(), header.column()), (0, 0));
+    assert_eq!(header.column_span(), 2);
+    assert_eq!(header.width(), 400.0);
+
+    let nav = &layout.items()[2];
+    assert_eq!((nav.x(), nav.y()), (0.0, 50.0));
+}
+
+#[test]
+fn test_unknown_area_name_falls_back_to_auto_placement() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container.set_template_rows(vec![px(50.0), px(50.0)]);
+    container.set_template_columns(vec![px(100.0), px(100.0)]);
+    container.set_template_areas(parse_grid_template_areas(r#"". ." ". side""#).unwrap());
+
+    let items = vec![area_item("missing"), area_item("side")];
+    let layout = engine.compute_grid_layout(&container, &items, (200.0, 100.0));
+
+    assert_eq!(
+        (layout.items()[0].row(), layout.items()[0].column()),
+        (0, 0)
+    );
+    assert_eq!(
+        (layout.items()[1].row(), layout.items()[1].column()),
+        (1, 1)
+    );
+}
+
+// ============================================================================
+// minmax() Track Tests
+// ============================================================================
+
+#[test]
+fn test_minmax_fixed_min_flexible_max_shares_space() {
+    let engine = BasicGridLayoutEngine::new();
+    let tracks = parse_track_list("minmax(100px, 1fr) 1fr").unwrap();
+
+    let sizes = engine.resolve_track_sizes(&tracks, 500.0, 0.0);
+    assert_eq!(sizes, vec![250.0, 250.0]);
+}
+
+#[test]
+fn test_minmax_fixed_min_floors_flexible_track() {
+    let engine = BasicGridLayoutEngine::new();
+    let tracks = parse_track_list("minmax(100px, 1fr) 1fr").unwrap();
+
+    // An even split would give 75px each; the minmax track keeps its 100px
+    let sizes = engine.resolve_track_sizes(&tracks, 150.0, 0.0);
+    assert_eq!(sizes, vec![100.0, 50.0]);
+
+    // With no space left it still keeps its minimum
+    let sizes = engine.resolve_track_sizes(&tracks, 50.0, 0.0);
+    assert_eq!(sizes, vec![100.0, 0.0]);
+}
+
+#[test]
+fn test_minmax_auto_min_fixed_max_grows_to_max() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container.set_template_columns(parse_track_list("minmax(auto, 200px)").unwrap());
+    container.set_template_rows(parse_track_list("50px").unwrap());
+    let items = vec![GridItem::new()];
+    let sizes = vec![ItemContentSize::new((30.0, 10.0), (80.0, 10.0))];
+
+    let layout =
+        engine.compute_grid_layout_with_content_sizes(&container, &items, &sizes, (500.0, 50.0));
+    assert_eq!(layout.items()[0].width(), 200.0);
+}
+
+#[test]
+fn test_minmax_auto_min_fixed_max_limited_by_free_space() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container.set_template_columns(parse_track_list("minmax(auto, 200px) 150px").unwrap());
+    container.set_template_rows(parse_track_list("50px").unwrap());
+    let items = vec![GridItem::new()];
+    let sizes = vec![ItemContentSize::new((30.0, 10.0), (80.0, 10.0))];
+
+    // 250px - 150px leaves 100px, above the 30px min-content floor
+    let layout =
+        engine.compute_grid_layout_with_content_sizes(&container, &items, &sizes, (250.0, 50.0));
+    assert_eq!(layout.items()[0].width(), 100.0);
+
+    // Without free space the track stays at its min-content floor
+    let layout =
+        engine.compute_grid_layout_with_content_sizes(&container, &items, &sizes, (100.0, 50.0));
+    assert_eq!(layout.items()[0].width(), 30.0);
+}
+
+// ============================================================================
+// fit-content() Track Tests
+// ============================================================================
+
+fn fit_content_column_widths(content_sizes: Vec<ItemContentSize>) -> Vec<f32> {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container
+        .set_template_columns(parse_track_list("fit-content(150px) fit-content(150px)").unwrap());
+    container.set_template_rows(parse_track_list("50px").unwrap());
+    let items = vec![GridItem::new(), GridItem::new()];
+
+    let layout = engine.compute_grid_layout_with_content_sizes(
+        &container,
+        &items,
+        &content_sizes,
+        (1000.0, 50.0),
+    );
+    layout.items().iter().map(|item| item.width()).collect()
+}
+
+#[test]
+fn test_fit_content_caps_large_content_at_limit() {
+    let widths = fit_content_column_widths(vec![
+        ItemContentSize::new((40.0, 10.0), (300.0, 10.0)),
+        ItemContentSize::new((20.0, 10.0), (90.0, 10.0)),
+    ]);
+
+    // Content larger than the limit is capped; smaller content keeps its size
+    assert_eq!(widths, vec![150.0, 90.0]);
+}
+
+#[test]
+fn test_fit_content_never_below_min_content() {
+    let widths = fit_content_column_widths(vec![
+        ItemContentSize::new((200.0, 10.0), (400.0, 10.0)),
+        ItemContentSize::default(),
+    ]);
+
+    assert_eq!(widths, vec![200.0, 0.0]);
+}
+
+// ============================================================================
+// Pixel Snapping Tests
+// ============================================================================
+
+fn fractional_grid_layout() -> GridLayout {
+    // Two 33.3px columns separated by a 0.45px gap, two rows of 12.7px
+    let mut items = Vec::new();
+    for row in 0..2 {
+        for column in 0..2 {
+            items.push(GridItemLayout::new(
+                10.2 + column as f32 * 33.75,
+                row as f32 * 12.7,
+                33.3,
+                12.7,
+                row,
+                column,
+                1,
+                1,
+            ));
+        }
+    }
+    GridLayout::new(items, (87.3, 25.4))
+}
+
+#[test]
+fn test_snap_grid_layout_dpr_1_keeps_rows_flush() {
+    let mut layout = fractional_grid_layout();
+    snap_grid_layout(&mut layout, 1.0);
+
+    let items = layout.items();
+    // Rows shared an edge before snapping and still do
+    assert_eq!(items[0].y() + items[0].height(), items[2].y());
+    assert_eq!(items[1].y() + items[1].height(), items[3].y());
+    for item in items {
+        assert_eq!(item.x().fract(), 0.0);
+        assert_eq!(item.width().fract(), 0.0);
+        assert_eq!(item.height().fract(), 0.0);
+    }
+    assert_eq!(items[0].x(), 10.0);
+    assert_eq!(items[0].width(), 34.0);
+    assert_eq!(items[1].x(), 44.0);
+    assert_eq!(layout.container_size(), (87.0, 25.0));
+}
+
+#[test]
+fn test_snap_grid_layout_dpr_2_keeps_rows_flush() {
+    let mut layout = fractional_grid_layout();
+    snap_grid_layout(&mut layout, 2.0);
+
+    let items = layout.items();
+    assert_eq!(items[0].y() + items[0].height(), items[2].y());
+    assert_eq!(items[1].y() + items[1].height(), items[3].y());
+    for item in items {
+        assert_eq!((item.x() * 2.0).fract(), 0.0);
+        assert_eq!((item.y() * 2.0).fract(), 0.0);
+        assert_eq!((item.width() * 2.0).fract(), 0.0);
+        assert_eq!((item.height() * 2.0).fract(), 0.0);
+    }
+    // Track indices are untouched
+    assert_eq!((items[3].row(), items[3].column()), (1, 1));
+    assert_eq!(layout.container_size(), (87.5, 25.5));
+}
+
+// ============================================================================
+// Single Track Resolution Tests
+// ============================================================================
+
+#[test]
+fn test_resolve_flexible_track() {
+    let ctx = TrackResolutionContext::new(600.0);
+    assert_eq!(TrackSizing::Flexible(1.0).resolve(&ctx, 150.0, 0.0), 150.0);
+    assert_eq!(TrackSizing::Flexible(2.5).resolve(&ctx, 40.0, 0.0), 100.0);
+}
+
+#[test]
+fn test_resolve_fixed_track() {
+    let ctx = TrackResolutionContext::new(600.0);
+    let track = TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px));
+    assert_eq!(track.resolve(&ctx, 150.0, 30.0), 100.0);
+}
+
+#[test]
+fn test_resolve_percentage_track() {
+    let ctx = TrackResolutionContext::new(600.0);
+    let track = TrackSizing::Fixed(Length::new(25.0, LengthUnit::Percent));
+    assert_eq!(track.resolve(&ctx, 0.0, 0.0), 150.0);
+
+    // The bulk pass resolves percentages against the same basis
+    let engine = BasicGridLayoutEngine::new();
+    let sizes = engine.resolve_track_sizes(&[track, TrackSizing::Flexible(1.0)], 600.0, 0.0);
+    assert_eq!(sizes, vec![150.0, 450.0]);
+}
+
+#[test]
+fn test_resolve_content_sized_tracks() {
+    let ctx = TrackResolutionContext::new(600.0);
+    assert_eq!(TrackSizing::Auto.resolve(&ctx, 0.0, 80.0), 80.0);
+    let fit = TrackSizing::FitContent(Length::new(50.0, LengthUnit::Px));
+    assert_eq!(fit.resolve(&ctx, 0.0, 80.0), 50.0);
+
+    let minmax = parse_track_list("minmax(100px, 1fr)").unwrap().remove(0);
+    assert_eq!(minmax.resolve(&ctx, 40.0, 0.0), 100.0);
+    assert_eq!(minmax.resolve(&ctx, 200.0, 0.0), 200.0);
+}
+
+// ============================================================================
+// Content Bounds Tests
+// ============================================================================
+
+#[test]
+fn test_content_bounds_include_overflowing_tracks() {
+    let engine = BasicGridLayoutEngine::new();
+    let mut container = GridContainer::new();
+    container.set_template_columns(parse_track_list("150px 150px").unwrap());
+    container.set_template_rows(parse_track_list("80px 80px").unwrap());
+    let items: Vec<GridItem> = (0..4).map(|_| GridItem::new()).collect();
+
+    let layout = engine.compute_grid_layout(&container, &items, (200.0, 100.0));
+
+    assert_eq!(layout.container_size(), (200.0, 100.0));
+    assert_eq!(layout.content_bounds(), Rect::new(0.0, 0.0, 300.0, 160.0));
 }
@@ -1,6 +1,9 @@
 //! Unit tests for CSS Grid basic types
 
-use css_layout_grid::{GridAutoFlow, GridLine, TrackSizing};
+use css_layout_grid::{
+    parse_grid_template_areas, parse_track_list, GridArea, GridAutoFlow, GridLine, TrackSizing,
+};
+use css_types::CssError;
 use css_types::{Length, LengthUnit};
 
 // ============================================================================
@@ -115,3 +118,136 @@ fn test_grid_line_span() {
         _ => panic!("Expected Span variant"),
     }
 }
+
+// ============================================================================
+// Grid Template Areas Tests
+// ============================================================================
+
+#[test]
+fn test_parse_grid_template_areas_two_by_two() {
+    let areas = parse_grid_template_areas(r#""header header" "nav main""#).unwrap();
+
+    assert_eq!(areas.row_count(), 2);
+    assert_eq!(areas.column_count(), 2);
+    assert_eq!(areas.get("header"), Some(GridArea::new(1, 2, 1, 3)));
+    assert_eq!(areas.get("nav"), Some(GridArea::new(2, 3, 1, 2)));
+    assert_eq!(areas.get("main"), Some(GridArea::new(2, 3, 2, 3)));
+    assert_eq!(areas.get("footer"), None);
+
+    let names: Vec<&str> = areas.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["header", "nav", "main"]);
+}
+
+#[test]
+fn test_parse_grid_template_areas_null_cells_and_spanning_rows() {
+    let areas = parse_grid_template_areas("'side .' 'side ...'").unwrap();
+
+    let side = areas.get("side").unwrap();
+    assert_eq!((side.row_span(), side.column_span()), (2, 1));
+    assert_eq!(areas.iter().count(), 1);
+}
+
+#[test]
+fn test_parse_grid_template_areas_none() {
+    let areas = parse_grid_template_areas("none").unwrap();
+    assert!(areas.is_empty());
+    assert_eq!(areas.row_count(), 0);
+}
+
+#[test]
+fn test_parse_grid_template_areas_rejects_non_rectangular_area() {
+    let result = parse_grid_template_areas(r#""a a" "a b""#);
+    assert!(matches!(result, Err(CssError::InvalidValue(_))));
+
+    // Disjoint cells of the same name are not one rectangle either
+    let result = parse_grid_template_areas(r#""a b a""#);
+    assert!(matches!(result, Err(CssError::InvalidValue(_))));
+}
+
+#[test]
+fn test_parse_grid_template_areas_rejects_malformed_input() {
+    assert!(parse_grid_template_areas(r#""a b" "c""#).is_err());
+    assert!(parse_grid_template_areas(r#""a b"#).is_err());
+    assert!(parse_grid_template_areas(r#""""#).is_err());
+    assert!(parse_grid_template_areas("a b").is_err());
+    assert!(parse_grid_template_areas("").is_err());
+}
+
+#[test]
+fn test_grid_areas_named_lines() {
+    let areas = parse_grid_template_areas(r#""header header" "nav main""#).unwrap();
+
+    assert_eq!(areas.row_line("main-start"), Some(2));
+    assert_eq!(areas.row_line("main-end"), Some(3));
+    assert_eq!(areas.column_line("header-end"), Some(3));
+    assert_eq!(areas.column_line("main"), None);
+    assert_eq!(areas.column_line("missing-start"), None);
+}
+
+// ============================================================================
+// Track List Parsing Tests
+// ============================================================================
+
+#[test]
+fn test_parse_track_list_basic_tracks() {
+    let tracks = parse_track_list("100px 1fr auto min-content max-content").unwrap();
+    assert_eq!(
+        tracks,
+        vec![
+            TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px)),
+            TrackSizing::Flexible(1.0),
+            TrackSizing::Auto,
+            TrackSizing::MinContent,
+            TrackSizing::MaxContent,
+        ]
+    );
+    assert!(parse_track_list("none").unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_track_list_minmax() {
+    let tracks = parse_track_list("minmax(100px, 1fr) minmax(auto,200px)").unwrap();
+    assert_eq!(
+        tracks,
+        vec![
+            TrackSizing::MinMax(
+                Box::new(TrackSizing::Fixed(Length::new(100.0, LengthUnit::Px))),
+                Box::new(TrackSizing::Flexible(1.0)),
+            ),
+            TrackSizing::MinMax(
+                Box::new(TrackSizing::Auto),
+                Box::new(TrackSizing::Fixed(Length::new(200.0, LengthUnit::Px))),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_track_list_fit_content() {
+    let tracks = parse_track_list("fit-content(150px) 1fr").unwrap();
+    assert_eq!(
+        tracks[0],
+        TrackSizing::FitContent(Length::new(150.0, LengthUnit::Px))
+    );
+    assert!(matches!(
+        parse_track_list("fit-content(-1px)"),
+        Err(CssError::InvalidValue(_))
+    ));
+    assert!(parse_track_list("minmax(fit-content(10px), 1fr)").is_err());
+}
+
+#[test]
+fn test_parse_track_list_rejects_invalid_tracks() {
+    assert!(matches!(
+        parse_track_list("minmax(1fr, 100px)"),
+        Err(CssError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        parse_track_list("-10px"),
+        Err(CssError::InvalidValue(_))
+    ));
+    assert!(parse_track_list("minmax(100px)").is_err());
+    assert!(parse_track_list("minmax(minmax(1px, 2px), 1fr)").is_err());
+    assert!(parse_track_list("wobbly").is_err());
+    assert!(parse_track_list("").is_err());
+}
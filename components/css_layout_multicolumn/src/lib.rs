@@ -9,56 +9,11 @@
 //! - Content balancing across columns
 
 use css_parser_core::ParseError;
-use css_types::{Color, CssValue, Length};
+use css_parser_values::parse_border;
+use css_types::{parse_gap, Color, CssValue, Length, LengthUnit};
 
-// ============================================================================
-// Border Style Type
-// ============================================================================
-
-/// Border style for column rules
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BorderStyle {
-    /// No border
-    None,
-    /// Solid line
-    Solid,
-    /// Dashed line
-    Dashed,
-    /// Dotted line
-    Dotted,
-    /// Double line
-    Double,
-    /// 3D groove
-    Groove,
-    /// 3D ridge
-    Ridge,
-    /// 3D inset
-    Inset,
-    /// 3D outset
-    Outset,
-}
-
-impl BorderStyle {
-    /// Parse a border style from a string
-    pub fn parse(input: &str) -> Result<Self, ParseError> {
-        match input.trim().to_lowercase().as_str() {
-            "none" => Ok(BorderStyle::None),
-            "solid" => Ok(BorderStyle::Solid),
-            "dashed" => Ok(BorderStyle::Dashed),
-            "dotted" => Ok(BorderStyle::Dotted),
-            "double" => Ok(BorderStyle::Double),
-            "groove" => Ok(BorderStyle::Groove),
-            "ridge" => Ok(BorderStyle::Ridge),
-            "inset" => Ok(BorderStyle::Inset),
-            "outset" => Ok(BorderStyle::Outset),
-            _ => Err(ParseError::new(
-                0,
-                0,
-                format!("Invalid border style: {}", input),
-            )),
-        }
-    }
-}
+/// Border style, re-exported from `css_types` where it is defined
+pub use css_types::BorderStyle;
 
 // ============================================================================
 // Column Count Type
@@ -90,14 +45,16 @@ pub enum ColumnWidth {
 // Column Gap Type
 // ============================================================================
 
-/// Gap between columns (normal or specific length)
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum ColumnGap {
-    /// Normal gap (typically 1em)
-    Normal,
-    /// Specific gap size
-    Length(Length),
-}
+/// Gap between columns, the shared `css_types` gap value
+///
+/// `ColumnGap::Normal` is 1em in multi-column layout; see
+/// [`NORMAL_COLUMN_GAP`].
+pub use css_types::Gap as ColumnGap;
+
+/// Width of a `normal` column gap: 1em at the default 16px font size
+///
+/// Flex and grid containers resolve `normal` to 0 instead.
+pub const NORMAL_COLUMN_GAP: f32 = 16.0;
 
 // ============================================================================
 // Column Rule Type
@@ -210,6 +167,10 @@ pub struct ComputedColumns {
 }
 
 impl ComputedColumns {
+    /// Widths within this many pixels of the available width still fit,
+    /// absorbing floating-point error from summing columns and gaps
+    pub const FIT_TOLERANCE: f32 = 0.01;
+
     /// Create a new computed columns result
     pub fn new(column_count: u32, column_width: f32, gap_width: f32, total_width: f32) -> Self {
         Self {
@@ -219,6 +180,29 @@ impl ComputedColumns {
             total_width,
         }
     }
+
+    /// Check whether the columns fit in `available_width`
+    ///
+    /// # Examples
+    /// ```
+    /// use css_layout_multicolumn::ComputedColumns;
+    ///
+    /// let columns = ComputedColumns::new(3, 200.0, 0.0, 600.0);
+    /// assert!(columns.fits_within(600.0));
+    /// assert!(!columns.fits_within(599.0));
+    /// ```
+    pub fn fits_within(&self, available_width: f32) -> bool {
+        self.total_width <= available_width + Self::FIT_TOLERANCE
+    }
+
+    /// Get how far the columns extend past `available_width`, or 0 if they fit
+    pub fn overflow_amount(&self, available_width: f32) -> f32 {
+        if self.fits_within(available_width) {
+            0.0
+        } else {
+            self.total_width - available_width
+        }
+    }
 }
 
 // ============================================================================
@@ -238,19 +222,18 @@ impl ComputedColumns {
 /// assert_eq!(count, ColumnCount::Count(3));
 /// ```
 pub fn parse_column_count(input: &str) -> Result<ColumnCount, ParseError> {
-    let input = input.trim();
+    let value = input.trim();
 
-    if input == "auto" {
+    if value == "auto" {
         return Ok(ColumnCount::Auto);
     }
 
-    match input.parse::<u32>() {
+    match value.parse::<u32>() {
         Ok(count) if count > 0 => Ok(ColumnCount::Count(count)),
-        Ok(_) => Err(ParseError::new(0, 0, "Column count must be greater than 0")),
-        Err(_) => Err(ParseError::new(
-            0,
-            0,
-            format!("Invalid column count: {}", input),
+        Ok(_) => Err(value_error(input, "Column count must be greater than 0")),
+        Err(_) => Err(value_error(
+            input,
+            format!("Invalid column count: {}", value),
         )),
     }
 }
@@ -268,19 +251,15 @@ pub fn parse_column_count(input: &str) -> Result<ColumnCount, ParseError> {
 /// assert!(matches!(width, ColumnWidth::Length(_)));
 /// ```
 pub fn parse_column_width(input: &str) -> Result<ColumnWidth, ParseError> {
-    let input = input.trim();
+    let value = input.trim();
 
-    if input == "auto" {
+    if value == "auto" {
         return Ok(ColumnWidth::Auto);
     }
 
-    match Length::parse(input) {
+    match Length::parse(value) {
         Ok(length) => Ok(ColumnWidth::Length(length)),
-        Err(e) => Err(ParseError::new(
-            0,
-            0,
-            format!("Invalid column width: {}", e),
-        )),
+        Err(e) => Err(value_error(input, format!("Invalid column width: {}", e))),
     }
 }
 
@@ -297,20 +276,37 @@ pub fn parse_column_width(input: &str) -> Result<ColumnWidth, ParseError> {
 /// assert!(matches!(gap, ColumnGap::Length(_)));
 /// ```
 pub fn parse_column_gap(input: &str) -> Result<ColumnGap, ParseError> {
-    let input = input.trim();
+    parse_gap(input).map_err(|e| value_error(input, format!("Invalid column gap: {}", e)))
+}
 
-    if input == "normal" {
-        return Ok(ColumnGap::Normal);
+/// Resolve a column gap to pixels
+///
+/// `em` and `rem` use the default 16px font size that [`NORMAL_COLUMN_GAP`]
+/// assumes, and percentages refer to the available width. Viewport units
+/// have no viewport to resolve against and fall back to `normal`.
+fn resolve_column_gap(gap: ColumnGap, available_width: f32) -> f32 {
+    match gap {
+        ColumnGap::Length(length) => match length.unit() {
+            LengthUnit::Em | LengthUnit::Rem => length.value() * NORMAL_COLUMN_GAP,
+            LengthUnit::Percent => length.value() / 100.0 * available_width,
+            _ => length.to_px().unwrap_or(NORMAL_COLUMN_GAP),
+        },
+        ColumnGap::Normal => NORMAL_COLUMN_GAP,
     }
+}
 
-    match Length::parse(input) {
-        Ok(length) => Ok(ColumnGap::Length(length)),
-        Err(e) => Err(ParseError::new(0, 0, format!("Invalid column gap: {}", e))),
-    }
+/// Build a parse error pointing at the trimmed value within `input`
+fn value_error(input: &str, message: impl Into<String>) -> ParseError {
+    let offset = input.len() - input.trim_start().len();
+    ParseError::new(0, 0, message).with_offset(offset, input.trim())
 }
 
 /// Parse column-rule shorthand property
 ///
+/// Accepts the same syntax as [`parse_border`]; omitted components take their
+/// initial values. A column rule has no element to take `currentColor` from
+/// here, so it resolves against the initial `color` value (black).
+///
 /// # Examples
 /// ```
 /// use css_layout_multicolumn::parse_column_rule;
@@ -319,30 +315,14 @@ pub fn parse_column_gap(input: &str) -> Result<ColumnGap, ParseError> {
 /// assert_eq!(rule.style, css_layout_multicolumn::BorderStyle::Solid);
 /// ```
 pub fn parse_column_rule(input: &str) -> Result<ColumnRule, ParseError> {
-    let input = input.trim();
-    let parts: Vec<&str> = input.split_whitespace().collect();
-
-    if parts.len() < 3 {
-        return Err(ParseError::new(
-            0,
-            0,
-            "Column rule requires width, style, and color",
-        ));
-    }
-
-    // Parse width
-    let width = Length::parse(parts[0])
-        .map_err(|e| ParseError::new(0, 0, format!("Invalid rule width: {}", e)))?;
-
-    // Parse style
-    let style = BorderStyle::parse(parts[1])?;
-
-    // Parse color (everything after style)
-    let color_str = parts[2..].join(" ");
-    let color = Color::parse(&color_str)
-        .map_err(|e| ParseError::new(0, 0, format!("Invalid rule color: {}", e)))?;
-
-    Ok(ColumnRule::new(width, style, color))
+    let (width, style, color) = parse_border(input)
+        .map_err(|e| value_error(input, format!("Invalid column rule: {}", e)))?;
+
+    Ok(ColumnRule::new(
+        width,
+        style,
+        color.resolve(Color::rgb(0, 0, 0)),
+    ))
 }
 
 // ============================================================================
@@ -356,10 +336,14 @@ pub fn parse_column_rule(input: &str) -> Result<ColumnRule, ParseError> {
 ///
 /// # Algorithm
 /// - If count is specified and width is auto: divide available width by count
-/// - If width is specified and count is auto: fit as many columns as possible
+/// - If width is specified and count is auto: fit as many columns as possible;
+///   when not even one fits, a single column clamped to the available width
 /// - If both are auto: use a default count (typically 1)
 /// - If both are specified: use specified values (may overflow container)
 ///
+/// A zero or negative available width never produces negative widths:
+/// auto-count layouts collapse to a single zero-width column.
+///
 /// # Examples
 /// ```
 /// use css_layout_multicolumn::{compute_column_layout, MultiColumnLayout, ColumnCount};
@@ -371,28 +355,29 @@ pub fn parse_column_rule(input: &str) -> Result<ColumnRule, ParseError> {
 /// assert_eq!(computed.column_count, 3);
 /// ```
 pub fn compute_column_layout(config: &MultiColumnLayout, available_width: f32) -> ComputedColumns {
-    // Determine gap width (default to 1em = 16px for normal)
-    let gap_width = match config.column_gap {
-        ColumnGap::Normal => 16.0,
-        ColumnGap::Length(length) => length.value(),
-    };
+    let gap_width = resolve_column_gap(config.column_gap, available_width);
 
     match (config.column_count, config.column_width) {
         // Both auto: default to 1 column
         (ColumnCount::Auto, ColumnWidth::Auto) => {
-            ComputedColumns::new(1, available_width, gap_width, available_width)
+            let width = available_width.max(0.0);
+            ComputedColumns::new(1, width, gap_width, width)
         }
 
         // Count specified, width auto: divide available width
         (ColumnCount::Count(count), ColumnWidth::Auto) => {
             let total_gap_width = gap_width * (count - 1) as f32;
-            let column_width = (available_width - total_gap_width) / count as f32;
+            let column_width = ((available_width - total_gap_width) / count as f32).max(0.0);
             ComputedColumns::new(count, column_width, gap_width, available_width)
         }
 
         // Width specified, count auto: fit as many columns as possible
         (ColumnCount::Auto, ColumnWidth::Length(width)) => {
-            let col_width = width.value();
+            if available_width <= 0.0 {
+                return ComputedColumns::new(1, 0.0, gap_width, 0.0);
+            }
+            // A single column narrower than requested rather than overflowing
+            let col_width = width.value().min(available_width);
             let mut count = 1;
             let mut total = col_width;
 
@@ -191,7 +191,7 @@ fn test_error_handling() {
     assert!(parse_column_width("invalid").is_err());
     assert!(parse_column_gap("invalid").is_err());
     assert!(parse_column_rule("invalid").is_err());
-    assert!(parse_column_rule("1px solid").is_err());
+    assert!(parse_column_rule("1px solid #000000 solid").is_err());
 }
 
 #[test]
@@ -205,3 +205,13 @@ fn test_default_multicolumn_layout() {
     assert_eq!(config.column_span, ColumnSpan::None);
     assert_eq!(config.column_fill, ColumnFill::Balance);
 }
+
+#[test]
+fn test_border_style_reexport_is_css_types_definition() {
+    // The multicolumn re-export and the canonical css_types type are the same
+    let style: css_types::BorderStyle = BorderStyle::Groove;
+    assert_eq!(style, css_types::BorderStyle::Groove);
+
+    let rule = parse_column_rule("1px ridge #000000").unwrap();
+    assert_eq!(rule.style, css_types::BorderStyle::Ridge);
+}
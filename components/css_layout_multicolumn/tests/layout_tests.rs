@@ -97,6 +97,40 @@ fn test_compute_column_layout_both_specified() {
     assert_eq!(computed.total_width, 630.0);
 }
 
+#[test]
+fn test_fixed_columns_overflow_container() {
+    let mut config = MultiColumnLayout::new();
+    config.column_count = ColumnCount::Count(3);
+    config.column_width = ColumnWidth::Length(Length::new(250.0, LengthUnit::Px));
+    config.column_gap = ColumnGap::Length(Length::new(20.0, LengthUnit::Px));
+
+    let computed = compute_column_layout(&config, 600.0);
+
+    // 3*250 + 2*20 = 790
+    assert_eq!(computed.total_width, 790.0);
+    assert!(!computed.fits_within(600.0));
+    assert_eq!(computed.overflow_amount(600.0), 190.0);
+    assert!(computed.fits_within(790.0));
+    assert_eq!(computed.overflow_amount(800.0), 0.0);
+}
+
+#[test]
+fn test_fits_within_tolerates_rounding() {
+    let mut config = MultiColumnLayout::new();
+    config.column_count = ColumnCount::Count(3);
+    config.column_gap = ColumnGap::Length(Length::new(10.0, LengthUnit::Px));
+
+    let computed = compute_column_layout(&config, 100.0);
+    let summed = computed.column_width * 3.0 + computed.gap_width * 2.0;
+
+    assert!(computed.fits_within(100.0));
+    assert!(ComputedColumns::new(3, 0.0, 0.0, summed).fits_within(100.0));
+    assert_eq!(
+        ComputedColumns::new(1, 100.005, 0.0, 100.005).overflow_amount(100.0),
+        0.0
+    );
+}
+
 #[test]
 fn test_compute_column_layout_single_column() {
     let mut config = MultiColumnLayout::new();
@@ -161,6 +195,37 @@ fn test_compute_column_layout_large_gap() {
     assert_eq!(computed.column_width, 250.0);
 }
 
+#[test]
+fn test_relative_gap_keeps_its_unit() {
+    let mut config = MultiColumnLayout::new();
+    config.column_count = ColumnCount::Count(2);
+
+    config.column_gap = css_types::parse_gap("1.5em").unwrap();
+    assert_eq!(compute_column_layout(&config, 400.0).gap_width, 24.0);
+
+    config.column_gap = css_types::parse_gap("10%").unwrap();
+    assert_eq!(compute_column_layout(&config, 400.0).gap_width, 40.0);
+
+    config.column_gap = css_types::parse_gap("1in").unwrap();
+    assert_eq!(compute_column_layout(&config, 400.0).gap_width, 96.0);
+}
+
+#[test]
+fn test_normal_gap_is_one_em() {
+    // The same parsed `normal` is 0 in flex and grid layout
+    let normal = css_types::parse_gap("normal").unwrap();
+    assert_eq!(normal.resolve(0.0), Some(0.0));
+
+    let mut config = MultiColumnLayout::new();
+    config.column_count = ColumnCount::Count(2);
+    config.column_gap = normal;
+    let computed = compute_column_layout(&config, 416.0);
+
+    assert_eq!(computed.gap_width, NORMAL_COLUMN_GAP);
+    assert_eq!(computed.gap_width, 16.0);
+    assert_eq!(computed.column_width, 200.0);
+}
+
 // ============================================================================
 // Content Balancing Tests
 // ============================================================================
@@ -282,9 +347,43 @@ fn test_very_narrow_container() {
 
     let computed = compute_column_layout(&config, 100.0);
 
-    // Container too narrow for specified width, but still creates 1 column
+    // Container too narrow for specified width: 1 column clamped to the container
     assert_eq!(computed.column_count, 1);
-    assert_eq!(computed.column_width, 200.0);
+    assert_eq!(computed.column_width, 100.0);
+    assert_eq!(computed.total_width, 100.0);
+}
+
+#[test]
+fn test_zero_available_width() {
+    let mut config = MultiColumnLayout::new();
+    config.column_width = ColumnWidth::Length(Length::new(200.0, LengthUnit::Px));
+
+    for available_width in [0.0, -50.0] {
+        let computed = compute_column_layout(&config, available_width);
+        assert_eq!(computed.column_count, 1);
+        assert_eq!(computed.column_width, 0.0);
+        assert_eq!(computed.total_width, 0.0);
+    }
+
+    let computed = compute_column_layout(&MultiColumnLayout::new(), -10.0);
+    assert_eq!(computed.column_width, 0.0);
+}
+
+#[test]
+fn test_tiny_available_width() {
+    let mut config = MultiColumnLayout::new();
+    config.column_width = ColumnWidth::Length(Length::new(200.0, LengthUnit::Px));
+
+    let computed = compute_column_layout(&config, 0.5);
+    assert_eq!(computed.column_count, 1);
+    assert_eq!(computed.column_width, 0.5);
+
+    // Gaps wider than the container do not produce negative columns
+    config.column_count = ColumnCount::Count(3);
+    config.column_width = ColumnWidth::Auto;
+    let computed = compute_column_layout(&config, 10.0);
+    assert_eq!(computed.column_count, 3);
+    assert_eq!(computed.column_width, 0.0);
 }
 
 #[test]
@@ -1,7 +1,7 @@
 //! Unit tests for CSS multi-column parsing functions
 
 use css_layout_multicolumn::*;
-use css_types::LengthUnit;
+use css_types::{Color, LengthUnit};
 
 // ============================================================================
 // Column Count Parsing Tests
@@ -41,6 +41,20 @@ fn test_parse_column_count_zero_invalid() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_parse_column_count_error_reports_offset() {
+    let err = parse_column_count("  abc ").unwrap_err();
+    assert_eq!(err.offset, Some(2));
+    assert_eq!(err.snippet.as_deref(), Some("abc"));
+}
+
+#[test]
+fn test_parse_column_gap_error_reports_offset() {
+    let err = parse_column_gap(" 10qq").unwrap_err();
+    assert_eq!(err.offset, Some(1));
+    assert_eq!(err.snippet.as_deref(), Some("10qq"));
+}
+
 #[test]
 fn test_parse_column_count_negative_invalid() {
     let result = parse_column_count("-1");
@@ -283,8 +297,16 @@ fn test_parse_column_rule_em_width() {
 }
 
 #[test]
-fn test_parse_column_rule_missing_parts() {
-    let result = parse_column_rule("1px solid");
+fn test_parse_column_rule_missing_parts_use_defaults() {
+    let rule = parse_column_rule("1px solid").unwrap();
+    assert_eq!(rule.width.value(), 1.0);
+    assert_eq!(rule.style, BorderStyle::Solid);
+    assert_eq!(rule.color, Color::rgb(0, 0, 0));
+}
+
+#[test]
+fn test_parse_column_rule_extra_token() {
+    let result = parse_column_rule("1px solid #000000 2px");
     assert!(result.is_err());
 }
 
@@ -306,6 +328,12 @@ fn test_parse_column_rule_invalid_color() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_parse_column_rule_current_color_uses_initial_color() {
+    let rule = parse_column_rule("1px solid currentColor").unwrap();
+    assert_eq!(rule.color, Color::rgb(0, 0, 0));
+}
+
 // ============================================================================
 // Border Style Parsing Tests
 // ============================================================================
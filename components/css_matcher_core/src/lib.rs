@@ -3,8 +3,9 @@
 //! This crate provides core selector matching functionality for CSS selectors.
 //! It supports CSS2.1 selectors including:
 //! - Simple selectors (tag, class, ID, universal)
+//! - Structural pseudo-classes (:first-child, :last-child, :only-child)
 //! - Compound selectors (combinations of simple selectors)
-//! - Combinators (descendant, child, adjacent sibling)
+//! - Combinators (descendant, child, adjacent sibling, general sibling)
 //!
 //! # Examples
 //!
@@ -28,6 +29,7 @@
 //! #     fn classes(&self) -> &[String] { &self.classes }
 //! #     fn parent(&self) -> Option<&Self> { None }
 //! #     fn previous_sibling(&self) -> Option<&Self> { None }
+//! #     fn next_sibling(&self) -> Option<&Self> { None }
 //! # }
 //! let matcher = SelectorMatcher;
 //! let selector = Selector {
@@ -41,5 +43,7 @@
 mod matcher;
 mod types;
 
-pub use matcher::{ElementLike, SelectorMatcher};
+pub use matcher::{
+    matches_complex_selector, matches_component, matches_selector, ElementLike, SelectorMatcher,
+};
 pub use types::{Combinator, ComplexSelector, Component, Selector};
@@ -3,6 +3,7 @@
 //! This module implements the core selector matching algorithm.
 
 use crate::types::{Combinator, ComplexSelector, Component, Selector};
+use css_parser_core::Selector as ParsedSelector;
 
 /// A trait for elements that can be matched against selectors
 ///
@@ -22,6 +23,9 @@ pub trait ElementLike {
 
     /// Get the element's previous sibling, if it has one
     fn previous_sibling(&self) -> Option<&Self>;
+
+    /// Get the element's next sibling, if it has one
+    fn next_sibling(&self) -> Option<&Self>;
 }
 
 /// The selector matcher
@@ -57,6 +61,7 @@ impl SelectorMatcher {
     /// #     fn classes(&self) -> &[String] { &self.classes }
     /// #     fn parent(&self) -> Option<&Self> { None }
     /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+    /// #     fn next_sibling(&self) -> Option<&Self> { None }
     /// # }
     /// let matcher = SelectorMatcher;
     /// let selector = Selector {
@@ -76,20 +81,7 @@ impl SelectorMatcher {
         selector
             .components
             .iter()
-            .all(|component| self.match_component(component, element))
-    }
-
-    /// Check if a single component matches an element
-    fn match_component<E: ElementLike>(&self, component: &Component, element: &E) -> bool {
-        match component {
-            Component::Universal => true,
-            Component::Tag(tag) => {
-                // Tag matching is case-insensitive for HTML
-                element.tag_name().eq_ignore_ascii_case(tag)
-            }
-            Component::Class(class) => element.classes().iter().any(|c| c == class),
-            Component::Id(id) => element.id().is_some_and(|element_id| element_id == id),
-        }
+            .all(|component| matches_component(component, element))
     }
 
     /// Check if an element matches a complex selector with combinators
@@ -132,6 +124,7 @@ impl SelectorMatcher {
     /// #     fn classes(&self) -> &[String] { &self.classes }
     /// #     fn parent(&self) -> Option<&Self> { self.parent.as_deref() }
     /// #     fn previous_sibling(&self) -> Option<&Self> { self.previous_sibling.as_deref() }
+    /// #     fn next_sibling(&self) -> Option<&Self> { None }
     /// # }
     /// let matcher = SelectorMatcher;
     /// let selector = ComplexSelector {
@@ -202,6 +195,10 @@ impl SelectorMatcher {
                     self.match_complex_recursive(remaining, sibling)
                 })
             }
+            Some(Combinator::GeneralSibling) => {
+                // Match any previous sibling
+                self.match_preceding_sibling(remaining, element)
+            }
             None => {
                 // This shouldn't happen in well-formed selectors
                 false
@@ -227,6 +224,114 @@ impl SelectorMatcher {
 
         false
     }
+
+    /// Match any preceding sibling (for general sibling combinator)
+    fn match_preceding_sibling<E: ElementLike>(
+        &self,
+        components: &[(Selector, Option<Combinator>)],
+        element: &E,
+    ) -> bool {
+        let mut current = element.previous_sibling();
+
+        // Walk back through the siblings until we find a match or run out
+        while let Some(sibling) = current {
+            if self.match_complex_recursive(components, sibling) {
+                return true;
+            }
+            current = sibling.previous_sibling();
+        }
+
+        false
+    }
+}
+
+/// Check if an element matches a complex selector with combinators
+///
+/// Matching proceeds right to left: the last compound selector must match
+/// `element`, then each combinator moves to the elements the selector to its
+/// left may match. Descendant (`a b`) walks up through `parent()`, child
+/// (`a > b`) checks only the immediate parent, adjacent sibling (`a + b`)
+/// checks only `previous_sibling()`, and general sibling (`a ~ b`) walks back
+/// through every previous sibling. This is equivalent to
+/// [`SelectorMatcher::matches_complex`].
+pub fn matches_complex_selector<E: ElementLike>(complex: &ComplexSelector, element: &E) -> bool {
+    SelectorMatcher.matches_complex(complex, element)
+}
+
+/// Check if a single selector component matches an element
+///
+/// Structural pseudo-classes are decided from the sibling links alone:
+/// `:first-child` has no previous sibling, `:last-child` no next sibling and
+/// `:only-child` neither. The default sibling positions in
+/// `css_matcher_pseudo` are counted along the same links, so both crates
+/// agree.
+pub fn matches_component<E: ElementLike>(component: &Component, element: &E) -> bool {
+    match component {
+        Component::Universal => true,
+        Component::Tag(tag) => {
+            // Tag matching is case-insensitive for HTML
+            element.tag_name().eq_ignore_ascii_case(tag)
+        }
+        Component::Class(class) => element.classes().iter().any(|c| c == class),
+        Component::Id(id) => element.id().is_some_and(|element_id| element_id == id),
+        Component::FirstChild => element.previous_sibling().is_none(),
+        Component::LastChild => element.next_sibling().is_none(),
+        Component::OnlyChild => {
+            element.previous_sibling().is_none() && element.next_sibling().is_none()
+        }
+    }
+}
+
+/// Check if an element matches a selector produced by `css_parser_core`
+///
+/// This is the entry point for matching parsed stylesheet selectors. Element
+/// names are compared ASCII case-insensitively, as in [`SelectorMatcher`],
+/// while classes and ids are compared exactly. A compound selector matches
+/// when every part it specifies matches; an element name of `*` matches any
+/// element.
+///
+/// # Examples
+///
+/// ```
+/// use css_matcher_core::{matches_selector, ElementLike};
+/// use css_parser_core::Selector;
+///
+/// # struct Element {
+/// #     classes: Vec<String>,
+/// # }
+/// # impl ElementLike for Element {
+/// #     fn tag_name(&self) -> &str { "div" }
+/// #     fn id(&self) -> Option<&str> { None }
+/// #     fn classes(&self) -> &[String] { &self.classes }
+/// #     fn parent(&self) -> Option<&Self> { None }
+/// #     fn previous_sibling(&self) -> Option<&Self> { None }
+/// #     fn next_sibling(&self) -> Option<&Self> { None }
+/// # }
+/// let element = Element { classes: vec!["foo".to_string()] };
+///
+/// assert!(matches_selector(&Selector::Class("foo".to_string()), &element));
+/// assert!(!matches_selector(&Selector::Class("bar".to_string()), &element));
+/// ```
+pub fn matches_selector<E: ElementLike>(selector: &ParsedSelector, element: &E) -> bool {
+    let matches_tag = |tag: &str| tag == "*" || element.tag_name().eq_ignore_ascii_case(tag);
+    let matches_class = |class: &str| element.classes().iter().any(|c| c == class);
+    let matches_id = |id: &str| element.id() == Some(id);
+
+    match selector {
+        ParsedSelector::Universal => true,
+        ParsedSelector::Element(tag) => matches_tag(tag),
+        ParsedSelector::Class(class) => matches_class(class),
+        ParsedSelector::Id(id) => matches_id(id),
+        ParsedSelector::Compound {
+            element: tag,
+            classes,
+            id,
+        } => {
+            tag.as_deref().map_or(true, matches_tag)
+                && classes.iter().all(|class| matches_class(class))
+                && id.as_deref().map_or(true, matches_id)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -281,6 +386,10 @@ mod tests {
         fn previous_sibling(&self) -> Option<&Self> {
             None
         }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
     }
 
     #[test]
@@ -13,6 +13,12 @@ pub enum Component {
     Class(String),
     /// ID selector (e.g., #header)
     Id(String),
+    /// :first-child - element has no previous sibling
+    FirstChild,
+    /// :last-child - element has no next sibling
+    LastChild,
+    /// :only-child - element has no siblings
+    OnlyChild,
 }
 
 /// A simple or compound selector
@@ -57,6 +63,8 @@ pub enum Combinator {
     Child,
     /// Adjacent sibling combinator (+) - matches immediately following sibling
     Adjacent,
+    /// General sibling combinator (~) - matches any following sibling
+    GeneralSibling,
 }
 
 /// A complex selector with combinators
@@ -66,6 +74,7 @@ pub enum Combinator {
 /// - `div span` is: [(Selector[Tag("div")], Descendant), (Selector[Tag("span")], None)]
 /// - `div > span` is: [(Selector[Tag("div")], Child), (Selector[Tag("span")], None)]
 /// - `div + span` is: [(Selector[Tag("div")], Adjacent), (Selector[Tag("span")], None)]
+/// - `div ~ span` is: [(Selector[Tag("div")], GeneralSibling), (Selector[Tag("span")], None)]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ComplexSelector {
     /// Pairs of (selector, optional combinator to next selector)
@@ -1,8 +1,10 @@
 // Integration tests for css_matcher_core
 
 use css_matcher_core::{
-    Combinator, ComplexSelector, Component, ElementLike, Selector, SelectorMatcher,
+    matches_complex_selector, matches_selector, Combinator, ComplexSelector, Component,
+    ElementLike, Selector, SelectorMatcher,
 };
+use css_parser_core::Selector as ParsedSelector;
 
 // Test element with parent and sibling support
 #[derive(Debug, Clone)]
@@ -12,6 +14,7 @@ struct Element {
     classes: Vec<String>,
     parent: Option<Box<Element>>,
     previous_sibling: Option<Box<Element>>,
+    next_sibling: Option<Box<Element>>,
 }
 
 impl Element {
@@ -22,6 +25,7 @@ impl Element {
             classes: Vec::new(),
             parent: None,
             previous_sibling: None,
+            next_sibling: None,
         }
     }
 
@@ -49,6 +53,11 @@ impl Element {
         self.previous_sibling = Some(Box::new(sibling));
         self
     }
+
+    fn with_next_sibling(mut self, sibling: Element) -> Self {
+        self.next_sibling = Some(Box::new(sibling));
+        self
+    }
 }
 
 impl ElementLike for Element {
@@ -71,6 +80,10 @@ impl ElementLike for Element {
     fn previous_sibling(&self) -> Option<&Self> {
         self.previous_sibling.as_deref()
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        self.next_sibling.as_deref()
+    }
 }
 
 // Simple Selector Tests
@@ -496,3 +509,242 @@ fn test_complex_selector_multiple_combinators() {
 
     assert!(matcher.matches_complex(&selector, &span));
 }
+
+#[test]
+fn test_matches_parsed_class_selector() {
+    let selector = ParsedSelector::Class("foo".to_string());
+
+    assert!(matches_selector(
+        &selector,
+        &Element::new("div").with_class("foo")
+    ));
+    assert!(!matches_selector(
+        &selector,
+        &Element::new("div").with_class("bar")
+    ));
+    assert!(!matches_selector(&selector, &Element::new("div")));
+}
+
+#[test]
+fn test_matches_parsed_simple_selectors() {
+    let element = Element::new("div").with_id("main");
+
+    assert!(matches_selector(&ParsedSelector::Universal, &element));
+    assert!(matches_selector(
+        &ParsedSelector::Element("DIV".to_string()),
+        &element
+    ));
+    assert!(!matches_selector(
+        &ParsedSelector::Element("span".to_string()),
+        &element
+    ));
+    assert!(matches_selector(
+        &ParsedSelector::Id("main".to_string()),
+        &element
+    ));
+    assert!(!matches_selector(
+        &ParsedSelector::Id("Main".to_string()),
+        &element
+    ));
+}
+
+#[test]
+fn test_matches_parsed_compound_selector() {
+    // div.foo#bar
+    let selector = ParsedSelector::Compound {
+        element: Some("div".to_string()),
+        classes: vec!["foo".to_string()],
+        id: Some("bar".to_string()),
+    };
+
+    let full = Element::new("div")
+        .with_classes(&["foo", "wide"])
+        .with_id("bar");
+    assert!(matches_selector(&selector, &full));
+
+    assert!(!matches_selector(
+        &selector,
+        &Element::new("span").with_class("foo").with_id("bar")
+    ));
+    assert!(!matches_selector(
+        &selector,
+        &Element::new("div").with_id("bar")
+    ));
+    assert!(!matches_selector(
+        &selector,
+        &Element::new("div").with_class("foo")
+    ));
+
+    let any_element = ParsedSelector::Compound {
+        element: Some("*".to_string()),
+        classes: vec!["foo".to_string()],
+        id: None,
+    };
+    assert!(matches_selector(
+        &any_element,
+        &Element::new("p").with_class("foo")
+    ));
+}
+
+fn compound(components: Vec<Component>) -> Selector {
+    Selector::with_components(components)
+}
+
+fn class(name: &str) -> Component {
+    Component::Class(name.to_string())
+}
+
+fn tag(name: &str) -> Component {
+    Component::Tag(name.to_string())
+}
+
+#[test]
+fn test_matches_complex_selector_child() {
+    // div > .child
+    let selector = ComplexSelector::with_components(vec![
+        (compound(vec![tag("div")]), Some(Combinator::Child)),
+        (compound(vec![class("child")]), None),
+    ]);
+
+    let direct = Element::new("span")
+        .with_class("child")
+        .with_parent(Element::new("div"));
+    assert!(matches_complex_selector(&selector, &direct));
+
+    let nested = Element::new("span")
+        .with_class("child")
+        .with_parent(Element::new("p").with_parent(Element::new("div")));
+    assert!(!matches_complex_selector(&selector, &nested));
+}
+
+#[test]
+fn test_matches_complex_selector_adjacent_sibling() {
+    // .a + .b
+    let selector = ComplexSelector::with_components(vec![
+        (compound(vec![class("a")]), Some(Combinator::Adjacent)),
+        (compound(vec![class("b")]), None),
+    ]);
+
+    let adjacent = Element::new("li")
+        .with_class("b")
+        .with_previous_sibling(Element::new("li").with_class("a"));
+    assert!(matches_complex_selector(&selector, &adjacent));
+
+    let separated = Element::new("li").with_class("b").with_previous_sibling(
+        Element::new("li").with_previous_sibling(Element::new("li").with_class("a")),
+    );
+    assert!(!matches_complex_selector(&selector, &separated));
+}
+
+#[test]
+fn test_matches_complex_selector_general_sibling() {
+    // .a ~ .b
+    let selector = ComplexSelector::with_components(vec![
+        (compound(vec![class("a")]), Some(Combinator::GeneralSibling)),
+        (compound(vec![class("b")]), None),
+    ]);
+
+    let separated = Element::new("li").with_class("b").with_previous_sibling(
+        Element::new("li").with_previous_sibling(Element::new("li").with_class("a")),
+    );
+    assert!(matches_complex_selector(&selector, &separated));
+
+    let no_match = Element::new("li")
+        .with_class("b")
+        .with_previous_sibling(Element::new("li").with_class("c"))
+        .with_parent(Element::new("ul").with_class("a"));
+    assert!(!matches_complex_selector(&selector, &no_match));
+}
+
+#[test]
+fn test_matches_complex_selector_descendant() {
+    // .nav a.link
+    let selector = ComplexSelector::with_components(vec![
+        (compound(vec![class("nav")]), Some(Combinator::Descendant)),
+        (compound(vec![tag("a"), class("link")]), None),
+    ]);
+
+    let tree =
+        Element::new("a")
+            .with_class("link")
+            .with_parent(Element::new("li").with_parent(
+                Element::new("ul").with_parent(Element::new("nav").with_class("nav")),
+            ));
+    assert!(matches_complex_selector(&selector, &tree));
+
+    let outside = Element::new("a")
+        .with_class("link")
+        .with_parent(Element::new("div"))
+        .with_previous_sibling(Element::new("div").with_class("nav"));
+    assert!(!matches_complex_selector(&selector, &outside));
+}
+
+/// The children of `<ul><li.a><li.b><li.c></ul>`, each linked to its siblings
+fn three_children() -> [Element; 3] {
+    let child = |class: &str| {
+        Element::new("li")
+            .with_class(class)
+            .with_parent(Element::new("ul"))
+    };
+
+    [
+        child("a").with_next_sibling(child("b")),
+        child("b")
+            .with_previous_sibling(child("a"))
+            .with_next_sibling(child("c")),
+        child("c").with_previous_sibling(child("b")),
+    ]
+}
+
+#[test]
+fn test_structural_first_last_child() {
+    let matcher = SelectorMatcher;
+    let first = compound(vec![Component::FirstChild]);
+    let last = compound(vec![Component::LastChild]);
+    let [a, b, c] = three_children();
+
+    assert!(matcher.matches(&first, &a));
+    assert!(!matcher.matches(&first, &b));
+    assert!(!matcher.matches(&first, &c));
+
+    assert!(!matcher.matches(&last, &a));
+    assert!(!matcher.matches(&last, &b));
+    assert!(matcher.matches(&last, &c));
+}
+
+#[test]
+fn test_structural_only_child() {
+    let matcher = SelectorMatcher;
+    let only = compound(vec![Component::OnlyChild]);
+
+    for child in three_children() {
+        assert!(!matcher.matches(&only, &child));
+    }
+
+    let lone = Element::new("li").with_parent(Element::new("ul"));
+    assert!(matcher.matches(&only, &lone));
+}
+
+#[test]
+fn test_structural_pseudo_class_in_complex_selector() {
+    // ul > li.b:last-child does not match, li:first-child + .b does
+    let [_, b, _] = three_children();
+
+    let last_b = ComplexSelector::with_components(vec![
+        (compound(vec![tag("ul")]), Some(Combinator::Child)),
+        (
+            compound(vec![tag("li"), class("b"), Component::LastChild]),
+            None,
+        ),
+    ]);
+    assert!(!matches_complex_selector(&last_b, &b));
+
+    let after_first = ComplexSelector::with_components(vec![
+        (
+            compound(vec![tag("li"), Component::FirstChild]),
+            Some(Combinator::Adjacent),
+        ),
+        (compound(vec![class("b")]), None),
+    ]);
+    assert!(matches_complex_selector(&after_first, &b));
+}
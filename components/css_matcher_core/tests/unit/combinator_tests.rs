@@ -69,6 +69,10 @@ impl ElementLike for Element {
     fn previous_sibling(&self) -> Option<&Self> {
         self.previous_sibling.as_deref()
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
 }
 
 #[test]
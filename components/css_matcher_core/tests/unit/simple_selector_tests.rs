@@ -55,6 +55,10 @@ impl ElementLike for Element {
     fn previous_sibling(&self) -> Option<&Self> {
         None
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
 }
 
 #[test]
@@ -5,6 +5,7 @@
 //! - Structural pseudo-classes (:first-child, :last-child, :nth-child)
 //! - User action pseudo-classes (:hover, :active, :focus)
 //! - UI state pseudo-classes (:enabled, :disabled, :checked)
+//! - Selector-list pseudo-classes (:not(), :is(), :where())
 //! - Pseudo-elements (::before, ::after, ::first-line, ::first-letter)
 
 mod nth;
@@ -13,7 +14,8 @@ mod pseudo_element;
 
 pub use nth::{parse_nth_selector, NthSelector};
 pub use pseudo_class::{
-    evaluate_pseudo_class, ElementLikeExt, MatchContext, PseudoClass, PseudoClassKind,
+    evaluate_pseudo_class, parse_functional_pseudo_class, ElementLikeExt, MatchContext,
+    PseudoClass, PseudoClassKind,
 };
 pub use pseudo_element::{
     DefaultPseudoElementMatcher, PseudoElement, PseudoElementKind, PseudoElementMatcher,
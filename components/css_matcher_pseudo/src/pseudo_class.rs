@@ -2,10 +2,12 @@
 //!
 //! This module provides types and functions for matching CSS pseudo-classes.
 
-use css_matcher_core::ElementLike;
+use css_matcher_core::{matches_selector, ElementLike};
+use css_parser_core::{parse_selector_list, Selector};
+use css_types::{split_function, CssError, Specificity};
 
 /// Types of pseudo-classes supported
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PseudoClassKind {
     /// :hover - element is being hovered
     Hover,
@@ -43,6 +45,12 @@ pub enum PseudoClassKind {
     Disabled,
     /// :checked - form element is checked
     Checked,
+    /// :not(selector-list) - element matches none of the selectors
+    Not(Vec<Selector>),
+    /// :is(selector-list) - element matches any of the selectors
+    Is(Vec<Selector>),
+    /// :where(selector-list) - like :is(), but adds no specificity
+    Where(Vec<Selector>),
 }
 
 /// Represents a pseudo-class selector
@@ -70,6 +78,71 @@ impl PseudoClass {
             argument: Some(argument),
         }
     }
+
+    /// Specificity the pseudo-class adds to its selector
+    ///
+    /// `:not()` and `:is()` take the specificity of their most specific
+    /// argument, `:where()` adds none and every other pseudo-class counts
+    /// as one class selector.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_matcher_pseudo::parse_functional_pseudo_class;
+    /// use css_types::Specificity;
+    ///
+    /// let is = parse_functional_pseudo_class(":is(div, #main)").unwrap();
+    /// assert_eq!(is.specificity(), Specificity::new(1, 0, 0));
+    ///
+    /// let where_ = parse_functional_pseudo_class(":where(div, #main)").unwrap();
+    /// assert_eq!(where_.specificity(), Specificity::zero());
+    /// ```
+    pub fn specificity(&self) -> Specificity {
+        match &self.kind {
+            PseudoClassKind::Not(selectors) | PseudoClassKind::Is(selectors) => selectors
+                .iter()
+                .map(Selector::specificity)
+                .fold(Specificity::zero(), Specificity::max),
+            PseudoClassKind::Where(_) => Specificity::zero(),
+            _ => Specificity::new(0, 1, 0),
+        }
+    }
+}
+
+/// Parse a functional pseudo-class taking a selector list
+///
+/// Accepts `:not()`, `:is()` and `:where()` (names are ASCII
+/// case-insensitive); the leading colon is optional.
+///
+/// # Examples
+/// ```
+/// use css_matcher_pseudo::{parse_functional_pseudo_class, PseudoClassKind};
+///
+/// let pseudo = parse_functional_pseudo_class(":not(.hidden, span)").unwrap();
+/// assert!(matches!(pseudo.kind, PseudoClassKind::Not(ref list) if list.len() == 2));
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` for other pseudo-classes, malformed
+/// calls and invalid selector lists.
+pub fn parse_functional_pseudo_class(input: &str) -> Result<PseudoClass, CssError> {
+    let input = input.trim();
+    let (name, arguments) = split_function(input.strip_prefix(':').unwrap_or(input))?;
+    let selectors = parse_selector_list(arguments).map_err(|e| {
+        CssError::ParseError(format!("Invalid selector list in :{}(): {}", name, e))
+    })?;
+
+    let kind = match name.to_ascii_lowercase().as_str() {
+        "not" => PseudoClassKind::Not(selectors),
+        "is" => PseudoClassKind::Is(selectors),
+        "where" => PseudoClassKind::Where(selectors),
+        _ => {
+            return Err(CssError::ParseError(format!(
+                "Unsupported functional pseudo-class: :{}()",
+                name
+            )))
+        }
+    };
+    Ok(PseudoClass::new(kind))
 }
 
 /// Match context provides state information for pseudo-class matching
@@ -135,7 +208,14 @@ impl MatchContext {
 }
 
 /// Trait extension for ElementLike to support pseudo-class matching
-pub trait ElementLikeExt {
+///
+/// All structural pseudo-classes (`:first-child`, `:nth-child()` and the
+/// rest) are decided from the sibling positions below. By default they are
+/// counted along the [`ElementLike`] sibling links, which is also how
+/// `css_matcher_core` matches `:first-child`, `:last-child` and
+/// `:only-child`; elements that already know their positions may override
+/// them.
+pub trait ElementLikeExt: ElementLike {
     /// Get element ID (for matching with context)
     fn element_id(&self) -> Option<&str> {
         None
@@ -143,22 +223,28 @@ pub trait ElementLikeExt {
 
     /// Get sibling count (number of siblings including self)
     fn sibling_count(&self) -> usize {
-        1
+        preceding_siblings(self).count() + 1 + following_siblings(self).count()
     }
 
     /// Get position among siblings (1-based)
     fn sibling_position(&self) -> usize {
-        1
+        preceding_siblings(self).count() + 1
     }
 
     /// Get position among siblings of same type (1-based)
     fn sibling_position_of_type(&self) -> usize {
-        1
+        preceding_siblings(self)
+            .filter(|sibling| same_type(*sibling, self))
+            .count()
+            + 1
     }
 
     /// Get count of siblings of same type (including self)
     fn sibling_count_of_type(&self) -> usize {
-        1
+        self.sibling_position_of_type()
+            + following_siblings(self)
+                .filter(|sibling| same_type(*sibling, self))
+                .count()
     }
 
     /// Check if element has children
@@ -182,6 +268,23 @@ pub trait ElementLikeExt {
     }
 }
 
+/// Siblings before `element`, nearest first
+fn preceding_siblings<E: ElementLike + ?Sized>(element: &E) -> impl Iterator<Item = &E> {
+    std::iter::successors(element.previous_sibling(), |sibling| {
+        sibling.previous_sibling()
+    })
+}
+
+/// Siblings after `element`, nearest first
+fn following_siblings<E: ElementLike + ?Sized>(element: &E) -> impl Iterator<Item = &E> {
+    std::iter::successors(element.next_sibling(), |sibling| sibling.next_sibling())
+}
+
+/// Whether two elements have the same type (element name, ignoring ASCII case)
+fn same_type<E: ElementLike + ?Sized>(a: &E, b: &E) -> bool {
+    a.tag_name().eq_ignore_ascii_case(b.tag_name())
+}
+
 /// Evaluate if an element matches a pseudo-class
 ///
 /// # Examples
@@ -207,6 +310,7 @@ pub trait ElementLikeExt {
 /// #     fn classes(&self) -> &[String] { &self.classes }
 /// #     fn parent(&self) -> Option<&Self> { None }
 /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+/// #     fn next_sibling(&self) -> Option<&Self> { None }
 /// # }
 /// # impl ElementLikeExt for Element {}
 /// let element = Element::new("div");
@@ -341,6 +445,12 @@ pub fn evaluate_pseudo_class<E: ElementLike + ElementLikeExt>(
         PseudoClassKind::Enabled => element.is_enabled(),
         PseudoClassKind::Disabled => !element.is_enabled(),
         PseudoClassKind::Checked => element.is_checked(),
+        PseudoClassKind::Not(selectors) => !selectors
+            .iter()
+            .any(|selector| matches_selector(selector, element)),
+        PseudoClassKind::Is(selectors) | PseudoClassKind::Where(selectors) => selectors
+            .iter()
+            .any(|selector| matches_selector(selector, element)),
     }
 }
 
@@ -439,6 +549,10 @@ mod tests {
         fn previous_sibling(&self) -> Option<&Self> {
             None
         }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
     }
 
     impl ElementLikeExt for TestElement {
@@ -142,6 +142,7 @@ pub trait PseudoElementMatcher {
     /// #     fn classes(&self) -> &[String] { &self.classes }
     /// #     fn parent(&self) -> Option<&Self> { None }
     /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+    /// #     fn next_sibling(&self) -> Option<&Self> { None }
     /// # }
     /// let matcher = DefaultMatcher;
     /// let element = Element::new("div");
@@ -187,6 +188,7 @@ pub trait PseudoElementMatcher {
     /// #     fn classes(&self) -> &[String] { &self.classes }
     /// #     fn parent(&self) -> Option<&Self> { None }
     /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+    /// #     fn next_sibling(&self) -> Option<&Self> { None }
     /// # }
     /// let matcher = DefaultMatcher;
     /// let element = Element::new("div");
@@ -286,6 +288,10 @@ mod tests {
         fn previous_sibling(&self) -> Option<&Self> {
             None
         }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
     }
 
     // ========================================================================
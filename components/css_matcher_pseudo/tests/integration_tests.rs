@@ -1,10 +1,12 @@
 //! Integration tests for complex pseudo-selector combinations
 
-use css_matcher_core::ElementLike;
+use css_matcher_core::{matches_component, Component, ElementLike};
 use css_matcher_pseudo::{
-    evaluate_pseudo_class, DefaultPseudoElementMatcher, ElementLikeExt, MatchContext, PseudoClass,
-    PseudoClassKind, PseudoElement, PseudoElementMatcher,
+    evaluate_pseudo_class, parse_functional_pseudo_class, DefaultPseudoElementMatcher,
+    ElementLikeExt, MatchContext, PseudoClass, PseudoClassKind, PseudoElement,
+    PseudoElementMatcher,
 };
+use css_types::Specificity;
 
 // Test element implementation with full support for pseudo-class matching
 #[derive(Debug, Clone)]
@@ -44,7 +46,6 @@ impl TestElement {
         self
     }
 
-    #[allow(dead_code)]
     fn with_class(mut self, class: &str) -> Self {
         self.classes.push(class.to_string());
         self
@@ -103,6 +104,10 @@ impl ElementLike for TestElement {
     fn previous_sibling(&self) -> Option<&Self> {
         None
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
 }
 
 impl ElementLikeExt for TestElement {
@@ -445,3 +450,202 @@ fn test_element_without_id_state_pseudo_classes() {
     assert!(!evaluate_pseudo_class(&element, &hover, &context));
     assert!(!evaluate_pseudo_class(&element, &focus, &context));
 }
+
+// ========================================================================
+// Integration Tests - Selector-List Pseudo-Classes
+// ========================================================================
+
+#[test]
+fn test_not_pseudo_class() {
+    let context = MatchContext::new();
+    let not_x = parse_functional_pseudo_class(":not(.x)").unwrap();
+
+    assert!(evaluate_pseudo_class(
+        &TestElement::new("div"),
+        &not_x,
+        &context
+    ));
+    let marked = TestElement::new("div").with_class("x");
+    assert!(!evaluate_pseudo_class(&marked, &not_x, &context));
+}
+
+#[test]
+fn test_is_pseudo_class_matches_any() {
+    let context = MatchContext::new();
+    let is = parse_functional_pseudo_class(":is(div, span)").unwrap();
+
+    assert!(evaluate_pseudo_class(
+        &TestElement::new("div"),
+        &is,
+        &context
+    ));
+    assert!(evaluate_pseudo_class(
+        &TestElement::new("SPAN"),
+        &is,
+        &context
+    ));
+    assert!(!evaluate_pseudo_class(
+        &TestElement::new("p"),
+        &is,
+        &context
+    ));
+}
+
+#[test]
+fn test_where_matches_like_is_without_specificity() {
+    let context = MatchContext::new();
+    let where_ = parse_functional_pseudo_class(":where(#main, .card)").unwrap();
+    let is = parse_functional_pseudo_class(":is(#main, .card)").unwrap();
+    let not = parse_functional_pseudo_class(":not(span, .a.b)").unwrap();
+
+    let card = TestElement::new("div").with_class("card");
+    assert!(evaluate_pseudo_class(&card, &where_, &context));
+    assert_eq!(where_.specificity(), Specificity::zero());
+    assert_eq!(is.specificity(), Specificity::new(1, 0, 0));
+    assert_eq!(not.specificity(), Specificity::new(0, 2, 0));
+    assert_eq!(
+        PseudoClass::new(PseudoClassKind::Hover).specificity(),
+        Specificity::new(0, 1, 0)
+    );
+}
+
+#[test]
+fn test_parse_functional_pseudo_class_errors() {
+    assert!(parse_functional_pseudo_class(":has(div)").is_err());
+    assert!(parse_functional_pseudo_class(":not()").is_err());
+    assert!(parse_functional_pseudo_class(":is(div").is_err());
+    assert!(matches!(
+        parse_functional_pseudo_class("IS(div)").unwrap().kind,
+        PseudoClassKind::Is(_)
+    ));
+}
+
+// Element that only knows its sibling links, leaving the positions to the
+// ElementLikeExt defaults
+#[derive(Debug)]
+struct LinkedElement {
+    tag_name: String,
+    previous: Option<Box<LinkedElement>>,
+    next: Option<Box<LinkedElement>>,
+}
+
+impl LinkedElement {
+    /// Build the child at `index` of a parent whose children have `tags`
+    fn child(tags: &[&str], index: usize) -> Self {
+        let previous = tags[..index].iter().fold(None, |previous, tag| {
+            Some(Box::new(Self {
+                tag_name: tag.to_string(),
+                previous,
+                next: None,
+            }))
+        });
+        let next = tags[index + 1..].iter().rev().fold(None, |next, tag| {
+            Some(Box::new(Self {
+                tag_name: tag.to_string(),
+                previous: None,
+                next,
+            }))
+        });
+        Self {
+            tag_name: tags[index].to_string(),
+            previous,
+            next,
+        }
+    }
+}
+
+impl ElementLike for LinkedElement {
+    fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+
+    fn id(&self) -> Option<&str> {
+        None
+    }
+
+    fn classes(&self) -> &[String] {
+        &[]
+    }
+
+    fn parent(&self) -> Option<&Self> {
+        None
+    }
+
+    fn previous_sibling(&self) -> Option<&Self> {
+        self.previous.as_deref()
+    }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        self.next.as_deref()
+    }
+}
+
+impl ElementLikeExt for LinkedElement {}
+
+#[test]
+fn test_structural_pseudo_classes_agree_for_three_children() {
+    let tags = ["p", "span", "p"];
+    let context = MatchContext::new();
+    let matches = |element: &LinkedElement, kind: PseudoClassKind, argument: Option<&str>| {
+        let pseudo = match argument {
+            Some(argument) => PseudoClass::with_argument(kind, argument.to_string()),
+            None => PseudoClass::new(kind),
+        };
+        evaluate_pseudo_class(element, &pseudo, &context)
+    };
+
+    for index in 0..tags.len() {
+        let element = LinkedElement::child(&tags, index);
+        let first = index == 0;
+        let last = index == tags.len() - 1;
+        let position = (index + 1).to_string();
+        let position_from_end = (tags.len() - index).to_string();
+
+        assert_eq!(element.sibling_position(), index + 1);
+        assert_eq!(element.sibling_count(), 3);
+        assert_eq!(matches(&element, PseudoClassKind::FirstChild, None), first);
+        assert_eq!(matches(&element, PseudoClassKind::LastChild, None), last);
+        assert!(!matches(&element, PseudoClassKind::OnlyChild, None));
+        assert_eq!(
+            matches(&element, PseudoClassKind::NthChild, Some("1")),
+            first
+        );
+        assert!(matches(
+            &element,
+            PseudoClassKind::NthChild,
+            Some(&position)
+        ));
+        assert!(matches(
+            &element,
+            PseudoClassKind::NthLastChild,
+            Some(&position_from_end)
+        ));
+
+        // css_matcher_core decides the same pseudo-classes from the links
+        assert_eq!(matches_component(&Component::FirstChild, &element), first);
+        assert_eq!(matches_component(&Component::LastChild, &element), last);
+        assert!(!matches_component(&Component::OnlyChild, &element));
+    }
+
+    // The two paragraphs are the first and last of their type around the span
+    let last_paragraph = LinkedElement::child(&tags, 2);
+    assert_eq!(last_paragraph.sibling_position_of_type(), 2);
+    assert_eq!(last_paragraph.sibling_count_of_type(), 2);
+    assert!(matches(
+        &last_paragraph,
+        PseudoClassKind::NthLastOfType,
+        Some("1")
+    ));
+    assert!(matches(
+        &last_paragraph,
+        PseudoClassKind::NthOfType,
+        Some("2")
+    ));
+    let span = LinkedElement::child(&tags, 1);
+    assert!(matches(&span, PseudoClassKind::NthOfType, Some("1")));
+    assert!(matches(&span, PseudoClassKind::NthLastOfType, Some("1")));
+
+    let only = LinkedElement::child(&["p"], 0);
+    assert!(matches(&only, PseudoClassKind::OnlyChild, None));
+    assert!(matches_component(&Component::OnlyChild, &only));
+}
@@ -1,6 +1,8 @@
 //! Media query evaluation implementation
 
 use crate::*;
+use css_custom_properties::CalcContext;
+use css_parser_core::{CssRule, MediaRule, StyleRule, Stylesheet};
 use css_types::LengthUnit;
 
 /// Trait for media query evaluation
@@ -78,6 +80,14 @@ pub fn evaluate_media_feature(
                 viewport.height > 0
             }
         }
+        MediaFeature::WidthCalc(expr) => {
+            let target_px = calc_to_px(expr, viewport);
+            compare_value(viewport.width as f32, target_px, range)
+        }
+        MediaFeature::HeightCalc(expr) => {
+            let target_px = calc_to_px(expr, viewport);
+            compare_value(viewport.height as f32, target_px, range)
+        }
         MediaFeature::Orientation(target_orientation) => {
             viewport.orientation == *target_orientation
         }
@@ -198,6 +208,193 @@ fn length_to_px(length: &Length, viewport: &ViewportInfo) -> f32 {
         }
         LengthUnit::Vw => (length.value() / 100.0) * viewport.width as f32,
         LengthUnit::Vh => (length.value() / 100.0) * viewport.height as f32,
+        LengthUnit::Vmin => (length.value() / 100.0) * viewport.width.min(viewport.height) as f32,
+        LengthUnit::Vmax => (length.value() / 100.0) * viewport.width.max(viewport.height) as f32,
+        unit @ (LengthUnit::Pt | LengthUnit::Cm | LengthUnit::Mm | LengthUnit::In) => {
+            length.value() * unit.px_per_unit().unwrap_or(1.0)
+        }
+    }
+}
+
+/// Evaluate a `calc()` expression to pixels based on viewport
+///
+/// Uses the same 16px font size assumption as [`length_to_px`], and resolves
+/// percentages against the viewport width.
+fn calc_to_px(expr: &CalcExpression, viewport: &ViewportInfo) -> f32 {
+    let context =
+        CalcContext::with_dimensions(viewport.width as f32, viewport.height as f32, 16.0, 16.0);
+    expr.evaluate(&context)
+}
+
+/// Check whether an `@media` rule applies to the viewport
+///
+/// The rule's comma-separated queries are combined with OR. A query that
+/// fails to parse evaluates to false without affecting the others, and a
+/// rule with no queries always applies.
+pub fn media_rule_matches(rule: &MediaRule, viewport: &ViewportInfo) -> bool {
+    if rule.media_queries.is_empty() {
+        return true;
+    }
+
+    let queries = rule
+        .media_queries
+        .iter()
+        .filter_map(|query| parse_media_query(query).ok())
+        .collect();
+
+    DefaultEvaluator.evaluate_list(&MediaQueryList::new(queries), viewport)
+}
+
+/// Collect the style rules that apply to the viewport, in source order
+///
+/// `@media` blocks are flattened: their style rules are included when the
+/// block matches (see [`media_rule_matches`]) and nested blocks are
+/// evaluated recursively. `@layer` blocks are flattened unconditionally.
+/// Import rules and `@layer` statements are skipped. Use
+/// [`active_layered_rules`] to keep each rule's cascade layer.
+pub fn active_rules<'a>(stylesheet: &'a Stylesheet, viewport: &ViewportInfo) -> Vec<&'a StyleRule> {
+    active_layered_rules(stylesheet, viewport)
+        .into_iter()
+        .map(|(rule, _)| rule)
+        .collect()
+}
+
+/// Collect the style rules that apply to the viewport with their cascade layer
+///
+/// Rules are returned in source order, as by [`active_rules`], each paired
+/// with its layer's position in the layer order (higher wins for normal
+/// declarations), or `None` if unlayered. Layers are ordered by first
+/// declaration, whether by an `@layer` statement or block; a nested layer
+/// (`@layer a { @layer b { ... } }` or `a.b`) ranks below the rules directly
+/// in its parent, and each anonymous block is a layer of its own.
+///
+/// # Examples
+/// ```
+/// use css_media_queries::{active_layered_rules, ViewportInfo};
+/// use css_parser_core::CssParser;
+///
+/// let css = "@layer base, theme; @layer theme { a { color: red } } @layer base { a { color: blue } }";
+/// let stylesheet = CssParser::new().parse(css).unwrap();
+/// let layers: Vec<_> = active_layered_rules(&stylesheet, &ViewportInfo::desktop())
+///     .into_iter()
+///     .map(|(_, layer)| layer)
+///     .collect();
+/// assert_eq!(layers, vec![Some(1), Some(0)]);
+/// ```
+pub fn active_layered_rules<'a>(
+    stylesheet: &'a Stylesheet,
+    viewport: &ViewportInfo,
+) -> Vec<(&'a StyleRule, Option<u32>)> {
+    let mut layers = LayerTree::default();
+    let mut active = Vec::new();
+    collect_active_rules(&stylesheet.rules, viewport, None, &mut layers, &mut active);
+
+    let ranks = layers.ranks();
+    active
+        .into_iter()
+        .map(|(rule, layer)| (rule, layer.map(|id| ranks[id])))
+        .collect()
+}
+
+fn collect_active_rules<'a>(
+    rules: &'a [CssRule],
+    viewport: &ViewportInfo,
+    layer: Option<usize>,
+    layers: &mut LayerTree,
+    active: &mut Vec<(&'a StyleRule, Option<usize>)>,
+) {
+    for rule in rules {
+        match rule {
+            CssRule::Style(style) => active.push((style, layer)),
+            CssRule::Media(media) => {
+                if media_rule_matches(media, viewport) {
+                    collect_active_rules(&media.rules, viewport, layer, layers, active);
+                }
+            }
+            CssRule::Layer(layer_rule) => match &layer_rule.rules {
+                Some(rules) => {
+                    let id = match layer_rule.names.first() {
+                        Some(name) => layers.declare(layer, name),
+                        None => layers.child(layer, None),
+                    };
+                    collect_active_rules(rules, viewport, Some(id), layers, active);
+                }
+                None => {
+                    for name in &layer_rule.names {
+                        layers.declare(layer, name);
+                    }
+                }
+            },
+            CssRule::Import(_) => {}
+        }
+    }
+}
+
+/// Cascade layers of a stylesheet, as a tree in declaration order
+#[derive(Default)]
+struct LayerTree {
+    /// Name (`None` if anonymous) and child layers of each layer, by id
+    layers: Vec<(Option<String>, Vec<usize>)>,
+    /// Top-level layers
+    roots: Vec<usize>,
+}
+
+impl LayerTree {
+    /// Find or declare a layer by its (possibly dotted) name under `parent`
+    fn declare(&mut self, parent: Option<usize>, name: &str) -> usize {
+        let mut layer = parent;
+        for segment in name.split('.') {
+            layer = Some(self.child(layer, Some(segment)));
+        }
+        layer.expect("a layer name has at least one segment")
+    }
+
+    /// Find or declare a direct child layer of `parent`
+    ///
+    /// Anonymous layers are never found, so each one is declared anew.
+    fn child(&mut self, parent: Option<usize>, name: Option<&str>) -> usize {
+        let siblings = match parent {
+            Some(parent) => &self.layers[parent].1,
+            None => &self.roots,
+        };
+        let existing = name.and_then(|name| {
+            siblings
+                .iter()
+                .copied()
+                .find(|&id| self.layers[id].0.as_deref() == Some(name))
+        });
+        if let Some(id) = existing {
+            return id;
+        }
+
+        let id = self.layers.len();
+        self.layers.push((name.map(str::to_string), Vec::new()));
+        match parent {
+            Some(parent) => self.layers[parent].1.push(id),
+            None => self.roots.push(id),
+        }
+        id
+    }
+
+    /// Position of each layer in the layer order, by id
+    ///
+    /// Layers are numbered in post-order, so sublayers come before their
+    /// parent and a parent's own rules beat those of its sublayers.
+    fn ranks(&self) -> Vec<u32> {
+        fn visit(tree: &LayerTree, id: usize, ranks: &mut [u32], next: &mut u32) {
+            for &child in &tree.layers[id].1 {
+                visit(tree, child, ranks, next);
+            }
+            ranks[id] = *next;
+            *next += 1;
+        }
+
+        let mut ranks = vec![0; self.layers.len()];
+        let mut next = 0;
+        for &root in &self.roots {
+            visit(self, root, &mut ranks, &mut next);
+        }
+        ranks
     }
 }
 
@@ -6,9 +6,13 @@
 //! - Range queries (min-width, max-width, etc.)
 //! - Logical operators (and, or, not)
 //! - Media query evaluation against viewport information
+//! - Selecting the style rules of a stylesheet active for a viewport
 
+pub use css_custom_properties::CalcExpression;
 pub use css_parser_core::ParseError;
-pub use css_types::{Length, LengthUnit};
+pub use css_types::{KeywordEnum, Length, LengthUnit};
+
+use std::collections::HashSet;
 
 // ============================================================================
 // Media Types
@@ -27,6 +31,16 @@ pub enum MediaType {
     Speech,
 }
 
+impl KeywordEnum for MediaType {
+    const KIND: &'static str = "media type";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("all", MediaType::All),
+        ("screen", MediaType::Screen),
+        ("print", MediaType::Print),
+        ("speech", MediaType::Speech),
+    ];
+}
+
 // ============================================================================
 // Media Features
 // ============================================================================
@@ -40,6 +54,14 @@ pub enum Orientation {
     Landscape,
 }
 
+impl KeywordEnum for Orientation {
+    const KIND: &'static str = "orientation";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("portrait", Orientation::Portrait),
+        ("landscape", Orientation::Landscape),
+    ];
+}
+
 /// Resolution unit
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ResolutionUnit {
@@ -85,6 +107,14 @@ pub enum Scan {
     Progressive,
 }
 
+impl KeywordEnum for Scan {
+    const KIND: &'static str = "scan type";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("interlace", Scan::Interlace),
+        ("progressive", Scan::Progressive),
+    ];
+}
+
 /// Output device update frequency
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Update {
@@ -96,6 +126,15 @@ pub enum Update {
     Fast,
 }
 
+impl KeywordEnum for Update {
+    const KIND: &'static str = "update type";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("none", Update::None),
+        ("slow", Update::Slow),
+        ("fast", Update::Fast),
+    ];
+}
+
 /// Hover capability
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HoverCapability {
@@ -105,6 +144,14 @@ pub enum HoverCapability {
     Hover,
 }
 
+impl KeywordEnum for HoverCapability {
+    const KIND: &'static str = "hover type";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("none", HoverCapability::None),
+        ("hover", HoverCapability::Hover),
+    ];
+}
+
 /// Pointer accuracy
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PointerCapability {
@@ -116,6 +163,15 @@ pub enum PointerCapability {
     Fine,
 }
 
+impl KeywordEnum for PointerCapability {
+    const KIND: &'static str = "pointer type";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("none", PointerCapability::None),
+        ("coarse", PointerCapability::Coarse),
+        ("fine", PointerCapability::Fine),
+    ];
+}
+
 /// Preferred color scheme
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorScheme {
@@ -125,6 +181,12 @@ pub enum ColorScheme {
     Dark,
 }
 
+impl KeywordEnum for ColorScheme {
+    const KIND: &'static str = "color scheme";
+    const KEYWORDS: &'static [(&'static str, Self)] =
+        &[("light", ColorScheme::Light), ("dark", ColorScheme::Dark)];
+}
+
 /// Reduced motion preference
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReducedMotion {
@@ -134,6 +196,14 @@ pub enum ReducedMotion {
     Reduce,
 }
 
+impl KeywordEnum for ReducedMotion {
+    const KIND: &'static str = "reduced motion";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("no-preference", ReducedMotion::NoPreference),
+        ("reduce", ReducedMotion::Reduce),
+    ];
+}
+
 /// Contrast preference
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Contrast {
@@ -145,6 +215,15 @@ pub enum Contrast {
     Less,
 }
 
+impl KeywordEnum for Contrast {
+    const KIND: &'static str = "contrast";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("no-preference", Contrast::NoPreference),
+        ("more", Contrast::More),
+        ("less", Contrast::Less),
+    ];
+}
+
 /// Media feature query
 #[derive(Debug, Clone, PartialEq)]
 pub enum MediaFeature {
@@ -152,6 +231,10 @@ pub enum MediaFeature {
     Width(Option<Length>),
     /// Height feature (min-height, max-height, height)
     Height(Option<Length>),
+    /// Width feature whose value is a `calc()` expression
+    WidthCalc(CalcExpression),
+    /// Height feature whose value is a `calc()` expression
+    HeightCalc(CalcExpression),
     /// Aspect ratio (numerator:denominator)
     AspectRatio { numerator: u32, denominator: u32 },
     /// Orientation
@@ -182,6 +265,70 @@ pub enum MediaFeature {
     PrefersContrast(Contrast),
 }
 
+/// Kind of a media feature, independent of its value
+///
+/// `calc()` variants share the kind of their plain counterpart, so
+/// `(min-width: calc(...))` reports [`MediaFeatureKind::Width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MediaFeatureKind {
+    /// `width`, `min-width`, `max-width`
+    Width,
+    /// `height`, `min-height`, `max-height`
+    Height,
+    /// `aspect-ratio`
+    AspectRatio,
+    /// `orientation`
+    Orientation,
+    /// `resolution`
+    Resolution,
+    /// `color-index`
+    ColorIndex,
+    /// `color`
+    Color,
+    /// `monochrome`
+    Monochrome,
+    /// `grid`
+    Grid,
+    /// `scan`
+    Scan,
+    /// `update`
+    Update,
+    /// `hover`
+    Hover,
+    /// `pointer`
+    Pointer,
+    /// `prefers-color-scheme`
+    PrefersColorScheme,
+    /// `prefers-reduced-motion`
+    PrefersReducedMotion,
+    /// `prefers-contrast`
+    PrefersContrast,
+}
+
+impl MediaFeature {
+    /// Get the kind of this feature
+    pub fn kind(&self) -> MediaFeatureKind {
+        match self {
+            MediaFeature::Width(_) | MediaFeature::WidthCalc(_) => MediaFeatureKind::Width,
+            MediaFeature::Height(_) | MediaFeature::HeightCalc(_) => MediaFeatureKind::Height,
+            MediaFeature::AspectRatio { .. } => MediaFeatureKind::AspectRatio,
+            MediaFeature::Orientation(_) => MediaFeatureKind::Orientation,
+            MediaFeature::Resolution(_) => MediaFeatureKind::Resolution,
+            MediaFeature::ColorIndex(_) => MediaFeatureKind::ColorIndex,
+            MediaFeature::Color(_) => MediaFeatureKind::Color,
+            MediaFeature::Monochrome(_) => MediaFeatureKind::Monochrome,
+            MediaFeature::Grid(_) => MediaFeatureKind::Grid,
+            MediaFeature::Scan(_) => MediaFeatureKind::Scan,
+            MediaFeature::Update(_) => MediaFeatureKind::Update,
+            MediaFeature::Hover(_) => MediaFeatureKind::Hover,
+            MediaFeature::Pointer(_) => MediaFeatureKind::Pointer,
+            MediaFeature::PrefersColorScheme(_) => MediaFeatureKind::PrefersColorScheme,
+            MediaFeature::PrefersReducedMotion(_) => MediaFeatureKind::PrefersReducedMotion,
+            MediaFeature::PrefersContrast(_) => MediaFeatureKind::PrefersContrast,
+        }
+    }
+}
+
 // ============================================================================
 // Media Conditions
 // ============================================================================
@@ -219,6 +366,103 @@ pub enum MediaCondition {
     Not { condition: Box<MediaCondition> },
 }
 
+impl MediaCondition {
+    /// Rewrite the condition into a canonical form
+    ///
+    /// Double negations are removed, `Not` is pushed inward with De Morgan's
+    /// laws so it only ever wraps a single feature, and nested `And`/`Or`
+    /// chains are flattened into a right-nested chain with duplicate operands
+    /// removed. Equivalent conditions written differently (e.g.
+    /// `(a and b) and c` vs `a and (b and c)`) simplify to equal values.
+    pub fn simplify(&self) -> MediaCondition {
+        match self {
+            MediaCondition::Feature { .. } => self.clone(),
+            MediaCondition::And { .. } => Self::rebuild(self, true),
+            MediaCondition::Or { .. } => Self::rebuild(self, false),
+            MediaCondition::Not { condition } => match condition.as_ref() {
+                MediaCondition::Feature { .. } => self.clone(),
+                MediaCondition::Not { condition } => condition.simplify(),
+                MediaCondition::And { left, right } => MediaCondition::Or {
+                    left: Box::new(left.as_ref().clone().negate()),
+                    right: Box::new(right.as_ref().clone().negate()),
+                }
+                .simplify(),
+                MediaCondition::Or { left, right } => MediaCondition::And {
+                    left: Box::new(left.as_ref().clone().negate()),
+                    right: Box::new(right.as_ref().clone().negate()),
+                }
+                .simplify(),
+            },
+        }
+    }
+
+    /// Add the kinds of every feature in the condition tree to `kinds`
+    fn collect_feature_kinds(&self, kinds: &mut HashSet<MediaFeatureKind>) {
+        match self {
+            MediaCondition::Feature { feature, .. } => {
+                kinds.insert(feature.kind());
+            }
+            MediaCondition::And { left, right } | MediaCondition::Or { left, right } => {
+                left.collect_feature_kinds(kinds);
+                right.collect_feature_kinds(kinds);
+            }
+            MediaCondition::Not { condition } => condition.collect_feature_kinds(kinds),
+        }
+    }
+
+    /// Wrap the condition in `Not`
+    fn negate(self) -> MediaCondition {
+        MediaCondition::Not {
+            condition: Box::new(self),
+        }
+    }
+
+    /// Flatten an `And` (or `Or`) chain and rebuild it canonically
+    fn rebuild(&self, is_and: bool) -> MediaCondition {
+        let mut operands = Vec::new();
+        self.collect_operands(is_and, &mut operands);
+
+        let mut unique: Vec<MediaCondition> = Vec::with_capacity(operands.len());
+        for operand in operands {
+            if !unique.contains(&operand) {
+                unique.push(operand);
+            }
+        }
+
+        let mut iter = unique.into_iter().rev();
+        let last = iter.next().expect("chains always have operands");
+        iter.fold(last, |right, left| {
+            let (left, right) = (Box::new(left), Box::new(right));
+            if is_and {
+                MediaCondition::And { left, right }
+            } else {
+                MediaCondition::Or { left, right }
+            }
+        })
+    }
+
+    /// Collect the simplified operands of a same-operator chain
+    fn collect_operands(&self, is_and: bool, operands: &mut Vec<MediaCondition>) {
+        match (self, is_and) {
+            (MediaCondition::And { left, right }, true)
+            | (MediaCondition::Or { left, right }, false) => {
+                left.collect_operands(is_and, operands);
+                right.collect_operands(is_and, operands);
+            }
+            _ => {
+                let simplified = self.simplify();
+                // Simplifying a negation can produce a chain of the same kind
+                match (&simplified, is_and) {
+                    (MediaCondition::And { .. }, true) | (MediaCondition::Or { .. }, false) => {
+                        simplified.collect_operands(is_and, operands)
+                    }
+                    _ => operands.push(simplified),
+                }
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Media Query
 // ============================================================================
@@ -257,6 +501,19 @@ impl MediaQuery {
     pub fn condition(condition: MediaCondition) -> Self {
         Self::new(None, Some(condition), false)
     }
+
+    /// Get the kinds of media feature the query depends on
+    ///
+    /// A query whose result can only change when one of these features
+    /// changes does not need re-evaluating for any other environment change.
+    /// Queries without a condition reference no features.
+    pub fn referenced_features(&self) -> HashSet<MediaFeatureKind> {
+        let mut kinds = HashSet::new();
+        if let Some(condition) = &self.condition {
+            condition.collect_feature_kinds(&mut kinds);
+        }
+        kinds
+    }
 }
 
 /// List of media queries (comma-separated)
@@ -303,6 +560,11 @@ pub struct ViewportInfo {
     pub monochrome_bits: u32,
     /// Resolution in DPI
     pub resolution_dpi: f32,
+    /// Page zoom factor applied when converting CSS px to device px
+    ///
+    /// Media queries compare against the unscaled CSS px dimensions above;
+    /// only layout lengths are scaled by zoom.
+    pub zoom: f32,
 }
 
 impl ViewportInfo {
@@ -324,9 +586,21 @@ impl ViewportInfo {
             color_bits: 24,
             monochrome_bits: 0,
             resolution_dpi: 96.0,
+            zoom: 1.0,
         }
     }
 
+    /// Set the zoom factor
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Convert a length in CSS px to device px using the zoom factor
+    pub fn to_device_px(&self, css_px: f32) -> f32 {
+        css_px * self.zoom
+    }
+
     /// Create a desktop viewport (1920x1080)
     pub fn desktop() -> Self {
         Self::new(1920, 1080)
@@ -351,7 +625,7 @@ mod evaluator;
 mod parser;
 
 pub use evaluator::{
-    evaluate_media_feature, evaluate_media_query, match_media_type, DefaultEvaluator,
-    MediaQueryEvaluator,
+    active_layered_rules, active_rules, evaluate_media_feature, evaluate_media_query,
+    match_media_type, media_rule_matches, DefaultEvaluator, MediaQueryEvaluator,
 };
 pub use parser::{parse_media_query, parse_media_query_list};
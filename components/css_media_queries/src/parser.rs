@@ -2,14 +2,14 @@
 
 use crate::*;
 use css_parser_core::ParseError;
-use css_types::{Length, LengthUnit};
+use css_types::{parse_finite_f32, Length, LengthUnit};
 
 /// Parse a complete media query from a string
-pub fn parse_media_query(input: &str) -> Result<MediaQuery, ParseError> {
-    let input = input.trim();
+pub fn parse_media_query(source: &str) -> Result<MediaQuery, ParseError> {
+    let input = source.trim();
 
     if input.is_empty() {
-        return Err(ParseError::new(0, 0, "Empty media query"));
+        return Err(ParseError::new(0, 0, "Empty media query").with_offset(0, source));
     }
 
     // Check for NOT prefix
@@ -21,7 +21,7 @@ pub fn parse_media_query(input: &str) -> Result<MediaQuery, ParseError> {
 
     // Check if starts with parenthesis (condition-only query)
     if input.starts_with('(') {
-        let condition = parse_media_condition(input)?;
+        let condition = parse_media_condition(input).map_err(|e| at(e, source, input))?;
         return Ok(MediaQuery::new(None, Some(condition), negated));
     }
 
@@ -31,22 +31,22 @@ pub fn parse_media_query(input: &str) -> Result<MediaQuery, ParseError> {
         let media_type_str = input[..space_pos].trim();
         let condition_str = input[space_pos + 5..].trim();
 
-        let media_type = parse_media_type(media_type_str)?;
-        let condition = parse_media_condition(condition_str)?;
+        let media_type =
+            parse_media_type(media_type_str).map_err(|e| at(e, source, media_type_str))?;
+        let condition =
+            parse_media_condition(condition_str).map_err(|e| at(e, source, condition_str))?;
 
         Ok(MediaQuery::new(Some(media_type), Some(condition), negated))
     } else {
         // Media type only: "screen"
-        let media_type = parse_media_type(input)?;
+        let media_type = parse_media_type(input).map_err(|e| at(e, source, input))?;
         Ok(MediaQuery::new(Some(media_type), None, negated))
     }
 }
 
 /// Parse a comma-separated media query list
 pub fn parse_media_query_list(input: &str) -> Result<MediaQueryList, ParseError> {
-    let input = input.trim();
-
-    if input.is_empty() {
+    if input.trim().is_empty() {
         return Ok(MediaQueryList::empty());
     }
 
@@ -54,7 +54,7 @@ pub fn parse_media_query_list(input: &str) -> Result<MediaQueryList, ParseError>
     let parts = input.split(',');
 
     for part in parts {
-        let query = parse_media_query(part.trim())?;
+        let query = parse_media_query(part).map_err(|e| at(e, input, part))?;
         queries.push(query);
     }
 
@@ -63,30 +63,20 @@ pub fn parse_media_query_list(input: &str) -> Result<MediaQueryList, ParseError>
 
 /// Parse a media type
 fn parse_media_type(input: &str) -> Result<MediaType, ParseError> {
-    match input {
-        "all" => Ok(MediaType::All),
-        "screen" => Ok(MediaType::Screen),
-        "print" => Ok(MediaType::Print),
-        "speech" => Ok(MediaType::Speech),
-        _ => Err(ParseError::new(
-            0,
-            0,
-            format!("Unknown media type: {}", input),
-        )),
-    }
+    parse_keyword(input)
 }
 
 /// Parse a media condition (with potential AND/OR operators)
-fn parse_media_condition(input: &str) -> Result<MediaCondition, ParseError> {
-    let input = input.trim();
+fn parse_media_condition(source: &str) -> Result<MediaCondition, ParseError> {
+    let input = source.trim();
 
     // Check for AND operator
     if let Some(and_pos) = find_operator(input, " and ") {
-        let left_str = &input[..and_pos].trim();
-        let right_str = &input[and_pos + 5..].trim();
+        let left_str = &input[..and_pos];
+        let right_str = &input[and_pos + 5..];
 
-        let left = parse_media_condition(left_str)?;
-        let right = parse_media_condition(right_str)?;
+        let left = parse_media_condition(left_str).map_err(|e| at(e, source, left_str))?;
+        let right = parse_media_condition(right_str).map_err(|e| at(e, source, right_str))?;
 
         return Ok(MediaCondition::And {
             left: Box::new(left),
@@ -96,11 +86,11 @@ fn parse_media_condition(input: &str) -> Result<MediaCondition, ParseError> {
 
     // Check for OR operator
     if let Some(or_pos) = find_operator(input, " or ") {
-        let left_str = &input[..or_pos].trim();
-        let right_str = &input[or_pos + 4..].trim();
+        let left_str = &input[..or_pos];
+        let right_str = &input[or_pos + 4..];
 
-        let left = parse_media_condition(left_str)?;
-        let right = parse_media_condition(right_str)?;
+        let left = parse_media_condition(left_str).map_err(|e| at(e, source, left_str))?;
+        let right = parse_media_condition(right_str).map_err(|e| at(e, source, right_str))?;
 
         return Ok(MediaCondition::Or {
             left: Box::new(left),
@@ -110,8 +100,7 @@ fn parse_media_condition(input: &str) -> Result<MediaCondition, ParseError> {
 
     // Check for NOT operator
     if let Some(stripped) = input.strip_prefix("not ") {
-        let inner_str = stripped.trim();
-        let inner = parse_media_condition(inner_str)?;
+        let inner = parse_media_condition(stripped).map_err(|e| at(e, source, stripped))?;
         return Ok(MediaCondition::Not {
             condition: Box::new(inner),
         });
@@ -119,15 +108,14 @@ fn parse_media_condition(input: &str) -> Result<MediaCondition, ParseError> {
 
     // Single feature: (min-width: 768px)
     if !input.starts_with('(') || !input.ends_with(')') {
-        return Err(ParseError::new(
-            0,
-            0,
-            "Media condition must be wrapped in parentheses",
-        ));
+        return Err(
+            ParseError::new(0, 0, "Media condition must be wrapped in parentheses")
+                .with_offset(offset_in(source, input), input),
+        );
     }
 
-    let content = &input[1..input.len() - 1].trim();
-    parse_media_feature_with_range(content)
+    let content = &input[1..input.len() - 1];
+    parse_media_feature_with_range(content).map_err(|e| at(e, source, content))
 }
 
 /// Find an operator at the top level (not inside parentheses)
@@ -156,8 +144,8 @@ fn find_operator(input: &str, operator: &str) -> Option<usize> {
 }
 
 /// Parse a media feature with range type
-fn parse_media_feature_with_range(content: &str) -> Result<MediaCondition, ParseError> {
-    let content = content.trim();
+fn parse_media_feature_with_range(source: &str) -> Result<MediaCondition, ParseError> {
+    let content = source.trim();
 
     // Check for colon (feature with value)
     if let Some(colon_pos) = content.find(':') {
@@ -173,11 +161,16 @@ fn parse_media_feature_with_range(content: &str) -> Result<MediaCondition, Parse
             (feature_name, RangeType::Exact)
         };
 
-        let feature = parse_media_feature(base_name, Some(value_str))?;
+        // Value errors point at the value; anything else at the whole feature
+        let feature = parse_media_feature(base_name, Some(value_str)).map_err(|e| {
+            e.offset_by(offset_in(source, value_str))
+                .with_offset(offset_in(source, content), content)
+        })?;
         Ok(MediaCondition::Feature { feature, range })
     } else {
         // Boolean feature (no value): (color)
-        let feature = parse_media_feature(content, None)?;
+        let feature = parse_media_feature(content, None)
+            .map_err(|e| e.with_offset(offset_in(source, content), content))?;
         Ok(MediaCondition::Feature {
             feature,
             range: RangeType::Exact,
@@ -189,7 +182,9 @@ fn parse_media_feature_with_range(content: &str) -> Result<MediaCondition, Parse
 fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature, ParseError> {
     match name {
         "width" => {
-            if let Some(val) = value {
+            if let Some(val) = value.filter(|v| is_calc(v)) {
+                Ok(MediaFeature::WidthCalc(parse_calc(val)?))
+            } else if let Some(val) = value {
                 let length = parse_length(val)?;
                 Ok(MediaFeature::Width(Some(length)))
             } else {
@@ -197,7 +192,9 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
             }
         }
         "height" => {
-            if let Some(val) = value {
+            if let Some(val) = value.filter(|v| is_calc(v)) {
+                Ok(MediaFeature::HeightCalc(parse_calc(val)?))
+            } else if let Some(val) = value {
                 let length = parse_length(val)?;
                 Ok(MediaFeature::Height(Some(length)))
             } else {
@@ -206,34 +203,25 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
         }
         "orientation" => {
             let val = value.ok_or_else(|| ParseError::new(0, 0, "orientation requires a value"))?;
-            let orientation = match val {
-                "portrait" => Orientation::Portrait,
-                "landscape" => Orientation::Landscape,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown orientation: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::Orientation(orientation))
+            Ok(MediaFeature::Orientation(parse_keyword(val)?))
         }
         "aspect-ratio" => {
             let val =
                 value.ok_or_else(|| ParseError::new(0, 0, "aspect-ratio requires a value"))?;
             let parts: Vec<&str> = val.split('/').collect();
             if parts.len() != 2 {
-                return Err(ParseError::new(0, 0, "aspect-ratio must be in format N/M"));
+                return Err(
+                    ParseError::new(0, 0, "aspect-ratio must be in format N/M").with_offset(0, val)
+                );
             }
-            let numerator = parts[0]
-                .trim()
-                .parse::<u32>()
-                .map_err(|_| ParseError::new(0, 0, "Invalid numerator"))?;
-            let denominator = parts[1]
-                .trim()
-                .parse::<u32>()
-                .map_err(|_| ParseError::new(0, 0, "Invalid denominator"))?;
+            let numerator = parts[0].trim().parse::<u32>().map_err(|_| {
+                ParseError::new(0, 0, "Invalid numerator")
+                    .with_offset(offset_in(val, parts[0]), parts[0])
+            })?;
+            let denominator = parts[1].trim().parse::<u32>().map_err(|_| {
+                ParseError::new(0, 0, "Invalid denominator")
+                    .with_offset(offset_in(val, parts[1]), parts[1])
+            })?;
             Ok(MediaFeature::AspectRatio {
                 numerator,
                 denominator,
@@ -248,7 +236,7 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
             if let Some(val) = value {
                 let bits = val
                     .parse::<u32>()
-                    .map_err(|_| ParseError::new(0, 0, "Invalid color bits"))?;
+                    .map_err(|_| ParseError::new(0, 0, "Invalid color bits").with_offset(0, val))?;
                 Ok(MediaFeature::Color(Some(bits)))
             } else {
                 Ok(MediaFeature::Color(None))
@@ -256,9 +244,9 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
         }
         "color-index" => {
             if let Some(val) = value {
-                let index = val
-                    .parse::<u32>()
-                    .map_err(|_| ParseError::new(0, 0, "Invalid color index"))?;
+                let index = val.parse::<u32>().map_err(|_| {
+                    ParseError::new(0, 0, "Invalid color index").with_offset(0, val)
+                })?;
                 Ok(MediaFeature::ColorIndex(Some(index)))
             } else {
                 Ok(MediaFeature::ColorIndex(None))
@@ -266,9 +254,9 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
         }
         "monochrome" => {
             if let Some(val) = value {
-                let bits = val
-                    .parse::<u32>()
-                    .map_err(|_| ParseError::new(0, 0, "Invalid monochrome bits"))?;
+                let bits = val.parse::<u32>().map_err(|_| {
+                    ParseError::new(0, 0, "Invalid monochrome bits").with_offset(0, val)
+                })?;
                 Ok(MediaFeature::Monochrome(Some(bits)))
             } else {
                 Ok(MediaFeature::Monochrome(None))
@@ -281,102 +269,34 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
         }
         "scan" => {
             let val = value.ok_or_else(|| ParseError::new(0, 0, "scan requires a value"))?;
-            let scan = match val {
-                "interlace" => Scan::Interlace,
-                "progressive" => Scan::Progressive,
-                _ => return Err(ParseError::new(0, 0, format!("Unknown scan type: {}", val))),
-            };
-            Ok(MediaFeature::Scan(scan))
+            Ok(MediaFeature::Scan(parse_keyword(val)?))
         }
         "update" => {
             let val = value.ok_or_else(|| ParseError::new(0, 0, "update requires a value"))?;
-            let update = match val {
-                "none" => Update::None,
-                "slow" => Update::Slow,
-                "fast" => Update::Fast,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown update type: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::Update(update))
+            Ok(MediaFeature::Update(parse_keyword(val)?))
         }
         "hover" => {
             let val = value.ok_or_else(|| ParseError::new(0, 0, "hover requires a value"))?;
-            let hover = match val {
-                "none" => HoverCapability::None,
-                "hover" => HoverCapability::Hover,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown hover type: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::Hover(hover))
+            Ok(MediaFeature::Hover(parse_keyword(val)?))
         }
         "pointer" => {
             let val = value.ok_or_else(|| ParseError::new(0, 0, "pointer requires a value"))?;
-            let pointer = match val {
-                "none" => PointerCapability::None,
-                "coarse" => PointerCapability::Coarse,
-                "fine" => PointerCapability::Fine,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown pointer type: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::Pointer(pointer))
+            Ok(MediaFeature::Pointer(parse_keyword(val)?))
         }
         "prefers-color-scheme" => {
             let val = value
                 .ok_or_else(|| ParseError::new(0, 0, "prefers-color-scheme requires a value"))?;
-            let scheme = match val {
-                "light" => ColorScheme::Light,
-                "dark" => ColorScheme::Dark,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown color scheme: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::PrefersColorScheme(scheme))
+            Ok(MediaFeature::PrefersColorScheme(parse_keyword(val)?))
         }
         "prefers-reduced-motion" => {
             let val = value
                 .ok_or_else(|| ParseError::new(0, 0, "prefers-reduced-motion requires a value"))?;
-            let motion = match val {
-                "no-preference" => ReducedMotion::NoPreference,
-                "reduce" => ReducedMotion::Reduce,
-                _ => {
-                    return Err(ParseError::new(
-                        0,
-                        0,
-                        format!("Unknown reduced motion: {}", val),
-                    ))
-                }
-            };
-            Ok(MediaFeature::PrefersReducedMotion(motion))
+            Ok(MediaFeature::PrefersReducedMotion(parse_keyword(val)?))
         }
         "prefers-contrast" => {
             let val =
                 value.ok_or_else(|| ParseError::new(0, 0, "prefers-contrast requires a value"))?;
-            let contrast = match val {
-                "no-preference" => Contrast::NoPreference,
-                "more" => Contrast::More,
-                "less" => Contrast::Less,
-                _ => return Err(ParseError::new(0, 0, format!("Unknown contrast: {}", val))),
-            };
-            Ok(MediaFeature::PrefersContrast(contrast))
+            Ok(MediaFeature::PrefersContrast(parse_keyword(val)?))
         }
         _ => Err(ParseError::new(
             0,
@@ -386,9 +306,26 @@ fn parse_media_feature(name: &str, value: Option<&str>) -> Result<MediaFeature,
     }
 }
 
+/// Parse a keyword feature value or media type (case-insensitive)
+fn parse_keyword<T: KeywordEnum>(input: &str) -> Result<T, ParseError> {
+    T::from_keyword(input).map_err(|e| ParseError::new(0, 0, e.to_string()).with_offset(0, input))
+}
+
+/// Check whether a feature value is a `calc()` expression
+fn is_calc(input: &str) -> bool {
+    input.trim_start().starts_with("calc(")
+}
+
+/// Parse a `calc()` feature value
+fn parse_calc(input: &str) -> Result<CalcExpression, ParseError> {
+    css_custom_properties::parse_calc_expression(input.trim()).map_err(|e| {
+        ParseError::new(0, 0, format!("Invalid calc() value: {}", e)).with_offset(0, input)
+    })
+}
+
 /// Parse a CSS length value
-fn parse_length(input: &str) -> Result<Length, ParseError> {
-    let input = input.trim();
+fn parse_length(source: &str) -> Result<Length, ParseError> {
+    let input = source.trim();
 
     // Find where the number ends and unit begins
     let mut num_end = 0;
@@ -401,15 +338,14 @@ fn parse_length(input: &str) -> Result<Length, ParseError> {
     }
 
     if num_end == 0 {
-        return Err(ParseError::new(0, 0, "Length must start with a number"));
+        return Err(ParseError::new(0, 0, "Length must start with a number").with_offset(0, source));
     }
 
     let value_str = &input[..num_end];
     let unit_str = &input[num_end..];
 
-    let value = value_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::new(0, 0, "Invalid number"))?;
+    let value = parse_finite_f32(value_str)
+        .map_err(|e| ParseError::new(0, 0, e.to_string()).with_offset(0, source))?;
 
     let unit = match unit_str {
         "px" => LengthUnit::Px,
@@ -418,15 +354,24 @@ fn parse_length(input: &str) -> Result<Length, ParseError> {
         "%" => LengthUnit::Percent,
         "vw" => LengthUnit::Vw,
         "vh" => LengthUnit::Vh,
-        _ => return Err(ParseError::new(0, 0, format!("Unknown unit: {}", unit_str))),
+        "vmin" => LengthUnit::Vmin,
+        "vmax" => LengthUnit::Vmax,
+        "pt" => LengthUnit::Pt,
+        "cm" => LengthUnit::Cm,
+        "mm" => LengthUnit::Mm,
+        "in" => LengthUnit::In,
+        _ => {
+            return Err(ParseError::new(0, 0, format!("Unknown unit: {}", unit_str))
+                .with_offset(offset_in(source, unit_str), unit_str))
+        }
     };
 
     Ok(Length::new(value, unit))
 }
 
 /// Parse a resolution value
-fn parse_resolution(input: &str) -> Result<Resolution, ParseError> {
-    let input = input.trim();
+fn parse_resolution(source: &str) -> Result<Resolution, ParseError> {
+    let input = source.trim();
 
     // Find where the number ends and unit begins
     let mut num_end = 0;
@@ -439,32 +384,51 @@ fn parse_resolution(input: &str) -> Result<Resolution, ParseError> {
     }
 
     if num_end == 0 {
-        return Err(ParseError::new(0, 0, "Resolution must start with a number"));
+        return Err(
+            ParseError::new(0, 0, "Resolution must start with a number").with_offset(0, source)
+        );
     }
 
     let value_str = &input[..num_end];
     let unit_str = &input[num_end..];
 
-    let value = value_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::new(0, 0, "Invalid resolution value"))?;
+    let value = parse_finite_f32(value_str)
+        .map_err(|e| ParseError::new(0, 0, e.to_string()).with_offset(0, source))?;
 
     let unit = match unit_str {
         "dpi" => ResolutionUnit::Dpi,
         "dpcm" => ResolutionUnit::Dpcm,
         "dppx" | "x" => ResolutionUnit::Dppx,
         _ => {
-            return Err(ParseError::new(
-                0,
-                0,
-                format!("Unknown resolution unit: {}", unit_str),
-            ))
+            return Err(
+                ParseError::new(0, 0, format!("Unknown resolution unit: {}", unit_str))
+                    .with_offset(offset_in(source, unit_str), unit_str),
+            )
         }
     };
 
     Ok(Resolution::new(value, unit))
 }
 
+/// Byte offset of `part` within `whole`
+///
+/// `part` must be a slice of `whole`, which holds for every sub-slice the
+/// parser hands down.
+fn offset_in(whole: &str, part: &str) -> usize {
+    part.as_ptr() as usize - whole.as_ptr() as usize
+}
+
+/// Map an error from parsing `part` back to an offset within `whole`
+///
+/// Errors that carry no location yet are attributed to the trimmed `part`.
+fn at(error: ParseError, whole: &str, part: &str) -> ParseError {
+    let base = offset_in(whole, part);
+    let trimmed = part.trim();
+    error
+        .with_offset(offset_in(part, trimmed), trimmed)
+        .offset_by(base)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,3 +228,107 @@ fn test_real_world_responsive_design() {
     assert!(!evaluator.evaluate_list(&tablet, &desktop_vp));
     assert!(evaluator.evaluate_list(&desktop, &desktop_vp));
 }
+
+fn selector_names(rules: &[&css_parser_core::StyleRule]) -> Vec<String> {
+    rules
+        .iter()
+        .flat_map(|rule| rule.selectors.iter())
+        .map(|selector| format!("{:?}", selector))
+        .collect()
+}
+
+#[test]
+fn test_active_rules_comma_media_rule_is_or() {
+    let css = r#"
+        div { color: red; }
+        @media (max-width: 400px), (min-width: 1200px) {
+            .edge { margin: 10px; }
+        }
+    "#;
+    let stylesheet = css_parser_core::CssParser::new().parse(css).unwrap();
+
+    // First alternative
+    let mobile = active_rules(&stylesheet, &ViewportInfo::mobile());
+    assert_eq!(mobile.len(), 2);
+
+    // Second alternative
+    let desktop = active_rules(&stylesheet, &ViewportInfo::desktop());
+    assert_eq!(desktop.len(), 2);
+    assert!(selector_names(&desktop)[1].contains("edge"));
+
+    // Neither alternative
+    let tablet = active_rules(&stylesheet, &ViewportInfo::tablet());
+    assert_eq!(tablet.len(), 1);
+}
+
+#[test]
+fn test_active_rules_media_type_alternative() {
+    let css = "@media print, screen { p { color: blue; } }";
+    let stylesheet = css_parser_core::CssParser::new().parse(css).unwrap();
+
+    // print never matches, screen always does
+    assert_eq!(active_rules(&stylesheet, &ViewportInfo::mobile()).len(), 1);
+}
+
+#[test]
+fn test_media_rule_with_invalid_query_uses_remaining_alternatives() {
+    let css = "@media (bogus-feature: 1), (min-width: 1px) { p { color: blue; } }";
+    let stylesheet = css_parser_core::CssParser::new().parse(css).unwrap();
+
+    assert_eq!(active_rules(&stylesheet, &ViewportInfo::mobile()).len(), 1);
+}
+
+#[test]
+fn test_active_rules_flattens_layer_blocks() {
+    let css = r#"
+        @layer base, theme;
+        @layer theme {
+            @media (max-width: 400px) { .narrow { margin: 0; } }
+            .button { color: red; }
+        }
+    "#;
+    let stylesheet = css_parser_core::CssParser::new().parse(css).unwrap();
+
+    let mobile = active_rules(&stylesheet, &ViewportInfo::mobile());
+    assert_eq!(mobile.len(), 2);
+    assert!(selector_names(&mobile)[1].contains("button"));
+
+    assert_eq!(active_rules(&stylesheet, &ViewportInfo::desktop()).len(), 1);
+}
+
+#[test]
+fn test_active_layered_rules_numbers_layers_by_declaration() {
+    let css = r#"
+        @layer base, theme;
+        .unlayered { color: black; }
+        @layer theme { .button { color: red; } }
+        @layer { .anonymous { color: green; } }
+        @layer base {
+            .base { color: blue; }
+            @layer reset { .reset { margin: 0; } }
+        }
+        @layer base.reset { .dotted { padding: 0; } }
+        @media (max-width: 400px) { @layer theme { .narrow { margin: 0; } } }
+    "#;
+    let stylesheet = css_parser_core::CssParser::new().parse(css).unwrap();
+
+    // base.reset ranks below base, which ranks below theme, then the
+    // anonymous layer; unlayered rules have no layer
+    let desktop = active_layered_rules(&stylesheet, &ViewportInfo::desktop());
+    let layers: Vec<Option<u32>> = desktop.iter().map(|(_, layer)| *layer).collect();
+    assert_eq!(
+        layers,
+        vec![None, Some(2), Some(3), Some(1), Some(0), Some(0)]
+    );
+
+    let mobile = active_layered_rules(&stylesheet, &ViewportInfo::mobile());
+    assert_eq!(mobile.len(), 7);
+    assert_eq!(mobile[6].1, Some(2));
+
+    // active_rules keeps the same rules and order
+    let rules: Vec<_> = desktop.iter().map(|(rule, _)| *rule).collect();
+    assert_eq!(
+        selector_names(&active_rules(&stylesheet, &ViewportInfo::desktop())),
+        selector_names(&rules)
+    );
+}
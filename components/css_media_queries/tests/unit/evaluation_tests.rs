@@ -116,6 +116,7 @@ fn test_evaluate_resolution_min_true() {
         color_bits: 24,
         monochrome_bits: 0,
         resolution_dpi: 192.0,
+        zoom: 1.0,
     };
 
     let feature = MediaFeature::Resolution(Resolution::new(96.0, ResolutionUnit::Dpi));
@@ -323,3 +324,41 @@ fn test_evaluate_pointer_coarse() {
     let result = evaluate_media_feature(&feature, &RangeType::Exact, &viewport);
     assert!(result);
 }
+
+#[test]
+fn test_zoom_does_not_affect_media_query_px() {
+    // 800 CSS px at 2x zoom is 1600 device px, but queries use CSS px
+    let viewport = ViewportInfo::new(800, 600).with_zoom(2.0);
+    assert_eq!(viewport.to_device_px(10.0), 20.0);
+
+    let query = parse_media_query("(min-width: 600px)").unwrap();
+    assert!(evaluate_media_query(&query, &viewport));
+
+    let query = parse_media_query("(min-width: 1000px)").unwrap();
+    assert!(!evaluate_media_query(&query, &viewport));
+}
+
+#[test]
+fn test_evaluate_max_width_calc() {
+    // calc(1000px - 200px) = 800px
+    let viewport = ViewportInfo::new(850, 600);
+    let query = parse_media_query("(max-width: calc(1000px - 200px))").unwrap();
+    assert!(!evaluate_media_query(&query, &viewport));
+
+    let viewport = ViewportInfo::new(750, 600);
+    assert!(evaluate_media_query(&query, &viewport));
+}
+
+#[test]
+fn test_evaluate_calc_against_viewport() {
+    // 50vh + 10em = 300px + 160px = 460px on a 1000x600 viewport
+    let viewport = ViewportInfo::new(1000, 600);
+    let query = parse_media_query("(min-width: calc(50vh + 10em))").unwrap();
+    assert!(evaluate_media_query(&query, &viewport));
+
+    let query = parse_media_query("(min-height: calc(50vh + 10em))").unwrap();
+    assert!(evaluate_media_query(&query, &viewport));
+
+    let query = parse_media_query("(min-height: calc(100vh + 1px))").unwrap();
+    assert!(!evaluate_media_query(&query, &viewport));
+}
@@ -1,3 +1,4 @@
 mod evaluation_tests;
 mod parsing_tests;
+mod simplify_tests;
 mod types_tests;
@@ -239,6 +239,34 @@ fn test_parse_missing_parenthesis() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_parse_error_reports_offset_of_bad_unit() {
+    let err = parse_media_query("screen and (min-width: 768qq)").unwrap_err();
+    assert_eq!(err.offset, Some(26));
+    assert_eq!(err.snippet.as_deref(), Some("qq"));
+}
+
+#[test]
+fn test_parse_error_reports_offset_of_bad_keyword() {
+    let err = parse_media_query("(min-width: 1px) and (orientation: sideways)").unwrap_err();
+    assert_eq!(err.offset, Some(35));
+    assert_eq!(err.snippet.as_deref(), Some("sideways"));
+}
+
+#[test]
+fn test_parse_error_reports_offset_of_unknown_feature() {
+    let err = parse_media_query("( invalid-feature: 100px)").unwrap_err();
+    assert_eq!(err.offset, Some(2));
+    assert_eq!(err.snippet.as_deref(), Some("invalid-feature: 100px"));
+}
+
+#[test]
+fn test_parse_list_error_reports_offset_within_list() {
+    let err = parse_media_query_list("screen, bogus").unwrap_err();
+    assert_eq!(err.offset, Some(8));
+    assert_eq!(err.snippet.as_deref(), Some("bogus"));
+}
+
 #[test]
 fn test_parse_resolution_dpi() {
     let result = parse_media_query("(min-resolution: 192dpi)");
@@ -341,3 +369,122 @@ fn test_parse_pointer_fine() {
         }
     }
 }
+
+#[test]
+fn test_parse_max_width_calc() {
+    let query = parse_media_query("(max-width: calc(1000px - 200px))").unwrap();
+
+    if let Some(MediaCondition::Feature { feature, range }) = query.condition {
+        assert_eq!(range, RangeType::Max);
+        assert!(matches!(feature, MediaFeature::WidthCalc(_)));
+    } else {
+        panic!("Expected Feature condition");
+    }
+}
+
+#[test]
+fn test_parse_min_height_calc_with_percentage() {
+    let query = parse_media_query("screen and (min-height: calc(100% - 20px))").unwrap();
+
+    if let Some(MediaCondition::Feature { feature, range }) = query.condition {
+        assert_eq!(range, RangeType::Min);
+        assert!(matches!(feature, MediaFeature::HeightCalc(_)));
+    } else {
+        panic!("Expected Feature condition");
+    }
+}
+
+#[test]
+fn test_parse_invalid_calc() {
+    assert!(parse_media_query("(min-width: calc(10px % 2px))").is_err());
+}
+
+#[test]
+fn test_parse_keywords_case_insensitive() {
+    let query = parse_media_query("SCREEN and (orientation: Landscape)").unwrap();
+    assert_eq!(query.media_type, Some(MediaType::Screen));
+
+    if let Some(MediaCondition::Feature { feature, .. }) = query.condition {
+        assert_eq!(feature, MediaFeature::Orientation(Orientation::Landscape));
+    } else {
+        panic!("Expected Feature condition");
+    }
+
+    let query = parse_media_query("(prefers-reduced-motion: REDUCE)").unwrap();
+    if let Some(MediaCondition::Feature { feature, .. }) = query.condition {
+        assert_eq!(
+            feature,
+            MediaFeature::PrefersReducedMotion(ReducedMotion::Reduce)
+        );
+    } else {
+        panic!("Expected Feature condition");
+    }
+}
+
+#[test]
+fn test_parse_unknown_keywords_error_uniformly() {
+    let cases = [
+        ("tv", "Unknown media type: tv"),
+        ("(orientation: square)", "Unknown orientation: square"),
+        ("(pointer: laser)", "Unknown pointer type: laser"),
+        ("(prefers-contrast: max)", "Unknown contrast: max"),
+    ];
+
+    for (input, message) in cases {
+        let error = parse_media_query(input).unwrap_err();
+        assert!(
+            error.message.ends_with(message),
+            "{} gave {:?}",
+            input,
+            error.message
+        );
+    }
+}
+
+#[test]
+fn test_referenced_features_width_only() {
+    let query = parse_media_query("screen and (min-width: 768px) and (max-width: 1024px)").unwrap();
+    let features = query.referenced_features();
+
+    assert_eq!(features.len(), 1);
+    assert!(features.contains(&MediaFeatureKind::Width));
+}
+
+#[test]
+fn test_referenced_features_mixed_query() {
+    let query = parse_media_query("(min-width: 600px) and (prefers-color-scheme: dark)").unwrap();
+    let features = query.referenced_features();
+
+    assert_eq!(features.len(), 2);
+    assert!(features.contains(&MediaFeatureKind::Width));
+    assert!(features.contains(&MediaFeatureKind::PrefersColorScheme));
+}
+
+#[test]
+fn test_referenced_features_walks_negations_and_calc() {
+    let query = MediaQuery::condition(MediaCondition::Not {
+        condition: Box::new(MediaCondition::Or {
+            left: Box::new(MediaCondition::Feature {
+                feature: MediaFeature::WidthCalc(CalcExpression::Value(
+                    css_custom_properties::CalcValue::Percentage(50.0),
+                )),
+                range: RangeType::Min,
+            }),
+            right: Box::new(MediaCondition::Feature {
+                feature: MediaFeature::Orientation(Orientation::Portrait),
+                range: RangeType::Exact,
+            }),
+        }),
+    });
+    let features = query.referenced_features();
+
+    assert_eq!(features.len(), 2);
+    assert!(features.contains(&MediaFeatureKind::Width));
+    assert!(features.contains(&MediaFeatureKind::Orientation));
+}
+
+#[test]
+fn test_referenced_features_media_type_only() {
+    let query = parse_media_query("print").unwrap();
+    assert!(query.referenced_features().is_empty());
+}
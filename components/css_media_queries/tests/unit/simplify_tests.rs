@@ -0,0 +1,107 @@
+//! Unit tests for media condition simplification
+
+use css_media_queries::*;
+
+fn min_width(px: f32) -> MediaCondition {
+    MediaCondition::Feature {
+        feature: MediaFeature::Width(Some(Length::new(px, LengthUnit::Px))),
+        range: RangeType::Min,
+    }
+}
+
+fn and(left: MediaCondition, right: MediaCondition) -> MediaCondition {
+    MediaCondition::And {
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn or(left: MediaCondition, right: MediaCondition) -> MediaCondition {
+    MediaCondition::Or {
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn not(condition: MediaCondition) -> MediaCondition {
+    MediaCondition::Not {
+        condition: Box::new(condition),
+    }
+}
+
+#[test]
+fn test_simplify_feature_is_unchanged() {
+    assert_eq!(min_width(600.0).simplify(), min_width(600.0));
+}
+
+#[test]
+fn test_simplify_double_negation() {
+    // not (not (min-width: 600px))
+    let condition = not(not(min_width(600.0)));
+    assert_eq!(condition.simplify(), min_width(600.0));
+}
+
+#[test]
+fn test_simplify_flattens_nested_and() {
+    let a = min_width(100.0);
+    let b = min_width(200.0);
+    let c = min_width(300.0);
+
+    let left_nested = and(and(a.clone(), b.clone()), c.clone());
+    let right_nested = and(a.clone(), and(b.clone(), c.clone()));
+
+    assert_eq!(left_nested.simplify(), right_nested.simplify());
+    assert_eq!(left_nested.simplify(), and(a, and(b, c)));
+}
+
+#[test]
+fn test_simplify_removes_duplicate_operands() {
+    let a = min_width(100.0);
+    let b = min_width(200.0);
+
+    let condition = or(a.clone(), or(b.clone(), a.clone()));
+    assert_eq!(condition.simplify(), or(a, b));
+}
+
+#[test]
+fn test_simplify_de_morgan_and() {
+    let a = min_width(100.0);
+    let b = min_width(200.0);
+
+    let condition = not(and(a.clone(), b.clone()));
+    assert_eq!(condition.simplify(), or(not(a), not(b)));
+}
+
+#[test]
+fn test_simplify_de_morgan_or_with_double_negation() {
+    let a = min_width(100.0);
+    let b = min_width(200.0);
+
+    // not (a or not b) => (not a) and b
+    let condition = not(or(a.clone(), not(b.clone())));
+    assert_eq!(condition.simplify(), and(not(a), b));
+}
+
+#[test]
+fn test_simplify_flattens_after_de_morgan() {
+    let a = min_width(100.0);
+    let b = min_width(200.0);
+    let c = min_width(300.0);
+
+    // (not (a or b)) and c => (not a) and (not b) and c
+    let condition = and(not(or(a.clone(), b.clone())), c.clone());
+    assert_eq!(condition.simplify(), and(not(a), and(not(b), c)));
+}
+
+#[test]
+fn test_simplified_condition_evaluates_the_same() {
+    let viewport = ViewportInfo::new(800, 600);
+    let condition = not(and(min_width(600.0), not(min_width(1000.0))));
+    let query = MediaQuery::condition(condition.clone());
+    let simplified = MediaQuery::condition(condition.simplify());
+
+    assert_eq!(
+        evaluate_media_query(&query, &viewport),
+        evaluate_media_query(&simplified, &viewport)
+    );
+}
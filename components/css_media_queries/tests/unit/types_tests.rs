@@ -140,6 +140,7 @@ fn test_viewport_info_creation() {
         color_bits: 24,
         monochrome_bits: 0,
         resolution_dpi: 96.0,
+        zoom: 1.0,
     };
 
     assert_eq!(viewport.width, 1920);
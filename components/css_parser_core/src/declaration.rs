@@ -1,26 +1,36 @@
 //! Property declaration parsing for CSS2.1
 
-use crate::{ParseError, PropertyDeclaration, PropertyValue};
-use css_types::{Color, Length, LengthUnit};
+use crate::{ParseError, PropertyDeclaration, PropertyValue, SourceSpan};
+use css_types::{parse_finite_f32, Color, Length, LengthUnit};
 
 /// Parse a block of declarations (inside braces)
 pub fn parse_declarations(input: &str) -> Result<Vec<PropertyDeclaration>, ParseError> {
-    let input = input.trim();
-
-    if input.is_empty() {
-        return Ok(Vec::new());
-    }
+    parse_declarations_at(input, None)
+}
 
+/// Parse declarations, recording their spans when `base` is given
+///
+/// `base` is the byte offset of `input` within the original source.
+pub(crate) fn parse_declarations_at(
+    input: &str,
+    base: Option<usize>,
+) -> Result<Vec<PropertyDeclaration>, ParseError> {
     let mut declarations = Vec::new();
+    let mut start = 0;
 
     // Split by semicolon
     for decl_text in input.split(';') {
+        let offset = start + decl_text.len() - decl_text.trim_start().len();
+        start += decl_text.len() + 1;
+
         let decl_text = decl_text.trim();
         if decl_text.is_empty() {
             continue;
         }
 
-        let declaration = parse_single_declaration(decl_text)?;
+        let mut declaration = parse_single_declaration(decl_text)?;
+        declaration.span =
+            base.map(|base| SourceSpan::new(base + offset, base + offset + decl_text.len()));
         declarations.push(declaration);
     }
 
@@ -62,11 +72,15 @@ fn parse_single_declaration(input: &str) -> Result<PropertyDeclaration, ParseErr
         name: property.to_string(),
         value,
         important,
+        span: None,
     })
 }
 
 /// Parse a property value based on property name
-fn parse_property_value(property: &str, value: &str) -> Result<PropertyValue, ParseError> {
+pub(crate) fn parse_property_value(
+    property: &str,
+    value: &str,
+) -> Result<PropertyValue, ParseError> {
     let value = value.trim();
 
     // Try to parse as color for color properties
@@ -203,9 +217,7 @@ fn parse_rgb_color(value: &str) -> Result<Color, ParseError> {
         .map_err(|_| ParseError::new(1, 1, "Invalid blue value"))?;
 
     if is_rgba {
-        let a = parts[3]
-            .parse::<f32>()
-            .map_err(|_| ParseError::new(1, 1, "Invalid alpha value"))?;
+        let a = parse_finite_f32(parts[3]).map_err(|e| ParseError::new(1, 1, e.to_string()))?;
         Ok(Color::rgba(r, g, b, a))
     } else {
         Ok(Color::rgb(r, g, b))
@@ -237,9 +249,7 @@ fn parse_length_value(value: &str) -> Result<PropertyValue, ParseError> {
     let num_str = &value[..num_end];
     let unit_str = &value[num_end..];
 
-    let num = num_str
-        .parse::<f32>()
-        .map_err(|_| ParseError::new(1, 1, format!("Invalid number: {}", num_str)))?;
+    let num = parse_finite_f32(num_str).map_err(|e| ParseError::new(1, 1, e.to_string()))?;
 
     let unit = match unit_str {
         "px" | "" => LengthUnit::Px,
@@ -248,6 +258,12 @@ fn parse_length_value(value: &str) -> Result<PropertyValue, ParseError> {
         "%" => LengthUnit::Percent,
         "vw" => LengthUnit::Vw,
         "vh" => LengthUnit::Vh,
+        "vmin" => LengthUnit::Vmin,
+        "vmax" => LengthUnit::Vmax,
+        "pt" => LengthUnit::Pt,
+        "cm" => LengthUnit::Cm,
+        "mm" => LengthUnit::Mm,
+        "in" => LengthUnit::In,
         _ => return Err(ParseError::new(1, 1, format!("Unknown unit: {}", unit_str))),
     };
 
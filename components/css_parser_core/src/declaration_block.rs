@@ -0,0 +1,234 @@
+//! Declaration blocks with shorthand expansion
+//!
+//! A block keeps at most one declaration per longhand property. Shorthands
+//! are expanded when inserted, so a later `margin` replaces an earlier
+//! `margin-top` and vice versa, while `!important` declarations can only be
+//! replaced by other `!important` declarations.
+
+use crate::declaration::{parse_declarations, parse_property_value};
+use crate::{ParseError, PropertyDeclaration, PropertyValue};
+use std::collections::HashMap;
+
+/// Ordered block of longhand declarations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeclarationBlock {
+    declarations: Vec<PropertyDeclaration>,
+}
+
+impl DeclarationBlock {
+    /// Create an empty declaration block
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the contents of a `{ ... }` block
+    ///
+    /// # Examples
+    /// ```
+    /// use css_parser_core::{DeclarationBlock, Length, PropertyValue};
+    /// use css_types::LengthUnit;
+    ///
+    /// let block = DeclarationBlock::parse("margin: 0; margin-top: 10px").unwrap();
+    /// assert_eq!(
+    ///     block.get("margin-top").map(|d| &d.value),
+    ///     Some(&PropertyValue::Length(Length::new(10.0, LengthUnit::Px)))
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut block = Self::new();
+        for declaration in parse_declarations(input)? {
+            block.insert(declaration);
+        }
+        Ok(block)
+    }
+
+    /// Add a declaration, expanding shorthands into their longhands
+    ///
+    /// Each longhand replaces any earlier declaration of the same property
+    /// and moves to the end of the block, unless the earlier one is
+    /// `!important` and the new one is not. Shorthands with an invalid
+    /// number of components are dropped, like any invalid declaration.
+    pub fn insert(&mut self, declaration: PropertyDeclaration) {
+        let Some(longhands) = expand_shorthand(&declaration) else {
+            return;
+        };
+
+        for longhand in longhands {
+            if let Some(index) = self.position(&longhand.name) {
+                if self.declarations[index].important && !longhand.important {
+                    continue;
+                }
+                self.declarations.remove(index);
+            }
+            self.declarations.push(longhand);
+        }
+    }
+
+    /// Get the declaration for a longhand property
+    pub fn get(&self, name: &str) -> Option<&PropertyDeclaration> {
+        self.position(name).map(|index| &self.declarations[index])
+    }
+
+    /// Longhand declarations in the order they were last set
+    pub fn declarations(&self) -> &[PropertyDeclaration] {
+        &self.declarations
+    }
+
+    /// Number of longhand declarations
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+
+    /// Check whether the block has no declarations
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+
+    /// Resolve the block to a map from longhand name to value
+    pub fn to_longhand_map(&self) -> HashMap<String, PropertyValue> {
+        self.declarations
+            .iter()
+            .map(|declaration| (declaration.name.clone(), declaration.value.clone()))
+            .collect()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.declarations
+            .iter()
+            .position(|declaration| declaration.name == name)
+    }
+}
+
+/// Longhands of the box shorthands, in top/right/bottom/left order
+fn box_longhands(shorthand: &str) -> Option<[&'static str; 4]> {
+    match shorthand {
+        "margin" => Some(["margin-top", "margin-right", "margin-bottom", "margin-left"]),
+        "padding" => Some([
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ]),
+        "inset" => Some(["top", "right", "bottom", "left"]),
+        _ => None,
+    }
+}
+
+/// Split a box shorthand value into top/right/bottom/left values
+///
+/// Each whitespace-separated component is parsed as a value of `longhand`.
+/// One to four components follow the usual fill rules; any other count
+/// returns `None`.
+fn expand_box_values(longhand: &str, value: &PropertyValue) -> Option<[PropertyValue; 4]> {
+    let components: Vec<PropertyValue> = match value {
+        PropertyValue::Keyword(text) => text
+            .split_whitespace()
+            .map(|part| parse_property_value(longhand, part))
+            .collect::<Result<_, _>>()
+            .ok()?,
+        value => vec![value.clone()],
+    };
+
+    let [top, right, bottom, left] = match components.as_slice() {
+        [all] => [all, all, all, all],
+        [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+        [top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+        [top, right, bottom, left] => [top, right, bottom, left],
+        _ => return None,
+    };
+
+    Some([top.clone(), right.clone(), bottom.clone(), left.clone()])
+}
+
+/// Expand a declaration into longhand declarations
+///
+/// Longhands are returned unchanged. Box shorthands take one to four
+/// components that follow the usual top/right/bottom/left fill rules.
+/// Every produced longhand carries the shorthand's `!important` flag, so
+/// the cascade can compare it against other longhands directly, and the
+/// shorthand's source span.
+/// Returns `None` when a shorthand value cannot be expanded.
+///
+/// # Examples
+/// ```
+/// use css_parser_core::{expand_shorthand, PropertyDeclaration, PropertyValue};
+///
+/// let margin = PropertyDeclaration {
+///     name: "margin".to_string(),
+///     value: PropertyValue::Keyword("0 auto".to_string()),
+///     important: true,
+///     span: None,
+/// };
+/// let longhands = expand_shorthand(&margin).unwrap();
+/// assert_eq!(longhands.len(), 4);
+/// assert!(longhands.iter().all(|longhand| longhand.important));
+/// ```
+pub fn expand_shorthand(declaration: &PropertyDeclaration) -> Option<Vec<PropertyDeclaration>> {
+    let Some(longhands) = box_longhands(&declaration.name) else {
+        return Some(vec![declaration.clone()]);
+    };
+
+    let values = expand_box_values(longhands[0], &declaration.value)?;
+
+    Some(
+        longhands
+            .iter()
+            .zip(values)
+            .map(|(name, value)| PropertyDeclaration {
+                name: name.to_string(),
+                value,
+                important: declaration.important,
+                span: declaration.span,
+            })
+            .collect(),
+    )
+}
+
+/// Offsets of a positioned box, as set by the `inset` shorthand
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inset {
+    /// Offset of the top edge
+    pub top: PropertyValue,
+    /// Offset of the right edge
+    pub right: PropertyValue,
+    /// Offset of the bottom edge
+    pub bottom: PropertyValue,
+    /// Offset of the left edge
+    pub left: PropertyValue,
+}
+
+/// Parse the value of the `inset` shorthand
+///
+/// One to four components are expanded to top/right/bottom/left offsets
+/// with the same fill rules as `margin` and `padding`.
+///
+/// # Examples
+/// ```
+/// use css_parser_core::{parse_inset, Length, PropertyValue};
+/// use css_types::LengthUnit;
+///
+/// let inset = parse_inset("10px 20px").unwrap();
+/// assert_eq!(inset.bottom, PropertyValue::Length(Length::new(10.0, LengthUnit::Px)));
+/// assert_eq!(inset.left, PropertyValue::Length(Length::new(20.0, LengthUnit::Px)));
+/// ```
+///
+/// # Errors
+/// Returns a `ParseError` when the value is empty or has more than four
+/// components.
+pub fn parse_inset(input: &str) -> Result<Inset, ParseError> {
+    let value = PropertyValue::Keyword(input.trim().to_string());
+    let [top, right, bottom, left] = expand_box_values("top", &value).ok_or_else(|| {
+        ParseError::new(
+            1,
+            1,
+            format!("inset takes one to four values, found '{}'", input.trim()),
+        )
+    })?;
+
+    Ok(Inset {
+        top,
+        right,
+        bottom,
+        left,
+    })
+}
@@ -1,16 +1,23 @@
 //! CSS Parser Core - CSS2.1 parsing implementation
 //!
 //! This module provides a basic CSS parser for CSS2.1 stylesheets,
-//! supporting simple selectors (element, class, id) and basic properties.
+//! supporting simple selectors (element, class, id) and basic properties,
+//! and serializing parsed stylesheets back to CSS text.
 
-pub use css_types::{Color, Length, Specificity};
+pub use css_types::{Color, KeywordEnum, Length, Specificity};
 use std::fmt;
 
 mod declaration;
+mod declaration_block;
 mod parser;
 mod selector;
+mod serializer;
+mod value_tokenizer;
 
+pub use declaration_block::{expand_shorthand, parse_inset, DeclarationBlock, Inset};
 pub use parser::CssParser;
+pub use selector::parse_selector_list;
+pub use value_tokenizer::{tokenize_value, Token};
 
 /// Stylesheet origin (author, user, user-agent)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +36,10 @@ pub struct ParseError {
     pub line: usize,
     pub column: usize,
     pub message: String,
+    /// Byte offset of the offending text within the parsed input, if known
+    pub offset: Option<usize>,
+    /// The offending text, if known
+    pub snippet: Option<String>,
 }
 
 impl ParseError {
@@ -38,8 +49,34 @@ impl ParseError {
             line,
             column,
             message: message.into(),
+            offset: None,
+            snippet: None,
         }
     }
+
+    /// Attach the byte offset and text of the offending input
+    ///
+    /// An offset that is already set is kept, so the innermost (most
+    /// precise) location wins when errors propagate outward.
+    pub fn with_offset(mut self, offset: usize, snippet: impl Into<String>) -> Self {
+        if self.offset.is_none() {
+            self.offset = Some(offset);
+            self.snippet = Some(snippet.into());
+        }
+        self
+    }
+
+    /// Shift the recorded offset by `base` bytes
+    ///
+    /// Value parsers report offsets relative to the string they were given;
+    /// callers that parsed a slice of a larger source use this to map the
+    /// error back to the source.
+    pub fn offset_by(mut self, base: usize) -> Self {
+        if let Some(offset) = self.offset.as_mut() {
+            *offset += base;
+        }
+        self
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -48,7 +85,11 @@ impl fmt::Display for ParseError {
             f,
             "Parse error at line {}, column {}: {}",
             self.line, self.column, self.message
-        )
+        )?;
+        if let (Some(offset), Some(snippet)) = (self.offset, &self.snippet) {
+            write!(f, " (at offset {}: '{}')", offset, snippet)?;
+        }
+        Ok(())
     }
 }
 
@@ -87,6 +128,8 @@ pub enum CssRule {
     Media(MediaRule),
     /// Import rule
     Import(ImportRule),
+    /// Cascade layer rule (`@layer`)
+    Layer(LayerRule),
 }
 
 /// Style rule with selectors and declarations
@@ -96,6 +139,9 @@ pub struct StyleRule {
     pub selectors: Vec<Selector>,
     /// List of property declarations
     pub declarations: Vec<PropertyDeclaration>,
+    /// Source range of the rule, from selector to closing brace, when
+    /// parsed with [`CssParser::with_spans`]
+    pub span: Option<SourceSpan>,
 }
 
 /// CSS selector (simple selectors for CSS2.1)
@@ -137,6 +183,51 @@ impl Selector {
             }
         }
     }
+
+    /// Check whether two selectors could both match the same element
+    ///
+    /// This is conservative: `false` means no element can match both, while
+    /// `true` only means the selectors were not proven exclusive. Selectors
+    /// are exclusive when they require different element names (compared
+    /// ASCII case-insensitively) or different ids.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_parser_core::Selector;
+    ///
+    /// let div = Selector::Element("div".to_string());
+    /// let span = Selector::Element("span".to_string());
+    /// assert!(!div.could_match_same(&span));
+    ///
+    /// let a = Selector::Class("a".to_string());
+    /// let b = Selector::Class("b".to_string());
+    /// assert!(a.could_match_same(&b));
+    /// ```
+    pub fn could_match_same(&self, other: &Selector) -> bool {
+        let (element, id) = self.required_element_and_id();
+        let (other_element, other_id) = other.required_element_and_id();
+
+        let elements_conflict = matches!(
+            (element, other_element),
+            (Some(a), Some(b)) if !a.eq_ignore_ascii_case(b)
+        );
+        let ids_conflict = matches!((id, other_id), (Some(a), Some(b)) if a != b);
+
+        !elements_conflict && !ids_conflict
+    }
+
+    /// Element name and id an element must have to match the selector
+    fn required_element_and_id(&self) -> (Option<&str>, Option<&str>) {
+        match self {
+            Selector::Element(name) => (Some(name), None),
+            Selector::Id(id) => (None, Some(id)),
+            Selector::Class(_) | Selector::Universal => (None, None),
+            Selector::Compound { element, id, .. } => (
+                element.as_deref().filter(|name| *name != "*"),
+                id.as_deref(),
+            ),
+        }
+    }
 }
 
 /// CSS property declaration
@@ -148,6 +239,53 @@ pub struct PropertyDeclaration {
     pub value: PropertyValue,
     /// Whether marked as !important
     pub important: bool,
+    /// Source range of the declaration, excluding the trailing `;`, when
+    /// parsed with [`CssParser::with_spans`]
+    pub span: Option<SourceSpan>,
+}
+
+/// Byte range of a parsed construct within the parser input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// Byte offset of the first character
+    pub start: usize,
+    /// Byte offset one past the last character
+    pub end: usize,
+}
+
+impl SourceSpan {
+    /// Create a span covering `start..end`
+    pub fn new(start: usize, end: usize) -> Self {
+        SourceSpan { start, end }
+    }
+
+    /// Get the spanned text from the source it was parsed from
+    ///
+    /// Returns `None` if the span does not fit `source`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_parser_core::{CssParser, CssRule};
+    ///
+    /// let css = "p { color: red }";
+    /// let sheet = CssParser::new().with_spans(true).parse(css).unwrap();
+    /// let CssRule::Style(rule) = &sheet.rules[0] else { unreachable!() };
+    /// let declaration = &rule.declarations[0];
+    /// assert_eq!(declaration.span.unwrap().slice(css), Some("color: red"));
+    /// ```
+    pub fn slice<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.start..self.end)
+    }
+
+    /// Length of the span in bytes
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Check whether the span is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// CSS property value (simplified for CSS2.1)
@@ -172,6 +310,19 @@ pub struct MediaRule {
     pub rules: Vec<CssRule>,
 }
 
+/// Cascade layer rule
+///
+/// The statement form `@layer a, b;` only declares layer order and has no
+/// rules. The block form `@layer a { ... }` names at most one layer; an
+/// anonymous block has no names.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerRule {
+    /// Layer names, in declaration order
+    pub names: Vec<String>,
+    /// Rules within the layer block (`None` for the statement form)
+    pub rules: Option<Vec<CssRule>>,
+}
+
 /// Import rule
 #[derive(Debug, Clone, PartialEq)]
 pub struct ImportRule {
@@ -211,4 +362,45 @@ mod tests {
         };
         assert_eq!(selector.specificity(), Specificity::new(1, 2, 1));
     }
+
+    #[test]
+    fn test_could_match_same_conflicting_elements() {
+        let div = Selector::Element("div".to_string());
+        let span = Selector::Element("span".to_string());
+        assert!(!div.could_match_same(&span));
+        assert!(!span.could_match_same(&div));
+        assert!(div.could_match_same(&Selector::Element("DIV".to_string())));
+    }
+
+    #[test]
+    fn test_could_match_same_classes_overlap() {
+        let a = Selector::Class("a".to_string());
+        let b = Selector::Class("b".to_string());
+        assert!(a.could_match_same(&b));
+        assert!(a.could_match_same(&Selector::Universal));
+        assert!(a.could_match_same(&Selector::Element("div".to_string())));
+    }
+
+    #[test]
+    fn test_could_match_same_ids_and_compounds() {
+        let header = Selector::Id("header".to_string());
+        let footer = Selector::Id("footer".to_string());
+        assert!(!header.could_match_same(&footer));
+        assert!(header.could_match_same(&header));
+
+        let div_header = Selector::Compound {
+            element: Some("div".to_string()),
+            classes: vec!["wide".to_string()],
+            id: Some("header".to_string()),
+        };
+        let span_any = Selector::Compound {
+            element: Some("span".to_string()),
+            classes: vec!["wide".to_string()],
+            id: None,
+        };
+        assert!(div_header.could_match_same(&header));
+        assert!(!div_header.could_match_same(&footer));
+        assert!(!div_header.could_match_same(&span_any));
+        assert!(div_header.could_match_same(&Selector::Class("narrow".to_string())));
+    }
 }
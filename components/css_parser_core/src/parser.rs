@@ -1,18 +1,29 @@
 //! CSS Parser implementation
 
-use crate::declaration::parse_declarations;
-use crate::selector::parse_selector_list;
-use crate::{CssRule, ParseError, StyleRule, Stylesheet};
+use crate::declaration::parse_declarations_at;
+use crate::selector::{is_valid_identifier, parse_selector_list};
+use crate::{CssRule, LayerRule, MediaRule, ParseError, SourceSpan, StyleRule, Stylesheet};
 
 /// CSS Parser for CSS2.1 stylesheets
 pub struct CssParser {
-    // Parser state (if needed in future)
+    /// Whether to record source spans on rules and declarations
+    spans: bool,
 }
 
 impl CssParser {
     /// Create a new CSS parser
     pub fn new() -> Self {
-        CssParser {}
+        CssParser { spans: false }
+    }
+
+    /// Builder: record the byte range of each style rule and declaration
+    ///
+    /// Spans are offsets into the text passed to [`parse`](Self::parse) or
+    /// [`parse_rule`](Self::parse_rule). Off by default to avoid the
+    /// overhead.
+    pub fn with_spans(mut self, spans: bool) -> Self {
+        self.spans = spans;
+        self
     }
 
     /// Parse a complete CSS stylesheet
@@ -27,9 +38,9 @@ impl CssParser {
         // Simple rule extraction: split by '}' and parse each rule
         let rules = self.extract_rules(input)?;
 
-        for rule_text in rules {
+        for (offset, rule_text) in rules {
             if !rule_text.trim().is_empty() {
-                match self.parse_rule(rule_text) {
+                match self.parse_rule_at(rule_text, offset) {
                     Ok(rule) => stylesheet.rules.push(rule),
                     Err(e) => return Err(e),
                 }
@@ -41,8 +52,23 @@ impl CssParser {
 
     /// Parse a single CSS rule
     pub fn parse_rule(&self, input: &str) -> Result<CssRule, ParseError> {
+        self.parse_rule_at(input, 0)
+    }
+
+    /// Parse a single rule that starts `base` bytes into the source
+    fn parse_rule_at(&self, input: &str, base: usize) -> Result<CssRule, ParseError> {
+        let base = base + input.len() - input.trim_start().len();
         let input = input.trim();
 
+        // Statement at-rules end in ';' rather than a block
+        if let Some(prelude) = strip_at_keyword(input, "@layer") {
+            if let Some(prelude) = prelude.strip_suffix(';') {
+                if !prelude.contains('{') {
+                    return self.parse_layer_statement(prelude);
+                }
+            }
+        }
+
         // Find the selector/declaration split at '{'
         let open_brace = input
             .find('{')
@@ -59,21 +85,112 @@ impl CssParser {
         // Extract selectors and declaration block
         let selector_text = &input[..open_brace];
         let declaration_text = &input[open_brace + 1..close_brace];
+        let block_base = base + open_brace + 1;
+
+        // At-rules
+        if let Some(prelude) = strip_at_keyword(selector_text.trim(), "@media") {
+            return self.parse_media_rule(prelude, declaration_text, block_base);
+        }
+        if let Some(prelude) = strip_at_keyword(selector_text.trim(), "@layer") {
+            return self.parse_layer_block(prelude, declaration_text, block_base);
+        }
 
         // Parse selectors
         let selectors = parse_selector_list(selector_text)?;
 
         // Parse declarations
-        let declarations = parse_declarations(declaration_text)?;
+        let declarations =
+            parse_declarations_at(declaration_text, self.spans.then_some(block_base))?;
 
         Ok(CssRule::Style(StyleRule {
             selectors,
             declarations,
+            span: self
+                .spans
+                .then(|| SourceSpan::new(base, base + close_brace + 1)),
+        }))
+    }
+
+    /// Parse an `@media` rule from its prelude and block contents
+    ///
+    /// Each comma-separated query in the prelude is kept as a separate entry
+    /// of `media_queries`; the rule applies when any of them matches.
+    fn parse_media_rule(
+        &self,
+        prelude: &str,
+        block: &str,
+        block_base: usize,
+    ) -> Result<CssRule, ParseError> {
+        let media_queries = prelude
+            .split(',')
+            .map(|query| query.trim().to_string())
+            .filter(|query| !query.is_empty())
+            .collect();
+
+        let mut rules = Vec::new();
+        for (offset, rule_text) in self.extract_rules(block)? {
+            if !rule_text.trim().is_empty() {
+                rules.push(self.parse_rule_at(rule_text, block_base + offset)?);
+            }
+        }
+
+        Ok(CssRule::Media(MediaRule {
+            media_queries,
+            rules,
         }))
     }
 
-    /// Extract individual rules from stylesheet text
-    fn extract_rules<'a>(&self, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    /// Parse an `@layer a, b;` statement from its prelude
+    fn parse_layer_statement(&self, prelude: &str) -> Result<CssRule, ParseError> {
+        let names = parse_layer_names(prelude)?;
+        if names.is_empty() {
+            return Err(ParseError::new(
+                1,
+                1,
+                "Expected layer name in @layer statement",
+            ));
+        }
+
+        Ok(CssRule::Layer(LayerRule { names, rules: None }))
+    }
+
+    /// Parse an `@layer` block rule from its prelude and block contents
+    ///
+    /// The prelude names at most one layer; an empty prelude declares an
+    /// anonymous layer.
+    fn parse_layer_block(
+        &self,
+        prelude: &str,
+        block: &str,
+        block_base: usize,
+    ) -> Result<CssRule, ParseError> {
+        let names = parse_layer_names(prelude)?;
+        if names.len() > 1 {
+            return Err(ParseError::new(
+                1,
+                1,
+                "@layer block must name at most one layer",
+            ));
+        }
+
+        let mut rules = Vec::new();
+        for (offset, rule_text) in self.extract_rules(block)? {
+            if !rule_text.trim().is_empty() {
+                rules.push(self.parse_rule_at(rule_text, block_base + offset)?);
+            }
+        }
+
+        Ok(CssRule::Layer(LayerRule {
+            names,
+            rules: Some(rules),
+        }))
+    }
+
+    /// Extract individual rules from stylesheet text, with their byte offsets
+    ///
+    /// Rules end at the brace closing their block, or at a top-level `;`
+    /// for statement at-rules such as `@layer a, b;`.
+    fn extract_rules<'a>(&self, input: &'a str) -> Result<Vec<(usize, &'a str)>, ParseError> {
         let mut rules = Vec::new();
         let mut start = 0;
         let mut brace_depth = 0;
@@ -84,10 +201,14 @@ impl CssParser {
                 '}' => {
                     brace_depth -= 1;
                     if brace_depth == 0 {
-                        rules.push(&input[start..=i]);
+                        rules.push((start, &input[start..=i]));
                         start = i + 1;
                     }
                 }
+                ';' if brace_depth == 0 && input[start..i].trim_start().starts_with('@') => {
+                    rules.push((start, &input[start..=i]));
+                    start = i + 1;
+                }
                 _ => {}
             }
         }
@@ -100,6 +221,44 @@ impl CssParser {
     }
 }
 
+/// Strip an at-keyword such as `@media` from the start of `text`
+///
+/// Returns `None` unless the keyword ends there, so `@mediafoo` does not
+/// match `@media`.
+fn strip_at_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = text.strip_prefix(keyword)?;
+    match rest.chars().next() {
+        Some(ch) if ch.is_alphanumeric() || ch == '-' || ch == '_' => None,
+        _ => Some(rest),
+    }
+}
+
+/// Split a comma-separated list of layer names
+///
+/// Each name is one or more identifiers joined by `.` (e.g. `theme.dark`).
+/// An empty prelude gives no names.
+fn parse_layer_names(prelude: &str) -> Result<Vec<String>, ParseError> {
+    if prelude.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    prelude
+        .split(',')
+        .map(|name| {
+            let name = name.trim();
+            if name.split('.').all(is_valid_identifier) {
+                Ok(name.to_string())
+            } else {
+                Err(ParseError::new(
+                    1,
+                    1,
+                    format!("Invalid layer name: '{}'", name),
+                ))
+            }
+        })
+        .collect()
+}
+
 impl Default for CssParser {
     fn default() -> Self {
         Self::new()
@@ -126,6 +285,26 @@ mod tests {
         assert_eq!(rules.len(), 2);
     }
 
+    #[test]
+    fn test_extract_rules_statement_at_rule() {
+        let parser = CssParser::new();
+        let css = "@layer base, theme; div { color: red; }";
+        let rules = parser.extract_rules(css).unwrap();
+        assert_eq!(
+            rules,
+            vec![(0, "@layer base, theme;"), (19, " div { color: red; }")]
+        );
+    }
+
+    #[test]
+    fn test_strip_at_keyword_requires_delimiter() {
+        assert_eq!(strip_at_keyword("@media screen", "@media"), Some(" screen"));
+        assert_eq!(strip_at_keyword("@media(color)", "@media"), Some("(color)"));
+        assert_eq!(strip_at_keyword("@media", "@media"), Some(""));
+        assert_eq!(strip_at_keyword("@mediafoo", "@media"), None);
+        assert_eq!(strip_at_keyword("@media-x", "@media"), None);
+    }
+
     #[test]
     fn test_extract_rules_nested_braces() {
         let parser = CssParser::new();
@@ -147,7 +147,7 @@ fn parse_compound_selector(input: &str) -> Result<Selector, ParseError> {
 }
 
 /// Check if a string is a valid CSS identifier
-fn is_valid_identifier(s: &str) -> bool {
+pub(crate) fn is_valid_identifier(s: &str) -> bool {
     !s.is_empty()
         && s.chars()
             .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
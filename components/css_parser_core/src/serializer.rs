@@ -0,0 +1,205 @@
+//! Serialization of parsed stylesheets back to CSS text
+//!
+//! Output re-parses to an equal stylesheet. Pretty output puts each
+//! declaration on its own line with two-space indentation; minified output
+//! drops all whitespace that is not needed to separate tokens.
+
+use crate::{
+    CssRule, ImportRule, LayerRule, MediaRule, PropertyDeclaration, PropertyValue, Selector,
+    StyleRule, Stylesheet,
+};
+use css_types::CssValue;
+
+impl Stylesheet {
+    /// Serialize the stylesheet to CSS text
+    ///
+    /// # Arguments
+    /// * `minify` - Omit optional whitespace
+    ///
+    /// # Examples
+    /// ```
+    /// use css_parser_core::CssParser;
+    ///
+    /// let sheet = CssParser::new().parse("h1 , .title { margin : 0 }").unwrap();
+    /// assert_eq!(sheet.to_css_string(true), "h1,.title{margin:0px}");
+    /// assert_eq!(sheet.to_css_string(false), "h1, .title {\n  margin: 0px;\n}\n");
+    /// ```
+    pub fn to_css_string(&self, minify: bool) -> String {
+        let mut output = String::new();
+        write_rules(&mut output, &self.rules, minify, 0);
+        output
+    }
+}
+
+impl Selector {
+    /// Serialize the selector to CSS text
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Selector::Element(name) => name.clone(),
+            Selector::Class(class) => format!(".{}", class),
+            Selector::Id(id) => format!("#{}", id),
+            Selector::Universal => "*".to_string(),
+            Selector::Compound {
+                element,
+                classes,
+                id,
+            } => {
+                let mut css = element.clone().unwrap_or_default();
+                if let Some(id) = id {
+                    css.push('#');
+                    css.push_str(id);
+                }
+                for class in classes {
+                    css.push('.');
+                    css.push_str(class);
+                }
+                css
+            }
+        }
+    }
+}
+
+impl PropertyValue {
+    /// Serialize the value to CSS text
+    pub fn to_css_string(&self) -> String {
+        match self {
+            PropertyValue::Color(color) => color.serialize(),
+            PropertyValue::Length(length) => length.serialize(),
+            PropertyValue::Keyword(keyword) => keyword.clone(),
+            PropertyValue::String(contents) => format!(
+                "\"{}\"",
+                contents.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+        }
+    }
+}
+
+impl PropertyDeclaration {
+    /// Serialize the declaration to CSS text, without a trailing semicolon
+    pub fn to_css_string(&self, minify: bool) -> String {
+        let separator = if minify { ":" } else { ": " };
+        let important = match (self.important, minify) {
+            (false, _) => "",
+            (true, true) => "!important",
+            (true, false) => " !important",
+        };
+        format!(
+            "{}{}{}{}",
+            self.name,
+            separator,
+            self.value.to_css_string(),
+            important
+        )
+    }
+}
+
+/// Write a list of rules at the given nesting depth
+fn write_rules(output: &mut String, rules: &[CssRule], minify: bool, depth: usize) {
+    for (index, rule) in rules.iter().enumerate() {
+        // Blank line between top-level blocks
+        let is_statement = matches!(
+            rule,
+            CssRule::Import(_) | CssRule::Layer(LayerRule { rules: None, .. })
+        );
+        if !minify && depth == 0 && index > 0 && !is_statement {
+            output.push('\n');
+        }
+        match rule {
+            CssRule::Style(style) => write_style_rule(output, style, minify, depth),
+            CssRule::Media(media) => write_media_rule(output, media, minify, depth),
+            CssRule::Import(import) => write_import_rule(output, import, minify, depth),
+            CssRule::Layer(layer) => write_layer_rule(output, layer, minify, depth),
+        }
+    }
+}
+
+fn write_style_rule(output: &mut String, rule: &StyleRule, minify: bool, depth: usize) {
+    let selectors: Vec<String> = rule.selectors.iter().map(Selector::to_css_string).collect();
+
+    if minify {
+        output.push_str(&selectors.join(","));
+        output.push('{');
+        let declarations: Vec<String> = rule
+            .declarations
+            .iter()
+            .map(|declaration| declaration.to_css_string(true))
+            .collect();
+        output.push_str(&declarations.join(";"));
+        output.push('}');
+        return;
+    }
+
+    let indent = "  ".repeat(depth);
+    output.push_str(&format!("{}{} {{\n", indent, selectors.join(", ")));
+    for declaration in &rule.declarations {
+        output.push_str(&format!(
+            "{}  {};\n",
+            indent,
+            declaration.to_css_string(false)
+        ));
+    }
+    output.push_str(&format!("{}}}\n", indent));
+}
+
+fn write_media_rule(output: &mut String, rule: &MediaRule, minify: bool, depth: usize) {
+    if minify {
+        output.push_str(&format!("@media {}{{", rule.media_queries.join(",")));
+        write_rules(output, &rule.rules, true, depth + 1);
+        output.push('}');
+        return;
+    }
+
+    let indent = "  ".repeat(depth);
+    output.push_str(&format!(
+        "{}@media {} {{\n",
+        indent,
+        rule.media_queries.join(", ")
+    ));
+    write_rules(output, &rule.rules, false, depth + 1);
+    output.push_str(&format!("{}}}\n", indent));
+}
+
+fn write_layer_rule(output: &mut String, rule: &LayerRule, minify: bool, depth: usize) {
+    let indent = if minify {
+        String::new()
+    } else {
+        "  ".repeat(depth)
+    };
+    let separator = if minify { "," } else { ", " };
+    let mut prelude = String::from("@layer");
+    if !rule.names.is_empty() {
+        prelude.push(' ');
+        prelude.push_str(&rule.names.join(separator));
+    }
+
+    match &rule.rules {
+        None if minify => output.push_str(&format!("{};", prelude)),
+        None => output.push_str(&format!("{}{};\n", indent, prelude)),
+        Some(rules) if minify => {
+            output.push_str(&format!("{}{{", prelude));
+            write_rules(output, rules, true, depth + 1);
+            output.push('}');
+        }
+        Some(rules) => {
+            output.push_str(&format!("{}{} {{\n", indent, prelude));
+            write_rules(output, rules, false, depth + 1);
+            output.push_str(&format!("{}}}\n", indent));
+        }
+    }
+}
+
+fn write_import_rule(output: &mut String, rule: &ImportRule, minify: bool, depth: usize) {
+    let separator = if minify { "," } else { ", " };
+    let mut css = format!("@import url(\"{}\")", rule.url);
+    if !rule.media_queries.is_empty() {
+        css.push(' ');
+        css.push_str(&rule.media_queries.join(separator));
+    }
+    css.push(';');
+
+    if minify {
+        output.push_str(&css);
+    } else {
+        output.push_str(&format!("{}{}\n", "  ".repeat(depth), css));
+    }
+}
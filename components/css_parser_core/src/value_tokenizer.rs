@@ -0,0 +1,227 @@
+//! Tokenizer for space-separated property values
+//!
+//! Shorthands such as `transition`, `transform` and `background` are lists of
+//! components separated by whitespace, where a component may be a function
+//! call whose arguments contain spaces of their own.
+
+use css_types::parse_finite_f32;
+
+/// A single component of a property value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// Function call such as `rotate(45deg)`
+    ///
+    /// `arguments` is the raw text between the outer parentheses. A bare
+    /// parenthesized group has an empty name.
+    Function { name: String, arguments: String },
+    /// Identifier or keyword such as `ease-in` or `opacity`
+    Ident(String),
+    /// Unitless number
+    Number(f32),
+    /// Number with a unit, including percentages (`10px`, `0.3s`, `50%`)
+    Dimension { value: f32, unit: String },
+    /// Quoted string, without its quotes
+    String(String),
+    /// Single-character delimiter such as `,` or `/`
+    Delimiter(char),
+}
+
+impl Token {
+    /// Serialize the token back to CSS text
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Token::Function { name, arguments } => format!("{}({})", name, arguments),
+            Token::Ident(ident) => ident.clone(),
+            Token::Number(value) => value.to_string(),
+            Token::Dimension { value, unit } => format!("{}{}", value, unit),
+            Token::String(contents) => {
+                format!(
+                    "\"{}\"",
+                    contents.replace('\\', "\\\\").replace('"', "\\\"")
+                )
+            }
+            Token::Delimiter(delimiter) => delimiter.to_string(),
+        }
+    }
+
+    /// Check whether the token is a function call with the given name
+    pub fn is_function(&self, function_name: &str) -> bool {
+        matches!(self, Token::Function { name, .. } if name == function_name)
+    }
+}
+
+/// Split a property value into classified tokens
+///
+/// Tokens are separated by whitespace outside of parentheses and quotes.
+/// Top-level `,` and `/` are emitted as delimiter tokens even without
+/// surrounding whitespace. Numbers that do not fit in a finite `f32` are
+/// left as identifiers so value parsers reject them.
+///
+/// # Examples
+/// ```
+/// use css_parser_core::{tokenize_value, Token};
+///
+/// let tokens = tokenize_value("translate(10px, 20px) rotate(45deg)");
+/// assert_eq!(
+///     tokens,
+///     vec![
+///         Token::Function { name: "translate".to_string(), arguments: "10px, 20px".to_string() },
+///         Token::Function { name: "rotate".to_string(), arguments: "45deg".to_string() },
+///     ]
+/// );
+/// ```
+pub fn tokenize_value(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, ch) in input.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' | '\'' => {
+                quote = Some(ch);
+                start.get_or_insert(i);
+            }
+            '(' => {
+                depth += 1;
+                start.get_or_insert(i);
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                start.get_or_insert(i);
+            }
+            _ if depth > 0 => {}
+            _ if ch.is_whitespace() => {
+                if let Some(s) = start.take() {
+                    tokens.push(classify_token(&input[s..i]));
+                }
+            }
+            ',' | '/' => {
+                if let Some(s) = start.take() {
+                    tokens.push(classify_token(&input[s..i]));
+                }
+                tokens.push(Token::Delimiter(ch));
+            }
+            _ => {
+                start.get_or_insert(i);
+            }
+        }
+    }
+
+    if let Some(s) = start {
+        tokens.push(classify_token(&input[s..]));
+    }
+
+    tokens
+}
+
+/// Classify the raw text of a single token
+fn classify_token(raw: &str) -> Token {
+    if let Some(q) = raw.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let inner = &raw[1..];
+        let inner = inner.strip_suffix(q).unwrap_or(inner);
+        return Token::String(unescape(inner));
+    }
+
+    if let Some(paren) = raw.find('(') {
+        let arguments = &raw[paren + 1..];
+        let arguments = arguments.strip_suffix(')').unwrap_or(arguments);
+        return Token::Function {
+            name: raw[..paren].to_string(),
+            arguments: arguments.to_string(),
+        };
+    }
+
+    if starts_number(raw) {
+        let number_end = number_prefix_len(raw);
+        if let Ok(value) = parse_finite_f32(&raw[..number_end]) {
+            let unit = &raw[number_end..];
+            return if unit.is_empty() {
+                Token::Number(value)
+            } else {
+                Token::Dimension {
+                    value,
+                    unit: unit.to_string(),
+                }
+            };
+        }
+        return Token::Ident(raw.to_string());
+    }
+
+    let mut chars = raw.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        if !ch.is_alphanumeric() && ch != '_' {
+            return Token::Delimiter(ch);
+        }
+    }
+
+    Token::Ident(raw.to_string())
+}
+
+/// Check whether text starts with a number (`1`, `-2`, `.5`, `+.5`)
+fn starts_number(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let rest = match bytes.first() {
+        Some(b'+' | b'-') => &bytes[1..],
+        _ => bytes,
+    };
+    matches!(rest, [b'0'..=b'9', ..] | [b'.', b'0'..=b'9', ..])
+}
+
+/// Length in bytes of the numeric prefix, including an optional exponent
+fn number_prefix_len(raw: &str) -> usize {
+    let bytes = raw.as_bytes();
+    let mut end = 0;
+
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        end += 1;
+    }
+    while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+        end += 1;
+    }
+
+    // An exponent needs at least one digit, so `1em` keeps its `em` unit
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let mut exponent_end = end + 1;
+        if matches!(bytes.get(exponent_end), Some(b'+' | b'-')) {
+            exponent_end += 1;
+        }
+        if bytes.get(exponent_end).is_some_and(u8::is_ascii_digit) {
+            end = exponent_end;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+    }
+
+    end
+}
+
+/// Remove backslash escapes from quoted string contents
+fn unescape(contents: &str) -> String {
+    let mut result = String::with_capacity(contents.len());
+    let mut escaped = false;
+    for ch in contents.chars() {
+        if escaped {
+            result.push(ch);
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
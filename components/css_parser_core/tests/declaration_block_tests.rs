@@ -0,0 +1,172 @@
+//! Tests for shorthand-aware declaration blocks
+
+use css_parser_core::{
+    expand_shorthand, parse_inset, DeclarationBlock, Length, PropertyDeclaration, PropertyValue,
+};
+use css_types::LengthUnit;
+
+fn px(value: f32) -> PropertyValue {
+    PropertyValue::Length(Length::new(value, LengthUnit::Px))
+}
+
+fn value<'a>(block: &'a DeclarationBlock, name: &str) -> Option<&'a PropertyValue> {
+    block.get(name).map(|declaration| &declaration.value)
+}
+
+#[test]
+fn test_longhand_after_shorthand_wins() {
+    let block = DeclarationBlock::parse("margin: 0; margin-top: 10px").unwrap();
+
+    assert_eq!(value(&block, "margin-top"), Some(&px(10.0)));
+    assert_eq!(value(&block, "margin-left"), Some(&px(0.0)));
+    assert_eq!(block.len(), 4);
+}
+
+#[test]
+fn test_shorthand_after_longhand_wins() {
+    let block = DeclarationBlock::parse("margin-top: 10px; margin: 0").unwrap();
+
+    assert_eq!(value(&block, "margin-top"), Some(&px(0.0)));
+    assert_eq!(block.len(), 4);
+}
+
+#[test]
+fn test_shorthand_component_fill_rules() {
+    let block = DeclarationBlock::parse("padding: 1px 2px 3px").unwrap();
+    let map = block.to_longhand_map();
+
+    assert_eq!(map.get("padding-top"), Some(&px(1.0)));
+    assert_eq!(map.get("padding-right"), Some(&px(2.0)));
+    assert_eq!(map.get("padding-bottom"), Some(&px(3.0)));
+    assert_eq!(map.get("padding-left"), Some(&px(2.0)));
+    assert!(!map.contains_key("padding"));
+
+    let block = DeclarationBlock::parse("margin: 1px 2px").unwrap();
+    assert_eq!(value(&block, "margin-bottom"), Some(&px(1.0)));
+    assert_eq!(value(&block, "margin-left"), Some(&px(2.0)));
+}
+
+#[test]
+fn test_important_longhand_survives_later_shorthand() {
+    let block = DeclarationBlock::parse("margin-top: 10px !important; margin: 0").unwrap();
+
+    assert_eq!(value(&block, "margin-top"), Some(&px(10.0)));
+    assert!(block.get("margin-top").unwrap().important);
+    assert_eq!(value(&block, "margin-right"), Some(&px(0.0)));
+}
+
+#[test]
+fn test_important_shorthand_survives_later_longhand() {
+    let block = DeclarationBlock::parse("margin: 0 !important; margin-top: 10px").unwrap();
+
+    assert_eq!(value(&block, "margin-top"), Some(&px(0.0)));
+
+    let block =
+        DeclarationBlock::parse("margin: 0 !important; margin-top: 10px !important").unwrap();
+    assert_eq!(value(&block, "margin-top"), Some(&px(10.0)));
+}
+
+#[test]
+fn test_important_shorthand_expands_to_important_longhands() {
+    let block = DeclarationBlock::parse("margin: 0 !important").unwrap();
+    let margin = block.declarations();
+
+    assert_eq!(margin.len(), 4);
+    for longhand in margin {
+        assert!(longhand.important, "{} is not important", longhand.name);
+        assert_eq!(longhand.value, px(0.0));
+    }
+
+    let longhands = expand_shorthand(&margin[0]).unwrap();
+    assert_eq!(longhands, vec![margin[0].clone()]);
+}
+
+#[test]
+fn test_important_longhands_beat_later_normal_longhand() {
+    let mut block = DeclarationBlock::parse("margin: 0 !important").unwrap();
+    // A later rule's non-important declaration cascades into the same block
+    block.insert(PropertyDeclaration {
+        name: "margin-top".to_string(),
+        value: px(10.0),
+        important: false,
+        span: None,
+    });
+
+    assert_eq!(value(&block, "margin-top"), Some(&px(0.0)));
+    assert!(block.get("margin-top").unwrap().important);
+    assert_eq!(block.len(), 4);
+}
+
+#[test]
+fn test_redeclared_property_moves_to_end() {
+    let block = DeclarationBlock::parse("color: red; width: 10px; color: blue").unwrap();
+    let names: Vec<&str> = block
+        .declarations()
+        .iter()
+        .map(|declaration| declaration.name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["width", "color"]);
+}
+
+#[test]
+fn test_invalid_shorthand_is_dropped() {
+    let mut block = DeclarationBlock::new();
+    block.insert(PropertyDeclaration {
+        name: "margin".to_string(),
+        value: PropertyValue::Keyword("1px 2px 3px 4px 5px".to_string()),
+        important: false,
+        span: None,
+    });
+
+    assert!(block.is_empty());
+}
+
+#[test]
+fn test_parse_inset_one_value() {
+    let inset = parse_inset("10px").unwrap();
+
+    assert_eq!(inset.top, px(10.0));
+    assert_eq!(inset.right, px(10.0));
+    assert_eq!(inset.bottom, px(10.0));
+    assert_eq!(inset.left, px(10.0));
+}
+
+#[test]
+fn test_parse_inset_two_values() {
+    let inset = parse_inset("10px 20px").unwrap();
+
+    assert_eq!(inset.top, px(10.0));
+    assert_eq!(inset.right, px(20.0));
+    assert_eq!(inset.bottom, px(10.0));
+    assert_eq!(inset.left, px(20.0));
+}
+
+#[test]
+fn test_parse_inset_four_values() {
+    let inset = parse_inset("1px auto 3px 50%").unwrap();
+
+    assert_eq!(inset.top, px(1.0));
+    assert_eq!(inset.right, PropertyValue::Keyword("auto".to_string()));
+    assert_eq!(inset.bottom, px(3.0));
+    assert_eq!(
+        inset.left,
+        PropertyValue::Length(Length::new(50.0, LengthUnit::Percent))
+    );
+}
+
+#[test]
+fn test_parse_inset_rejects_wrong_value_count() {
+    assert!(parse_inset("").is_err());
+    assert!(parse_inset("1px 2px 3px 4px 5px").is_err());
+}
+
+#[test]
+fn test_inset_shorthand_expands_in_block() {
+    let block = DeclarationBlock::parse("top: 5px; inset: 0 10px").unwrap();
+
+    assert_eq!(value(&block, "top"), Some(&px(0.0)));
+    assert_eq!(value(&block, "right"), Some(&px(10.0)));
+    assert_eq!(value(&block, "left"), Some(&px(10.0)));
+    assert_eq!(block.len(), 4);
+}
@@ -1,6 +1,6 @@
 //! Integration tests for CSS parser
 
-use css_parser_core::{CssParser, CssRule, Origin};
+use css_parser_core::{CssParser, CssRule, ImportRule, Origin, Stylesheet};
 
 #[test]
 fn test_parse_empty_stylesheet() {
@@ -89,3 +89,232 @@ fn test_parse_single_rule() {
         _ => panic!("Expected StyleRule"),
     }
 }
+
+#[test]
+fn test_parse_media_rule_with_query_list() {
+    let parser = CssParser::new();
+    let css = r#"
+        div { color: red; }
+        @media screen, (min-width: 600px) {
+            .wide { margin: 10px; }
+            p { color: blue; }
+        }
+    "#;
+    let stylesheet = parser.parse(css).unwrap();
+    assert_eq!(stylesheet.rules.len(), 2);
+
+    match &stylesheet.rules[1] {
+        CssRule::Media(rule) => {
+            assert_eq!(
+                rule.media_queries,
+                vec!["screen".to_string(), "(min-width: 600px)".to_string()]
+            );
+            assert_eq!(rule.rules.len(), 2);
+            assert!(matches!(rule.rules[0], CssRule::Style(_)));
+        }
+        _ => panic!("Expected MediaRule"),
+    }
+}
+
+#[test]
+fn test_parse_empty_media_rule() {
+    let parser = CssParser::new();
+    let stylesheet = parser.parse("@media print { }").unwrap();
+
+    match &stylesheet.rules[0] {
+        CssRule::Media(rule) => {
+            assert_eq!(rule.media_queries, vec!["print".to_string()]);
+            assert!(rule.rules.is_empty());
+        }
+        _ => panic!("Expected MediaRule"),
+    }
+}
+
+#[test]
+fn test_media_keyword_requires_delimiter() {
+    let parser = CssParser::new();
+    assert!(parser.parse("@mediafoo { p { color: red; } }").is_err());
+
+    let stylesheet = parser
+        .parse("@media(min-width: 1px) { p { color: red; } }")
+        .unwrap();
+    assert!(matches!(stylesheet.rules[0], CssRule::Media(_)));
+}
+
+#[test]
+fn test_stylesheet_serialization_roundtrip() {
+    let parser = CssParser::new();
+    let css = "h1, .title { color: red; margin: 10px !important; }\n\
+               @media screen, print { div#main.wide { font-size: 2em; display: none } }";
+    let stylesheet = parser.parse(css).unwrap();
+
+    let pretty = stylesheet.to_css_string(false);
+    assert_eq!(
+        pretty,
+        "h1, .title {\n  color: rgb(255, 0, 0);\n  margin: 10px !important;\n}\n\n\
+         @media screen, print {\n  div#main.wide {\n    font-size: 2em;\n    display: none;\n  }\n}\n"
+    );
+    assert_eq!(parser.parse(&pretty).unwrap(), stylesheet);
+
+    let minified = stylesheet.to_css_string(true);
+    assert_eq!(
+        minified,
+        "h1,.title{color:rgb(255, 0, 0);margin:10px!important}\
+         @media screen,print{div#main.wide{font-size:2em;display:none}}"
+    );
+    assert_eq!(parser.parse(&minified).unwrap(), stylesheet);
+}
+
+#[test]
+fn test_serialize_import_rule() {
+    let mut stylesheet = Stylesheet::author();
+    stylesheet.rules.push(CssRule::Import(ImportRule {
+        url: "print.css".to_string(),
+        media_queries: vec!["print".to_string(), "tv".to_string()],
+    }));
+
+    assert_eq!(
+        stylesheet.to_css_string(false),
+        "@import url(\"print.css\") print, tv;\n"
+    );
+    assert_eq!(
+        stylesheet.to_css_string(true),
+        "@import url(\"print.css\") print,tv;"
+    );
+}
+
+#[test]
+fn test_parse_layer_statement_and_blocks() {
+    let parser = CssParser::new();
+    let css = r#"
+        @layer base, theme;
+        @layer theme { .button { color: red; } }
+        @layer { p { margin: 0; } }
+    "#;
+    let stylesheet = parser.parse(css).unwrap();
+    assert_eq!(stylesheet.rules.len(), 3);
+
+    match &stylesheet.rules[0] {
+        CssRule::Layer(rule) => {
+            assert_eq!(rule.names, vec!["base".to_string(), "theme".to_string()]);
+            assert!(rule.rules.is_none());
+        }
+        _ => panic!("Expected LayerRule"),
+    }
+    match &stylesheet.rules[1] {
+        CssRule::Layer(rule) => {
+            assert_eq!(rule.names, vec!["theme".to_string()]);
+            let rules = rule.rules.as_ref().unwrap();
+            assert_eq!(rules.len(), 1);
+            assert!(matches!(rules[0], CssRule::Style(_)));
+        }
+        _ => panic!("Expected LayerRule"),
+    }
+    match &stylesheet.rules[2] {
+        CssRule::Layer(rule) => {
+            assert!(rule.names.is_empty());
+            assert_eq!(rule.rules.as_ref().unwrap().len(), 1);
+        }
+        _ => panic!("Expected LayerRule"),
+    }
+}
+
+#[test]
+fn test_parse_invalid_layer_rules() {
+    let parser = CssParser::new();
+    assert!(parser.parse("@layer;").is_err());
+    assert!(parser.parse("@layer a, b { p { margin: 0; } }").is_err());
+
+    // The keyword must end at a delimiter
+    assert!(parser.parse("@layerfoo;").is_err());
+    assert!(parser.parse("@layerfoo { p { margin: 0; } }").is_err());
+
+    // Layer names are identifiers, optionally dotted
+    assert!(parser.parse("@layer a, , b;").is_err());
+    assert!(parser.parse("@layer a b;").is_err());
+    assert!(parser.parse("@layer \"a\" { p { margin: 0; } }").is_err());
+    assert!(parser.parse("@layer theme..dark;").is_err());
+    assert!(parser.parse("@layer theme.dark, base;").is_ok());
+}
+
+#[test]
+fn test_layer_serialization_roundtrip() {
+    let parser = CssParser::new();
+    let css = "@layer base, theme; @layer theme { p { margin: 0 } }";
+    let stylesheet = parser.parse(css).unwrap();
+
+    let pretty = stylesheet.to_css_string(false);
+    assert_eq!(
+        pretty,
+        "@layer base, theme;\n\n@layer theme {\n  p {\n    margin: 0px;\n  }\n}\n"
+    );
+    assert_eq!(parser.parse(&pretty).unwrap(), stylesheet);
+
+    let minified = stylesheet.to_css_string(true);
+    assert_eq!(minified, "@layer base,theme;@layer theme{p{margin:0px}}");
+    assert_eq!(parser.parse(&minified).unwrap(), stylesheet);
+}
+
+fn style_rule(rule: &CssRule) -> &css_parser_core::StyleRule {
+    match rule {
+        CssRule::Style(style) => style,
+        _ => panic!("Expected StyleRule"),
+    }
+}
+
+#[test]
+fn test_spans_slice_back_to_source() {
+    let css = "  h1 { color: red; margin: 10px !important; }\n.title{ display: none }";
+    let stylesheet = CssParser::new().with_spans(true).parse(css).unwrap();
+    assert_eq!(stylesheet.rules.len(), 2);
+
+    let first = style_rule(&stylesheet.rules[0]);
+    assert_eq!(
+        first.span.unwrap().slice(css),
+        Some("h1 { color: red; margin: 10px !important; }")
+    );
+    let declarations: Vec<_> = first
+        .declarations
+        .iter()
+        .map(|declaration| declaration.span.unwrap().slice(css).unwrap())
+        .collect();
+    assert_eq!(declarations, vec!["color: red", "margin: 10px !important"]);
+
+    let second = style_rule(&stylesheet.rules[1]);
+    assert_eq!(
+        second.span.unwrap().slice(css),
+        Some(".title{ display: none }")
+    );
+    assert_eq!(
+        second.declarations[0].span.unwrap().slice(css),
+        Some("display: none")
+    );
+}
+
+#[test]
+fn test_spans_inside_at_rule_blocks() {
+    let css = "@media screen { @layer base { p { color: blue } } }";
+    let stylesheet = CssParser::new().with_spans(true).parse(css).unwrap();
+
+    let CssRule::Media(media) = &stylesheet.rules[0] else {
+        panic!("Expected MediaRule");
+    };
+    let CssRule::Layer(layer) = &media.rules[0] else {
+        panic!("Expected LayerRule");
+    };
+    let rule = style_rule(&layer.rules.as_ref().unwrap()[0]);
+    assert_eq!(rule.span.unwrap().slice(css), Some("p { color: blue }"));
+    assert_eq!(
+        rule.declarations[0].span.unwrap().slice(css),
+        Some("color: blue")
+    );
+}
+
+#[test]
+fn test_spans_are_off_by_default() {
+    let stylesheet = CssParser::new().parse("p { color: red }").unwrap();
+
+    let rule = style_rule(&stylesheet.rules[0]);
+    assert!(rule.span.is_none());
+    assert!(rule.declarations[0].span.is_none());
+}
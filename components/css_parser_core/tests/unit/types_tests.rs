@@ -34,6 +34,7 @@ fn test_stylesheet_with_rules() {
     let rule = CssRule::Style(StyleRule {
         selectors: vec![],
         declarations: vec![],
+        span: None,
     });
 
     let stylesheet = Stylesheet {
@@ -50,6 +51,7 @@ fn test_style_rule_creation() {
     let rule = StyleRule {
         selectors: vec![],
         declarations: vec![],
+        span: None,
     };
 
     assert_eq!(rule.selectors.len(), 0);
@@ -0,0 +1,112 @@
+//! Tests for the space-separated value tokenizer
+
+use css_parser_core::{tokenize_value, Token};
+
+fn function(name: &str, arguments: &str) -> Token {
+    Token::Function {
+        name: name.to_string(),
+        arguments: arguments.to_string(),
+    }
+}
+
+fn dimension(value: f32, unit: &str) -> Token {
+    Token::Dimension {
+        value,
+        unit: unit.to_string(),
+    }
+}
+
+#[test]
+fn test_tokenize_transform_list() {
+    let tokens = tokenize_value("translate(10px, 20px) rotate(45deg)");
+    assert_eq!(
+        tokens,
+        vec![
+            function("translate", "10px, 20px"),
+            function("rotate", "45deg")
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_nested_functions() {
+    let tokens = tokenize_value("calc(100% - (2 * 10px))  auto");
+    assert_eq!(
+        tokens,
+        vec![
+            function("calc", "100% - (2 * 10px)"),
+            Token::Ident("auto".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_classifies_tokens() {
+    let tokens = tokenize_value("opacity 0.3s ease-in 2 -1.5em 50% 1e2px");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Ident("opacity".to_string()),
+            dimension(0.3, "s"),
+            Token::Ident("ease-in".to_string()),
+            Token::Number(2.0),
+            dimension(-1.5, "em"),
+            dimension(50.0, "%"),
+            dimension(100.0, "px"),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_strings_keep_spaces() {
+    let tokens = tokenize_value(r#""Times New Roman", 'it\'s' serif"#);
+    assert_eq!(
+        tokens,
+        vec![
+            Token::String("Times New Roman".to_string()),
+            Token::Delimiter(','),
+            Token::String("it's".to_string()),
+            Token::Ident("serif".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_delimiters() {
+    let tokens = tokenize_value("center/cover, red + 1");
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Ident("center".to_string()),
+            Token::Delimiter('/'),
+            Token::Ident("cover".to_string()),
+            Token::Delimiter(','),
+            Token::Ident("red".to_string()),
+            Token::Delimiter('+'),
+            Token::Number(1.0),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenize_non_finite_number_is_ident() {
+    assert_eq!(
+        tokenize_value("1e40px"),
+        vec![Token::Ident("1e40px".to_string())]
+    );
+}
+
+#[test]
+fn test_tokenize_empty_and_whitespace() {
+    assert!(tokenize_value("").is_empty());
+    assert!(tokenize_value("   \t\n").is_empty());
+}
+
+#[test]
+fn test_token_to_css_string() {
+    let tokens = tokenize_value("translate(10px, 20px) 0.5s \"a b\" /");
+    let css: Vec<String> = tokens.iter().map(Token::to_css_string).collect();
+    assert_eq!(css, vec!["translate(10px, 20px)", "0.5s", "\"a b\"", "/"]);
+    assert!(tokens[0].is_function("translate"));
+    assert!(!tokens[0].is_function("rotate"));
+}
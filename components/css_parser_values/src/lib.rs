@@ -3,10 +3,18 @@
 //! This module provides advanced CSS value parsing including:
 //! - Attribute selectors ([attr], [attr=value], etc.)
 //! - Complex color values (hex, rgb, rgba, hsl, hsla, named colors)
+//! - Border-like shorthands (width, style and color in any order)
 //! - CSS functions (url(), calc(), var(), gradients)
 //! - Generic value parsing (numbers, strings, lengths, keywords)
+//! - Opacity values (numbers and percentages, clamped)
+//! - Aspect ratios (`auto`, `<ratio>`, or both)
+//! - Shorthands with comma-separated layers (background, box-shadow,
+//!   text-shadow)
 
-use css_types::{Color, CssError, CssValue, Length};
+use css_types::{
+    parse_finite_f32, split_function, BorderStyle, Color, ColorValue, CssError, CssValue, Length,
+    LengthUnit,
+};
 
 // ============================================================================
 // Attribute Selector Types
@@ -261,6 +269,137 @@ pub fn parse_color_value(input: &str) -> Result<Color, CssError> {
     parse_named_color(input)
 }
 
+/// Parse a CSS color value that may be the `currentColor` keyword
+///
+/// `currentColor` is matched case-insensitively and kept as
+/// [`ColorValue::CurrentColor`] so it can be resolved against the element's
+/// computed `color` after the cascade. Anything else is parsed with
+/// [`parse_color_value`].
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_color_value_or_current;
+/// use css_types::{Color, ColorValue};
+///
+/// assert_eq!(parse_color_value_or_current("currentColor").unwrap(), ColorValue::CurrentColor);
+/// assert_eq!(
+///     parse_color_value_or_current("red").unwrap(),
+///     ColorValue::Rgba(Color::rgb(255, 0, 0))
+/// );
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is not a valid color.
+pub fn parse_color_value_or_current(input: &str) -> Result<ColorValue, CssError> {
+    ColorValue::parse_with(input, parse_color_value)
+}
+
+/// Parse a border-like shorthand (`border`, `border-top`, `column-rule`, ...)
+///
+/// The width, style, and color may appear in any order, each at most once.
+/// Missing components default to `medium` (3px), `none`, and `currentColor`.
+/// The color is returned unresolved so the caller can substitute the
+/// element's computed `color`.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_border;
+/// use css_types::{BorderStyle, Color, ColorValue};
+///
+/// let (width, style, color) = parse_border("red dashed 2px").unwrap();
+/// assert_eq!(width.value(), 2.0);
+/// assert_eq!(style, BorderStyle::Dashed);
+/// assert_eq!(color, ColorValue::Rgba(Color::rgb(255, 0, 0)));
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is empty, a token is not a
+/// width, style, or color, or a component is given twice.
+pub fn parse_border(input: &str) -> Result<(Length, BorderStyle, ColorValue), CssError> {
+    let tokens = split_border_tokens(input);
+
+    if tokens.is_empty() {
+        return Err(CssError::ParseError("Empty border value".to_string()));
+    }
+
+    let mut width = None;
+    let mut style = None;
+    let mut color = None;
+
+    for token in tokens {
+        if let Some(parsed) = parse_border_width(token) {
+            if width.replace(parsed).is_some() {
+                return Err(CssError::ParseError(format!(
+                    "Duplicate border width: {}",
+                    token
+                )));
+            }
+        } else if let Ok(parsed) = BorderStyle::parse(token) {
+            if style.replace(parsed).is_some() {
+                return Err(CssError::ParseError(format!(
+                    "Duplicate border style: {}",
+                    token
+                )));
+            }
+        } else if let Ok(parsed) = parse_color_value_or_current(token) {
+            if color.replace(parsed).is_some() {
+                return Err(CssError::ParseError(format!(
+                    "Duplicate border color: {}",
+                    token
+                )));
+            }
+        } else {
+            return Err(CssError::ParseError(format!(
+                "Invalid border component: {}",
+                token
+            )));
+        }
+    }
+
+    Ok((
+        width.unwrap_or_else(|| Length::new(3.0, LengthUnit::Px)),
+        style.unwrap_or(BorderStyle::None),
+        color.unwrap_or(ColorValue::CurrentColor),
+    ))
+}
+
+/// Parse a border width keyword or length
+fn parse_border_width(token: &str) -> Option<Length> {
+    match token.to_lowercase().as_str() {
+        "thin" => Some(Length::new(1.0, LengthUnit::Px)),
+        "medium" => Some(Length::new(3.0, LengthUnit::Px)),
+        "thick" => Some(Length::new(5.0, LengthUnit::Px)),
+        _ => Length::parse(token).ok(),
+    }
+}
+
+/// Split on whitespace outside of parentheses so `rgb(0, 0, 0)` stays whole
+fn split_border_tokens(input: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut start = None;
+
+    for (i, ch) in input.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    tokens.push(&input[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        tokens.push(&input[s..]);
+    }
+
+    tokens
+}
+
 /// Parse HSL/HSLA color
 fn parse_hsl(input: &str) -> Result<Color, CssError> {
     let input = input.trim();
@@ -287,9 +426,7 @@ fn parse_hsl(input: &str) -> Result<Color, CssError> {
         let h = parse_hue(parts[0])?;
         let s = parse_percentage(parts[1])?;
         let l = parse_percentage(parts[2])?;
-        let a = parts[3]
-            .parse::<f32>()
-            .map_err(|_| CssError::ParseError("Invalid alpha value".to_string()))?;
+        let a = parse_finite_f32(parts[3])?;
 
         if !(0.0..=1.0).contains(&a) {
             return Err(CssError::OutOfRange(
@@ -316,35 +453,51 @@ fn parse_hsl(input: &str) -> Result<Color, CssError> {
 /// Parse hue value (0-360)
 fn parse_hue(s: &str) -> Result<f32, CssError> {
     let s = s.trim();
-    let value = s
-        .parse::<f32>()
-        .map_err(|_| CssError::ParseError("Invalid hue value".to_string()))?;
+    let value = parse_finite_f32(s)?;
 
     // Normalize to 0-360 range
     Ok(value % 360.0)
 }
 
-/// Parse percentage value (0-100%)
+/// Parse a percentage value (0-100%) for HSL color components
+///
+/// Out-of-range values are rejected rather than clamped.
 fn parse_percentage(s: &str) -> Result<f32, CssError> {
-    let s = s.trim();
-    if !s.ends_with('%') {
-        return Err(CssError::ParseError(
-            "Expected percentage value".to_string(),
-        ));
-    }
+    let value = parse_percentage_raw(s)?;
 
-    let value_str = &s[..s.len() - 1];
-    let value = value_str
-        .parse::<f32>()
-        .map_err(|_| CssError::ParseError("Invalid percentage".to_string()))?;
-
-    if !(0.0..=100.0).contains(&value) {
+    if !(0.0..=1.0).contains(&value) {
         return Err(CssError::OutOfRange(
             "Percentage must be 0-100%".to_string(),
         ));
     }
 
-    Ok(value / 100.0)
+    Ok(value)
+}
+
+/// Parse a percentage into an unrestricted fraction
+///
+/// Accepts an optional sign, decimals and exponents; the result is not
+/// range-checked, so contexts that allow negative or over-100% values can
+/// apply their own limits.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_percentage_raw;
+///
+/// assert_eq!(parse_percentage_raw("50%").unwrap(), 0.5);
+/// assert_eq!(parse_percentage_raw("-50%").unwrap(), -0.5);
+/// assert_eq!(parse_percentage_raw("150%").unwrap(), 1.5);
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is not a number followed by
+/// `%`, and `CssError::InvalidValue` if the number is not finite.
+pub fn parse_percentage_raw(input: &str) -> Result<f32, CssError> {
+    let value_str = input.trim().strip_suffix('%').ok_or_else(|| {
+        CssError::ParseError("Expected percentage value".to_string())
+    })?;
+
+    Ok(parse_finite_f32(value_str)? / 100.0)
 }
 
 /// Convert HSL to RGB
@@ -459,32 +612,11 @@ impl FunctionValue {
 /// ```
 ///
 /// # Errors
-/// Returns `CssError::ParseError` if the input is not a valid function.
+/// Returns `CssError::ParseError` if the input is not a valid function,
+/// including when the function name contains whitespace (`cal c(1px)`).
 pub fn parse_function_value(input: &str) -> Result<FunctionValue, CssError> {
-    let input = input.trim();
-
-    // Find the opening parenthesis
-    let paren_pos = input
-        .find('(')
-        .ok_or_else(|| CssError::ParseError("Function must have parentheses".to_string()))?;
-
-    let name = input[..paren_pos].trim().to_string();
-
-    if name.is_empty() {
-        return Err(CssError::ParseError(
-            "Function name cannot be empty".to_string(),
-        ));
-    }
-
-    // Check for closing parenthesis
-    if !input.ends_with(')') {
-        return Err(CssError::ParseError(
-            "Missing closing parenthesis".to_string(),
-        ));
-    }
-
-    // Extract arguments
-    let args_str = &input[paren_pos + 1..input.len() - 1].trim();
+    let (name, args_str) = split_function(input)?;
+    let name = name.to_string();
 
     // Parse arguments
     let args = if args_str.is_empty() {
@@ -606,7 +738,7 @@ pub fn parse_value(input: &str, _property: &str) -> Result<ComplexValue, CssErro
     }
 
     // Check for percentage
-    if input.ends_with('%') {
+    if parse_percentage_raw(input).is_ok() {
         return Ok(ComplexValue::new(ValueKind::Percentage, input.to_string()));
     }
 
@@ -616,7 +748,7 @@ pub fn parse_value(input: &str, _property: &str) -> Result<ComplexValue, CssErro
     }
 
     // Check for number
-    if input.parse::<f32>().is_ok() {
+    if parse_finite_f32(input).is_ok() {
         return Ok(ComplexValue::new(ValueKind::Number, input.to_string()));
     }
 
@@ -624,6 +756,879 @@ pub fn parse_value(input: &str, _property: &str) -> Result<ComplexValue, CssErro
     Ok(ComplexValue::new(ValueKind::Keyword, input.to_string()))
 }
 
+// ============================================================================
+// Opacity
+// ============================================================================
+
+/// Parse an `opacity` value
+///
+/// Accepts a number or a percentage. Out-of-range values are valid and are
+/// clamped to `0.0..=1.0`, giving the value stored in
+/// `ComputedValues::opacity`.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_opacity;
+///
+/// assert_eq!(parse_opacity("0.5").unwrap(), 0.5);
+/// assert_eq!(parse_opacity("50%").unwrap(), 0.5);
+/// assert_eq!(parse_opacity("1.5").unwrap(), 1.0);
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input is not a number or
+/// percentage, and `CssError::InvalidValue` if the number is not finite.
+pub fn parse_opacity(input: &str) -> Result<f32, CssError> {
+    let input = input.trim();
+
+    let value = if input.ends_with('%') {
+        parse_percentage_raw(input)?
+    } else {
+        parse_finite_f32(input)?
+    };
+
+    Ok(value.clamp(0.0, 1.0))
+}
+
+// ============================================================================
+// Aspect Ratio
+// ============================================================================
+
+/// Value of the `aspect-ratio` property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AspectRatio {
+    auto: bool,
+    ratio: Option<(f32, f32)>,
+}
+
+impl AspectRatio {
+    /// Create an aspect ratio
+    ///
+    /// # Arguments
+    /// * `auto` - Whether `auto` was given (replaced elements prefer their
+    ///   natural ratio)
+    /// * `ratio` - Preferred ratio as `(width, height)`, if given
+    pub fn new(auto: bool, ratio: Option<(f32, f32)>) -> Self {
+        Self { auto, ratio }
+    }
+
+    /// Check whether the `auto` keyword was given
+    pub fn is_auto(&self) -> bool {
+        self.auto
+    }
+
+    /// Get the preferred ratio as written, as `(width, height)`
+    pub fn ratio_parts(&self) -> Option<(f32, f32)> {
+        self.ratio
+    }
+
+    /// Get the preferred width-to-height ratio
+    ///
+    /// Returns `None` when no ratio was given or it is degenerate (either
+    /// part is zero), in which case the box behaves as if only `auto` was
+    /// specified.
+    pub fn ratio(&self) -> Option<f32> {
+        self.ratio
+            .filter(|(width, height)| *width > 0.0 && *height > 0.0)
+            .map(|(width, height)| width / height)
+    }
+}
+
+/// Parse an `aspect-ratio` value
+///
+/// Accepts `auto`, a ratio written as `<width> / <height>` or a single
+/// number (meaning `<number> / 1`), or `auto` together with a ratio in
+/// either order.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_aspect_ratio;
+///
+/// let ratio = parse_aspect_ratio("16 / 9").unwrap();
+/// assert_eq!(ratio.ratio_parts(), Some((16.0, 9.0)));
+/// assert!(!ratio.is_auto());
+///
+/// assert!(parse_aspect_ratio("auto 4/3").unwrap().is_auto());
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` for malformed input, `CssError::InvalidValue`
+/// for non-finite numbers and `CssError::OutOfRange` for negative numbers.
+pub fn parse_aspect_ratio(input: &str) -> Result<AspectRatio, CssError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty aspect-ratio value".to_string(),
+        ));
+    }
+
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    let is_auto = |token: &str| token.eq_ignore_ascii_case("auto");
+    let auto_count = tokens.iter().filter(|token| is_auto(token)).count();
+    if auto_count > 1 {
+        return Err(CssError::ParseError(
+            "Duplicate auto in aspect-ratio".to_string(),
+        ));
+    }
+
+    // `auto` may only come before or after the ratio, not inside it
+    let ratio_tokens = match (tokens.first(), tokens.last()) {
+        _ if auto_count == 0 => &tokens[..],
+        (Some(first), _) if is_auto(first) => &tokens[1..],
+        (_, Some(last)) if is_auto(last) => &tokens[..tokens.len() - 1],
+        _ => {
+            return Err(CssError::ParseError(format!(
+                "Invalid aspect-ratio: {}",
+                input
+            )))
+        }
+    };
+
+    if ratio_tokens.is_empty() {
+        return Ok(AspectRatio::new(true, None));
+    }
+
+    // `<number>` or `<number> / <number>`; keeping the spaces between tokens
+    // makes stray numbers such as `16 9` fail to parse instead of merging
+    let ratio_text = ratio_tokens.join(" ");
+    let (width, height) = match ratio_text.split_once('/') {
+        Some((width, height)) => (parse_ratio_part(width)?, parse_ratio_part(height)?),
+        None => (parse_ratio_part(&ratio_text)?, 1.0),
+    };
+
+    Ok(AspectRatio::new(auto_count == 1, Some((width, height))))
+}
+
+/// Parse one side of an aspect ratio
+fn parse_ratio_part(input: &str) -> Result<f32, CssError> {
+    let value = parse_finite_f32(input)?;
+    if value < 0.0 {
+        return Err(CssError::OutOfRange(format!(
+            "Aspect ratio cannot be negative: {}",
+            input
+        )));
+    }
+    Ok(value)
+}
+
+// ============================================================================
+// Background Shorthand
+// ============================================================================
+
+/// Value of the `background-repeat` longhand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundRepeat {
+    /// Tile in both directions
+    Repeat,
+    /// Tile horizontally only
+    RepeatX,
+    /// Tile vertically only
+    RepeatY,
+    /// Paint the image once
+    NoRepeat,
+    /// Tile without clipping, distributing leftover space between tiles
+    Space,
+    /// Tile without clipping, rescaling tiles to fit
+    Round,
+}
+
+impl BackgroundRepeat {
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "repeat" => Some(Self::Repeat),
+            "repeat-x" => Some(Self::RepeatX),
+            "repeat-y" => Some(Self::RepeatY),
+            "no-repeat" => Some(Self::NoRepeat),
+            "space" => Some(Self::Space),
+            "round" => Some(Self::Round),
+            _ => None,
+        }
+    }
+}
+
+/// Value of the `background-size` longhand
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackgroundSize {
+    /// Intrinsic image size
+    Auto,
+    /// Scale to cover the whole background area
+    Cover,
+    /// Scale to fit entirely within the background area
+    Contain,
+    /// Explicit width and height (either may be the `auto` keyword)
+    Explicit(ComplexValue, ComplexValue),
+}
+
+/// A single comma-separated layer of the `background` shorthand
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundLayer {
+    image: Option<FunctionValue>,
+    repeat: BackgroundRepeat,
+    position: (ComplexValue, ComplexValue),
+    size: BackgroundSize,
+}
+
+impl BackgroundLayer {
+    /// Get the background image (`None` for `none`)
+    pub fn image(&self) -> Option<&FunctionValue> {
+        self.image.as_ref()
+    }
+
+    /// Get the repeat style
+    pub fn repeat(&self) -> BackgroundRepeat {
+        self.repeat
+    }
+
+    /// Get the horizontal and vertical position components
+    pub fn position(&self) -> (&ComplexValue, &ComplexValue) {
+        (&self.position.0, &self.position.1)
+    }
+
+    /// Get the background size
+    pub fn size(&self) -> &BackgroundSize {
+        &self.size
+    }
+}
+
+impl Default for BackgroundLayer {
+    fn default() -> Self {
+        Self {
+            image: None,
+            repeat: BackgroundRepeat::Repeat,
+            position: (
+                ComplexValue::new(ValueKind::Percentage, "0%".to_string()),
+                ComplexValue::new(ValueKind::Percentage, "0%".to_string()),
+            ),
+            size: BackgroundSize::Auto,
+        }
+    }
+}
+
+/// Longhand values produced by the `background` shorthand
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundLonghands {
+    layers: Vec<BackgroundLayer>,
+    color: Color,
+}
+
+impl BackgroundLonghands {
+    /// Get the image layers, topmost first
+    pub fn layers(&self) -> &[BackgroundLayer] {
+        &self.layers
+    }
+
+    /// Get the background color (transparent if not specified)
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+/// Parse the `background` shorthand into its longhands
+///
+/// Layers are separated by top-level commas. Each layer may contain an image
+/// (`url()`, a gradient, or `none`), a repeat keyword, a position, and a size
+/// written after the position as `position / size`. Only the final layer may
+/// specify a color. Missing pieces take their initial values.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::{parse_background, BackgroundRepeat, BackgroundSize};
+///
+/// let bg = parse_background("#fff url(x.png) no-repeat center / cover").unwrap();
+/// assert_eq!(bg.color().r(), 255);
+/// assert_eq!(bg.layers()[0].repeat(), BackgroundRepeat::NoRepeat);
+/// assert_eq!(bg.layers()[0].size(), &BackgroundSize::Cover);
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if a layer contains an unrecognized or
+/// duplicated component, or a color appears in a layer other than the last.
+pub fn parse_background(input: &str) -> Result<BackgroundLonghands, CssError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty background value".to_string(),
+        ));
+    }
+
+    let layer_strs = split_top_level(input, ',');
+    let last_index = layer_strs.len() - 1;
+    let mut layers = Vec::with_capacity(layer_strs.len());
+    let mut color = Color::rgba(0, 0, 0, 0.0);
+
+    for (index, layer_str) in layer_strs.iter().enumerate() {
+        let (layer, layer_color) = parse_background_layer(layer_str)?;
+        if let Some(layer_color) = layer_color {
+            if index != last_index {
+                return Err(CssError::ParseError(
+                    "Background color is only allowed in the final layer".to_string(),
+                ));
+            }
+            color = layer_color;
+        }
+        layers.push(layer);
+    }
+
+    Ok(BackgroundLonghands { layers, color })
+}
+
+/// Parse one background layer, returning the layer and its color (if any)
+fn parse_background_layer(input: &str) -> Result<(BackgroundLayer, Option<Color>), CssError> {
+    let tokens = tokenize_layer(input);
+
+    if tokens.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty background layer".to_string(),
+        ));
+    }
+
+    let mut layer = BackgroundLayer::default();
+    let mut image_set = false;
+    let mut repeat_set = false;
+    let mut color = None;
+    let mut position: Vec<ComplexValue> = Vec::new();
+    let mut size: Option<BackgroundSize> = None;
+
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(token) = iter.next() {
+        let lower = token.to_ascii_lowercase();
+
+        if token == "/" {
+            if position.is_empty() || size.is_some() {
+                return Err(CssError::ParseError(
+                    "Background size must follow a position".to_string(),
+                ));
+            }
+            let mut size_tokens = Vec::new();
+            while let Some(next) = iter.peek() {
+                if size_tokens.len() == 2 || !is_background_size_token(next) {
+                    break;
+                }
+                size_tokens.push(iter.next().unwrap_or_default());
+            }
+            size = Some(parse_background_size(&size_tokens)?);
+        } else if is_background_position_token(&token) {
+            if size.is_some() || position.len() == 2 {
+                return Err(CssError::ParseError(format!(
+                    "Unexpected background position component: {}",
+                    token
+                )));
+            }
+            position.push(parse_value(&token, "background-position")?);
+        } else if let Some(repeat) = BackgroundRepeat::from_keyword(&lower) {
+            if repeat_set {
+                return Err(CssError::ParseError(
+                    "Duplicate background-repeat".to_string(),
+                ));
+            }
+            layer.repeat = repeat;
+            repeat_set = true;
+        } else if lower == "none" || lower.starts_with("url(") || lower.contains("gradient(") {
+            if image_set {
+                return Err(CssError::ParseError(
+                    "Duplicate background-image".to_string(),
+                ));
+            }
+            if lower != "none" {
+                layer.image = Some(parse_function_value(&token)?);
+            }
+            image_set = true;
+        } else if let Ok(parsed) = parse_color_value(&token) {
+            if color.is_some() {
+                return Err(CssError::ParseError(
+                    "Duplicate background-color".to_string(),
+                ));
+            }
+            color = Some(parsed);
+        } else {
+            return Err(CssError::ParseError(format!(
+                "Unknown background component: {}",
+                token
+            )));
+        }
+    }
+
+    if !position.is_empty() {
+        layer.position = resolve_background_position(position);
+    }
+    if let Some(size) = size {
+        layer.size = size;
+    }
+
+    Ok((layer, color))
+}
+
+/// Split a layer into whitespace-separated tokens, keeping function
+/// arguments together and emitting `/` as its own token
+fn tokenize_layer(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+
+    for ch in input.chars() {
+        match ch {
+            '(' => {
+                depth += 1;
+                current.push(ch);
+            }
+            ')' => {
+                depth -= 1;
+                current.push(ch);
+            }
+            '/' if depth == 0 => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push("/".to_string());
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(ch),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Split on a separator that is not nested inside parentheses
+fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, ch) in input.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(input[start..i].trim());
+                start = i + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(input[start..].trim());
+
+    parts
+}
+
+/// Check whether a token can be part of a background position
+fn is_background_position_token(token: &str) -> bool {
+    matches!(
+        token.to_ascii_lowercase().as_str(),
+        "left" | "right" | "top" | "bottom" | "center"
+    ) || is_length_or_percentage(token)
+}
+
+/// Check whether a token can be part of a background size
+fn is_background_size_token(token: &str) -> bool {
+    matches!(
+        token.to_ascii_lowercase().as_str(),
+        "auto" | "cover" | "contain"
+    ) || is_length_or_percentage(token)
+}
+
+fn is_length_or_percentage(token: &str) -> bool {
+    matches!(
+        parse_value(token, ""),
+        Ok(ref value) if matches!(
+            value.kind(),
+            ValueKind::Length | ValueKind::Percentage | ValueKind::Number
+        )
+    )
+}
+
+/// Parse the components following the `/` in a background layer
+fn parse_background_size(tokens: &[String]) -> Result<BackgroundSize, CssError> {
+    let auto = || ComplexValue::new(ValueKind::Keyword, "auto".to_string());
+
+    match tokens {
+        [single] => match single.to_ascii_lowercase().as_str() {
+            "cover" => Ok(BackgroundSize::Cover),
+            "contain" => Ok(BackgroundSize::Contain),
+            "auto" => Ok(BackgroundSize::Auto),
+            _ => Ok(BackgroundSize::Explicit(
+                parse_value(single, "background-size")?,
+                auto(),
+            )),
+        },
+        [width, height] => {
+            let is_keyword = |t: &str| matches!(t, "cover" | "contain");
+            if is_keyword(&width.to_ascii_lowercase()) || is_keyword(&height.to_ascii_lowercase()) {
+                return Err(CssError::ParseError(
+                    "cover and contain cannot be combined with another size".to_string(),
+                ));
+            }
+            Ok(BackgroundSize::Explicit(
+                parse_value(width, "background-size")?,
+                parse_value(height, "background-size")?,
+            ))
+        }
+        _ => Err(CssError::ParseError(
+            "Expected a background size after /".to_string(),
+        )),
+    }
+}
+
+/// Expand one or two position components into horizontal and vertical values
+fn resolve_background_position(mut components: Vec<ComplexValue>) -> (ComplexValue, ComplexValue) {
+    let center = || ComplexValue::new(ValueKind::Keyword, "center".to_string());
+    let is_vertical = |value: &ComplexValue| matches!(value.data(), "top" | "bottom");
+    let is_horizontal = |value: &ComplexValue| matches!(value.data(), "left" | "right");
+
+    if components.len() == 1 {
+        let only = components.remove(0);
+        return if is_vertical(&only) {
+            (center(), only)
+        } else {
+            (only, center())
+        };
+    }
+
+    let second = components.remove(1);
+    let first = components.remove(0);
+    // Keywords may be written vertical-first ("top left")
+    if is_vertical(&first) || is_horizontal(&second) {
+        (second, first)
+    } else {
+        (first, second)
+    }
+}
+
+// ============================================================================
+// Box Shadow
+// ============================================================================
+
+/// A single comma-separated layer of the `box-shadow` property
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxShadow {
+    inset: bool,
+    offset_x: Length,
+    offset_y: Length,
+    blur: Length,
+    spread: Length,
+    color: ColorValue,
+}
+
+impl BoxShadow {
+    /// Create a shadow layer
+    ///
+    /// # Arguments
+    /// * `inset` - Whether the shadow is drawn inside the border box
+    /// * `offset_x` - Horizontal offset
+    /// * `offset_y` - Vertical offset
+    /// * `blur` - Blur radius
+    /// * `spread` - Spread distance
+    /// * `color` - Shadow color
+    pub fn new(
+        inset: bool,
+        offset_x: Length,
+        offset_y: Length,
+        blur: Length,
+        spread: Length,
+        color: ColorValue,
+    ) -> Self {
+        Self {
+            inset,
+            offset_x,
+            offset_y,
+            blur,
+            spread,
+            color,
+        }
+    }
+
+    /// Check whether the shadow is drawn inside the border box
+    pub fn inset(&self) -> bool {
+        self.inset
+    }
+
+    /// Get the horizontal offset
+    pub fn offset_x(&self) -> Length {
+        self.offset_x
+    }
+
+    /// Get the vertical offset
+    pub fn offset_y(&self) -> Length {
+        self.offset_y
+    }
+
+    /// Get the blur radius (0 if not specified)
+    pub fn blur(&self) -> Length {
+        self.blur
+    }
+
+    /// Get the spread distance (0 if not specified)
+    pub fn spread(&self) -> Length {
+        self.spread
+    }
+
+    /// Get the shadow color (`currentcolor` if not specified)
+    pub fn color(&self) -> ColorValue {
+        self.color
+    }
+}
+
+/// Parse the `box-shadow` property into its layers
+///
+/// Layers are separated by top-level commas, topmost first. Each layer has
+/// two to four lengths (offset-x, offset-y, blur, spread), written next to
+/// each other, plus an optional `inset` keyword and color on either side.
+/// `none` produces an empty list.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_box_shadow;
+///
+/// let shadows = parse_box_shadow("0 2px 4px rgba(0,0,0,.3), inset 0 0 2px red").unwrap();
+/// assert_eq!(shadows.len(), 2);
+/// assert_eq!(shadows[0].blur().value(), 4.0);
+/// assert!(shadows[1].inset());
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if a layer has fewer than two or more
+/// than four lengths, a duplicated or unrecognized component, or `none` is
+/// combined with other layers. Returns `CssError::InvalidValue` for a
+/// negative blur radius.
+pub fn parse_box_shadow(input: &str) -> Result<Vec<BoxShadow>, CssError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty box-shadow value".to_string(),
+        ));
+    }
+    if input.eq_ignore_ascii_case("none") {
+        return Ok(Vec::new());
+    }
+
+    split_top_level(input, ',')
+        .into_iter()
+        .map(parse_box_shadow_layer)
+        .collect()
+}
+
+/// Parse one box-shadow layer
+fn parse_box_shadow_layer(input: &str) -> Result<BoxShadow, CssError> {
+    let layer = parse_shadow_layer(input, "box-shadow", true, 4)?;
+    let zero = Length::new(0.0, LengthUnit::Px);
+
+    Ok(BoxShadow {
+        inset: layer.inset,
+        offset_x: layer.lengths[0],
+        offset_y: layer.lengths[1],
+        blur: layer.lengths.get(2).copied().unwrap_or(zero),
+        spread: layer.lengths.get(3).copied().unwrap_or(zero),
+        color: layer.color,
+    })
+}
+
+// ============================================================================
+// Text Shadow
+// ============================================================================
+
+/// A single comma-separated layer of the `text-shadow` property
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextShadow {
+    offset_x: Length,
+    offset_y: Length,
+    blur: Length,
+    color: ColorValue,
+}
+
+impl TextShadow {
+    /// Create a shadow layer
+    ///
+    /// # Arguments
+    /// * `offset_x` - Horizontal offset
+    /// * `offset_y` - Vertical offset
+    /// * `blur` - Blur radius
+    /// * `color` - Shadow color
+    pub fn new(offset_x: Length, offset_y: Length, blur: Length, color: ColorValue) -> Self {
+        Self {
+            offset_x,
+            offset_y,
+            blur,
+            color,
+        }
+    }
+
+    /// Get the horizontal offset
+    pub fn offset_x(&self) -> Length {
+        self.offset_x
+    }
+
+    /// Get the vertical offset
+    pub fn offset_y(&self) -> Length {
+        self.offset_y
+    }
+
+    /// Get the blur radius (0 if not specified)
+    pub fn blur(&self) -> Length {
+        self.blur
+    }
+
+    /// Get the shadow color (`currentcolor` if not specified)
+    pub fn color(&self) -> ColorValue {
+        self.color
+    }
+}
+
+/// Parse the `text-shadow` property into its layers
+///
+/// Same syntax as `box-shadow` without `inset` and the spread distance:
+/// two or three lengths (offset-x, offset-y, blur) and an optional color on
+/// either side. `none` produces an empty list.
+///
+/// # Examples
+/// ```
+/// use css_parser_values::parse_text_shadow;
+///
+/// let shadows = parse_text_shadow("1px 1px 2px black, red 0 0 1em").unwrap();
+/// assert_eq!(shadows.len(), 2);
+/// assert_eq!(shadows[0].blur().value(), 2.0);
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if a layer has fewer than two or more
+/// than three lengths, a duplicated or unrecognized component, or `none` is
+/// combined with other layers. Returns `CssError::InvalidValue` for a
+/// negative blur radius.
+pub fn parse_text_shadow(input: &str) -> Result<Vec<TextShadow>, CssError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty text-shadow value".to_string(),
+        ));
+    }
+    if input.eq_ignore_ascii_case("none") {
+        return Ok(Vec::new());
+    }
+
+    split_top_level(input, ',')
+        .into_iter()
+        .map(|layer| {
+            let layer = parse_shadow_layer(layer, "text-shadow", false, 3)?;
+            Ok(TextShadow {
+                offset_x: layer.lengths[0],
+                offset_y: layer.lengths[1],
+                blur: layer
+                    .lengths
+                    .get(2)
+                    .copied()
+                    .unwrap_or(Length::new(0.0, LengthUnit::Px)),
+                color: layer.color,
+            })
+        })
+        .collect()
+}
+
+/// Components shared by box-shadow and text-shadow layers
+struct ShadowLayer {
+    inset: bool,
+    /// Between two and the property's maximum number of lengths
+    lengths: Vec<Length>,
+    color: ColorValue,
+}
+
+/// Parse one shadow layer
+///
+/// The lengths must be contiguous; `inset` (when allowed) and the color may
+/// appear on either side of them.
+fn parse_shadow_layer(
+    input: &str,
+    property: &str,
+    allow_inset: bool,
+    max_lengths: usize,
+) -> Result<ShadowLayer, CssError> {
+    let tokens = tokenize_layer(input);
+
+    if tokens.is_empty() {
+        return Err(CssError::ParseError(format!(
+            "Empty {} layer",
+            property
+        )));
+    }
+
+    let mut inset = false;
+    let mut color = None;
+    let mut lengths: Vec<Length> = Vec::new();
+    let mut lengths_done = false;
+
+    for token in &tokens {
+        if let Some(length) = parse_shadow_length(token) {
+            if lengths_done || lengths.len() == max_lengths {
+                return Err(CssError::ParseError(format!(
+                    "Unexpected {} length: {}",
+                    property, token
+                )));
+            }
+            lengths.push(length);
+            continue;
+        }
+        lengths_done = !lengths.is_empty();
+
+        if allow_inset && token.eq_ignore_ascii_case("inset") {
+            if inset {
+                return Err(CssError::ParseError(
+                    "Duplicate inset keyword".to_string(),
+                ));
+            }
+            inset = true;
+        } else if let Ok(parsed) = parse_color_value_or_current(token) {
+            if color.is_some() {
+                return Err(CssError::ParseError(format!(
+                    "Duplicate {} color",
+                    property
+                )));
+            }
+            color = Some(parsed);
+        } else {
+            return Err(CssError::ParseError(format!(
+                "Unknown {} component: {}",
+                property, token
+            )));
+        }
+    }
+
+    if lengths.len() < 2 {
+        return Err(CssError::ParseError(format!(
+            "{} requires at least two lengths",
+            property
+        )));
+    }
+    if lengths.get(2).is_some_and(|blur| blur.value() < 0.0) {
+        return Err(CssError::InvalidValue(format!(
+            "{} blur radius cannot be negative",
+            property
+        )));
+    }
+
+    Ok(ShadowLayer {
+        inset,
+        lengths,
+        color: color.unwrap_or(ColorValue::CurrentColor),
+    })
+}
+
+/// Parse a shadow length, accepting a unitless zero
+fn parse_shadow_length(token: &str) -> Option<Length> {
+    if parse_finite_f32(token).is_ok_and(|value| value == 0.0) {
+        return Some(Length::new(0.0, LengthUnit::Px));
+    }
+    Length::parse(token).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,13 @@
 // Test runner for all unit tests
 mod tests_unit {
+    mod aspect_ratio_tests;
     mod attribute_selector_tests;
+    mod background_tests;
+    mod border_tests;
+    mod box_shadow_tests;
     mod color_tests;
     mod complex_value_tests;
     mod function_tests;
+    mod opacity_tests;
+    mod text_shadow_tests;
 }
@@ -0,0 +1,61 @@
+// Unit tests for aspect-ratio parsing
+
+use css_parser_values::parse_aspect_ratio;
+use css_types::CssError;
+
+#[test]
+fn test_parse_aspect_ratio_with_slash() {
+    let ratio = parse_aspect_ratio("16 / 9").unwrap();
+    assert!(!ratio.is_auto());
+    assert_eq!(ratio.ratio_parts(), Some((16.0, 9.0)));
+    assert_eq!(ratio.ratio(), Some(16.0 / 9.0));
+
+    assert_eq!(parse_aspect_ratio("16/9").unwrap(), ratio);
+    assert_eq!(parse_aspect_ratio("16 /9").unwrap(), ratio);
+    assert_eq!(parse_aspect_ratio("16/ 9").unwrap(), ratio);
+}
+
+#[test]
+fn test_parse_aspect_ratio_single_number() {
+    let ratio = parse_aspect_ratio("1").unwrap();
+    assert_eq!(ratio.ratio_parts(), Some((1.0, 1.0)));
+    assert_eq!(ratio.ratio(), Some(1.0));
+}
+
+#[test]
+fn test_parse_aspect_ratio_auto_with_ratio() {
+    let ratio = parse_aspect_ratio("auto 4/3").unwrap();
+    assert!(ratio.is_auto());
+    assert_eq!(ratio.ratio_parts(), Some((4.0, 3.0)));
+
+    assert_eq!(parse_aspect_ratio("4 / 3 AUTO").unwrap(), ratio);
+}
+
+#[test]
+fn test_parse_aspect_ratio_auto_only() {
+    let ratio = parse_aspect_ratio("auto").unwrap();
+    assert!(ratio.is_auto());
+    assert_eq!(ratio.ratio(), None);
+}
+
+#[test]
+fn test_degenerate_aspect_ratio_has_no_ratio() {
+    assert_eq!(parse_aspect_ratio("0 / 1").unwrap().ratio(), None);
+}
+
+#[test]
+fn test_parse_aspect_ratio_invalid() {
+    assert!(parse_aspect_ratio("").is_err());
+    assert!(parse_aspect_ratio("auto auto").is_err());
+    assert!(parse_aspect_ratio("16 auto / 9").is_err());
+    assert!(parse_aspect_ratio("16 / 9 / 2").is_err());
+    assert!(parse_aspect_ratio("16 9").is_err());
+    assert!(parse_aspect_ratio("1 6 / 9").is_err());
+    assert!(parse_aspect_ratio("16 / 9 2").is_err());
+    assert!(parse_aspect_ratio("auto 16 9").is_err());
+    assert!(parse_aspect_ratio("wide").is_err());
+    assert!(matches!(
+        parse_aspect_ratio("-1 / 2"),
+        Err(CssError::OutOfRange(_))
+    ));
+}
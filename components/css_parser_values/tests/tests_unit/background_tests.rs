@@ -0,0 +1,89 @@
+// Unit tests for the background shorthand
+
+use css_parser_values::{parse_background, BackgroundRepeat, BackgroundSize, ValueKind};
+
+#[test]
+fn test_parse_background_full_single_layer() {
+    let bg = parse_background("#fff url(x.png) no-repeat center / cover").unwrap();
+
+    assert_eq!(bg.color().r(), 255);
+    assert_eq!(bg.color().g(), 255);
+    assert_eq!(bg.color().b(), 255);
+    assert_eq!(bg.layers().len(), 1);
+
+    let layer = &bg.layers()[0];
+    let image = layer.image().unwrap();
+    assert_eq!(image.name(), "url");
+    assert_eq!(image.args()[0], "x.png");
+    assert_eq!(layer.repeat(), BackgroundRepeat::NoRepeat);
+    let (x, y) = layer.position();
+    assert_eq!(x.data(), "center");
+    assert_eq!(y.data(), "center");
+    assert_eq!(layer.size(), &BackgroundSize::Cover);
+}
+
+#[test]
+fn test_parse_background_two_layers() {
+    let bg = parse_background(
+        "url(top.png) repeat-x left top, linear-gradient(red, blue) 10px 20%/50% auto green",
+    )
+    .unwrap();
+
+    assert_eq!(bg.layers().len(), 2);
+    assert_eq!(bg.color().g(), 128);
+
+    let first = &bg.layers()[0];
+    assert_eq!(first.image().unwrap().name(), "url");
+    assert_eq!(first.repeat(), BackgroundRepeat::RepeatX);
+    assert_eq!(first.position().0.data(), "left");
+    assert_eq!(first.position().1.data(), "top");
+    assert_eq!(first.size(), &BackgroundSize::Auto);
+
+    let second = &bg.layers()[1];
+    assert_eq!(second.image().unwrap().name(), "linear-gradient");
+    assert_eq!(second.repeat(), BackgroundRepeat::Repeat);
+    assert_eq!(second.position().0.kind(), ValueKind::Length);
+    assert_eq!(second.position().1.data(), "20%");
+    match second.size() {
+        BackgroundSize::Explicit(width, height) => {
+            assert_eq!(width.data(), "50%");
+            assert_eq!(height.data(), "auto");
+        }
+        other => panic!("Expected explicit size, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_background_defaults() {
+    let bg = parse_background("red").unwrap();
+
+    assert_eq!(bg.color().r(), 255);
+    let layer = &bg.layers()[0];
+    assert!(layer.image().is_none());
+    assert_eq!(layer.repeat(), BackgroundRepeat::Repeat);
+    assert_eq!(layer.position().0.data(), "0%");
+    assert_eq!(layer.position().1.data(), "0%");
+    assert_eq!(layer.size(), &BackgroundSize::Auto);
+}
+
+#[test]
+fn test_parse_background_single_vertical_keyword() {
+    let bg = parse_background("url(a.png) bottom").unwrap();
+    let (x, y) = bg.layers()[0].position();
+    assert_eq!(x.data(), "center");
+    assert_eq!(y.data(), "bottom");
+    assert_eq!(bg.color().a(), 0.0);
+}
+
+#[test]
+fn test_parse_background_color_only_in_final_layer() {
+    assert!(parse_background("red url(a.png), url(b.png)").is_err());
+}
+
+#[test]
+fn test_parse_background_rejects_invalid() {
+    assert!(parse_background("").is_err());
+    assert!(parse_background("url(a.png) url(b.png)").is_err());
+    assert!(parse_background("url(a.png) / cover").is_err());
+    assert!(parse_background("url(a.png) wobbly").is_err());
+}
@@ -0,0 +1,52 @@
+// Unit tests for the border shorthand
+
+use css_parser_values::parse_border;
+use css_types::{BorderStyle, Color, ColorValue, LengthUnit};
+
+#[test]
+fn test_parse_border_full() {
+    let (width, style, color) = parse_border("1px solid red").unwrap();
+    assert_eq!(width.value(), 1.0);
+    assert_eq!(width.unit(), LengthUnit::Px);
+    assert_eq!(style, BorderStyle::Solid);
+    assert_eq!(color, ColorValue::Rgba(Color::rgb(255, 0, 0)));
+}
+
+#[test]
+fn test_parse_border_style_only() {
+    let (width, style, color) = parse_border("solid").unwrap();
+    assert_eq!(width.value(), 3.0);
+    assert_eq!(style, BorderStyle::Solid);
+    assert_eq!(color, ColorValue::CurrentColor);
+}
+
+#[test]
+fn test_parse_border_any_order() {
+    let (width, style, color) = parse_border("red dashed 2px").unwrap();
+    assert_eq!(width.value(), 2.0);
+    assert_eq!(style, BorderStyle::Dashed);
+    assert_eq!(color, ColorValue::Rgba(Color::rgb(255, 0, 0)));
+}
+
+#[test]
+fn test_parse_border_current_color() {
+    let (_, style, color) = parse_border("2px currentColor dotted").unwrap();
+    assert_eq!(style, BorderStyle::Dotted);
+    assert_eq!(color, ColorValue::CurrentColor);
+    assert!(parse_border("currentcolor red").is_err());
+}
+
+#[test]
+fn test_parse_border_width_keywords() {
+    assert_eq!(parse_border("thin").unwrap().0.value(), 1.0);
+    assert_eq!(parse_border("thick double").unwrap().0.value(), 5.0);
+    let (_, style, _) = parse_border("medium").unwrap();
+    assert_eq!(style, BorderStyle::None);
+}
+
+#[test]
+fn test_parse_border_invalid_extra_token() {
+    assert!(parse_border("1px solid red blue").is_err());
+    assert!(parse_border("1px solid red wobbly").is_err());
+    assert!(parse_border("").is_err());
+}
@@ -0,0 +1,77 @@
+// Unit tests for the box-shadow property
+
+use css_parser_values::parse_box_shadow;
+use css_types::{Color, ColorValue, CssError, Length, LengthUnit};
+
+fn px(value: f32) -> Length {
+    Length::new(value, LengthUnit::Px)
+}
+
+#[test]
+fn test_parse_box_shadow_single_layer() {
+    let shadows = parse_box_shadow("0 2px 4px rgba(0,0,0,.3)").unwrap();
+
+    assert_eq!(shadows.len(), 1);
+    let shadow = &shadows[0];
+    assert!(!shadow.inset());
+    assert_eq!(shadow.offset_x(), px(0.0));
+    assert_eq!(shadow.offset_y(), px(2.0));
+    assert_eq!(shadow.blur(), px(4.0));
+    assert_eq!(shadow.spread(), px(0.0));
+    assert_eq!(shadow.color(), ColorValue::Rgba(Color::rgba(0, 0, 0, 0.3)));
+}
+
+#[test]
+fn test_parse_box_shadow_inset() {
+    let shadows = parse_box_shadow("inset 1px 1px 2px 3px red").unwrap();
+
+    let shadow = &shadows[0];
+    assert!(shadow.inset());
+    assert_eq!(shadow.blur(), px(2.0));
+    assert_eq!(shadow.spread(), px(3.0));
+    assert_eq!(shadow.color(), ColorValue::Rgba(Color::rgb(255, 0, 0)));
+
+    // The keyword and color may also follow the lengths
+    let trailing = parse_box_shadow("1px 1px red inset").unwrap();
+    assert!(trailing[0].inset());
+}
+
+#[test]
+fn test_parse_box_shadow_two_layers() {
+    let shadows = parse_box_shadow("0 2px 4px rgba(0,0,0,.3), inset 0 0 2px red").unwrap();
+
+    assert_eq!(shadows.len(), 2);
+    assert!(!shadows[0].inset());
+    assert_eq!(shadows[0].offset_y(), px(2.0));
+    assert!(shadows[1].inset());
+    assert_eq!(shadows[1].blur(), px(2.0));
+    assert_eq!(shadows[1].color(), ColorValue::Rgba(Color::rgb(255, 0, 0)));
+}
+
+#[test]
+fn test_parse_box_shadow_defaults_to_current_color() {
+    let shadows = parse_box_shadow("1em -1em").unwrap();
+
+    assert_eq!(shadows[0].offset_x(), Length::new(1.0, LengthUnit::Em));
+    assert_eq!(shadows[0].offset_y(), Length::new(-1.0, LengthUnit::Em));
+    assert_eq!(shadows[0].color(), ColorValue::CurrentColor);
+}
+
+#[test]
+fn test_parse_box_shadow_none() {
+    assert!(parse_box_shadow("none").unwrap().is_empty());
+    assert!(parse_box_shadow("none, 1px 1px red").is_err());
+}
+
+#[test]
+fn test_parse_box_shadow_invalid() {
+    assert!(parse_box_shadow("").is_err());
+    assert!(parse_box_shadow("1px red").is_err());
+    assert!(parse_box_shadow("1px 2px 3px 4px 5px").is_err());
+    assert!(parse_box_shadow("1px red 2px").is_err());
+    assert!(parse_box_shadow("inset inset 1px 1px").is_err());
+    assert!(matches!(
+        parse_box_shadow("1px 1px -2px"),
+        Err(CssError::InvalidValue(_))
+    ));
+}
@@ -1,6 +1,7 @@
 // Unit tests for advanced color parsing
 
-use css_parser_values::parse_color_value;
+use css_parser_values::{parse_color_value, parse_color_value_or_current};
+use css_types::{Color, ColorValue};
 
 // Test hex colors
 #[test]
@@ -154,3 +155,28 @@ fn test_parse_hsl_invalid_saturation() {
     let result = parse_color_value("hsl(0, 150%, 50%)");
     assert!(result.is_err());
 }
+
+// Test currentColor
+#[test]
+fn test_parse_current_color_keyword() {
+    for input in ["currentColor", "currentcolor", " CURRENTCOLOR "] {
+        assert_eq!(
+            parse_color_value_or_current(input).unwrap(),
+            ColorValue::CurrentColor
+        );
+    }
+}
+
+#[test]
+fn test_parse_color_value_or_current_concrete() {
+    assert_eq!(
+        parse_color_value_or_current("blue").unwrap(),
+        ColorValue::Rgba(Color::rgb(0, 0, 255))
+    );
+    assert!(parse_color_value_or_current("current-color").is_err());
+}
+
+#[test]
+fn test_parse_hsl_rejects_negative_lightness() {
+    assert!(parse_color_value("hsl(0, 50%, -10%)").is_err());
+}
@@ -1,6 +1,7 @@
 // Unit tests for ComplexValue parsing
 
-use css_parser_values::{parse_value, ValueKind};
+use css_parser_values::{parse_percentage_raw, parse_value, ValueKind};
+use css_types::CssError;
 
 #[test]
 fn test_parse_number_value() {
@@ -87,3 +88,47 @@ fn test_parse_invalid_value() {
     let result = parse_value("", "color");
     assert!(result.is_err());
 }
+
+#[test]
+fn test_parse_percentage_raw_negative() {
+    assert_eq!(parse_percentage_raw("-50%").unwrap(), -0.5);
+    assert_eq!(parse_percentage_raw("+25%").unwrap(), 0.25);
+}
+
+#[test]
+fn test_parse_percentage_raw_over_one_hundred() {
+    assert_eq!(parse_percentage_raw("150%").unwrap(), 1.5);
+    assert_eq!(parse_percentage_raw(" 1e3% ").unwrap(), 10.0);
+}
+
+#[test]
+fn test_parse_percentage_raw_decimals() {
+    assert!((parse_percentage_raw("12.5%").unwrap() - 0.125).abs() < 1e-6);
+    assert!((parse_percentage_raw(".5%").unwrap() - 0.005).abs() < 1e-6);
+}
+
+#[test]
+fn test_parse_percentage_raw_rejects_invalid_input() {
+    assert!(matches!(
+        parse_percentage_raw("50"),
+        Err(CssError::ParseError(_))
+    ));
+    assert!(matches!(parse_percentage_raw("%"), Err(CssError::ParseError(_))));
+    assert!(matches!(
+        parse_percentage_raw("abc%"),
+        Err(CssError::ParseError(_))
+    ));
+    assert!(matches!(
+        parse_percentage_raw("NaN%"),
+        Err(CssError::InvalidValue(_))
+    ));
+}
+
+#[test]
+fn test_parse_value_invalid_percentage_is_keyword() {
+    let value = parse_value("-12.5%", "margin").unwrap();
+    assert_eq!(value.kind(), ValueKind::Percentage);
+
+    let value = parse_value("abc%", "margin").unwrap();
+    assert_eq!(value.kind(), ValueKind::Keyword);
+}
@@ -1,6 +1,7 @@
 // Unit tests for CSS function parsing
 
 use css_parser_values::parse_function_value;
+use css_types::CssError;
 
 #[test]
 fn test_parse_url_function() {
@@ -107,3 +108,18 @@ fn test_parse_function_empty_args() {
     assert_eq!(func.name(), "func");
     assert!(func.args().is_empty() || func.args()[0].is_empty());
 }
+
+#[test]
+fn test_parse_function_space_before_paren() {
+    let func = parse_function_value("calc (100% - 50px)").unwrap();
+    assert_eq!(func.name(), "calc");
+    assert_eq!(func.args()[0], "100% - 50px");
+}
+
+#[test]
+fn test_parse_function_rejects_whitespace_in_name() {
+    match parse_function_value("cal c(100% - 50px)") {
+        Err(CssError::ParseError(message)) => assert!(message.contains("whitespace")),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
@@ -0,0 +1,49 @@
+// Unit tests for opacity parsing
+
+use css_parser_values::parse_opacity;
+use css_types::CssError;
+
+#[test]
+fn test_parse_opacity_number() {
+    assert_eq!(parse_opacity("0.5").unwrap(), 0.5);
+    assert_eq!(parse_opacity("  0  ").unwrap(), 0.0);
+}
+
+#[test]
+fn test_parse_opacity_percentage() {
+    assert_eq!(parse_opacity("50%").unwrap(), 0.5);
+    assert_eq!(parse_opacity("100%").unwrap(), 1.0);
+}
+
+#[test]
+fn test_parse_opacity_clamps_above_one() {
+    assert_eq!(parse_opacity("1.5").unwrap(), 1.0);
+    assert_eq!(parse_opacity("250%").unwrap(), 1.0);
+}
+
+#[test]
+fn test_parse_opacity_clamps_below_zero() {
+    assert_eq!(parse_opacity("-0.2").unwrap(), 0.0);
+    assert_eq!(parse_opacity("-10%").unwrap(), 0.0);
+}
+
+#[test]
+fn test_parse_opacity_rejects_invalid_input() {
+    assert!(matches!(
+        parse_opacity("half"),
+        Err(CssError::ParseError(_))
+    ));
+    assert!(matches!(parse_opacity(""), Err(CssError::ParseError(_))));
+    assert!(matches!(
+        parse_opacity("0.5px"),
+        Err(CssError::ParseError(_))
+    ));
+    assert!(matches!(
+        parse_opacity("NaN"),
+        Err(CssError::InvalidValue(_))
+    ));
+    assert!(matches!(
+        parse_opacity("inf%"),
+        Err(CssError::InvalidValue(_))
+    ));
+}
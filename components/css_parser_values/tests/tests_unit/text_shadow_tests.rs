@@ -0,0 +1,53 @@
+// Unit tests for the text-shadow property
+
+use css_parser_values::parse_text_shadow;
+use css_types::{Color, ColorValue, CssError, Length, LengthUnit};
+
+fn px(value: f32) -> Length {
+    Length::new(value, LengthUnit::Px)
+}
+
+#[test]
+fn test_parse_text_shadow_single_layer() {
+    let shadows = parse_text_shadow("1px 1px 2px black").unwrap();
+
+    assert_eq!(shadows.len(), 1);
+    let shadow = &shadows[0];
+    assert_eq!(shadow.offset_x(), px(1.0));
+    assert_eq!(shadow.offset_y(), px(1.0));
+    assert_eq!(shadow.blur(), px(2.0));
+    assert_eq!(shadow.color(), ColorValue::Rgba(Color::rgb(0, 0, 0)));
+}
+
+#[test]
+fn test_parse_text_shadow_multiple_layers() {
+    let shadows = parse_text_shadow("red 0 0 1em, 2px 3px rgba(0, 0, 255, 0.5)").unwrap();
+
+    assert_eq!(shadows.len(), 2);
+    assert_eq!(shadows[0].color(), ColorValue::Rgba(Color::rgb(255, 0, 0)));
+    assert_eq!(shadows[0].blur(), Length::new(1.0, LengthUnit::Em));
+    assert_eq!(shadows[1].offset_y(), px(3.0));
+    assert_eq!(shadows[1].blur(), px(0.0));
+    assert_eq!(
+        shadows[1].color(),
+        ColorValue::Rgba(Color::rgba(0, 0, 255, 0.5))
+    );
+}
+
+#[test]
+fn test_parse_text_shadow_defaults_to_current_color() {
+    let shadows = parse_text_shadow("1px 2px").unwrap();
+    assert_eq!(shadows[0].color(), ColorValue::CurrentColor);
+    assert!(parse_text_shadow("none").unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_text_shadow_rejects_box_shadow_only_syntax() {
+    assert!(parse_text_shadow("inset 1px 1px red").is_err());
+    assert!(parse_text_shadow("1px 1px 2px 3px red").is_err());
+    assert!(parse_text_shadow("1px").is_err());
+    assert!(matches!(
+        parse_text_shadow("1px 1px -2px"),
+        Err(CssError::InvalidValue(_))
+    ));
+}
@@ -1,8 +1,11 @@
 //! CSS Stylist Cache - Style sharing and caching optimizations
 //!
 //! This crate provides efficient caching and style sharing for CSS style computation:
-//! - StyleCache: Cache computed styles to avoid redundant computation
+//! - StyleCache: Cache computed styles to avoid redundant computation, with
+//!   optional capacity-bounded eviction that favors `will-change` elements
+//! - StyleSnapshot: Capture the selector-relevant state of an element
 //! - StyleSharing: Share styles between similar elements
+//! - MatchCache: Reuse selector matches when only element state changes
 //! - StateFlags: Track element states for cache invalidation
 //!
 //! # Performance Targets
@@ -11,8 +14,8 @@
 //! - Cache hit rate target: > 60%
 //! - Memory overhead: < 50MB for 10,000 elements
 
-use css_matcher_core::ElementLike;
-use css_stylist_core::ComputedValues;
+use css_matcher_core::{ComplexSelector, ElementLike, SelectorMatcher};
+use css_stylist_core::{ComputedValues, StyleContext, Stylist};
 use servo_arc::Arc;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -86,6 +89,28 @@ impl StateFlags {
     pub fn visited(&self) -> bool {
         self.visited
     }
+
+    /// Check if no state is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check if every state set in `required` is also set here
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_cache::StateFlags;
+    ///
+    /// let hover_focus = StateFlags::new(true, false, true, false);
+    /// assert!(hover_focus.contains(&StateFlags::new(true, false, false, false)));
+    /// assert!(!hover_focus.contains(&StateFlags::new(false, true, false, false)));
+    /// ```
+    pub fn contains(&self, required: &StateFlags) -> bool {
+        (self.hover || !required.hover)
+            && (self.active || !required.active)
+            && (self.focus || !required.focus)
+            && (self.visited || !required.visited)
+    }
 }
 
 impl Default for StateFlags {
@@ -151,6 +176,20 @@ impl StyleKey {
         }
     }
 
+    /// Builder: replace the parent hash
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_cache::{StateFlags, StyleKey};
+    ///
+    /// let key = StyleKey::new(100, None, StateFlags::default()).with_parent_hash(Some(7));
+    /// assert_eq!(key.parent_hash(), Some(7));
+    /// ```
+    pub fn with_parent_hash(mut self, parent_hash: Option<u64>) -> Self {
+        self.parent_hash = parent_hash;
+        self
+    }
+
     /// Get the selector hash
     pub fn selector_hash(&self) -> u64 {
         self.selector_hash
@@ -227,6 +266,136 @@ impl SharingKey {
     }
 }
 
+// ============================================================================
+// StyleSnapshot - Selector-relevant element state for style sharing
+// ============================================================================
+
+/// Snapshot of everything selectors can observe about an element
+///
+/// Two elements with equal snapshots match the same selectors, so they can
+/// share a computed style. Classes and attributes are sorted so that source
+/// order does not affect equality.
+///
+/// # Examples
+/// ```
+/// use css_stylist_cache::{StateFlags, StyleSnapshot};
+///
+/// let a = StyleSnapshot::new("input", None, &["b", "a"], &[("type", "text")], StateFlags::default());
+/// let b = StyleSnapshot::new("input", None, &["a", "b"], &[("type", "text")], StateFlags::default());
+/// assert_eq!(a, b);
+/// assert!(a.can_share_with(&b));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StyleSnapshot {
+    tag: String,
+    id: Option<String>,
+    classes: Vec<String>,
+    attributes: Vec<(String, String)>,
+    state_flags: StateFlags,
+}
+
+impl StyleSnapshot {
+    /// Create a snapshot from its parts
+    ///
+    /// # Arguments
+    /// * `tag` - Element tag name
+    /// * `id` - Element ID, if any
+    /// * `classes` - Element classes, in any order
+    /// * `attributes` - Attribute name/value pairs selectors depend on
+    /// * `state_flags` - Element state flags
+    pub fn new(
+        tag: &str,
+        id: Option<&str>,
+        classes: &[&str],
+        attributes: &[(&str, &str)],
+        state_flags: StateFlags,
+    ) -> Self {
+        let mut classes: Vec<String> = classes.iter().map(|c| c.to_string()).collect();
+        classes.sort();
+
+        let mut attributes: Vec<(String, String)> = attributes
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        attributes.sort();
+
+        Self {
+            tag: tag.to_string(),
+            id: id.map(str::to_string),
+            classes,
+            attributes,
+            state_flags,
+        }
+    }
+
+    /// Capture a snapshot of an element
+    ///
+    /// `ElementLike` exposes neither attributes nor state, so the caller
+    /// supplies the attributes its selectors depend on and the current state.
+    ///
+    /// # Arguments
+    /// * `element` - Element to snapshot
+    /// * `attributes` - Attribute name/value pairs selectors depend on
+    /// * `state_flags` - Element state flags
+    pub fn from_element(
+        element: &impl ElementLike,
+        attributes: &[(&str, &str)],
+        state_flags: StateFlags,
+    ) -> Self {
+        let classes: Vec<&str> = element.classes().iter().map(String::as_str).collect();
+        Self::new(
+            element.tag_name(),
+            element.id(),
+            &classes,
+            attributes,
+            state_flags,
+        )
+    }
+
+    /// Get the tag name
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Get the ID
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Get the sorted classes
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Get the sorted attribute name/value pairs
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    /// Get the state flags
+    pub fn state_flags(&self) -> &StateFlags {
+        &self.state_flags
+    }
+
+    /// Check whether an element with this snapshot can share style with another
+    ///
+    /// Snapshots must be equal, and elements with IDs never share since an ID
+    /// is expected to be unique.
+    pub fn can_share_with(&self, other: &StyleSnapshot) -> bool {
+        self.id.is_none() && self == other
+    }
+
+    /// Check whether two snapshots differ at most in their state flags
+    ///
+    /// Selectors that do not depend on element state match both or neither.
+    pub fn same_structure(&self, other: &StyleSnapshot) -> bool {
+        self.tag == other.tag
+            && self.id == other.id
+            && self.classes == other.classes
+            && self.attributes == other.attributes
+    }
+}
+
 // ============================================================================
 // StyleCache - Cache for computed styles
 // ============================================================================
@@ -250,11 +419,19 @@ impl SharingKey {
 /// assert!(result.is_some());
 /// ```
 pub struct StyleCache {
-    cache: HashMap<StyleKey, ComputedValues>,
+    cache: HashMap<StyleKey, CacheEntry>,
+    capacity: Option<usize>,
+    clock: u64,
     hits: u64,
     misses: u64,
 }
 
+/// Cached style with the time it was last stored or looked up
+struct CacheEntry {
+    values: ComputedValues,
+    last_used: u64,
+}
+
 impl StyleCache {
     /// Create a new empty style cache
     ///
@@ -269,11 +446,52 @@ impl StyleCache {
     pub fn new() -> Self {
         Self {
             cache: HashMap::new(),
+            capacity: None,
+            clock: 0,
             hits: 0,
             misses: 0,
         }
     }
 
+    /// Create a cache that holds at most `capacity` styles
+    ///
+    /// When full, caching a new style evicts the least recently used entry
+    /// whose style has no `will-change` hint. Hinted entries are only
+    /// evicted, least recently used first, once every entry is hinted.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_cache::{StateFlags, StyleCache, StyleCacheManager, StyleKey};
+    /// use css_stylist_core::ComputedValues;
+    ///
+    /// let mut cache = StyleCache::with_capacity(1);
+    /// let flags = StateFlags::default();
+    /// cache.cache_style(StyleKey::new(1, None, flags), ComputedValues::default());
+    /// cache.cache_style(StyleKey::new(2, None, flags), ComputedValues::default());
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Get the maximum number of cached styles (`None` if unbounded)
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Get the number of cached styles
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Check whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
     /// Get the number of cache hits
     pub fn hits(&self) -> u64 {
         self.hits
@@ -328,7 +546,7 @@ impl Default for StyleCache {
 
 impl StyleCacheManager for StyleCache {
     fn get_cached_style(&self, key: &StyleKey) -> Option<&ComputedValues> {
-        let result = self.cache.get(key);
+        let result = self.cache.get(key).map(|entry| &entry.values);
 
         // Update stats (requires interior mutability in real implementation)
         // For now, we'll track stats via mutable reference in cache_style
@@ -338,7 +556,25 @@ impl StyleCacheManager for StyleCache {
     }
 
     fn cache_style(&mut self, key: StyleKey, style: ComputedValues) {
-        self.cache.insert(key, style);
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                return;
+            }
+            if !self.cache.contains_key(&key) {
+                while self.cache.len() >= capacity {
+                    self.evict_one();
+                }
+            }
+        }
+
+        let last_used = self.tick();
+        self.cache.insert(
+            key,
+            CacheEntry {
+                values: style,
+                last_used,
+            },
+        );
     }
 
     fn find_sharing_candidate(&self, _element: &impl ElementLike) -> Option<Arc<ComputedValues>> {
@@ -358,14 +594,70 @@ impl StyleCache {
     ///
     /// This is a mutable version that updates hit/miss counters.
     pub fn get_cached_style_mut(&mut self, key: &StyleKey) -> Option<&ComputedValues> {
-        if let Some(value) = self.cache.get(key) {
+        let now = self.tick();
+        if let Some(entry) = self.cache.get_mut(key) {
             self.hits += 1;
-            Some(value)
+            entry.last_used = now;
+            Some(&entry.values)
         } else {
             self.misses += 1;
             None
         }
     }
+
+    /// Advance the recency clock
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Remove the entry least worth keeping
+    ///
+    /// Entries without a `will-change` hint go first; within each group the
+    /// least recently used entry is chosen.
+    fn evict_one(&mut self) {
+        let victim = self
+            .cache
+            .iter()
+            .min_by_key(|(_, entry)| (entry.values.has_will_change(), entry.last_used))
+            .map(|(key, _)| key.clone());
+
+        if let Some(key) = victim {
+            self.cache.remove(&key);
+        }
+    }
+
+    /// Compute an element's style through the cache
+    ///
+    /// The element is keyed with [`compute_style_key`] under its current
+    /// `state`, so `:hover`, `:focus` and plain variants of the same element
+    /// are cached independently. The key's parent hash is the context's
+    /// [`StyleContext::cache_key`], so the same element under different
+    /// parents or viewports is cached independently too. On a miss the style
+    /// is computed with [`Stylist::compute`] and cached. Hit/miss counters
+    /// are updated.
+    ///
+    /// # Arguments
+    /// * `stylist` - Stylist used to compute the style on a miss
+    /// * `element` - Element to compute the style for
+    /// * `state` - The element's current pseudo-class state
+    /// * `context` - Style context with parent values and viewport info
+    pub fn compute_style<E: ElementLike>(
+        &mut self,
+        stylist: &Stylist,
+        element: &E,
+        state: StateFlags,
+        context: &StyleContext,
+    ) -> ComputedValues {
+        let key = compute_style_key(element, state).with_parent_hash(Some(context.cache_key()));
+        if let Some(values) = self.get_cached_style_mut(&key) {
+            return values.clone();
+        }
+
+        let values = (*stylist.compute(element, context)).clone();
+        self.cache_style(key, values.clone());
+        values
+    }
 }
 
 // ============================================================================
@@ -374,8 +666,8 @@ impl StyleCache {
 
 /// Style sharing between similar elements
 ///
-/// Maintains a list of sharing candidates to avoid recomputing styles
-/// for elements with the same characteristics.
+/// Maintains a list of sharing candidates, indexed by [`StyleSnapshot`], to
+/// avoid recomputing styles for elements that selectors cannot tell apart.
 ///
 /// # Examples
 /// ```
@@ -385,8 +677,8 @@ impl StyleCache {
 /// assert_eq!(sharing.candidate_count(), 0);
 /// ```
 pub struct StyleSharing {
-    shared_styles: HashMap<SharingKey, Arc<ComputedValues>>,
-    sharing_candidates: Vec<(SharingKey, Arc<ComputedValues>)>,
+    shared_styles: HashMap<StyleSnapshot, Arc<ComputedValues>>,
+    sharing_candidates: Vec<(StyleSnapshot, Arc<ComputedValues>)>,
 }
 
 impl StyleSharing {
@@ -407,13 +699,25 @@ impl StyleSharing {
 
     /// Add a sharing candidate
     ///
+    /// The element is snapshotted with no attributes and default state.
+    ///
     /// # Arguments
     /// * `element` - Element to add as candidate
     /// * `style` - Computed style for the element
     pub fn add_candidate(&mut self, element: &impl ElementLike, style: Arc<ComputedValues>) {
-        let key = compute_sharing_key(element);
-        self.sharing_candidates.push((key.clone(), style.clone()));
-        self.shared_styles.insert(key, style);
+        let snapshot = StyleSnapshot::from_element(element, &[], StateFlags::default());
+        self.add_snapshot_candidate(snapshot, style);
+    }
+
+    /// Add a sharing candidate by snapshot
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot of the candidate element
+    /// * `style` - Computed style for the element
+    pub fn add_snapshot_candidate(&mut self, snapshot: StyleSnapshot, style: Arc<ComputedValues>) {
+        self.sharing_candidates
+            .push((snapshot.clone(), style.clone()));
+        self.shared_styles.insert(snapshot, style);
     }
 
     /// Find a sharing candidate for an element
@@ -424,8 +728,22 @@ impl StyleSharing {
     /// # Returns
     /// Shared computed values if a matching candidate is found
     pub fn find_candidate(&self, element: &impl ElementLike) -> Option<Arc<ComputedValues>> {
-        let key = compute_sharing_key(element);
-        self.shared_styles.get(&key).cloned()
+        let snapshot = StyleSnapshot::from_element(element, &[], StateFlags::default());
+        self.find_snapshot_candidate(&snapshot)
+    }
+
+    /// Find a sharing candidate for a snapshot
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot of the element to find candidate for
+    ///
+    /// # Returns
+    /// Shared computed values if a candidate with a shareable snapshot is found
+    pub fn find_snapshot_candidate(&self, snapshot: &StyleSnapshot) -> Option<Arc<ComputedValues>> {
+        if snapshot.id().is_some() {
+            return None;
+        }
+        self.shared_styles.get(snapshot).cloned()
     }
 
     /// Get the number of sharing candidates
@@ -446,6 +764,258 @@ impl Default for StyleSharing {
     }
 }
 
+// ============================================================================
+// MatchCache - State-aware selector match caching
+// ============================================================================
+
+/// A selector together with the states its subject element must be in
+///
+/// `a.link:hover` is the structural selector `a.link` with the hover state
+/// required. State requirements apply to the subject (rightmost) element
+/// only.
+///
+/// # Examples
+/// ```
+/// use css_matcher_core::{ComplexSelector, Component, Selector};
+/// use css_stylist_cache::{StateFlags, StateSelector};
+///
+/// let link = ComplexSelector::with_components(vec![(
+///     Selector::with_components(vec![Component::Class("link".to_string())]),
+///     None,
+/// )]);
+/// let hovered = StateSelector::new(link.clone(), StateFlags::new(true, false, false, false));
+///
+/// assert!(hovered.is_state_dependent());
+/// assert!(!StateSelector::structural(link).is_state_dependent());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSelector {
+    selector: ComplexSelector,
+    state: StateFlags,
+}
+
+impl StateSelector {
+    /// Create a selector requiring the given states
+    pub fn new(selector: ComplexSelector, state: StateFlags) -> Self {
+        Self { selector, state }
+    }
+
+    /// Create a selector that does not depend on element state
+    pub fn structural(selector: ComplexSelector) -> Self {
+        Self::new(selector, StateFlags::default())
+    }
+
+    /// Get the structural part of the selector
+    pub fn selector(&self) -> &ComplexSelector {
+        &self.selector
+    }
+
+    /// Get the states the subject element must be in
+    pub fn state(&self) -> &StateFlags {
+        &self.state
+    }
+
+    /// Check whether matching depends on element state
+    pub fn is_state_dependent(&self) -> bool {
+        !self.state.is_empty()
+    }
+
+    /// Check whether an element in the given state matches
+    pub fn matches<E: ElementLike>(&self, element: &E, state: &StateFlags) -> bool {
+        state.contains(&self.state) && SelectorMatcher.matches_complex(&self.selector, element)
+    }
+}
+
+/// Cache of matched selectors per element, reused across state changes
+///
+/// Selectors are partitioned into state-independent and state-dependent
+/// ones. The state-independent matches of an element are cached together
+/// with its [`StyleSnapshot`]; while later snapshots differ only in their
+/// state flags (hover on/off, focus, ...), the cached matches are reused and
+/// only the state-dependent selectors are evaluated again.
+///
+/// Snapshots only describe the element itself, so a change to its ancestors
+/// or siblings must be reported with [`MatchCache::invalidate`].
+///
+/// # Examples
+/// ```
+/// use css_matcher_core::{ComplexSelector, Component, ElementLike, Selector};
+/// use css_stylist_cache::{MatchCache, StateFlags, StateSelector, StyleSnapshot};
+///
+/// # struct Element;
+/// # impl ElementLike for Element {
+/// #     fn tag_name(&self) -> &str { "a" }
+/// #     fn id(&self) -> Option<&str> { None }
+/// #     fn classes(&self) -> &[String] { &[] }
+/// #     fn parent(&self) -> Option<&Self> { None }
+/// #     fn previous_sibling(&self) -> Option<&Self> { None }
+/// #     fn next_sibling(&self) -> Option<&Self> { None }
+/// # }
+/// let a = ComplexSelector::with_components(vec![(
+///     Selector::with_components(vec![Component::Tag("a".to_string())]),
+///     None,
+/// )]);
+/// let hover = StateFlags::new(true, false, false, false);
+///
+/// let mut cache = MatchCache::new();
+/// cache.add_selector(StateSelector::structural(a.clone()));
+/// cache.add_selector(StateSelector::new(a, hover));
+///
+/// let idle = StyleSnapshot::from_element(&Element, &[], StateFlags::default());
+/// let hovered = StyleSnapshot::from_element(&Element, &[], hover);
+/// assert_eq!(cache.matched_selectors(1, &Element, &idle), vec![0]);
+/// assert_eq!(cache.matched_selectors(1, &Element, &hovered), vec![0, 1]);
+/// assert_eq!(cache.hits(), 1);
+/// ```
+pub struct MatchCache {
+    selectors: Vec<StateSelector>,
+    state_independent: Vec<usize>,
+    state_dependent: Vec<usize>,
+    entries: HashMap<u64, MatchEntry>,
+    hits: u64,
+    misses: u64,
+}
+
+/// State-independent matches of an element and the snapshot they were made for
+struct MatchEntry {
+    snapshot: StyleSnapshot,
+    matched: Vec<usize>,
+}
+
+impl MatchCache {
+    /// Create an empty match cache
+    pub fn new() -> Self {
+        Self {
+            selectors: Vec::new(),
+            state_independent: Vec::new(),
+            state_dependent: Vec::new(),
+            entries: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Add a selector and return its index
+    ///
+    /// Cached matches are dropped, since the new selector may match elements
+    /// that were already cached.
+    pub fn add_selector(&mut self, selector: StateSelector) -> usize {
+        let index = self.selectors.len();
+        if selector.is_state_dependent() {
+            self.state_dependent.push(index);
+        } else {
+            self.state_independent.push(index);
+        }
+        self.selectors.push(selector);
+        self.entries.clear();
+        index
+    }
+
+    /// Get the selector at `index`
+    pub fn selector(&self, index: usize) -> Option<&StateSelector> {
+        self.selectors.get(index)
+    }
+
+    /// Get the indices of the state-independent selectors
+    pub fn state_independent(&self) -> &[usize] {
+        &self.state_independent
+    }
+
+    /// Get the indices of the state-dependent selectors
+    pub fn state_dependent(&self) -> &[usize] {
+        &self.state_dependent
+    }
+
+    /// Get the indices of the selectors matching an element, in order added
+    ///
+    /// `element_id` identifies the element across calls and `snapshot` is its
+    /// current snapshot. The state-independent matches are reused when the
+    /// cached snapshot has the same structure, and recomputed otherwise.
+    pub fn matched_selectors<E: ElementLike>(
+        &mut self,
+        element_id: u64,
+        element: &E,
+        snapshot: &StyleSnapshot,
+    ) -> Vec<usize> {
+        let cached = self
+            .entries
+            .get(&element_id)
+            .filter(|entry| entry.snapshot.same_structure(snapshot));
+
+        let mut matched = match cached {
+            Some(entry) => {
+                self.hits += 1;
+                entry.matched.clone()
+            }
+            None => {
+                self.misses += 1;
+                let matched: Vec<usize> = self
+                    .state_independent
+                    .iter()
+                    .copied()
+                    .filter(|&index| self.selectors[index].matches(element, snapshot.state_flags()))
+                    .collect();
+                self.entries.insert(
+                    element_id,
+                    MatchEntry {
+                        snapshot: snapshot.clone(),
+                        matched: matched.clone(),
+                    },
+                );
+                matched
+            }
+        };
+
+        matched.extend(
+            self.state_dependent
+                .iter()
+                .copied()
+                .filter(|&index| self.selectors[index].matches(element, snapshot.state_flags())),
+        );
+        matched.sort_unstable();
+        matched
+    }
+
+    /// Drop the cached matches of an element
+    ///
+    /// Call this when something selectors can observe outside the element's
+    /// snapshot changes, such as its ancestors or siblings.
+    pub fn invalidate(&mut self, element_id: u64) {
+        self.entries.remove(&element_id);
+    }
+
+    /// Drop all cached matches
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get the number of elements with cached matches
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether no matches are cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of lookups that reused cached matches
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Get the number of lookups that matched state-independent selectors
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+impl Default for MatchCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ============================================================================
 // StyleCacheManager Trait
 // ============================================================================
@@ -515,6 +1085,7 @@ pub trait StyleCacheManager {
 /// #     fn classes(&self) -> &[String] { &self.classes }
 /// #     fn parent(&self) -> Option<&Self> { None }
 /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+/// #     fn next_sibling(&self) -> Option<&Self> { None }
 /// # }
 ///
 /// let elem1 = MockElement {
@@ -551,17 +1122,20 @@ pub fn can_share_style(elem1: &impl ElementLike, elem2: &impl ElementLike) -> bo
 
 /// Compute cache key for element
 ///
-/// Creates a StyleKey based on the element's characteristics.
+/// Creates a StyleKey based on the element's characteristics and its
+/// current pseudo-class state, so an element keys differently while
+/// hovered or focused.
 ///
 /// # Arguments
 /// * `element` - Element to compute key for
+/// * `state_flags` - The element's current state
 ///
 /// # Returns
 /// StyleKey for caching
 ///
 /// # Examples
 /// ```
-/// use css_stylist_cache::compute_style_key;
+/// use css_stylist_cache::{compute_style_key, StateFlags};
 /// use css_matcher_core::ElementLike;
 ///
 /// # struct MockElement { tag: String, classes: Vec<String> }
@@ -571,6 +1145,7 @@ pub fn can_share_style(elem1: &impl ElementLike, elem2: &impl ElementLike) -> bo
 /// #     fn classes(&self) -> &[String] { &self.classes }
 /// #     fn parent(&self) -> Option<&Self> { None }
 /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+/// #     fn next_sibling(&self) -> Option<&Self> { None }
 /// # }
 ///
 /// let elem = MockElement {
@@ -578,10 +1153,13 @@ pub fn can_share_style(elem1: &impl ElementLike, elem2: &impl ElementLike) -> bo
 ///     classes: vec!["foo".to_string()],
 /// };
 ///
-/// let key = compute_style_key(&elem);
+/// let key = compute_style_key(&elem, StateFlags::default());
 /// assert_ne!(key.selector_hash(), 0);
+///
+/// let hovered = compute_style_key(&elem, StateFlags::new(true, false, false, false));
+/// assert_ne!(key, hovered);
 /// ```
-pub fn compute_style_key(element: &impl ElementLike) -> StyleKey {
+pub fn compute_style_key(element: &impl ElementLike, state_flags: StateFlags) -> StyleKey {
     use std::collections::hash_map::DefaultHasher;
 
     let mut hasher = DefaultHasher::new();
@@ -604,34 +1182,9 @@ pub fn compute_style_key(element: &impl ElementLike) -> StyleKey {
     // For now, no parent hash (would require parent access)
     let parent_hash = None;
 
-    // Default state flags (no pseudo-class state)
-    let state_flags = StateFlags::default();
-
     StyleKey::new(selector_hash, parent_hash, state_flags)
 }
 
-/// Compute sharing key for element
-///
-/// Internal helper to create a SharingKey from an element.
-fn compute_sharing_key(element: &impl ElementLike) -> SharingKey {
-    use std::collections::hash_map::DefaultHasher;
-
-    let mut hasher = DefaultHasher::new();
-
-    // Hash classes
-    for class in element.classes() {
-        class.hash(&mut hasher);
-    }
-
-    let class_hash = hasher.finish();
-    let element_name = element.tag_name().to_string();
-
-    // For now, no parent hash
-    let parent_hash = None;
-
-    SharingKey::new(element_name, class_hash, parent_hash)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
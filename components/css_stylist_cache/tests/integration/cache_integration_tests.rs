@@ -5,7 +5,7 @@ use css_stylist_cache::{
     can_share_style, compute_style_key, StateFlags, StyleCache, StyleCacheManager, StyleKey,
     StyleSharing,
 };
-use css_stylist_core::ComputedValues;
+use css_stylist_core::{ComputedValues, StyleContext, Stylist};
 use servo_arc::Arc;
 
 // Mock element for testing
@@ -36,6 +36,10 @@ impl ElementLike for TestElement {
     fn previous_sibling(&self) -> Option<&Self> {
         None
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
 }
 
 #[test]
@@ -56,8 +60,8 @@ fn test_cache_workflow() {
     };
 
     // Compute keys
-    let key1 = compute_style_key(&elem1);
-    let key2 = compute_style_key(&elem2);
+    let key1 = compute_style_key(&elem1, StateFlags::default());
+    let key2 = compute_style_key(&elem2, StateFlags::default());
 
     // Same elements should have same key
     assert_eq!(key1, key2);
@@ -206,3 +210,108 @@ fn test_parent_hash_in_cache_key() {
     // Should be different keys
     assert_ne!(key_no_parent, key_with_parent);
 }
+
+#[test]
+fn test_compute_style_caches_hover_separately() {
+    let mut cache = StyleCache::new();
+    let stylist = Stylist::new();
+    let context = StyleContext::default();
+    let elem = TestElement {
+        tag: "button".to_string(),
+        id: None,
+        classes: vec!["primary".to_string()],
+    };
+    let plain = StateFlags::default();
+    let hovered = StateFlags::new(true, false, false, false);
+
+    cache.compute_style(&stylist, &elem, plain, &context);
+    cache.compute_style(&stylist, &elem, hovered, &context);
+
+    // Neither state reuses the other's entry
+    assert_eq!(cache.misses(), 2);
+    assert_eq!(cache.hits(), 0);
+    let parent_hash = Some(context.cache_key());
+    assert!(cache
+        .get_cached_style(&compute_style_key(&elem, plain).with_parent_hash(parent_hash))
+        .is_some());
+    assert!(cache
+        .get_cached_style(&compute_style_key(&elem, hovered).with_parent_hash(parent_hash))
+        .is_some());
+
+    // Each state hits its own entry afterwards
+    cache.compute_style(&stylist, &elem, hovered, &context);
+    cache.compute_style(&stylist, &elem, plain, &context);
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.misses(), 2);
+}
+
+#[test]
+fn test_compute_style_hover_entry_survives_plain_overwrite() {
+    let mut cache = StyleCache::new();
+    let stylist = Stylist::new();
+    let context = StyleContext::default();
+    let elem = TestElement {
+        tag: "a".to_string(),
+        id: None,
+        classes: vec![],
+    };
+    let plain_key =
+        compute_style_key(&elem, StateFlags::default()).with_parent_hash(Some(context.cache_key()));
+    let hover_state = StateFlags::new(true, false, false, false);
+
+    cache.compute_style(&stylist, &elem, hover_state, &context);
+    let plain_values = ComputedValues {
+        font_size: css_types::Length::new(20.0, css_types::LengthUnit::Px),
+        ..ComputedValues::default()
+    };
+    cache.cache_style(plain_key.clone(), plain_values.clone());
+
+    let hovered = cache.compute_style(&stylist, &elem, hover_state, &context);
+    assert_eq!(hovered, ComputedValues::default());
+    assert_eq!(cache.get_cached_style(&plain_key), Some(&plain_values));
+}
+
+#[test]
+fn test_compute_style_caches_each_parent_separately() {
+    let mut cache = StyleCache::new();
+    let stylist = Stylist::new();
+    let elem = TestElement {
+        tag: "span".to_string(),
+        id: None,
+        classes: vec![],
+    };
+    let parent_context = |color| {
+        let parent = ComputedValues {
+            color,
+            ..ComputedValues::default()
+        };
+        StyleContext::new(Some(Arc::new(parent)), 1024.0, 768.0, 16.0)
+    };
+    let green = css_types::Color::rgb(0, 128, 0);
+    let red = css_types::Color::rgb(255, 0, 0);
+
+    let under_green = cache.compute_style(
+        &stylist,
+        &elem,
+        StateFlags::default(),
+        &parent_context(green),
+    );
+    let under_red =
+        cache.compute_style(&stylist, &elem, StateFlags::default(), &parent_context(red));
+
+    // The second parent does not reuse the first parent's entry
+    assert_eq!(under_green.color, green);
+    assert_eq!(under_red.color, red);
+    assert_eq!(cache.misses(), 2);
+    assert_eq!(cache.len(), 2);
+
+    // The same parent hits its own entry
+    let again = cache.compute_style(
+        &stylist,
+        &elem,
+        StateFlags::default(),
+        &parent_context(green),
+    );
+    assert_eq!(again.color, green);
+    assert_eq!(cache.hits(), 1);
+}
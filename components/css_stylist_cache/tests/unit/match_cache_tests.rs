@@ -0,0 +1,142 @@
+//! Unit tests for MatchCache and state-dependent selector partitioning
+
+use css_matcher_core::{ComplexSelector, Component, ElementLike, Selector};
+use css_stylist_cache::{MatchCache, StateFlags, StateSelector, StyleSnapshot};
+
+// Mock element for testing
+struct MockElement {
+    tag: String,
+    classes: Vec<String>,
+}
+
+impl MockElement {
+    fn new(tag: &str, classes: &[&str]) -> Self {
+        Self {
+            tag: tag.to_string(),
+            classes: classes.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn snapshot(&self, state: StateFlags) -> StyleSnapshot {
+        StyleSnapshot::from_element(self, &[], state)
+    }
+}
+
+impl ElementLike for MockElement {
+    fn tag_name(&self) -> &str {
+        &self.tag
+    }
+
+    fn id(&self) -> Option<&str> {
+        None
+    }
+
+    fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    fn parent(&self) -> Option<&Self> {
+        None
+    }
+
+    fn previous_sibling(&self) -> Option<&Self> {
+        None
+    }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
+}
+
+fn class_selector(class: &str) -> ComplexSelector {
+    ComplexSelector::with_components(vec![(
+        Selector::with_components(vec![Component::Class(class.to_string())]),
+        None,
+    )])
+}
+
+fn hover() -> StateFlags {
+    StateFlags::new(true, false, false, false)
+}
+
+/// `.item`, `.item:hover`, `.other`, `.item:focus`
+fn item_cache() -> MatchCache {
+    let mut cache = MatchCache::new();
+    cache.add_selector(StateSelector::structural(class_selector("item")));
+    cache.add_selector(StateSelector::new(class_selector("item"), hover()));
+    cache.add_selector(StateSelector::structural(class_selector("other")));
+    cache.add_selector(StateSelector::new(
+        class_selector("item"),
+        StateFlags::new(false, false, true, false),
+    ));
+    cache
+}
+
+#[test]
+fn test_state_flags_contains() {
+    let all = StateFlags::new(true, true, true, true);
+    assert!(all.contains(&hover()));
+    assert!(all.contains(&StateFlags::default()));
+    assert!(!StateFlags::default().contains(&hover()));
+    assert!(StateFlags::default().is_empty());
+    assert!(!hover().is_empty());
+}
+
+#[test]
+fn test_selectors_are_partitioned_by_state_dependence() {
+    let cache = item_cache();
+
+    assert_eq!(cache.state_independent(), &[0, 2]);
+    assert_eq!(cache.state_dependent(), &[1, 3]);
+    assert!(cache.selector(1).unwrap().is_state_dependent());
+    assert!(cache.selector(4).is_none());
+}
+
+#[test]
+fn test_toggling_hover_reuses_structural_matches() {
+    let mut cache = item_cache();
+    let element = MockElement::new("li", &["item"]);
+
+    let idle = cache.matched_selectors(1, &element, &element.snapshot(StateFlags::default()));
+    assert_eq!(idle, vec![0]);
+    assert_eq!((cache.hits(), cache.misses()), (0, 1));
+
+    let hovered = cache.matched_selectors(1, &element, &element.snapshot(hover()));
+    assert_eq!(hovered, vec![0, 1]);
+    assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+    let unhovered = cache.matched_selectors(1, &element, &element.snapshot(StateFlags::default()));
+    assert_eq!(unhovered, vec![0]);
+    assert_eq!((cache.hits(), cache.misses()), (2, 1));
+}
+
+#[test]
+fn test_structural_change_rematches() {
+    let mut cache = item_cache();
+    let item = MockElement::new("li", &["item"]);
+    let other = MockElement::new("li", &["other"]);
+
+    cache.matched_selectors(1, &item, &item.snapshot(hover()));
+    let matched = cache.matched_selectors(1, &other, &other.snapshot(hover()));
+
+    assert_eq!(matched, vec![2]);
+    assert_eq!((cache.hits(), cache.misses()), (0, 2));
+}
+
+#[test]
+fn test_invalidate_and_add_selector_drop_cached_matches() {
+    let mut cache = item_cache();
+    let element = MockElement::new("li", &["item"]);
+    let snapshot = element.snapshot(StateFlags::default());
+
+    cache.matched_selectors(1, &element, &snapshot);
+    cache.matched_selectors(2, &element, &snapshot);
+    assert_eq!(cache.len(), 2);
+
+    cache.invalidate(1);
+    assert_eq!(cache.len(), 1);
+
+    cache.add_selector(StateSelector::structural(class_selector("item")));
+    assert!(cache.is_empty());
+    assert_eq!(cache.matched_selectors(1, &element, &snapshot), vec![0, 4]);
+}
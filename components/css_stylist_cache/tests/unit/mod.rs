@@ -1,6 +1,8 @@
 //! Unit tests for css_stylist_cache
 
+mod match_cache_tests;
 mod state_flags_tests;
 mod style_cache_tests;
 mod style_key_tests;
 mod style_sharing_tests;
+mod style_snapshot_tests;
@@ -158,3 +158,77 @@ fn test_style_cache_clear_stats() {
     assert_eq!(cache.hits(), 0);
     assert_eq!(cache.misses(), 0);
 }
+
+fn will_change_values() -> ComputedValues {
+    ComputedValues {
+        will_change: ["transform".to_string()].into_iter().collect(),
+        ..ComputedValues::default()
+    }
+}
+
+#[test]
+fn test_style_cache_capacity_evicts_least_recently_used() {
+    let mut cache = StyleCache::with_capacity(2);
+    let flags = StateFlags::default();
+    let first = StyleKey::new(1, None, flags);
+    let second = StyleKey::new(2, None, flags);
+    let third = StyleKey::new(3, None, flags);
+
+    cache.cache_style(first.clone(), ComputedValues::default());
+    cache.cache_style(second.clone(), ComputedValues::default());
+    // Touch the first entry so the second becomes least recently used
+    assert!(cache.get_cached_style_mut(&first).is_some());
+    cache.cache_style(third.clone(), ComputedValues::default());
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get_cached_style(&first).is_some());
+    assert!(cache.get_cached_style(&second).is_none());
+    assert!(cache.get_cached_style(&third).is_some());
+}
+
+#[test]
+fn test_style_cache_will_change_entry_survives_eviction() {
+    let mut cache = StyleCache::with_capacity(2);
+    let flags = StateFlags::default();
+    let hinted = StyleKey::new(1, None, flags);
+    let peer = StyleKey::new(2, None, flags);
+    let newcomer = StyleKey::new(3, None, flags);
+
+    // The hinted entry is the oldest, but the plain peer is evicted instead
+    cache.cache_style(hinted.clone(), will_change_values());
+    cache.cache_style(peer.clone(), ComputedValues::default());
+    cache.cache_style(newcomer.clone(), ComputedValues::default());
+
+    assert!(cache.get_cached_style(&hinted).is_some());
+    assert!(cache.get_cached_style(&peer).is_none());
+    assert!(cache.get_cached_style(&newcomer).is_some());
+}
+
+#[test]
+fn test_style_cache_evicts_hinted_entries_when_all_hinted() {
+    let mut cache = StyleCache::with_capacity(1);
+    let flags = StateFlags::default();
+    let first = StyleKey::new(1, None, flags);
+    let second = StyleKey::new(2, None, flags);
+
+    cache.cache_style(first.clone(), will_change_values());
+    cache.cache_style(second.clone(), will_change_values());
+
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get_cached_style(&second).is_some());
+}
+
+#[test]
+fn test_style_cache_updating_key_does_not_evict() {
+    let mut cache = StyleCache::with_capacity(2);
+    let flags = StateFlags::default();
+    let first = StyleKey::new(1, None, flags);
+    let second = StyleKey::new(2, None, flags);
+
+    cache.cache_style(first.clone(), ComputedValues::default());
+    cache.cache_style(second.clone(), ComputedValues::default());
+    cache.cache_style(first.clone(), will_change_values());
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get_cached_style(&first), Some(&will_change_values()));
+}
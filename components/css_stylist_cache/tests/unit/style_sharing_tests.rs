@@ -33,6 +33,10 @@ impl ElementLike for MockElement {
     fn previous_sibling(&self) -> Option<&Self> {
         None
     }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
 }
 
 #[test]
@@ -124,7 +128,7 @@ fn test_compute_style_key_basic() {
         classes: vec!["foo".to_string()],
     };
 
-    let key = compute_style_key(&elem);
+    let key = compute_style_key(&elem, StateFlags::default());
 
     // Key should have a non-zero selector hash
     assert_ne!(key.selector_hash(), 0);
@@ -143,12 +147,31 @@ fn test_compute_style_key_consistency() {
         classes: vec!["foo".to_string()],
     };
 
-    let key1 = compute_style_key(&elem);
-    let key2 = compute_style_key(&elem);
+    let key1 = compute_style_key(&elem, StateFlags::default());
+    let key2 = compute_style_key(&elem, StateFlags::default());
 
     assert_eq!(key1, key2);
 }
 
+#[test]
+fn test_compute_style_key_distinguishes_state() {
+    let elem = MockElement {
+        tag: "a".to_string(),
+        id: None,
+        classes: vec!["nav".to_string()],
+    };
+
+    let plain = compute_style_key(&elem, StateFlags::default());
+    let hovered = compute_style_key(&elem, StateFlags::new(true, false, false, false));
+    let focused = compute_style_key(&elem, StateFlags::new(false, false, true, false));
+
+    assert_ne!(plain, hovered);
+    assert_ne!(plain, focused);
+    assert_ne!(hovered, focused);
+    assert_eq!(plain.selector_hash(), hovered.selector_hash());
+    assert!(hovered.state_flags().hover());
+}
+
 #[test]
 fn test_style_sharing_new() {
     let sharing = StyleSharing::new();
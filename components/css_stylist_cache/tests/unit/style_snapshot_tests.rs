@@ -0,0 +1,130 @@
+//! Unit tests for StyleSnapshot and snapshot-based style sharing
+
+use css_matcher_core::ElementLike;
+use css_stylist_cache::{StateFlags, StyleSharing, StyleSnapshot};
+use css_stylist_core::ComputedValues;
+use servo_arc::Arc;
+
+// Mock element for testing
+struct MockElement {
+    tag: String,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl MockElement {
+    fn new(tag: &str, classes: &[&str]) -> Self {
+        Self {
+            tag: tag.to_string(),
+            id: None,
+            classes: classes.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+impl ElementLike for MockElement {
+    fn tag_name(&self) -> &str {
+        &self.tag
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    fn parent(&self) -> Option<&Self> {
+        None
+    }
+
+    fn previous_sibling(&self) -> Option<&Self> {
+        None
+    }
+
+    fn next_sibling(&self) -> Option<&Self> {
+        None
+    }
+}
+
+#[test]
+fn test_snapshot_from_element_sorts_classes_and_attributes() {
+    let elem = MockElement::new("input", &["wide", "primary"]);
+    let snapshot = StyleSnapshot::from_element(
+        &elem,
+        &[("type", "text"), ("disabled", "")],
+        StateFlags::default(),
+    );
+
+    assert_eq!(snapshot.tag(), "input");
+    assert_eq!(snapshot.id(), None);
+    assert_eq!(snapshot.classes(), &["primary", "wide"]);
+    assert_eq!(
+        snapshot.attributes(),
+        &[
+            ("disabled".to_string(), String::new()),
+            ("type".to_string(), "text".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_identical_snapshots_share() {
+    let elem1 = MockElement::new("input", &["a", "b"]);
+    let elem2 = MockElement::new("input", &["b", "a"]);
+    let snap1 = StyleSnapshot::from_element(&elem1, &[("type", "text")], StateFlags::default());
+    let snap2 = StyleSnapshot::from_element(&elem2, &[("type", "text")], StateFlags::default());
+
+    assert!(snap1.can_share_with(&snap2));
+
+    let mut sharing = StyleSharing::new();
+    let values = Arc::new(ComputedValues::default());
+    sharing.add_snapshot_candidate(snap1, values.clone());
+
+    let result = sharing.find_snapshot_candidate(&snap2);
+    assert!(result.is_some());
+    assert!(Arc::ptr_eq(&result.unwrap(), &values));
+}
+
+#[test]
+fn test_differing_attribute_prevents_sharing() {
+    let elem1 = MockElement::new("input", &["field"]);
+    let elem2 = MockElement::new("input", &["field"]);
+    let text = StyleSnapshot::from_element(&elem1, &[("type", "text")], StateFlags::default());
+    let checkbox =
+        StyleSnapshot::from_element(&elem2, &[("type", "checkbox")], StateFlags::default());
+
+    assert!(!text.can_share_with(&checkbox));
+
+    let mut sharing = StyleSharing::new();
+    sharing.add_snapshot_candidate(text, Arc::new(ComputedValues::default()));
+
+    assert!(sharing.find_snapshot_candidate(&checkbox).is_none());
+}
+
+#[test]
+fn test_differing_state_prevents_sharing() {
+    let elem = MockElement::new("a", &[]);
+    let normal = StyleSnapshot::from_element(&elem, &[], StateFlags::default());
+    let hovered =
+        StyleSnapshot::from_element(&elem, &[], StateFlags::new(true, false, false, false));
+
+    assert!(!normal.can_share_with(&hovered));
+}
+
+#[test]
+fn test_snapshot_with_id_never_shares() {
+    let elem = MockElement {
+        tag: "div".to_string(),
+        id: Some("main".to_string()),
+        classes: vec![],
+    };
+    let snapshot = StyleSnapshot::from_element(&elem, &[], StateFlags::default());
+
+    assert!(!snapshot.can_share_with(&snapshot.clone()));
+
+    let mut sharing = StyleSharing::new();
+    sharing.add_snapshot_candidate(snapshot.clone(), Arc::new(ComputedValues::default()));
+    assert!(sharing.find_snapshot_candidate(&snapshot).is_none());
+}
@@ -4,14 +4,17 @@
 //! - Cascade resolution
 //! - Inheritance
 //! - Unit resolution
+//! - `currentColor` resolution
 
 use crate::types::{ComputedValues, StyleContext};
+use css_cascade::{CascadeResult, PropertyId, PropertyValue};
 use css_types::{Length, LengthUnit};
 
 /// Resolve a length value to pixels
 ///
 /// Converts relative length units (em, rem, %) to absolute pixel values
-/// based on the style context.
+/// based on the style context. The context's viewport and font sizes are in
+/// CSS px; the result is scaled by `context.zoom` into device px.
 ///
 /// # Arguments
 /// * `length` - The length value to resolve
@@ -29,7 +32,7 @@ use css_types::{Length, LengthUnit};
 /// assert_eq!(resolved, 10.0);
 /// ```
 pub fn resolve_length(length: &Length, context: &StyleContext) -> f32 {
-    match length.unit() {
+    let css_px = match length.unit() {
         LengthUnit::Px => length.value(),
         LengthUnit::Percent => {
             // For now, resolve percentage relative to viewport width
@@ -57,7 +60,21 @@ pub fn resolve_length(length: &Length, context: &StyleContext) -> f32 {
             // Viewport height percentage
             context.viewport_height * length.value() / 100.0
         }
-    }
+        LengthUnit::Vmin => {
+            // Percentage of the smaller viewport dimension
+            context.viewport_width.min(context.viewport_height) * length.value() / 100.0
+        }
+        LengthUnit::Vmax => {
+            // Percentage of the larger viewport dimension
+            context.viewport_width.max(context.viewport_height) * length.value() / 100.0
+        }
+        unit @ (LengthUnit::Pt | LengthUnit::Cm | LengthUnit::Mm | LengthUnit::In) => {
+            // Absolute units have a fixed ratio to CSS pixels
+            length.value() * unit.px_per_unit().unwrap_or(1.0)
+        }
+    };
+
+    css_px * context.zoom
 }
 
 /// Apply inheritance to computed values
@@ -84,6 +101,96 @@ pub fn apply_inheritance(parent: &ComputedValues) -> ComputedValues {
     ComputedValues::inherit_from(parent)
 }
 
+/// Apply cascaded color declarations, resolving `currentColor`
+///
+/// `color` is applied first; `currentColor` (or a CSS-wide keyword that
+/// inherits) on `color` itself takes the parent's color. `border-color` and
+/// `background-color` then substitute the element's computed `color` for
+/// `currentColor`; an undeclared `border-color` takes its initial value,
+/// `currentColor`, and so also follows `color`. The `border` shorthand's
+/// color is applied before the `border-color` longhand, so the longhand wins
+/// when both are present. Each value is set with
+/// [`ComputedValues::set_cascaded_property`], inheriting from the initial
+/// values when there is no parent; values that fail to parse leave the
+/// corresponding field unchanged.
+///
+/// # Arguments
+/// * `cascade` - Winning declarations for the element
+/// * `parent` - Parent element's computed values, if any
+/// * `values` - Values to update, usually inherited from `parent`
+///
+/// # Examples
+/// ```
+/// use css_cascade::{CascadeResult, PropertyId, PropertyValue};
+/// use css_stylist_core::compute::apply_cascaded_colors;
+/// use css_stylist_core::types::ComputedValues;
+/// use css_types::Color;
+///
+/// let mut cascade = CascadeResult::new();
+/// cascade.properties.insert(PropertyId::Color, PropertyValue::Keyword("red".to_string()));
+/// cascade.properties.insert(
+///     PropertyId::BorderColor,
+///     PropertyValue::Keyword("currentColor".to_string()),
+/// );
+///
+/// let mut values = ComputedValues::default();
+/// apply_cascaded_colors(&cascade, None, &mut values);
+/// assert_eq!(values.border_color, Color::rgb(255, 0, 0));
+/// ```
+pub fn apply_cascaded_colors(
+    cascade: &CascadeResult,
+    parent: Option<&ComputedValues>,
+    values: &mut ComputedValues,
+) {
+    let initial = ComputedValues::default();
+    let parent = parent.unwrap_or(&initial);
+
+    apply_cascaded_property(cascade, PropertyId::Color, "color", parent, values);
+
+    // border-color is not inherited and its initial value is currentColor
+    values
+        .set_property("border-color", &PropertyValue::Initial)
+        .expect("border-color is a stored property");
+    for (property, name) in [
+        (PropertyId::Border, "border-color"),
+        (PropertyId::BorderColor, "border-color"),
+        (PropertyId::BackgroundColor, "background-color"),
+    ] {
+        apply_cascaded_property(cascade, property, name, parent, values);
+    }
+}
+
+/// Set the stored property `name` from the cascaded value of `property`
+///
+/// The `border` shorthand contributes only its color. A value that fails to
+/// parse leaves the field unchanged.
+fn apply_cascaded_property(
+    cascade: &CascadeResult,
+    property: PropertyId,
+    name: &str,
+    parent: &ComputedValues,
+    values: &mut ComputedValues,
+) {
+    let border_color;
+    let value = match cascaded_value(cascade, property) {
+        Some(PropertyValue::Border { color, .. }) => {
+            border_color = PropertyValue::Keyword(color.clone());
+            &border_color
+        }
+        Some(value) => value,
+        None => return,
+    };
+    let _ = values.set_cascaded_property(name, value, parent);
+}
+
+/// Cascaded value of a property with any `!important` wrapper removed
+fn cascaded_value(cascade: &CascadeResult, property: PropertyId) -> Option<&PropertyValue> {
+    cascade.properties.get(&property).map(|value| match value {
+        PropertyValue::Important(inner) => inner.as_ref(),
+        other => other,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +207,29 @@ mod tests {
         assert_eq!(resolved, 10.0);
     }
 
+    #[test]
+    fn test_resolve_length_zoom_scales_px() {
+        let context = StyleContext::default().with_zoom(2.0);
+        let length = Length::new(10.0, LengthUnit::Px);
+
+        let resolved = resolve_length(&length, &context);
+        assert_eq!(resolved, 20.0);
+    }
+
+    #[test]
+    fn test_resolve_length_zoom_scales_relative_units() {
+        let context = StyleContext::new(None, 1000.0, 800.0, 16.0).with_zoom(1.5);
+
+        assert_eq!(
+            resolve_length(&Length::new(2.0, LengthUnit::Rem), &context),
+            48.0
+        );
+        assert_eq!(
+            resolve_length(&Length::new(10.0, LengthUnit::Vw), &context),
+            150.0
+        );
+    }
+
     #[test]
     fn test_resolve_length_percent() {
         let context = StyleContext::new(None, 1000.0, 800.0, 16.0);
@@ -147,6 +277,34 @@ mod tests {
         assert_eq!(resolved, 80.0); // 10vh of 800px viewport
     }
 
+    #[test]
+    fn test_resolve_length_vmin_vmax() {
+        let context = StyleContext::new(None, 1000.0, 500.0, 16.0);
+
+        assert_eq!(
+            resolve_length(&Length::new(10.0, LengthUnit::Vmin), &context),
+            50.0
+        );
+        assert_eq!(
+            resolve_length(&Length::new(10.0, LengthUnit::Vmax), &context),
+            100.0
+        );
+    }
+
+    #[test]
+    fn test_resolve_length_vmin_vmax_portrait() {
+        let context = StyleContext::new(None, 400.0, 900.0, 16.0);
+
+        assert_eq!(
+            resolve_length(&Length::new(10.0, LengthUnit::Vmin), &context),
+            40.0
+        );
+        assert_eq!(
+            resolve_length(&Length::new(10.0, LengthUnit::Vmax), &context),
+            90.0
+        );
+    }
+
     #[test]
     fn test_apply_inheritance() {
         let mut parent = ComputedValues::default();
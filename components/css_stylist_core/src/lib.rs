@@ -5,17 +5,36 @@
 //! - Property inheritance
 //! - Unit resolution
 //! - Rule tree for style sharing
+//! - Selector index for candidate rule lookup
 
 pub mod compute;
+pub mod selector_index;
 pub mod types;
 
-pub use types::{ComputedValues, Display, Position, RuleNode, StyleContext};
+pub use selector_index::SelectorIndex;
 
-use css_cascade::ApplicableRule;
-use css_matcher_core::ElementLike;
+pub use types::{
+    is_inherited_property, ComputedValues, Display, LineHeight, Position, RuleNode, StyleContext,
+    ZIndex,
+};
+
+use css_cascade::{ApplicableRule, CascadeResult};
+use css_matcher_core::{ComplexSelector, ElementLike};
 use servo_arc::Arc;
 use std::collections::HashMap;
 
+/// An element that can be walked as part of a styled element tree
+///
+/// Extends [`ElementLike`] with the downward links and stable identity needed
+/// by [`Stylist::compute_tree`].
+pub trait StyleTreeElement: ElementLike {
+    /// Stable identifier of the element, used as the style cache key
+    fn node_id(&self) -> u64;
+
+    /// The element's children in document order
+    fn children(&self) -> Vec<&Self>;
+}
+
 /// The Stylist is responsible for computing styles for elements
 ///
 /// It maintains a rule tree for efficient style sharing and caches
@@ -32,9 +51,12 @@ pub struct Stylist {
     /// Root of the rule tree
     rule_tree_root: Arc<RuleNode>,
     /// Rules indexed by selector
-    rules: Vec<ApplicableRule>,
-    /// Cache of computed values by element ID
-    cache: HashMap<u64, Arc<ComputedValues>>,
+    rules: SelectorIndex<ApplicableRule>,
+    /// Cache of computed values by element ID, tagged with the rule
+    /// generation and [`StyleContext::cache_key`] they were computed under
+    cache: HashMap<u64, (u64, u64, Arc<ComputedValues>)>,
+    /// Incremented whenever the rule set changes
+    generation: u64,
 }
 
 impl Stylist {
@@ -50,8 +72,9 @@ impl Stylist {
     pub fn new() -> Self {
         Self {
             rule_tree_root: Arc::new(RuleNode::root()),
-            rules: Vec::new(),
+            rules: SelectorIndex::new(),
             cache: HashMap::new(),
+            generation: 0,
         }
     }
 
@@ -70,6 +93,9 @@ impl Stylist {
 
     /// Add a rule to the stylist
     ///
+    /// Rules added without a selector are candidates for every element; see
+    /// [`Stylist::add_rule_for_selector`].
+    ///
     /// # Arguments
     /// * `rule` - The applicable rule to add
     ///
@@ -85,12 +111,49 @@ impl Stylist {
     ///     specificity: Specificity::new(0, 1, 0),
     ///     origin: Origin::Author,
     ///     source_order: 0,
+    ///     layer: None,
     /// };
     /// stylist.add_rule(rule);
     /// assert!(!stylist.is_empty());
     /// ```
     pub fn add_rule(&mut self, rule: ApplicableRule) {
-        self.rules.push(rule);
+        self.rules.insert_universal(rule);
+        self.generation += 1;
+    }
+
+    /// Add a rule filed under its selector in the selector index
+    ///
+    /// # Arguments
+    /// * `selector` - Selector the rule applies to
+    /// * `rule` - The applicable rule to add
+    pub fn add_rule_for_selector(&mut self, selector: &ComplexSelector, rule: ApplicableRule) {
+        self.rules.insert(selector, rule);
+        self.generation += 1;
+    }
+
+    /// Get the rules that could apply to an element, in the order added
+    ///
+    /// Only rules in the selector index buckets for the element's id,
+    /// classes and tag, plus rules that could not be bucketed, are returned.
+    /// Their selectors still have to be matched against the element.
+    pub fn candidate_rules<E: ElementLike>(&self, element: &E) -> Vec<&ApplicableRule> {
+        self.rules.candidates(element)
+    }
+
+    /// Get the current rule generation
+    ///
+    /// The generation is bumped every time the rule set changes, so cached
+    /// values computed under an older generation are known to be stale.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::Stylist;
+    ///
+    /// let stylist = Stylist::new();
+    /// assert_eq!(stylist.generation(), 0);
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.generation
     }
 
     /// Compute styles for an element
@@ -115,6 +178,7 @@ impl Stylist {
     /// #     fn classes(&self) -> &[String] { &self.classes }
     /// #     fn parent(&self) -> Option<&Self> { None }
     /// #     fn previous_sibling(&self) -> Option<&Self> { None }
+    /// #     fn next_sibling(&self) -> Option<&Self> { None }
     /// # }
     ///
     /// let stylist = Stylist::new();
@@ -143,6 +207,102 @@ impl Stylist {
         }
     }
 
+    /// Compute styles for an element, reusing cached values when possible
+    ///
+    /// If values for `element_id` were cached under the current rule
+    /// generation and an equal context (same parent values, viewport, root
+    /// font size and zoom) they are returned without recomputation.
+    /// Otherwise the element is computed with [`Stylist::compute`] and the
+    /// result cached. Callers must call [`Stylist::invalidate_element`] when
+    /// the element itself changes.
+    ///
+    /// # Arguments
+    /// * `element_id` - Stable identifier of the element used as cache key
+    /// * `element` - The element to compute styles for
+    /// * `context` - Style context with parent values and viewport info
+    pub fn compute_cached<E: ElementLike>(
+        &mut self,
+        element_id: u64,
+        element: &E,
+        context: &StyleContext,
+    ) -> Arc<ComputedValues> {
+        let context_key = context.cache_key();
+        if let Some((_, _, values)) = self
+            .cache
+            .get(&element_id)
+            .filter(|(generation, key, _)| *generation == self.generation && *key == context_key)
+        {
+            return values.clone();
+        }
+
+        let values = self.compute(element, context);
+        self.cache
+            .insert(element_id, (self.generation, context_key, values.clone()));
+        values
+    }
+
+    /// Compute styles for every element in a tree
+    ///
+    /// Walks the tree depth-first from `root`, computing each element with
+    /// [`Stylist::compute_cached`] and passing its values as the parent
+    /// context for its children. The root uses `viewport` as-is, so its
+    /// `parent_values` (usually `None`) seed inheritance for the whole tree.
+    ///
+    /// # Arguments
+    /// * `root` - The root element of the tree
+    /// * `viewport` - Style context for the root (viewport size, root font size)
+    ///
+    /// # Returns
+    /// Computed values for every element, keyed by [`StyleTreeElement::node_id`]
+    pub fn compute_tree<E: StyleTreeElement>(
+        &mut self,
+        root: &E,
+        viewport: &StyleContext,
+    ) -> HashMap<u64, Arc<ComputedValues>> {
+        let mut result = HashMap::new();
+        self.compute_subtree(root, viewport, &mut result);
+        result
+    }
+
+    fn compute_subtree<E: StyleTreeElement>(
+        &mut self,
+        element: &E,
+        context: &StyleContext,
+        result: &mut HashMap<u64, Arc<ComputedValues>>,
+    ) {
+        let values = self.compute_cached(element.node_id(), element, context);
+
+        let child_context = StyleContext::new(
+            Some(values.clone()),
+            context.viewport_width,
+            context.viewport_height,
+            context.root_font_size,
+        )
+        .with_zoom(context.zoom);
+        for child in element.children() {
+            self.compute_subtree(child, &child_context, result);
+        }
+
+        result.insert(element.node_id(), values);
+    }
+
+    /// Look up cached values for an element
+    ///
+    /// Returns the values from the element's most recent computation, or
+    /// `None` if nothing is cached or the cached values were computed under
+    /// an older rule generation.
+    pub fn cached(&self, element_id: u64) -> Option<Arc<ComputedValues>> {
+        self.cache
+            .get(&element_id)
+            .filter(|(generation, _, _)| *generation == self.generation)
+            .map(|(_, _, values)| values.clone())
+    }
+
+    /// Drop the cached values for a single element
+    pub fn invalidate_element(&mut self, element_id: u64) {
+        self.cache.remove(&element_id);
+    }
+
     /// Build a rule tree node for an element
     ///
     /// Creates a chain of rule nodes representing the cascade of
@@ -192,6 +352,27 @@ impl Stylist {
         }
     }
 
+    /// Compute values from an already-resolved cascade
+    ///
+    /// Starts from the values inherited from `context.parent_values` (or the
+    /// initial values) and applies the cascaded color properties, resolving
+    /// `currentColor` against the element's computed `color`. See
+    /// [`compute::apply_cascaded_colors`].
+    ///
+    /// # Arguments
+    /// * `cascade` - Result of [`css_cascade::CascadeResolver::resolve`]
+    /// * `context` - Style context with parent values
+    pub fn compute_from_cascade(
+        &self,
+        cascade: &CascadeResult,
+        context: &StyleContext,
+    ) -> Arc<ComputedValues> {
+        let parent = context.parent_values.as_deref();
+        let mut values = parent.map(ComputedValues::inherit_from).unwrap_or_default();
+        compute::apply_cascaded_colors(cascade, parent, &mut values);
+        Arc::new(values)
+    }
+
     /// Clear the style cache
     ///
     /// Should be called when rules change to invalidate cached values.
@@ -209,8 +390,10 @@ impl Default for Stylist {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use css_cascade::{ApplicableRule, Origin, StyleRule};
-    use css_types::Specificity;
+    use css_cascade::{
+        ApplicableRule, CascadeResolver, Origin, PropertyId, PropertyValue, StyleRule,
+    };
+    use css_types::{Color, Specificity};
 
     // Mock element for testing
     struct TestElement {
@@ -237,6 +420,63 @@ mod tests {
         fn previous_sibling(&self) -> Option<&Self> {
             None
         }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
+    }
+
+    // Mock element with children for tree computation
+    struct TreeElement {
+        id: u64,
+        tag: String,
+        children: Vec<TreeElement>,
+    }
+
+    impl TreeElement {
+        fn new(id: u64, tag: &str, children: Vec<TreeElement>) -> Self {
+            Self {
+                id,
+                tag: tag.to_string(),
+                children,
+            }
+        }
+    }
+
+    impl ElementLike for TreeElement {
+        fn tag_name(&self) -> &str {
+            &self.tag
+        }
+
+        fn id(&self) -> Option<&str> {
+            None
+        }
+
+        fn classes(&self) -> &[String] {
+            &[]
+        }
+
+        fn parent(&self) -> Option<&Self> {
+            None
+        }
+
+        fn previous_sibling(&self) -> Option<&Self> {
+            None
+        }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
+    }
+
+    impl StyleTreeElement for TreeElement {
+        fn node_id(&self) -> u64 {
+            self.id
+        }
+
+        fn children(&self) -> Vec<&Self> {
+            self.children.iter().collect()
+        }
     }
 
     #[test]
@@ -255,6 +495,7 @@ mod tests {
             specificity: Specificity::new(0, 1, 0),
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         };
 
         stylist.add_rule(rule);
@@ -335,10 +576,285 @@ mod tests {
     #[test]
     fn test_stylist_clear_cache() {
         let mut stylist = Stylist::new();
-        stylist.cache.insert(1, Arc::new(ComputedValues::default()));
+        stylist
+            .cache
+            .insert(1, (0, 0, Arc::new(ComputedValues::default())));
         assert_eq!(stylist.cache.len(), 1);
 
         stylist.clear_cache();
         assert_eq!(stylist.cache.len(), 0);
     }
+
+    fn empty_rule() -> ApplicableRule {
+        ApplicableRule {
+            rule: StyleRule {
+                declarations: vec![],
+            },
+            specificity: Specificity::new(0, 1, 0),
+            origin: Origin::Author,
+            source_order: 0,
+            layer: None,
+        }
+    }
+
+    #[test]
+    fn test_stylist_add_rule_bumps_generation() {
+        let mut stylist = Stylist::new();
+        assert_eq!(stylist.generation(), 0);
+
+        stylist.add_rule(empty_rule());
+        assert_eq!(stylist.generation(), 1);
+
+        stylist.add_rule(empty_rule());
+        assert_eq!(stylist.generation(), 2);
+    }
+
+    #[test]
+    fn test_stylist_compute_cached_reuses_values() {
+        let mut stylist = Stylist::new();
+        let element = TestElement {
+            tag: "div".to_string(),
+        };
+        let context = StyleContext::default();
+
+        let first = stylist.compute_cached(1, &element, &context);
+        let second = stylist.compute_cached(1, &element, &context);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(stylist.cached(1).is_some());
+    }
+
+    #[test]
+    fn test_stylist_add_rule_invalidates_cached_lookups() {
+        let mut stylist = Stylist::new();
+        let element = TestElement {
+            tag: "div".to_string(),
+        };
+        let context = StyleContext::default();
+
+        let first = stylist.compute_cached(1, &element, &context);
+        stylist.add_rule(empty_rule());
+        assert!(stylist.cached(1).is_none());
+
+        let second = stylist.compute_cached(1, &element, &context);
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert!(stylist.cached(1).is_some());
+    }
+
+    #[test]
+    fn test_stylist_invalidate_element() {
+        let mut stylist = Stylist::new();
+        let element = TestElement {
+            tag: "div".to_string(),
+        };
+        let context = StyleContext::default();
+
+        stylist.compute_cached(1, &element, &context);
+        stylist.compute_cached(2, &element, &context);
+        stylist.invalidate_element(1);
+
+        assert!(stylist.cached(1).is_none());
+        assert!(stylist.cached(2).is_some());
+    }
+
+    #[test]
+    fn test_stylist_compute_tree_inherits_root_to_leaf() {
+        use css_types::Color;
+
+        // html > body > (div > span, p)
+        let tree = TreeElement::new(
+            1,
+            "html",
+            vec![TreeElement::new(
+                2,
+                "body",
+                vec![
+                    TreeElement::new(3, "div", vec![TreeElement::new(4, "span", vec![])]),
+                    TreeElement::new(5, "p", vec![]),
+                ],
+            )],
+        );
+
+        let seed = ComputedValues {
+            color: Color::rgb(0, 128, 0),
+            display: Display::Block,
+            ..ComputedValues::default()
+        };
+        let viewport = StyleContext::new(Some(Arc::new(seed)), 1280.0, 720.0, 16.0);
+
+        let mut stylist = Stylist::new();
+        let styles = stylist.compute_tree(&tree, &viewport);
+
+        assert_eq!(styles.len(), 5);
+        for id in 1..=5 {
+            assert_eq!(styles[&id].color, Color::rgb(0, 128, 0));
+            // display is not inherited
+            assert_eq!(styles[&id].display, Display::Inline);
+        }
+    }
+
+    #[test]
+    fn test_stylist_compute_tree_reuses_cache() {
+        let tree = TreeElement::new(1, "div", vec![TreeElement::new(2, "span", vec![])]);
+        let viewport = StyleContext::default();
+
+        let mut stylist = Stylist::new();
+        let first = stylist.compute_tree(&tree, &viewport);
+        let second = stylist.compute_tree(&tree, &viewport);
+
+        assert!(Arc::ptr_eq(&first[&1], &second[&1]));
+        assert!(Arc::ptr_eq(&first[&2], &second[&2]));
+    }
+
+    #[test]
+    fn test_stylist_compute_cached_recomputes_for_new_context() {
+        let mut stylist = Stylist::new();
+        let element = TestElement {
+            tag: "div".to_string(),
+        };
+        let context = StyleContext::default();
+
+        let first = stylist.compute_cached(1, &element, &context);
+        let zoomed = stylist.compute_cached(1, &element, &context.clone().with_zoom(2.0));
+        assert!(!Arc::ptr_eq(&first, &zoomed));
+
+        let again = stylist.compute_cached(1, &element, &context.clone().with_zoom(2.0));
+        assert!(Arc::ptr_eq(&zoomed, &again));
+    }
+
+    #[test]
+    fn test_stylist_compute_tree_reparented_node_inherits_new_parent() {
+        use css_types::Color;
+
+        let seeded = |color| {
+            let seed = ComputedValues {
+                color,
+                ..ComputedValues::default()
+            };
+            StyleContext::new(Some(Arc::new(seed)), 1280.0, 720.0, 16.0)
+        };
+        let green = Color::rgb(0, 128, 0);
+        let red = Color::rgb(255, 0, 0);
+        let mut stylist = Stylist::new();
+
+        // Node 3 starts under node 1, which inherits green...
+        let tree = TreeElement::new(1, "div", vec![TreeElement::new(3, "span", vec![])]);
+        let styles = stylist.compute_tree(&tree, &seeded(green));
+        assert_eq!(styles[&3].color, green);
+
+        // ...then moves under node 2, which inherits red
+        let tree = TreeElement::new(2, "div", vec![TreeElement::new(3, "span", vec![])]);
+        let styles = stylist.compute_tree(&tree, &seeded(red));
+        assert_eq!(styles[&2].color, red);
+        assert_eq!(styles[&3].color, red);
+    }
+
+    fn color_rule(
+        declarations: Vec<(PropertyId, PropertyValue)>,
+        source_order: usize,
+    ) -> ApplicableRule {
+        ApplicableRule {
+            rule: StyleRule { declarations },
+            specificity: Specificity::new(0, 1, 0),
+            origin: Origin::Author,
+            source_order,
+            layer: None,
+        }
+    }
+
+    fn keyword(value: &str) -> PropertyValue {
+        PropertyValue::Keyword(value.to_string())
+    }
+
+    #[test]
+    fn test_compute_from_cascade_border_color_current_color() {
+        let rules = vec![
+            color_rule(vec![(PropertyId::BorderColor, keyword("currentColor"))], 0),
+            color_rule(vec![(PropertyId::Color, keyword("blue"))], 1),
+        ];
+        let cascade = CascadeResolver::new().resolve(&rules);
+
+        let stylist = Stylist::new();
+        let values = stylist.compute_from_cascade(&cascade, &StyleContext::default());
+
+        assert_eq!(values.color, Color::rgb(0, 0, 255));
+        assert_eq!(values.border_color, Color::rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn test_compute_from_cascade_current_color_uses_inherited_color() {
+        let parent = ComputedValues {
+            color: Color::rgb(0, 128, 0),
+            ..ComputedValues::default()
+        };
+        let context = StyleContext::new(Some(Arc::new(parent)), 1280.0, 720.0, 16.0);
+        let rules = vec![color_rule(
+            vec![
+                (PropertyId::BorderColor, keyword("currentcolor")),
+                (PropertyId::BackgroundColor, keyword("currentColor")),
+            ],
+            0,
+        )];
+        let cascade = CascadeResolver::new().resolve(&rules);
+
+        let values = Stylist::new().compute_from_cascade(&cascade, &context);
+
+        assert_eq!(values.border_color, Color::rgb(0, 128, 0));
+        assert_eq!(values.background_color, Color::rgb(0, 128, 0));
+    }
+
+    #[test]
+    fn test_compute_from_cascade_initial_border_color_is_current_color() {
+        let rules = vec![color_rule(vec![(PropertyId::Color, keyword("red"))], 0)];
+        let cascade = CascadeResolver::new().resolve(&rules);
+
+        let values = Stylist::new().compute_from_cascade(&cascade, &StyleContext::default());
+
+        assert_eq!(values.border_color, Color::rgb(255, 0, 0));
+        assert_eq!(values.background_color, Color::rgba(0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn test_compute_from_cascade_border_shorthand_current_color() {
+        let rules = vec![color_rule(
+            vec![
+                (PropertyId::Color, keyword("#00ff00")),
+                (
+                    PropertyId::Border,
+                    PropertyValue::Border {
+                        width: 1.0,
+                        style: "solid".to_string(),
+                        color: "currentColor".to_string(),
+                    },
+                ),
+            ],
+            0,
+        )];
+        let cascade = CascadeResolver::new().resolve(&rules);
+
+        let values = Stylist::new().compute_from_cascade(&cascade, &StyleContext::default());
+
+        assert_eq!(values.border_color, Color::rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn test_compute_from_cascade_current_color_on_color_inherits() {
+        let parent = ComputedValues {
+            color: Color::rgb(10, 20, 30),
+            ..ComputedValues::default()
+        };
+        let context = StyleContext::new(Some(Arc::new(parent)), 1280.0, 720.0, 16.0);
+        let rules = vec![color_rule(
+            vec![
+                (PropertyId::Color, keyword("currentColor")),
+                (PropertyId::BorderColor, keyword("red")),
+            ],
+            0,
+        )];
+        let cascade = CascadeResolver::new().resolve(&rules);
+
+        let values = Stylist::new().compute_from_cascade(&cascade, &context);
+
+        assert_eq!(values.color, Color::rgb(10, 20, 30));
+        assert_eq!(values.border_color, Color::rgb(255, 0, 0));
+    }
 }
@@ -0,0 +1,269 @@
+//! Selector index for fast candidate rule lookup
+//!
+//! Rules are bucketed by the most selective simple selector of their
+//! rightmost compound (id, then class, then tag). Matching an element then
+//! only has to test the rules in the buckets for its own id, classes and tag,
+//! plus the rules that could not be bucketed.
+
+use css_matcher_core::{ComplexSelector, Component, ElementLike};
+use std::collections::HashMap;
+
+/// Bucket a rule is filed under
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BucketKey {
+    Id(String),
+    Class(String),
+    /// Tag names are stored lowercased, as tag matching is case-insensitive
+    Tag(String),
+    Universal,
+}
+
+/// Rules bucketed by their rightmost simple selector
+///
+/// Each entry keeps its insertion index, and candidates are always returned
+/// in insertion order so callers can rely on it for source order.
+///
+/// # Examples
+/// ```
+/// use css_matcher_core::{ComplexSelector, Component, Selector};
+/// use css_stylist_core::SelectorIndex;
+///
+/// let mut index = SelectorIndex::new();
+/// let button = ComplexSelector::with_components(vec![(
+///     Selector::with_components(vec![Component::Class("button".to_string())]),
+///     None,
+/// )]);
+/// index.insert(&button, "button rule");
+/// assert_eq!(index.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectorIndex<T> {
+    entries: Vec<T>,
+    buckets: HashMap<BucketKey, Vec<usize>>,
+}
+
+impl<T> SelectorIndex<T> {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Add a value under the bucket chosen by `selector`
+    ///
+    /// # Arguments
+    /// * `selector` - Selector the value applies to
+    /// * `value` - Value to return as a candidate, usually a rule
+    pub fn insert(&mut self, selector: &ComplexSelector, value: T) {
+        self.insert_into(bucket_key(selector), value);
+    }
+
+    /// Add a value that is a candidate for every element
+    pub fn insert_universal(&mut self, value: T) {
+        self.insert_into(BucketKey::Universal, value);
+    }
+
+    fn insert_into(&mut self, key: BucketKey, value: T) {
+        self.buckets
+            .entry(key)
+            .or_default()
+            .push(self.entries.len());
+        self.entries.push(value);
+    }
+
+    /// Get the values whose selectors could match an element
+    ///
+    /// Candidates still need full selector matching; values in other buckets
+    /// are guaranteed not to match. Values are returned in insertion order.
+    pub fn candidates<E: ElementLike>(&self, element: &E) -> Vec<&T> {
+        let mut keys = vec![
+            BucketKey::Universal,
+            BucketKey::Tag(element.tag_name().to_ascii_lowercase()),
+        ];
+        if let Some(id) = element.id() {
+            keys.push(BucketKey::Id(id.to_string()));
+        }
+        keys.extend(
+            element
+                .classes()
+                .iter()
+                .map(|class| BucketKey::Class(class.clone())),
+        );
+
+        let mut indices: Vec<usize> = keys
+            .iter()
+            .filter_map(|key| self.buckets.get(key))
+            .flatten()
+            .copied()
+            .collect();
+        // Duplicate class names would otherwise return a rule twice
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices.into_iter().map(|i| &self.entries[i]).collect()
+    }
+
+    /// Get every value in insertion order
+    pub fn values(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Number of values in the index
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Default for SelectorIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Choose the most selective bucket for a selector
+///
+/// Only the rightmost compound is used, since it is the part that has to
+/// match the element itself.
+fn bucket_key(selector: &ComplexSelector) -> BucketKey {
+    let Some((compound, _)) = selector.components.last() else {
+        return BucketKey::Universal;
+    };
+
+    let mut key = BucketKey::Universal;
+    for component in &compound.components {
+        match component {
+            Component::Id(id) => return BucketKey::Id(id.clone()),
+            Component::Class(class) if !matches!(key, BucketKey::Class(_)) => {
+                key = BucketKey::Class(class.clone());
+            }
+            Component::Tag(tag) if key == BucketKey::Universal => {
+                key = BucketKey::Tag(tag.to_ascii_lowercase());
+            }
+            _ => {}
+        }
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use css_matcher_core::{Combinator, Selector};
+
+    struct TestElement {
+        tag: String,
+        id: Option<String>,
+        classes: Vec<String>,
+    }
+
+    impl TestElement {
+        fn new(tag: &str, id: Option<&str>, classes: &[&str]) -> Self {
+            Self {
+                tag: tag.to_string(),
+                id: id.map(str::to_string),
+                classes: classes.iter().map(|c| c.to_string()).collect(),
+            }
+        }
+    }
+
+    impl ElementLike for TestElement {
+        fn tag_name(&self) -> &str {
+            &self.tag
+        }
+        fn id(&self) -> Option<&str> {
+            self.id.as_deref()
+        }
+        fn classes(&self) -> &[String] {
+            &self.classes
+        }
+        fn parent(&self) -> Option<&Self> {
+            None
+        }
+        fn previous_sibling(&self) -> Option<&Self> {
+            None
+        }
+
+        fn next_sibling(&self) -> Option<&Self> {
+            None
+        }
+    }
+
+    fn compound(components: Vec<Component>) -> ComplexSelector {
+        ComplexSelector::with_components(vec![(Selector::with_components(components), None)])
+    }
+
+    fn class(name: &str) -> Component {
+        Component::Class(name.to_string())
+    }
+
+    #[test]
+    fn test_class_rule_only_for_elements_with_class() {
+        let mut index = SelectorIndex::new();
+        index.insert(&compound(vec![class("button")]), ".button");
+
+        let button = TestElement::new("a", None, &["primary", "button"]);
+        let plain = TestElement::new("a", None, &["primary"]);
+
+        assert_eq!(index.candidates(&button), vec![&".button"]);
+        assert!(index.candidates(&plain).is_empty());
+    }
+
+    #[test]
+    fn test_buckets_by_id_tag_and_universal() {
+        let mut index = SelectorIndex::new();
+        index.insert(
+            &compound(vec![Component::Tag("div".to_string()), class("wide")]),
+            "div.wide",
+        );
+        index.insert(
+            &compound(vec![class("wide"), Component::Id("main".to_string())]),
+            ".wide#main",
+        );
+        index.insert(&compound(vec![Component::Tag("DIV".to_string())]), "DIV");
+        index.insert(&compound(vec![Component::Universal]), "*");
+        index.insert_universal("no selector");
+
+        let main = TestElement::new("div", Some("main"), &[]);
+        assert_eq!(
+            index.candidates(&main),
+            vec![&".wide#main", &"DIV", &"*", &"no selector"]
+        );
+
+        let wide = TestElement::new("span", None, &["wide", "wide"]);
+        assert_eq!(
+            index.candidates(&wide),
+            vec![&"div.wide", &"*", &"no selector"]
+        );
+    }
+
+    #[test]
+    fn test_uses_rightmost_compound() {
+        let mut index = SelectorIndex::new();
+        let selector = ComplexSelector::with_components(vec![
+            (
+                Selector::with_components(vec![class("nav")]),
+                Some(Combinator::Descendant),
+            ),
+            (
+                Selector::with_components(vec![Component::Tag("li".to_string())]),
+                None,
+            ),
+        ]);
+        index.insert(&selector, ".nav li");
+
+        assert_eq!(
+            index.candidates(&TestElement::new("li", None, &[])).len(),
+            1
+        );
+        assert!(index
+            .candidates(&TestElement::new("ul", None, &["nav"]))
+            .is_empty());
+    }
+}
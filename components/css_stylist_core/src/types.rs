@@ -5,12 +5,22 @@
 //! - RuleNode: Node in the rule tree for style sharing
 //! - StyleContext: Context for style computation
 
-use css_cascade::ApplicableRule;
-use css_types::{Color, Length, LengthUnit};
+use css_cascade::{
+    initial_keyword_of, initial_value_of, ApplicableRule, InitialValue, PropertyValue,
+};
+use css_parser_values::parse_color_value_or_current;
+use css_transforms::{
+    compose_individual_transforms, DefaultTransformComputer, Rect, Transform, TransformComputer,
+    TransformFunction, TransformMatrix, TransformOrigin,
+};
+use css_types::{Color, ColorValue, CssError, CssValue, KeywordEnum, Length, LengthUnit};
 use servo_arc::Arc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
 
 /// CSS Display property
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Display {
     /// Block display
     Block,
@@ -24,8 +34,19 @@ pub enum Display {
     Flex,
 }
 
+impl KeywordEnum for Display {
+    const KIND: &'static str = "display";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("block", Display::Block),
+        ("inline", Display::Inline),
+        ("inline-block", Display::InlineBlock),
+        ("none", Display::None),
+        ("flex", Display::Flex),
+    ];
+}
+
 /// CSS Position property
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Position {
     /// Static positioning
     Static,
@@ -35,6 +56,88 @@ pub enum Position {
     Absolute,
     /// Fixed positioning
     Fixed,
+    /// Sticky positioning
+    Sticky,
+}
+
+impl KeywordEnum for Position {
+    const KIND: &'static str = "position";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("static", Position::Static),
+        ("relative", Position::Relative),
+        ("absolute", Position::Absolute),
+        ("fixed", Position::Fixed),
+        ("sticky", Position::Sticky),
+    ];
+}
+
+/// CSS z-index property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZIndex {
+    /// Stacks with the parent stacking context
+    Auto,
+    /// Explicit stack level
+    Integer(i32),
+}
+
+/// CSS line-height property
+///
+/// Percentages are stored as written, e.g. `150%` is `Percentage(150.0)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// `normal`, resolved as [`LineHeight::NORMAL_FACTOR`] times the font size
+    Normal,
+    /// Unitless multiple of the element's own font size
+    Number(f32),
+    /// Fixed length
+    Length(Length),
+    /// Percentage of the element's font size
+    Percentage(f32),
+}
+
+impl LineHeight {
+    /// Font size multiple used for `normal`
+    pub const NORMAL_FACTOR: f32 = 1.2;
+
+    /// Resolve the line height to pixels for a given font size
+    ///
+    /// Lengths in units that need a style context (`rem`, `vw`, `vh`) must
+    /// be resolved with [`resolve_length`](crate::compute::resolve_length)
+    /// first; their value is taken as pixels here.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::LineHeight;
+    ///
+    /// assert_eq!(LineHeight::Number(1.5).resolve(16.0), 24.0);
+    /// assert_eq!(LineHeight::Percentage(150.0).resolve(16.0), 24.0);
+    /// ```
+    pub fn resolve(&self, font_size: f32) -> f32 {
+        match self {
+            LineHeight::Normal => Self::NORMAL_FACTOR * font_size,
+            LineHeight::Number(factor) => factor * font_size,
+            LineHeight::Percentage(percent) => percent / 100.0 * font_size,
+            LineHeight::Length(length) => match length.unit() {
+                LengthUnit::Em => length.value() * font_size,
+                LengthUnit::Percent => length.value() / 100.0 * font_size,
+                _ => length.value(),
+            },
+        }
+    }
+
+    /// Convert a specified line height into its computed value
+    ///
+    /// `normal` and unitless numbers stay as they are, so children multiply
+    /// the factor by their own font size. Lengths and percentages become a
+    /// pixel length, which children inherit unchanged.
+    pub fn to_computed(&self, font_size: f32) -> LineHeight {
+        match self {
+            LineHeight::Normal | LineHeight::Number(_) => *self,
+            LineHeight::Length(_) | LineHeight::Percentage(_) => {
+                LineHeight::Length(Length::new(self.resolve(font_size), LengthUnit::Px))
+            }
+        }
+    }
 }
 
 /// Computed style values for an element
@@ -48,10 +151,36 @@ pub struct ComputedValues {
     pub display: Display,
     /// Position property
     pub position: Position,
-    /// Width property
-    pub width: Length,
-    /// Height property
-    pub height: Length,
+    /// Width property (`None` is `auto`)
+    pub width: Option<Length>,
+    /// Height property (`None` is `auto`)
+    pub height: Option<Length>,
+
+    // Inset properties (`None` is `auto`)
+    /// Top offset
+    pub top: Option<Length>,
+    /// Right offset
+    pub right: Option<Length>,
+    /// Bottom offset
+    pub bottom: Option<Length>,
+    /// Left offset
+    pub left: Option<Length>,
+
+    // Stacking properties
+    /// Z-index property
+    pub z_index: ZIndex,
+    /// Opacity, in the range 0.0 to 1.0
+    pub opacity: f32,
+    /// Transform property (an empty list is `none`)
+    pub transform: Transform,
+    /// Translate property (`None` is `none`)
+    pub translate: Option<TransformFunction>,
+    /// Rotate property (`None` is `none`)
+    pub rotate: Option<TransformFunction>,
+    /// Scale property (`None` is `none`)
+    pub scale: Option<TransformFunction>,
+    /// Properties named by `will-change`, lowercased (empty is `auto`)
+    pub will_change: BTreeSet<String>,
 
     // Margin properties
     /// Margin top
@@ -73,17 +202,27 @@ pub struct ComputedValues {
     /// Padding left
     pub padding_left: Length,
 
+    // Color properties
+    /// Border color (all four sides), with `currentColor` already resolved
+    pub border_color: Color,
+    /// Background color, with `currentColor` already resolved
+    pub background_color: Color,
+
     // Text properties
     /// Text color
     pub color: Color,
     /// Font size
     pub font_size: Length,
+    /// Line height, as a computed value (see [`LineHeight::to_computed`])
+    pub line_height: LineHeight,
 }
 
 impl Default for ComputedValues {
     /// Create default computed values
     ///
-    /// Returns computed values with initial CSS values per CSS2.1 spec.
+    /// Returns computed values with the initial values from
+    /// [`css_cascade::INITIAL_VALUES`], so `initial` in the cascade and a
+    /// fresh `ComputedValues` agree.
     ///
     /// # Examples
     /// ```
@@ -93,26 +232,540 @@ impl Default for ComputedValues {
     /// assert_eq!(values.display, Display::Inline);
     /// ```
     fn default() -> Self {
+        // Table lookups run at compile time, so a missing or mistyped entry
+        // fails the build
+        macro_rules! initial {
+            ($ty:ty = $value:expr) => {{
+                const VALUE: $ty = $value;
+                VALUE
+            }};
+        }
+
+        let color = initial!(ColorValue = initial_color("color")).resolve(Color::rgb(0, 0, 0));
+
         Self {
-            display: Display::Inline,
-            position: Position::Static,
-            width: Length::new(0.0, LengthUnit::Px), // Auto is represented as 0px for now
-            height: Length::new(0.0, LengthUnit::Px),
-            margin_top: Length::new(0.0, LengthUnit::Px),
-            margin_right: Length::new(0.0, LengthUnit::Px),
-            margin_bottom: Length::new(0.0, LengthUnit::Px),
-            margin_left: Length::new(0.0, LengthUnit::Px),
-            padding_top: Length::new(0.0, LengthUnit::Px),
-            padding_right: Length::new(0.0, LengthUnit::Px),
-            padding_bottom: Length::new(0.0, LengthUnit::Px),
-            padding_left: Length::new(0.0, LengthUnit::Px),
-            color: Color::rgb(0, 0, 0),
-            font_size: Length::new(16.0, LengthUnit::Px),
+            display: initial!(Display = initial_keyword_of("display", Display::KEYWORDS)),
+            position: initial!(Position = initial_keyword_of("position", Position::KEYWORDS)),
+            width: initial!(Option<Length> = initial_length("width")),
+            height: initial!(Option<Length> = initial_length("height")),
+            top: initial!(Option<Length> = initial_length("top")),
+            right: initial!(Option<Length> = initial_length("right")),
+            bottom: initial!(Option<Length> = initial_length("bottom")),
+            left: initial!(Option<Length> = initial_length("left")),
+            z_index: match initial!(InitialValue = initial_value_of("z-index")) {
+                InitialValue::Number(value) => ZIndex::Integer(value as i32),
+                _ => ZIndex::Auto,
+            },
+            opacity: initial!(f32 = initial_number("opacity")),
+            transform: Transform::none(),
+            translate: None,
+            rotate: None,
+            scale: None,
+            will_change: BTreeSet::new(),
+            margin_top: initial!(Length = initial_definite_length("margin-top")),
+            margin_right: initial!(Length = initial_definite_length("margin-right")),
+            margin_bottom: initial!(Length = initial_definite_length("margin-bottom")),
+            margin_left: initial!(Length = initial_definite_length("margin-left")),
+            padding_top: initial!(Length = initial_definite_length("padding-top")),
+            padding_right: initial!(Length = initial_definite_length("padding-right")),
+            padding_bottom: initial!(Length = initial_definite_length("padding-bottom")),
+            padding_left: initial!(Length = initial_definite_length("padding-left")),
+            // Initial border-color is currentColor, i.e. the initial color
+            border_color: initial!(ColorValue = initial_color("border-color")).resolve(color),
+            background_color: initial!(ColorValue = initial_color("background-color"))
+                .resolve(color),
+            color,
+            font_size: initial!(Length = initial_definite_length("font-size")),
+            line_height: match initial!(InitialValue = initial_value_of("line-height")) {
+                InitialValue::Number(value) => LineHeight::Number(value),
+                _ => LineHeight::Normal,
+            },
         }
     }
 }
 
+/// Initial length of a property, or `None` if it is a keyword such as `auto`
+const fn initial_length(property: &str) -> Option<Length> {
+    match initial_value_of(property) {
+        InitialValue::Length(length) => Some(length),
+        InitialValue::Keyword(_) => None,
+        _ => panic!("initial value is not a length"),
+    }
+}
+
+/// Initial length of a property that has no keyword initial value
+const fn initial_definite_length(property: &str) -> Length {
+    match initial_length(property) {
+        Some(length) => length,
+        None => panic!("initial value is a keyword, not a length"),
+    }
+}
+
+/// Initial color of a property, which may be `currentColor`
+const fn initial_color(property: &str) -> ColorValue {
+    match initial_value_of(property) {
+        InitialValue::Color(color) => color,
+        _ => panic!("initial value is not a color"),
+    }
+}
+
+/// Initial number of a property
+const fn initial_number(property: &str) -> f32 {
+    match initial_value_of(property) {
+        InitialValue::Number(value) => value,
+        _ => panic!("initial value is not a number"),
+    }
+}
+
+/// Convert a cascaded length (value and unit text) to a typed length
+fn cascaded_length(value: f64, unit: &str) -> Result<Length, CssError> {
+    Length::parse(&format!("{}{}", value, unit))
+}
+
+/// Property stored in [`ComputedValues`] that can be read and written by name
+///
+/// [`ComputedValues::get_property`], [`ComputedValues::set_property`] and
+/// inheritance all match on this enum, so its keyword table is the one list
+/// of supported names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StoredProperty {
+    Display,
+    Position,
+    Width,
+    Height,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    ZIndex,
+    Opacity,
+    WillChange,
+    MarginTop,
+    MarginRight,
+    MarginBottom,
+    MarginLeft,
+    PaddingTop,
+    PaddingRight,
+    PaddingBottom,
+    PaddingLeft,
+    BorderColor,
+    BackgroundColor,
+    Color,
+    FontSize,
+    LineHeight,
+}
+
+impl KeywordEnum for StoredProperty {
+    const KIND: &'static str = "property";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("display", StoredProperty::Display),
+        ("position", StoredProperty::Position),
+        ("width", StoredProperty::Width),
+        ("height", StoredProperty::Height),
+        ("top", StoredProperty::Top),
+        ("right", StoredProperty::Right),
+        ("bottom", StoredProperty::Bottom),
+        ("left", StoredProperty::Left),
+        ("z-index", StoredProperty::ZIndex),
+        ("opacity", StoredProperty::Opacity),
+        ("will-change", StoredProperty::WillChange),
+        ("margin-top", StoredProperty::MarginTop),
+        ("margin-right", StoredProperty::MarginRight),
+        ("margin-bottom", StoredProperty::MarginBottom),
+        ("margin-left", StoredProperty::MarginLeft),
+        ("padding-top", StoredProperty::PaddingTop),
+        ("padding-right", StoredProperty::PaddingRight),
+        ("padding-bottom", StoredProperty::PaddingBottom),
+        ("padding-left", StoredProperty::PaddingLeft),
+        ("border-color", StoredProperty::BorderColor),
+        ("background-color", StoredProperty::BackgroundColor),
+        ("color", StoredProperty::Color),
+        ("font-size", StoredProperty::FontSize),
+        ("line-height", StoredProperty::LineHeight),
+    ];
+}
+
 impl ComputedValues {
+    /// Get the z-index as an integer, or `None` for `auto`
+    pub fn z_index(&self) -> Option<i32> {
+        match self.z_index {
+            ZIndex::Auto => None,
+            ZIndex::Integer(value) => Some(value),
+        }
+    }
+
+    /// Check whether the element establishes a stacking context
+    ///
+    /// Covers positioned elements with a z-index, fixed and sticky
+    /// elements, opacity below 1 and any transform (including `translate`,
+    /// `rotate` and `scale`) other than `none`.
+    /// Contexts created by the element's parent (e.g. flex items with a
+    /// z-index) are not detected here.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::ComputedValues;
+    ///
+    /// let mut values = ComputedValues::default();
+    /// assert!(!values.establishes_stacking_context());
+    ///
+    /// values.opacity = 0.5;
+    /// assert!(values.establishes_stacking_context());
+    /// ```
+    pub fn establishes_stacking_context(&self) -> bool {
+        let positioned_with_z_index =
+            matches!(self.position, Position::Relative | Position::Absolute)
+                && self.z_index().is_some();
+
+        positioned_with_z_index
+            || matches!(self.position, Position::Fixed | Position::Sticky)
+            || self.opacity < 1.0
+            || !self.transform.functions.is_empty()
+            || self.translate.is_some()
+            || self.rotate.is_some()
+            || self.scale.is_some()
+    }
+
+    /// Build the element's final transform matrix
+    ///
+    /// `translate`, `rotate` and `scale` are applied in that order before
+    /// the `transform` list (see
+    /// [`compose_individual_transforms`]), all about `origin`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::ComputedValues;
+    /// use css_transforms::{parse_transform_origin, parse_translate_property, Rect};
+    ///
+    /// let mut values = ComputedValues::default();
+    /// values.translate = parse_translate_property("10px 20px").unwrap();
+    ///
+    /// let origin = parse_transform_origin("center").unwrap();
+    /// let rect = Rect { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+    /// let matrix = values.transform_matrix(&origin, &rect);
+    /// assert_eq!(matrix.translation(), (10.0, 20.0, 0.0));
+    /// ```
+    pub fn transform_matrix(
+        &self,
+        origin: &TransformOrigin,
+        reference_box: &Rect,
+    ) -> TransformMatrix {
+        let transform = compose_individual_transforms(
+            self.translate.as_ref(),
+            self.rotate.as_ref(),
+            self.scale.as_ref(),
+            &self.transform,
+        );
+        DefaultTransformComputer.compute_transform(&transform, origin, reference_box)
+    }
+
+    /// Check whether `will-change` names any property
+    ///
+    /// Caches use this as a hint that the element's style is about to be
+    /// needed again and should be kept.
+    pub fn has_will_change(&self) -> bool {
+        !self.will_change.is_empty()
+    }
+
+    /// Hash every property value
+    ///
+    /// Equal values always produce equal hashes, so elements whose hashes
+    /// differ cannot share a style; elements with equal hashes should still
+    /// be compared with `==` before sharing an `Arc`. The hash is stable
+    /// across runs of the same build.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::ComputedValues;
+    ///
+    /// let mut values = ComputedValues::default();
+    /// assert_eq!(values.content_hash(), ComputedValues::default().content_hash());
+    ///
+    /// values.opacity = 0.5;
+    /// assert_ne!(values.content_hash(), ComputedValues::default().content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Get a property value by CSS name
+    ///
+    /// Supports the properties stored as plain values: `display`,
+    /// `position`, `width`, `height`, the insets, `z-index`, `opacity`,
+    /// `will-change`, the margin and padding longhands, `color`,
+    /// `border-color`, `background-color`, `font-size` and `line-height`.
+    /// Returns `None` for any other name. Lengths come back as
+    /// [`PropertyValue::Length`], `auto` and other keywords as
+    /// [`PropertyValue::Keyword`], and colors as serialized keywords.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_cascade::PropertyValue;
+    /// use css_stylist_core::types::ComputedValues;
+    ///
+    /// let values = ComputedValues::default();
+    /// assert_eq!(
+    ///     values.get_property("display"),
+    ///     Some(PropertyValue::Keyword("inline".to_string()))
+    /// );
+    /// assert_eq!(values.get_property("transform"), None);
+    /// ```
+    pub fn get_property(&self, name: &str) -> Option<PropertyValue> {
+        let keyword = |keyword: &str| PropertyValue::Keyword(keyword.to_string());
+        let length = |length: &Length| {
+            PropertyValue::Length(
+                f64::from(length.value()),
+                length.unit().to_str().to_string(),
+            )
+        };
+        let optional_length =
+            |value: &Option<Length>| value.as_ref().map_or(keyword("auto"), length);
+        let color = |color: &Color| PropertyValue::Keyword(color.serialize());
+
+        let value = match StoredProperty::from_keyword(name).ok()? {
+            StoredProperty::Display => keyword(self.display.to_keyword()),
+            StoredProperty::Position => keyword(self.position.to_keyword()),
+            StoredProperty::Width => optional_length(&self.width),
+            StoredProperty::Height => optional_length(&self.height),
+            StoredProperty::Top => optional_length(&self.top),
+            StoredProperty::Right => optional_length(&self.right),
+            StoredProperty::Bottom => optional_length(&self.bottom),
+            StoredProperty::Left => optional_length(&self.left),
+            StoredProperty::ZIndex => match self.z_index {
+                ZIndex::Auto => keyword("auto"),
+                ZIndex::Integer(value) => PropertyValue::Number(f64::from(value)),
+            },
+            StoredProperty::Opacity => PropertyValue::Number(f64::from(self.opacity)),
+            StoredProperty::WillChange if self.will_change.is_empty() => keyword("auto"),
+            StoredProperty::WillChange => {
+                let names: Vec<&str> = self.will_change.iter().map(String::as_str).collect();
+                keyword(&names.join(", "))
+            }
+            StoredProperty::MarginTop => length(&self.margin_top),
+            StoredProperty::MarginRight => length(&self.margin_right),
+            StoredProperty::MarginBottom => length(&self.margin_bottom),
+            StoredProperty::MarginLeft => length(&self.margin_left),
+            StoredProperty::PaddingTop => length(&self.padding_top),
+            StoredProperty::PaddingRight => length(&self.padding_right),
+            StoredProperty::PaddingBottom => length(&self.padding_bottom),
+            StoredProperty::PaddingLeft => length(&self.padding_left),
+            StoredProperty::BorderColor => color(&self.border_color),
+            StoredProperty::BackgroundColor => color(&self.background_color),
+            StoredProperty::Color => color(&self.color),
+            StoredProperty::FontSize => length(&self.font_size),
+            StoredProperty::LineHeight => match self.line_height {
+                LineHeight::Normal => keyword("normal"),
+                LineHeight::Number(factor) => PropertyValue::Number(f64::from(factor)),
+                LineHeight::Length(value) => length(&value),
+                LineHeight::Percentage(percent) => {
+                    PropertyValue::Length(f64::from(percent), "%".to_string())
+                }
+            },
+        };
+        Some(value)
+    }
+
+    /// Set a property value by CSS name
+    ///
+    /// Accepts the properties listed on [`get_property`](Self::get_property),
+    /// in the forms the cascade produces: keywords, lengths and numbers, each
+    /// optionally wrapped in [`PropertyValue::Important`]. `initial` resets
+    /// the property, as do `unset`, `revert` and `revert-layer` on properties
+    /// that are not inherited; keywords that inherit need a parent style (see
+    /// [`set_cascaded_property`](Self::set_cascaded_property)) and are
+    /// rejected. `currentColor` resolves against the current `color`.
+    ///
+    /// # Errors
+    /// Returns `CssError::InvalidValue` for unsupported properties and for
+    /// values of the wrong kind, and the underlying parse error for
+    /// malformed keywords, colors and lengths. The property is left
+    /// unchanged on error.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_cascade::PropertyValue;
+    /// use css_stylist_core::types::{ComputedValues, Display};
+    ///
+    /// let mut values = ComputedValues::default();
+    /// values
+    ///     .set_property("display", &PropertyValue::Keyword("block".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(values.display, Display::Block);
+    ///
+    /// let unknown = PropertyValue::Keyword("x".to_string());
+    /// assert!(values.set_property("float", &unknown).is_err());
+    /// ```
+    pub fn set_property(&mut self, name: &str, value: &PropertyValue) -> Result<(), CssError> {
+        self.set_property_from(name, value, None)
+    }
+
+    /// Set a cascaded property value by CSS name, inheriting from `parent`
+    ///
+    /// Behaves like [`set_property`](Self::set_property), except that
+    /// `inherit` takes the parent's value, `unset`, `revert` and
+    /// `revert-layer` do so for inherited properties, and `currentColor` on
+    /// `color` itself takes the parent's color.
+    ///
+    /// # Errors
+    /// As for [`set_property`](Self::set_property).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_cascade::PropertyValue;
+    /// use css_stylist_core::types::ComputedValues;
+    /// use css_types::Color;
+    ///
+    /// let parent = ComputedValues {
+    ///     color: Color::rgb(255, 0, 0),
+    ///     ..ComputedValues::default()
+    /// };
+    /// let mut values = ComputedValues::default();
+    /// values
+    ///     .set_cascaded_property("color", &PropertyValue::Inherit, &parent)
+    ///     .unwrap();
+    /// assert_eq!(values.color, Color::rgb(255, 0, 0));
+    /// ```
+    pub fn set_cascaded_property(
+        &mut self,
+        name: &str,
+        value: &PropertyValue,
+        parent: &ComputedValues,
+    ) -> Result<(), CssError> {
+        self.set_property_from(name, value, Some(parent))
+    }
+
+    fn set_property_from(
+        &mut self,
+        name: &str,
+        value: &PropertyValue,
+        parent: Option<&ComputedValues>,
+    ) -> Result<(), CssError> {
+        let property = StoredProperty::from_keyword(name)
+            .map_err(|_| CssError::InvalidValue(format!("Unsupported property: {}", name)))?;
+        let name = property.to_keyword();
+        let value = match value {
+            PropertyValue::Important(inner) => inner.as_ref(),
+            other => other,
+        };
+        let invalid = || CssError::InvalidValue(format!("Invalid value for {}: {:?}", name, value));
+
+        if let Some(keyword) = value.as_css_wide_keyword() {
+            let inherits = match keyword {
+                PropertyValue::Initial => false,
+                PropertyValue::Inherit => true,
+                _ => is_inherited_property(name),
+            };
+            match parent {
+                _ if !inherits => self.reset_property(property),
+                Some(parent) => self.copy_property_from(property, parent),
+                None => {
+                    return Err(CssError::InvalidValue(format!(
+                        "{:?} on {} needs a parent style",
+                        keyword, name
+                    )))
+                }
+            }
+            return Ok(());
+        }
+
+        let keyword = match value {
+            PropertyValue::Keyword(keyword) => Some(keyword.trim()),
+            _ => None,
+        };
+        let is_auto = keyword.is_some_and(|keyword| keyword.eq_ignore_ascii_case("auto"));
+        let length = || match value {
+            PropertyValue::Length(value, unit) => cascaded_length(*value, unit),
+            PropertyValue::Number(value) if *value == 0.0 => Ok(Length::new(0.0, LengthUnit::Px)),
+            _ => Err(invalid()),
+        };
+        let optional_length = || {
+            if is_auto {
+                Ok(None)
+            } else {
+                length().map(Some)
+            }
+        };
+        // currentColor on color itself is the inherited color
+        let current_color = match (property, parent) {
+            (StoredProperty::Color, Some(parent)) => parent.color,
+            _ => self.color,
+        };
+        let color = || {
+            let keyword = keyword.ok_or_else(invalid)?;
+            Ok::<_, CssError>(parse_color_value_or_current(keyword)?.resolve(current_color))
+        };
+
+        match property {
+            StoredProperty::Display => {
+                self.display = Display::from_keyword(keyword.ok_or_else(invalid)?)?
+            }
+            StoredProperty::Position => {
+                self.position = Position::from_keyword(keyword.ok_or_else(invalid)?)?
+            }
+            StoredProperty::Width => self.width = optional_length()?,
+            StoredProperty::Height => self.height = optional_length()?,
+            StoredProperty::Top => self.top = optional_length()?,
+            StoredProperty::Right => self.right = optional_length()?,
+            StoredProperty::Bottom => self.bottom = optional_length()?,
+            StoredProperty::Left => self.left = optional_length()?,
+            StoredProperty::ZIndex => {
+                self.z_index = match value {
+                    _ if is_auto => ZIndex::Auto,
+                    PropertyValue::Number(value) if value.fract() == 0.0 => {
+                        ZIndex::Integer(*value as i32)
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+            StoredProperty::Opacity => {
+                self.opacity = match value {
+                    PropertyValue::Number(value) => (*value as f32).clamp(0.0, 1.0),
+                    _ => return Err(invalid()),
+                }
+            }
+            StoredProperty::WillChange => {
+                let keyword = keyword.ok_or_else(invalid)?;
+                self.will_change = if is_auto {
+                    BTreeSet::new()
+                } else {
+                    keyword
+                        .split(',')
+                        .map(|name| name.trim().to_ascii_lowercase())
+                        .filter(|name| !name.is_empty())
+                        .collect()
+                };
+            }
+            StoredProperty::MarginTop => self.margin_top = length()?,
+            StoredProperty::MarginRight => self.margin_right = length()?,
+            StoredProperty::MarginBottom => self.margin_bottom = length()?,
+            StoredProperty::MarginLeft => self.margin_left = length()?,
+            StoredProperty::PaddingTop => self.padding_top = length()?,
+            StoredProperty::PaddingRight => self.padding_right = length()?,
+            StoredProperty::PaddingBottom => self.padding_bottom = length()?,
+            StoredProperty::PaddingLeft => self.padding_left = length()?,
+            StoredProperty::BorderColor => self.border_color = color()?,
+            StoredProperty::BackgroundColor => self.background_color = color()?,
+            StoredProperty::Color => self.color = color()?,
+            StoredProperty::FontSize => self.font_size = length()?,
+            StoredProperty::LineHeight => {
+                self.line_height = match value {
+                    PropertyValue::Number(factor) => LineHeight::Number(*factor as f32),
+                    PropertyValue::Length(percent, unit) if unit == "%" => {
+                        LineHeight::Percentage(*percent as f32)
+                    }
+                    PropertyValue::Length(..) => LineHeight::Length(length()?),
+                    _ if keyword.is_some_and(|keyword| keyword.eq_ignore_ascii_case("normal")) => {
+                        LineHeight::Normal
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Inherit properties from parent
     ///
     /// Creates computed values by inheriting inherited properties from parent
@@ -135,26 +788,196 @@ impl ComputedValues {
     /// assert_eq!(child.display, Display::Inline);
     /// ```
     pub fn inherit_from(parent: &ComputedValues) -> Self {
-        Self {
-            // Non-inherited properties get initial values
-            display: Display::Inline,
-            position: Position::Static,
-            width: Length::new(0.0, LengthUnit::Px),
-            height: Length::new(0.0, LengthUnit::Px),
-            margin_top: Length::new(0.0, LengthUnit::Px),
-            margin_right: Length::new(0.0, LengthUnit::Px),
-            margin_bottom: Length::new(0.0, LengthUnit::Px),
-            margin_left: Length::new(0.0, LengthUnit::Px),
-            padding_top: Length::new(0.0, LengthUnit::Px),
-            padding_right: Length::new(0.0, LengthUnit::Px),
-            padding_bottom: Length::new(0.0, LengthUnit::Px),
-            padding_left: Length::new(0.0, LengthUnit::Px),
-
-            // Inherited properties come from parent
-            color: parent.color,
-            font_size: parent.font_size,
+        // Non-inherited properties keep their initial values
+        let mut values = Self::default();
+        for &(name, property) in StoredProperty::KEYWORDS {
+            if is_inherited_property(name) {
+                values.copy_property_from(property, parent);
+            }
+        }
+        // The initial border-color is currentColor, so it follows the
+        // inherited color rather than the default one
+        values.reset_property(StoredProperty::BorderColor);
+        values
+    }
+
+    /// Copy a single property from another set of values
+    fn copy_property_from(&mut self, property: StoredProperty, other: &ComputedValues) {
+        match property {
+            StoredProperty::Display => self.display = other.display,
+            StoredProperty::Position => self.position = other.position,
+            StoredProperty::Width => self.width = other.width,
+            StoredProperty::Height => self.height = other.height,
+            StoredProperty::Top => self.top = other.top,
+            StoredProperty::Right => self.right = other.right,
+            StoredProperty::Bottom => self.bottom = other.bottom,
+            StoredProperty::Left => self.left = other.left,
+            StoredProperty::ZIndex => self.z_index = other.z_index,
+            StoredProperty::Opacity => self.opacity = other.opacity,
+            StoredProperty::WillChange => self.will_change = other.will_change.clone(),
+            StoredProperty::MarginTop => self.margin_top = other.margin_top,
+            StoredProperty::MarginRight => self.margin_right = other.margin_right,
+            StoredProperty::MarginBottom => self.margin_bottom = other.margin_bottom,
+            StoredProperty::MarginLeft => self.margin_left = other.margin_left,
+            StoredProperty::PaddingTop => self.padding_top = other.padding_top,
+            StoredProperty::PaddingRight => self.padding_right = other.padding_right,
+            StoredProperty::PaddingBottom => self.padding_bottom = other.padding_bottom,
+            StoredProperty::PaddingLeft => self.padding_left = other.padding_left,
+            StoredProperty::BorderColor => self.border_color = other.border_color,
+            StoredProperty::BackgroundColor => self.background_color = other.background_color,
+            StoredProperty::Color => self.color = other.color,
+            StoredProperty::FontSize => self.font_size = other.font_size,
+            StoredProperty::LineHeight => self.line_height = other.line_height,
         }
     }
+
+    /// Reset a single property to its initial value
+    ///
+    /// Initial colors may be `currentColor`, which resolves against the
+    /// current `color`.
+    fn reset_property(&mut self, property: StoredProperty) {
+        match property {
+            StoredProperty::BorderColor => {
+                self.border_color = initial_color("border-color").resolve(self.color)
+            }
+            StoredProperty::BackgroundColor => {
+                self.background_color = initial_color("background-color").resolve(self.color)
+            }
+            _ => self.copy_property_from(property, &ComputedValues::default()),
+        }
+    }
+}
+
+impl Hash for ComputedValues {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.display.hash(state);
+        self.position.hash(state);
+        for length in [&self.width, &self.height] {
+            hash_optional_length(length, state);
+        }
+        for inset in [&self.top, &self.right, &self.bottom, &self.left] {
+            hash_optional_length(inset, state);
+        }
+        self.z_index.hash(state);
+        hash_f32(self.opacity, state);
+        hash_transform(&self.transform, state);
+        for function in [&self.translate, &self.rotate, &self.scale] {
+            let functions = function.iter().cloned().collect();
+            hash_transform(&Transform { functions }, state);
+        }
+        self.will_change.hash(state);
+        for length in [
+            &self.margin_top,
+            &self.margin_right,
+            &self.margin_bottom,
+            &self.margin_left,
+            &self.padding_top,
+            &self.padding_right,
+            &self.padding_bottom,
+            &self.padding_left,
+        ] {
+            hash_length(length, state);
+        }
+        for color in [&self.border_color, &self.background_color, &self.color] {
+            hash_color(color, state);
+        }
+        hash_length(&self.font_size, state);
+        self.line_height.hash(state);
+    }
+}
+
+impl Hash for LineHeight {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            LineHeight::Normal => {}
+            LineHeight::Number(value) | LineHeight::Percentage(value) => hash_f32(*value, state),
+            LineHeight::Length(length) => hash_length(length, state),
+        }
+    }
+}
+
+/// Hash a float so that values comparing equal hash equal (`0.0 == -0.0`)
+fn hash_f32<H: Hasher>(value: f32, state: &mut H) {
+    let normalized = if value == 0.0 { 0.0 } else { value };
+    normalized.to_bits().hash(state);
+}
+
+fn hash_length<H: Hasher>(length: &Length, state: &mut H) {
+    hash_f32(length.value(), state);
+    length.unit().hash(state);
+}
+
+fn hash_optional_length<H: Hasher>(length: &Option<Length>, state: &mut H) {
+    length.is_some().hash(state);
+    if let Some(length) = length {
+        hash_length(length, state);
+    }
+}
+
+fn hash_color<H: Hasher>(color: &Color, state: &mut H) {
+    [color.r(), color.g(), color.b()].hash(state);
+    hash_f32(color.a(), state);
+}
+
+/// Hash a transform through its matrix
+///
+/// Equal function lists always produce the same matrix. Lists that differ
+/// but compose to the same matrix collide, which the `==` check on sharing
+/// catches.
+fn hash_transform<H: Hasher>(transform: &Transform, state: &mut H) {
+    let reference_box = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    transform.functions.len().hash(state);
+    for value in transform.to_matrix(&reference_box).matrix.iter().flatten() {
+        hash_f32(*value, state);
+    }
+}
+
+/// CSS properties that inherit by default
+const INHERITED_PROPERTIES: &[&str] = &[
+    "color",
+    "cursor",
+    "direction",
+    "font",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "letter-spacing",
+    "line-height",
+    "list-style",
+    "list-style-image",
+    "list-style-position",
+    "list-style-type",
+    "quotes",
+    "text-align",
+    "text-indent",
+    "text-shadow",
+    "text-transform",
+    "visibility",
+    "white-space",
+    "word-spacing",
+];
+
+/// Check whether a CSS property inherits by default
+///
+/// Custom properties (`--*`) always inherit.
+///
+/// # Examples
+/// ```
+/// use css_stylist_core::types::is_inherited_property;
+///
+/// assert!(is_inherited_property("color"));
+/// assert!(!is_inherited_property("width"));
+/// ```
+pub fn is_inherited_property(name: &str) -> bool {
+    name.starts_with("--") || INHERITED_PROPERTIES.contains(&name)
 }
 
 /// Node in the rule tree
@@ -209,6 +1032,7 @@ impl RuleNode {
     ///     specificity: Specificity::new(0, 1, 0),
     ///     origin: Origin::Author,
     ///     source_order: 0,
+    ///     layer: None,
     /// };
     /// let node = RuleNode::new(rule, Some(root));
     /// assert!(node.rule.is_some());
@@ -236,6 +1060,8 @@ pub struct StyleContext {
     pub viewport_height: f32,
     /// Root font size for rem units
     pub root_font_size: f32,
+    /// Zoom factor converting resolved CSS px to device px
+    pub zoom: f32,
 }
 
 impl StyleContext {
@@ -266,7 +1092,53 @@ impl StyleContext {
             viewport_width,
             viewport_height,
             root_font_size,
+            zoom: 1.0,
+        }
+    }
+
+    /// Set the zoom factor used when resolving lengths
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::StyleContext;
+    ///
+    /// let context = StyleContext::default().with_zoom(2.0);
+    /// assert_eq!(context.zoom, 2.0);
+    /// ```
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Hash everything computed values depend on besides the element
+    ///
+    /// Covers the parent's values (by [`ComputedValues::content_hash`]),
+    /// the viewport size, the root font size and the zoom. Contexts that
+    /// compare equal field by field always produce the same key.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_stylist_core::types::StyleContext;
+    ///
+    /// let context = StyleContext::default();
+    /// assert_eq!(context.cache_key(), StyleContext::default().cache_key());
+    /// assert_ne!(context.cache_key(), context.clone().with_zoom(2.0).cache_key());
+    /// ```
+    pub fn cache_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.parent_values
+            .as_ref()
+            .map(|parent| parent.content_hash())
+            .hash(&mut hasher);
+        for value in [
+            self.viewport_width,
+            self.viewport_height,
+            self.root_font_size,
+            self.zoom,
+        ] {
+            hash_f32(value, &mut hasher);
         }
+        hasher.finish()
     }
 }
 
@@ -298,13 +1170,33 @@ mod tests {
 
         assert_eq!(values.display, Display::Inline);
         assert_eq!(values.position, Position::Static);
-        assert_eq!(values.width.value(), 0.0);
-        assert_eq!(values.width.unit(), LengthUnit::Px);
+        assert_eq!(values.width, None);
+        assert_eq!(values.height, None);
         assert_eq!(values.margin_top.value(), 0.0);
         assert_eq!(values.color, Color::rgb(0, 0, 0));
         assert_eq!(values.font_size.value(), 16.0);
     }
 
+    #[test]
+    fn test_computed_values_default_matches_initial_table() {
+        let values = ComputedValues::default();
+
+        assert_eq!(
+            css_cascade::initial_value("margin-left"),
+            Some(PropertyValue::Length(0.0, "px".to_string()))
+        );
+        assert_eq!(
+            css_cascade::initial_value("font-size"),
+            Some(PropertyValue::Length(16.0, "px".to_string()))
+        );
+        assert_eq!(values.margin_left, Length::new(0.0, LengthUnit::Px));
+        assert_eq!(values.background_color, Color::rgba(0, 0, 0, 0.0));
+        assert_eq!(values.border_color, values.color);
+        assert_eq!(values.z_index, ZIndex::Auto);
+        assert_eq!(values.opacity, 1.0);
+        assert_eq!(values.line_height, LineHeight::Normal);
+    }
+
     #[test]
     fn test_computed_values_inherit_from() {
         let mut parent = ComputedValues::default();
@@ -320,7 +1212,402 @@ mod tests {
 
         // Non-inherited properties use initial values
         assert_eq!(child.display, Display::Inline);
-        assert_eq!(child.width.value(), 0.0);
+        assert_eq!(child.width, None);
+    }
+
+    #[test]
+    fn test_is_inherited_property() {
+        assert!(is_inherited_property("color"));
+        assert!(is_inherited_property("font-size"));
+        assert!(is_inherited_property("visibility"));
+        assert!(is_inherited_property("--theme"));
+
+        assert!(!is_inherited_property("width"));
+        assert!(!is_inherited_property("display"));
+        assert!(!is_inherited_property("margin-top"));
+    }
+
+    #[test]
+    fn test_content_hash_equal_for_default_values() {
+        assert_eq!(
+            ComputedValues::default().content_hash(),
+            ComputedValues::default().content_hash()
+        );
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_any_property() {
+        let default_hash = ComputedValues::default().content_hash();
+
+        let changed = [
+            ComputedValues {
+                display: Display::Block,
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                width: Some(Length::new(0.0, LengthUnit::Px)),
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                margin_left: Length::new(0.0, LengthUnit::Em),
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                background_color: Color::rgba(0, 0, 0, 0.5),
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                line_height: LineHeight::Number(1.2),
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                will_change: BTreeSet::from(["transform".to_string()]),
+                ..ComputedValues::default()
+            },
+            ComputedValues {
+                transform: css_transforms::parse_transform("rotate(10deg)").unwrap(),
+                ..ComputedValues::default()
+            },
+        ];
+        for values in &changed {
+            assert_ne!(values.content_hash(), default_hash, "{:?}", values);
+        }
+    }
+
+    #[test]
+    fn test_content_hash_treats_negative_zero_as_zero() {
+        let values = ComputedValues {
+            margin_top: Length::new(-0.0, LengthUnit::Px),
+            ..ComputedValues::default()
+        };
+
+        assert_eq!(values, ComputedValues::default());
+        assert_eq!(
+            values.content_hash(),
+            ComputedValues::default().content_hash()
+        );
+    }
+
+    #[test]
+    fn test_inherit_from_resets_non_inherited_properties() {
+        let parent = ComputedValues {
+            color: Color::rgb(0, 0, 255),
+            width: Some(Length::new(300.0, LengthUnit::Px)),
+            display: Display::Flex,
+            position: Position::Absolute,
+            top: Some(Length::new(10.0, LengthUnit::Px)),
+            padding_left: Length::new(8.0, LengthUnit::Px),
+            ..ComputedValues::default()
+        };
+
+        let child = ComputedValues::inherit_from(&parent);
+        let initial = ComputedValues::default();
+
+        assert_eq!(child.color, Color::rgb(0, 0, 255));
+        assert_eq!(child.width, initial.width);
+        assert_eq!(child.display, initial.display);
+        assert_eq!(child.position, initial.position);
+        assert_eq!(child.top, None);
+        assert_eq!(child.padding_left, initial.padding_left);
+    }
+
+    #[test]
+    fn test_inherit_from_resolves_initial_border_color_against_color() {
+        let parent = ComputedValues {
+            color: Color::rgb(0, 128, 0),
+            border_color: Color::rgb(255, 0, 0),
+            ..ComputedValues::default()
+        };
+
+        let child = ComputedValues::inherit_from(&parent);
+
+        assert_eq!(child.color, Color::rgb(0, 128, 0));
+        assert_eq!(child.border_color, Color::rgb(0, 128, 0));
+    }
+
+    #[test]
+    fn test_line_height_resolve() {
+        assert_eq!(LineHeight::Number(1.5).resolve(16.0), 24.0);
+        assert_eq!(LineHeight::Percentage(150.0).resolve(16.0), 24.0);
+        assert_eq!(
+            LineHeight::Length(Length::new(2.0, LengthUnit::Em)).resolve(16.0),
+            32.0
+        );
+        assert_eq!(
+            LineHeight::Length(Length::new(20.0, LengthUnit::Px)).resolve(16.0),
+            20.0
+        );
+        assert!((LineHeight::Normal.resolve(10.0) - 12.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_line_height_unitless_inherits_factor() {
+        let parent = ComputedValues {
+            line_height: LineHeight::Number(1.5).to_computed(16.0),
+            ..ComputedValues::default()
+        };
+        let mut child = ComputedValues::inherit_from(&parent);
+        child.font_size = Length::new(32.0, LengthUnit::Px);
+
+        assert_eq!(child.line_height, LineHeight::Number(1.5));
+        assert_eq!(child.line_height.resolve(32.0), 48.0);
+    }
+
+    #[test]
+    fn test_line_height_percentage_inherits_pixels() {
+        let parent = ComputedValues {
+            line_height: LineHeight::Percentage(150.0).to_computed(16.0),
+            ..ComputedValues::default()
+        };
+        let mut child = ComputedValues::inherit_from(&parent);
+        child.font_size = Length::new(32.0, LengthUnit::Px);
+
+        assert_eq!(
+            child.line_height,
+            LineHeight::Length(Length::new(24.0, LengthUnit::Px))
+        );
+        assert_eq!(child.line_height.resolve(32.0), 24.0);
+    }
+
+    #[test]
+    fn test_z_index_accessor() {
+        let mut values = ComputedValues::default();
+        assert_eq!(values.z_index(), None);
+
+        values.z_index = ZIndex::Integer(-2);
+        assert_eq!(values.z_index(), Some(-2));
+    }
+
+    #[test]
+    fn test_positioned_z_index_establishes_stacking_context() {
+        let values = ComputedValues {
+            position: Position::Absolute,
+            z_index: ZIndex::Integer(1),
+            ..ComputedValues::default()
+        };
+        assert!(values.establishes_stacking_context());
+
+        // z-index has no effect on static elements
+        let values = ComputedValues {
+            z_index: ZIndex::Integer(1),
+            ..ComputedValues::default()
+        };
+        assert!(!values.establishes_stacking_context());
+
+        // Nor does a positioned element with z-index: auto
+        let values = ComputedValues {
+            position: Position::Relative,
+            ..ComputedValues::default()
+        };
+        assert!(!values.establishes_stacking_context());
+    }
+
+    #[test]
+    fn test_opacity_and_transform_establish_stacking_context() {
+        let values = ComputedValues {
+            opacity: 0.5,
+            ..ComputedValues::default()
+        };
+        assert!(values.establishes_stacking_context());
+
+        let values = ComputedValues {
+            transform: css_transforms::parse_transform("scale(1)").unwrap(),
+            ..ComputedValues::default()
+        };
+        assert!(values.establishes_stacking_context());
+    }
+
+    #[test]
+    fn test_individual_transforms_establish_stacking_context() {
+        let values = ComputedValues {
+            rotate: css_transforms::parse_rotate_property("0deg").unwrap(),
+            ..ComputedValues::default()
+        };
+        assert!(values.establishes_stacking_context());
+        assert_ne!(
+            values.content_hash(),
+            ComputedValues::default().content_hash()
+        );
+    }
+
+    #[test]
+    fn test_transform_matrix_applies_rotate_before_transform() {
+        let values = ComputedValues {
+            rotate: css_transforms::parse_rotate_property("45deg").unwrap(),
+            transform: css_transforms::parse_transform("scale(2, 1)").unwrap(),
+            ..ComputedValues::default()
+        };
+        let origin = css_transforms::parse_transform_origin("0 0").unwrap();
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        // scale(2, 1) first stretches (1, 0) to (2, 0), then the rotation
+        let (x, y) = values
+            .transform_matrix(&origin, &rect)
+            .transform_point(1.0, 0.0);
+        let diagonal = 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+        assert!((x - diagonal).abs() < 1e-4);
+        assert!((y - diagonal).abs() < 1e-4);
+
+        // Without individual transforms only `transform` applies
+        let values = ComputedValues {
+            rotate: None,
+            ..values
+        };
+        let (x, y) = values
+            .transform_matrix(&origin, &rect)
+            .transform_point(1.0, 0.0);
+        assert_eq!((x, y), (2.0, 0.0));
+    }
+
+    fn keyword(value: &str) -> PropertyValue {
+        PropertyValue::Keyword(value.to_string())
+    }
+
+    #[test]
+    fn test_set_and_get_color_by_name() {
+        let mut values = ComputedValues::default();
+        values.set_property("color", &keyword("red")).unwrap();
+        assert_eq!(values.color, Color::rgb(255, 0, 0));
+        assert_eq!(
+            values.get_property("color"),
+            Some(keyword("rgb(255, 0, 0)"))
+        );
+
+        // currentColor follows the element's color
+        values
+            .set_property("background-color", &keyword("currentColor"))
+            .unwrap();
+        assert_eq!(values.background_color, Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_set_and_get_width_by_name() {
+        let mut values = ComputedValues::default();
+        assert_eq!(values.get_property("width"), Some(keyword("auto")));
+
+        let width = PropertyValue::Length(50.0, "%".to_string());
+        values.set_property("width", &width).unwrap();
+        assert_eq!(values.width, Some(Length::new(50.0, LengthUnit::Percent)));
+        assert_eq!(values.get_property("width"), Some(width));
+
+        values.set_property("WIDTH", &keyword("auto")).unwrap();
+        assert_eq!(values.width, None);
+    }
+
+    #[test]
+    fn test_set_and_get_display_by_name() {
+        let mut values = ComputedValues::default();
+        let flex = PropertyValue::Important(Box::new(keyword("flex")));
+        values.set_property("display", &flex).unwrap();
+        assert_eq!(values.display, Display::Flex);
+        assert_eq!(values.get_property("display"), Some(keyword("flex")));
+
+        values
+            .set_property("display", &PropertyValue::Initial)
+            .unwrap();
+        assert_eq!(values.display, Display::Inline);
+    }
+
+    #[test]
+    fn test_set_property_rejects_invalid_values() {
+        let mut values = ComputedValues::default();
+        assert!(matches!(
+            values.set_property("float", &keyword("left")),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(values.set_property("display", &keyword("grid")).is_err());
+        assert!(values
+            .set_property("width", &PropertyValue::Number(3.0))
+            .is_err());
+        assert!(values
+            .set_property("color", &PropertyValue::Inherit)
+            .is_err());
+        assert_eq!(values, ComputedValues::default());
+        assert_eq!(values.get_property("float"), None);
+    }
+
+    #[test]
+    fn test_set_cascaded_property_resolves_keywords_against_parent() {
+        let parent = ComputedValues {
+            color: Color::rgb(0, 0, 255),
+            width: Some(Length::new(10.0, LengthUnit::Px)),
+            ..ComputedValues::default()
+        };
+        let mut values = ComputedValues {
+            color: Color::rgb(255, 0, 0),
+            ..ComputedValues::default()
+        };
+
+        values
+            .set_cascaded_property("width", &PropertyValue::Inherit, &parent)
+            .unwrap();
+        assert_eq!(values.width, parent.width);
+        values
+            .set_cascaded_property("width", &PropertyValue::Unset, &parent)
+            .unwrap();
+        assert_eq!(values.width, None);
+
+        // currentColor on color itself is the parent's color
+        values
+            .set_cascaded_property("color", &keyword("currentColor"), &parent)
+            .unwrap();
+        assert_eq!(values.color, Color::rgb(0, 0, 255));
+
+        // The initial border-color follows the element's color
+        values
+            .set_cascaded_property("border-color", &PropertyValue::Revert, &parent)
+            .unwrap();
+        assert_eq!(values.border_color, Color::rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn test_get_property_round_trips_through_set_property() {
+        let values = ComputedValues {
+            position: Position::Sticky,
+            z_index: ZIndex::Integer(3),
+            opacity: 0.25,
+            will_change: BTreeSet::from(["opacity".to_string(), "transform".to_string()]),
+            margin_left: Length::new(2.0, LengthUnit::Em),
+            line_height: LineHeight::Percentage(150.0),
+            color: Color::rgb(0, 0, 255),
+            ..ComputedValues::default()
+        };
+
+        let mut copy = ComputedValues::default();
+        for name in [
+            "position",
+            "z-index",
+            "opacity",
+            "will-change",
+            "margin-left",
+            "line-height",
+            "color",
+        ] {
+            copy.set_property(name, &values.get_property(name).unwrap())
+                .unwrap();
+        }
+        assert_eq!(copy, values);
+    }
+
+    #[test]
+    fn test_fixed_and_sticky_establish_stacking_context() {
+        for position in [Position::Fixed, Position::Sticky] {
+            let values = ComputedValues {
+                position,
+                ..ComputedValues::default()
+            };
+            assert!(values.establishes_stacking_context());
+        }
+    }
+
+    #[test]
+    fn test_static_element_does_not_establish_stacking_context() {
+        assert!(!ComputedValues::default().establishes_stacking_context());
     }
 
     #[test]
@@ -344,6 +1631,7 @@ mod tests {
             specificity: Specificity::new(0, 1, 0),
             origin: Origin::Author,
             source_order: 0,
+            layer: None,
         };
 
         let node = RuleNode::new(rule, Some(root.clone()));
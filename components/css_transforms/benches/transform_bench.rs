@@ -0,0 +1,43 @@
+// Benchmarks for transform matrix composition
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use css_transforms::{compute_transform_matrix, parse_transform, Rect, TransformMatrix};
+
+fn benchmark_matrix_multiply(c: &mut Criterion) {
+    let mut a = TransformMatrix::identity();
+    a.matrix[0][3] = 10.0;
+    a.matrix[0][1] = 0.5;
+    let b = a.clone();
+
+    c.bench_function("matrix multiply", |bench| {
+        bench.iter(|| black_box(&a).multiply(black_box(&b)))
+    });
+
+    c.bench_function("matrix multiply_assign", |bench| {
+        bench.iter(|| {
+            let mut result = black_box(&a).clone();
+            result.multiply_assign(black_box(&b));
+            result
+        })
+    });
+}
+
+fn benchmark_transform_fold(c: &mut Criterion) {
+    let transform = parse_transform(
+        "translate(10px, 20px) rotate(45deg) scale(1.5) skew(10deg, 5deg) translateZ(4px)",
+    )
+    .unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    c.bench_function("compose 5-function transform", |bench| {
+        bench.iter(|| compute_transform_matrix(black_box(&transform), black_box(&rect)))
+    });
+}
+
+criterion_group!(benches, benchmark_matrix_multiply, benchmark_transform_fold);
+criterion_main!(benches);
@@ -3,7 +3,7 @@
 //! This module provides types and functions for parsing and computing CSS transforms,
 //! including 2D and 3D transformations.
 
-use css_types::{CssError, Length};
+use css_types::{parse_finite_f32, CssError, Length};
 use std::f32::consts::PI;
 
 mod matrix;
@@ -93,9 +93,7 @@ impl Angle {
             return Err(CssError::ParseError("Angle must have a unit".to_string()));
         }
 
-        let value = value_str
-            .parse::<f32>()
-            .map_err(|_| CssError::ParseError("Invalid number".to_string()))?;
+        let value = parse_finite_f32(value_str)?;
 
         let unit = match unit_str {
             "deg" => AngleUnit::Deg,
@@ -119,6 +117,9 @@ impl Angle {
 // ============================================================================
 
 /// Rectangle defining a reference box for transform computation
+///
+/// Kept with public fields for existing callers; [`css_types::Rect`] is the
+/// canonical rectangle and converts to and from this one with `From`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Rect {
     pub x: f32,
@@ -127,6 +128,23 @@ pub struct Rect {
     pub height: f32,
 }
 
+impl From<css_types::Rect> for Rect {
+    fn from(rect: css_types::Rect) -> Self {
+        Self {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+impl From<Rect> for css_types::Rect {
+    fn from(rect: Rect) -> Self {
+        css_types::Rect::new(rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
 // ============================================================================
 // Transform Types
 // ============================================================================
@@ -214,12 +232,397 @@ pub enum TransformFunction {
     },
 }
 
+impl TransformFunction {
+    /// Check whether this function leaves every point unchanged
+    ///
+    /// Zero translations, unit scales, zero rotations/skews and identity
+    /// matrices are no-ops. `perspective()` is never treated as a no-op.
+    pub fn is_identity(&self) -> bool {
+        let zero = |length: &Length| length.value() == 0.0;
+        let no_angle = |angle: &Angle| angle.value() == 0.0;
+
+        match self {
+            TransformFunction::Translate { x, y } => zero(x) && zero(y),
+            TransformFunction::TranslateX { value }
+            | TransformFunction::TranslateY { value }
+            | TransformFunction::TranslateZ { value } => zero(value),
+            TransformFunction::Translate3d { x, y, z } => zero(x) && zero(y) && zero(z),
+            TransformFunction::Scale { x, y } => *x == 1.0 && *y == 1.0,
+            TransformFunction::ScaleX { value }
+            | TransformFunction::ScaleY { value }
+            | TransformFunction::ScaleZ { value } => *value == 1.0,
+            TransformFunction::Scale3d { x, y, z } => *x == 1.0 && *y == 1.0 && *z == 1.0,
+            TransformFunction::Rotate { angle }
+            | TransformFunction::RotateX { angle }
+            | TransformFunction::RotateY { angle }
+            | TransformFunction::RotateZ { angle }
+            | TransformFunction::Rotate3d { angle, .. }
+            | TransformFunction::SkewX { angle }
+            | TransformFunction::SkewY { angle } => no_angle(angle),
+            TransformFunction::Skew { x, y } => no_angle(x) && no_angle(y),
+            TransformFunction::Matrix { a, b, c, d, tx, ty } => {
+                *a == 1.0 && *b == 0.0 && *c == 0.0 && *d == 1.0 && *tx == 0.0 && *ty == 0.0
+            }
+            TransformFunction::Matrix3d { values } => values
+                .iter()
+                .enumerate()
+                .all(|(i, v)| *v == if i % 5 == 0 { 1.0 } else { 0.0 }),
+            TransformFunction::Perspective { .. } => false,
+        }
+    }
+}
+
 /// Complete transform value (list of functions)
 #[derive(Debug, Clone, PartialEq)]
 pub struct Transform {
     pub functions: Vec<TransformFunction>,
 }
 
+impl Transform {
+    /// Create the `none` transform, an empty function list
+    pub fn none() -> Self {
+        Self {
+            functions: Vec::new(),
+        }
+    }
+
+    /// Check whether this transform is a no-op
+    ///
+    /// An empty function list, or one made up entirely of no-op functions
+    /// (e.g. `translate(0px, 0px) scale(1)`), is an identity transform.
+    /// Compositors can use this to skip allocating a layer.
+    pub fn is_identity(&self) -> bool {
+        self.functions.iter().all(TransformFunction::is_identity)
+    }
+
+    /// Compose the function list into a single matrix
+    ///
+    /// Functions are folded left to right into one accumulator with
+    /// [`TransformMatrix::multiply_assign`]. The reference box resolves
+    /// percentage translations.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_transforms::{parse_transform, Rect};
+    ///
+    /// let transform = parse_transform("translate(10px, 20px) scale(2)").unwrap();
+    /// let rect = Rect { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+    /// let matrix = transform.to_matrix(&rect);
+    /// assert_eq!(matrix.matrix[0][0], 2.0);
+    /// assert_eq!(matrix.matrix[1][3], 20.0);
+    /// ```
+    pub fn to_matrix(&self, reference_box: &Rect) -> TransformMatrix {
+        self.functions
+            .iter()
+            .fold(TransformMatrix::identity(), |mut result, func| {
+                result.multiply_assign(&matrix::compute_function_matrix(func, reference_box));
+                result
+            })
+    }
+
+    /// Interpolate between two transforms
+    ///
+    /// When both lists have the same length and each pair of functions is
+    /// the same kind (with matching units), every argument is interpolated
+    /// separately. An empty list (`none`) is treated as the identity
+    /// version of the other list. Any other combination would need matrix
+    /// decomposition and instead flips discretely at `progress = 0.5`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_transforms::{parse_transform, TransformFunction};
+    ///
+    /// let from = parse_transform("scale(1)").unwrap();
+    /// let to = parse_transform("scale(3)").unwrap();
+    /// let halfway = from.interpolate(&to, 0.5);
+    /// assert_eq!(halfway.functions, vec![TransformFunction::Scale { x: 2.0, y: 2.0 }]);
+    /// ```
+    pub fn interpolate(&self, other: &Transform, progress: f32) -> Transform {
+        let discrete = || {
+            if progress < 0.5 {
+                self.clone()
+            } else {
+                other.clone()
+            }
+        };
+
+        let from = if self.functions.is_empty() {
+            other.identity_functions()
+        } else {
+            Some(self.functions.clone())
+        };
+        let to = if other.functions.is_empty() {
+            self.identity_functions()
+        } else {
+            Some(other.functions.clone())
+        };
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) if from.len() == to.len() => (from, to),
+            _ => return discrete(),
+        };
+
+        from.iter()
+            .zip(&to)
+            .map(|(from, to)| from.interpolate(to, progress))
+            .collect::<Option<Vec<_>>>()
+            .map(|functions| Transform { functions })
+            .unwrap_or_else(discrete)
+    }
+
+    /// Identity counterparts of every function in the list
+    fn identity_functions(&self) -> Option<Vec<TransformFunction>> {
+        self.functions
+            .iter()
+            .map(TransformFunction::identity_counterpart)
+            .collect()
+    }
+}
+
+impl TransformFunction {
+    /// No-op function of the same kind, e.g. `scale(1)` for `scale(2)`
+    ///
+    /// Returns `None` for matrices, which are never interpolated argument by
+    /// argument, and for `perspective()`, which has no finite no-op value.
+    fn identity_counterpart(&self) -> Option<TransformFunction> {
+        let zero = |length: &Length| Length::new(0.0, length.unit());
+        let no_angle = |angle: &Angle| Angle::new(0.0, angle.unit());
+
+        let identity = match self {
+            TransformFunction::Translate { x, y } => TransformFunction::Translate {
+                x: zero(x),
+                y: zero(y),
+            },
+            TransformFunction::TranslateX { value } => {
+                TransformFunction::TranslateX { value: zero(value) }
+            }
+            TransformFunction::TranslateY { value } => {
+                TransformFunction::TranslateY { value: zero(value) }
+            }
+            TransformFunction::TranslateZ { value } => {
+                TransformFunction::TranslateZ { value: zero(value) }
+            }
+            TransformFunction::Translate3d { x, y, z } => TransformFunction::Translate3d {
+                x: zero(x),
+                y: zero(y),
+                z: zero(z),
+            },
+            TransformFunction::Scale { .. } => TransformFunction::Scale { x: 1.0, y: 1.0 },
+            TransformFunction::ScaleX { .. } => TransformFunction::ScaleX { value: 1.0 },
+            TransformFunction::ScaleY { .. } => TransformFunction::ScaleY { value: 1.0 },
+            TransformFunction::ScaleZ { .. } => TransformFunction::ScaleZ { value: 1.0 },
+            TransformFunction::Scale3d { .. } => TransformFunction::Scale3d {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            TransformFunction::Rotate { angle } => TransformFunction::Rotate {
+                angle: no_angle(angle),
+            },
+            TransformFunction::RotateX { angle } => TransformFunction::RotateX {
+                angle: no_angle(angle),
+            },
+            TransformFunction::RotateY { angle } => TransformFunction::RotateY {
+                angle: no_angle(angle),
+            },
+            TransformFunction::RotateZ { angle } => TransformFunction::RotateZ {
+                angle: no_angle(angle),
+            },
+            TransformFunction::Rotate3d { x, y, z, angle } => TransformFunction::Rotate3d {
+                x: *x,
+                y: *y,
+                z: *z,
+                angle: no_angle(angle),
+            },
+            TransformFunction::Skew { x, y } => TransformFunction::Skew {
+                x: no_angle(x),
+                y: no_angle(y),
+            },
+            TransformFunction::SkewX { angle } => TransformFunction::SkewX {
+                angle: no_angle(angle),
+            },
+            TransformFunction::SkewY { angle } => TransformFunction::SkewY {
+                angle: no_angle(angle),
+            },
+            TransformFunction::Matrix { .. }
+            | TransformFunction::Matrix3d { .. }
+            | TransformFunction::Perspective { .. } => return None,
+        };
+        Some(identity)
+    }
+
+    /// Interpolate the arguments of two functions of the same kind
+    ///
+    /// Returns `None` when the functions differ in kind, length units or
+    /// rotation axis, and for matrices, which need decomposition.
+    fn interpolate(&self, other: &TransformFunction, progress: f32) -> Option<TransformFunction> {
+        let number = |from: f32, to: f32| from + (to - from) * progress;
+        let length = |from: &Length, to: &Length| {
+            (from.unit() == to.unit())
+                .then(|| Length::new(number(from.value(), to.value()), from.unit()))
+        };
+        let angle = |from: &Angle, to: &Angle| {
+            if from.unit() == to.unit() {
+                Angle::new(number(from.value(), to.value()), from.unit())
+            } else {
+                Angle::new(number(from.to_radians(), to.to_radians()), AngleUnit::Rad)
+            }
+        };
+
+        let function = match (self, other) {
+            (
+                TransformFunction::Translate { x: x1, y: y1 },
+                TransformFunction::Translate { x: x2, y: y2 },
+            ) => TransformFunction::Translate {
+                x: length(x1, x2)?,
+                y: length(y1, y2)?,
+            },
+            (
+                TransformFunction::TranslateX { value: from },
+                TransformFunction::TranslateX { value: to },
+            ) => TransformFunction::TranslateX {
+                value: length(from, to)?,
+            },
+            (
+                TransformFunction::TranslateY { value: from },
+                TransformFunction::TranslateY { value: to },
+            ) => TransformFunction::TranslateY {
+                value: length(from, to)?,
+            },
+            (
+                TransformFunction::TranslateZ { value: from },
+                TransformFunction::TranslateZ { value: to },
+            ) => TransformFunction::TranslateZ {
+                value: length(from, to)?,
+            },
+            (
+                TransformFunction::Translate3d {
+                    x: x1,
+                    y: y1,
+                    z: z1,
+                },
+                TransformFunction::Translate3d {
+                    x: x2,
+                    y: y2,
+                    z: z2,
+                },
+            ) => TransformFunction::Translate3d {
+                x: length(x1, x2)?,
+                y: length(y1, y2)?,
+                z: length(z1, z2)?,
+            },
+            (
+                TransformFunction::Scale { x: x1, y: y1 },
+                TransformFunction::Scale { x: x2, y: y2 },
+            ) => TransformFunction::Scale {
+                x: number(*x1, *x2),
+                y: number(*y1, *y2),
+            },
+            (
+                TransformFunction::ScaleX { value: from },
+                TransformFunction::ScaleX { value: to },
+            ) => TransformFunction::ScaleX {
+                value: number(*from, *to),
+            },
+            (
+                TransformFunction::ScaleY { value: from },
+                TransformFunction::ScaleY { value: to },
+            ) => TransformFunction::ScaleY {
+                value: number(*from, *to),
+            },
+            (
+                TransformFunction::ScaleZ { value: from },
+                TransformFunction::ScaleZ { value: to },
+            ) => TransformFunction::ScaleZ {
+                value: number(*from, *to),
+            },
+            (
+                TransformFunction::Scale3d {
+                    x: x1,
+                    y: y1,
+                    z: z1,
+                },
+                TransformFunction::Scale3d {
+                    x: x2,
+                    y: y2,
+                    z: z2,
+                },
+            ) => TransformFunction::Scale3d {
+                x: number(*x1, *x2),
+                y: number(*y1, *y2),
+                z: number(*z1, *z2),
+            },
+            (
+                TransformFunction::Rotate { angle: from },
+                TransformFunction::Rotate { angle: to },
+            ) => TransformFunction::Rotate {
+                angle: angle(from, to),
+            },
+            (
+                TransformFunction::RotateX { angle: from },
+                TransformFunction::RotateX { angle: to },
+            ) => TransformFunction::RotateX {
+                angle: angle(from, to),
+            },
+            (
+                TransformFunction::RotateY { angle: from },
+                TransformFunction::RotateY { angle: to },
+            ) => TransformFunction::RotateY {
+                angle: angle(from, to),
+            },
+            (
+                TransformFunction::RotateZ { angle: from },
+                TransformFunction::RotateZ { angle: to },
+            ) => TransformFunction::RotateZ {
+                angle: angle(from, to),
+            },
+            (
+                TransformFunction::Rotate3d {
+                    x: x1,
+                    y: y1,
+                    z: z1,
+                    angle: from,
+                },
+                TransformFunction::Rotate3d {
+                    x: x2,
+                    y: y2,
+                    z: z2,
+                    angle: to,
+                },
+            ) if (x1, y1, z1) == (x2, y2, z2) => TransformFunction::Rotate3d {
+                x: *x1,
+                y: *y1,
+                z: *z1,
+                angle: angle(from, to),
+            },
+            (
+                TransformFunction::Skew { x: x1, y: y1 },
+                TransformFunction::Skew { x: x2, y: y2 },
+            ) => TransformFunction::Skew {
+                x: angle(x1, x2),
+                y: angle(y1, y2),
+            },
+            (TransformFunction::SkewX { angle: from }, TransformFunction::SkewX { angle: to }) => {
+                TransformFunction::SkewX {
+                    angle: angle(from, to),
+                }
+            }
+            (TransformFunction::SkewY { angle: from }, TransformFunction::SkewY { angle: to }) => {
+                TransformFunction::SkewY {
+                    angle: angle(from, to),
+                }
+            }
+            (
+                TransformFunction::Perspective { value: from },
+                TransformFunction::Perspective { value: to },
+            ) => TransformFunction::Perspective {
+                value: length(from, to)?,
+            },
+            _ => return None,
+        };
+        Some(function)
+    }
+}
+
 /// Transform origin point
 #[derive(Debug, Clone, PartialEq)]
 pub struct TransformOrigin {
@@ -256,18 +659,118 @@ impl TransformMatrix {
 
     /// Multiply two matrices
     pub fn multiply(&self, other: &TransformMatrix) -> TransformMatrix {
-        let mut result = TransformMatrix::identity();
+        let mut result = self.clone();
+        result.multiply_assign(other);
+        result
+    }
 
-        for i in 0..4 {
-            for j in 0..4 {
-                result.matrix[i][j] = 0.0;
-                for k in 0..4 {
-                    result.matrix[i][j] += self.matrix[i][k] * other.matrix[k][j];
-                }
-            }
+    /// Multiply by another matrix in place (`self = self * other`)
+    ///
+    /// Each row of the product only depends on the same row of `self`, so
+    /// rows are replaced one at a time without building a second matrix.
+    /// The 4x4 product is written out in full rather than looped.
+    pub fn multiply_assign(&mut self, other: &TransformMatrix) {
+        let b = &other.matrix;
+        for row in &mut self.matrix {
+            let [r0, r1, r2, r3] = *row;
+            *row = [
+                r0 * b[0][0] + r1 * b[1][0] + r2 * b[2][0] + r3 * b[3][0],
+                r0 * b[0][1] + r1 * b[1][1] + r2 * b[2][1] + r3 * b[3][1],
+                r0 * b[0][2] + r1 * b[1][2] + r2 * b[2][2] + r3 * b[3][2],
+                r0 * b[0][3] + r1 * b[1][3] + r2 * b[2][3] + r3 * b[3][3],
+            ];
         }
+    }
 
-        result
+    /// Map a point on the z = 0 plane through the matrix
+    ///
+    /// The result is projected back to 2D by dividing by `w`. Points that
+    /// end up at or behind the viewer (`w <= 0`) are clamped to a tiny
+    /// positive `w`, which pushes them far out rather than flipping them.
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let m = &self.matrix;
+        let tx = m[0][0] * x + m[0][1] * y + m[0][3];
+        let ty = m[1][0] * x + m[1][1] * y + m[1][3];
+        let w = m[3][0] * x + m[3][1] * y + m[3][3];
+        let w = w.max(f32::EPSILON);
+        (tx / w, ty / w)
+    }
+
+    /// Axis-aligned bounding box of a transformed rectangle
+    ///
+    /// Transforms the four corners with [`TransformMatrix::transform_point`]
+    /// (projecting 3D transforms to 2D) and returns the smallest rectangle
+    /// containing them, e.g. for invalidation regions.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_transforms::{Rect, TransformMatrix};
+    ///
+    /// let rect = Rect { x: 10.0, y: 10.0, width: 50.0, height: 20.0 };
+    /// assert_eq!(TransformMatrix::identity().transform_rect_bounds(&rect), rect);
+    /// ```
+    pub fn transform_rect_bounds(&self, rect: &Rect) -> Rect {
+        let corners = [
+            self.transform_point(rect.x, rect.y),
+            self.transform_point(rect.x + rect.width, rect.y),
+            self.transform_point(rect.x, rect.y + rect.height),
+            self.transform_point(rect.x + rect.width, rect.y + rect.height),
+        ];
+
+        let (mut min_x, mut min_y) = corners[0];
+        let (mut max_x, mut max_y) = corners[0];
+        for &(x, y) in &corners[1..] {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        Rect {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
+
+    /// Check whether this matrix is the identity within `epsilon`
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        self.matrix.iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, value)| {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (value - expected).abs() <= epsilon
+            })
+        })
+    }
+
+    /// Translation component as `(x, y, z)`
+    ///
+    /// Matrices map column vectors, so the translation is the last column.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_transforms::{compute_transform_matrix, parse_transform, Rect};
+    ///
+    /// let rect = Rect { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+    /// let matrix = compute_transform_matrix(&parse_transform("translate(10px, 20px)").unwrap(), &rect);
+    /// assert_eq!(matrix.translation(), (10.0, 20.0, 0.0));
+    /// ```
+    pub fn translation(&self) -> (f32, f32, f32) {
+        let m = &self.matrix;
+        (m[0][3], m[1][3], m[2][3])
+    }
+
+    /// Scale along each axis as `(x, y, z)`
+    ///
+    /// Each factor is the length of the corresponding column of the upper
+    /// 3x3 part, so rotation and translation do not affect it. Factors are
+    /// always non-negative; a mirrored axis reports its magnitude.
+    pub fn scale_factors(&self) -> (f32, f32, f32) {
+        let m = &self.matrix;
+        let column_length =
+            |j: usize| (m[0][j] * m[0][j] + m[1][j] * m[1][j] + m[2][j] * m[2][j]).sqrt();
+        (column_length(0), column_length(1), column_length(2))
     }
 }
 
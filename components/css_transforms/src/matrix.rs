@@ -1,12 +1,15 @@
 //! Transform matrix computation
 
-use crate::{Rect, Transform, TransformFunction, TransformMatrix, TransformOrigin};
+use crate::{
+    Rect, Transform, TransformComputer, TransformFunction, TransformMatrix, TransformOrigin,
+};
 use css_types::LengthUnit;
 
 /// Compute 4x4 matrix from transform list
 ///
 /// Computes a single 4x4 transformation matrix by composing all transform functions
 /// in the given transform. The reference box is used to resolve percentage values.
+/// An empty list (`transform: none`) is the identity matrix.
 ///
 /// # Examples
 /// ```
@@ -18,19 +21,96 @@ use css_types::LengthUnit;
 /// assert_eq!(matrix.matrix[0][3], 10.0);
 /// ```
 pub fn compute_transform_matrix(transform: &Transform, reference_box: &Rect) -> TransformMatrix {
-    let mut result = TransformMatrix::identity();
-
-    // Apply each transform function in order (left to right composition)
-    for func in &transform.functions {
-        let func_matrix = compute_function_matrix(func, reference_box);
-        result = result.multiply(&func_matrix);
+    if transform.functions.is_empty() {
+        return TransformMatrix::identity();
     }
 
-    result
+    transform.to_matrix(reference_box)
 }
 
-fn compute_function_matrix(func: &TransformFunction, reference_box: &Rect) -> TransformMatrix {
+/// Compose the individual transform properties with a `transform` list
+///
+/// The result applies `translate`, then `rotate`, then `scale`, then the
+/// `transform` functions, so its matrix is
+/// `translate * rotate * scale * transform`. Properties set to `none` are
+/// passed as `None` and skipped.
+///
+/// # Examples
+/// ```
+/// use css_transforms::{
+///     compose_individual_transforms, parse_rotate_property, parse_transform, Transform,
+/// };
+///
+/// let rotate = parse_rotate_property("45deg").unwrap();
+/// let transform = parse_transform("scale(2)").unwrap();
+/// let composed = compose_individual_transforms(None, rotate.as_ref(), None, &transform);
+/// assert_eq!(composed.functions.len(), 2);
+///
+/// let none = compose_individual_transforms(None, None, None, &Transform::none());
+/// assert!(none.functions.is_empty());
+/// ```
+pub fn compose_individual_transforms(
+    translate: Option<&TransformFunction>,
+    rotate: Option<&TransformFunction>,
+    scale: Option<&TransformFunction>,
+    transform: &Transform,
+) -> Transform {
+    let functions = [translate, rotate, scale]
+        .into_iter()
+        .flatten()
+        .chain(&transform.functions)
+        .cloned()
+        .collect();
+
+    Transform { functions }
+}
+
+/// Default transform computer
+///
+/// Composes the transform functions against the element's own reference box
+/// (so percentage translations resolve against its width/height) and then
+/// applies the transform origin.
+///
+/// # Examples
+/// ```
+/// use css_transforms::{
+///     parse_transform, parse_transform_origin, DefaultTransformComputer, Rect, TransformComputer,
+/// };
+///
+/// let transform = parse_transform("translate(50%, 100%)").unwrap();
+/// let origin = parse_transform_origin("center center").unwrap();
+/// let rect = Rect { x: 0.0, y: 0.0, width: 200.0, height: 100.0 };
+/// let matrix = DefaultTransformComputer.compute_transform(&transform, &origin, &rect);
+/// assert_eq!(matrix.matrix[0][3], 100.0);
+/// assert_eq!(matrix.matrix[1][3], 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultTransformComputer;
+
+impl TransformComputer for DefaultTransformComputer {
+    fn compute_transform(
+        &self,
+        transform: &Transform,
+        origin: &TransformOrigin,
+        reference_box: &Rect,
+    ) -> TransformMatrix {
+        // The origin shift cancels out around an identity matrix
+        if transform.functions.is_empty() {
+            return TransformMatrix::identity();
+        }
+
+        let mut matrix = compute_transform_matrix(transform, reference_box);
+        apply_transform_origin(&mut matrix, origin, reference_box);
+        matrix
+    }
+}
+
+pub(crate) fn compute_function_matrix(
+    func: &TransformFunction,
+    reference_box: &Rect,
+) -> TransformMatrix {
     match func {
+        // Percentage translations resolve against the element's own box
         TransformFunction::Translate { x, y } => {
             let tx = resolve_length(x, reference_box.width);
             let ty = resolve_length(y, reference_box.height);
@@ -106,16 +186,21 @@ pub fn apply_transform_origin(
     let translate_back = translation_matrix(ox, oy, oz);
 
     // Compose: translate_back * matrix * translate_to_origin
-    let temp = matrix.multiply(&translate_to_origin);
-    *matrix = translate_back.multiply(&temp);
+    matrix.multiply_assign(&translate_to_origin);
+    *matrix = translate_back.multiply(matrix);
 }
 
 fn resolve_length(length: &css_types::Length, reference: f32) -> f32 {
     match length.unit() {
         LengthUnit::Px => length.value(),
+        unit @ (LengthUnit::Pt | LengthUnit::Cm | LengthUnit::Mm | LengthUnit::In) => {
+            length.value() * unit.px_per_unit().unwrap_or(1.0)
+        }
         LengthUnit::Percent => length.value() * reference / 100.0,
         LengthUnit::Em | LengthUnit::Rem => length.value() * 16.0, // Assume 16px base
-        LengthUnit::Vw | LengthUnit::Vh => length.value() * 10.0,  // Simplified
+        LengthUnit::Vw | LengthUnit::Vh | LengthUnit::Vmin | LengthUnit::Vmax => {
+            length.value() * 10.0 // Simplified
+        }
     }
 }
 
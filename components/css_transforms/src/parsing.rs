@@ -2,7 +2,7 @@
 
 use crate::{Angle, Transform, TransformFunction, TransformOrigin};
 use css_parser_core::ParseError;
-use css_types::{Length, LengthUnit};
+use css_types::{parse_finite_f32, Length, LengthUnit};
 
 /// Parse CSS transform property value
 ///
@@ -14,17 +14,27 @@ use css_types::{Length, LengthUnit};
 ///
 /// let transform = parse_transform("translate(10px, 20px) rotate(45deg)").unwrap();
 /// assert_eq!(transform.functions.len(), 2);
+///
+/// // `none` is an empty function list
+/// assert!(parse_transform("none").unwrap().functions.is_empty());
 /// ```
 ///
 /// # Errors
-/// Returns `ParseError` if the input is not a valid CSS transform.
+/// Returns `ParseError` if the input is not a valid CSS transform. When a
+/// specific function is at fault, the error's `offset` and `snippet` point at
+/// that function within `input`.
 pub fn parse_transform(input: &str) -> Result<Transform, ParseError> {
+    let leading = input.len() - input.trim_start().len();
     let input = input.trim();
 
     if input.is_empty() {
         return Err(ParseError::new(0, 0, "Empty transform string"));
     }
 
+    if input.eq_ignore_ascii_case("none") {
+        return Ok(Transform::none());
+    }
+
     let mut functions = Vec::new();
     let mut current_pos = 0;
 
@@ -46,11 +56,10 @@ pub fn parse_transform(input: &str) -> Result<Transform, ParseError> {
         }
 
         if current_pos >= input.len() {
-            return Err(ParseError::new(
-                0,
-                current_pos,
-                "Expected opening parenthesis",
-            ));
+            return Err(
+                ParseError::new(0, current_pos, "Expected opening parenthesis")
+                    .with_offset(leading + start, &input[start..]),
+            );
         }
 
         let func_name = &input[start..current_pos];
@@ -69,11 +78,13 @@ pub fn parse_transform(input: &str) -> Result<Transform, ParseError> {
         }
 
         if paren_depth != 0 {
-            return Err(ParseError::new(0, current_pos, "Unmatched parenthesis"));
+            return Err(ParseError::new(0, current_pos, "Unmatched parenthesis")
+                .with_offset(leading + start, &input[start..]));
         }
 
         let args = &input[args_start..current_pos - 1];
-        let function = parse_transform_function(func_name, args)?;
+        let function = parse_transform_function(func_name, args)
+            .map_err(|e| e.with_offset(leading + start, &input[start..current_pos]))?;
         functions.push(function);
     }
 
@@ -90,12 +101,21 @@ fn parse_transform_function(name: &str, args: &str) -> Result<TransformFunction,
 
     match name {
         "translate" => {
-            if parts.len() != 2 {
-                return Err(ParseError::new(0, 0, "translate() requires 2 arguments"));
+            if parts.is_empty() || parts.len() > 2 {
+                return Err(ParseError::new(
+                    0,
+                    0,
+                    "translate() requires 1 or 2 arguments",
+                ));
             }
+            // A missing y translation is zero
+            let y = match parts.get(1) {
+                Some(y) => parse_length(y)?,
+                None => Length::new(0.0, LengthUnit::Px),
+            };
             Ok(TransformFunction::Translate {
                 x: parse_length(parts[0])?,
-                y: parse_length(parts[1])?,
+                y,
             })
         }
         "translateX" => {
@@ -286,6 +306,181 @@ fn parse_transform_function(name: &str, args: &str) -> Result<TransformFunction,
     }
 }
 
+/// Parse the `translate` property value
+///
+/// One to three whitespace-separated lengths give the x, y and z offsets;
+/// a missing y is zero. `none` yields `None`.
+///
+/// # Examples
+/// ```
+/// use css_transforms::{parse_translate_property, TransformFunction};
+///
+/// let translate = parse_translate_property("10px 50%").unwrap();
+/// assert!(matches!(translate, Some(TransformFunction::Translate { .. })));
+/// assert_eq!(parse_translate_property("none").unwrap(), None);
+/// ```
+///
+/// # Errors
+/// Returns `ParseError` if the input is not a valid `translate` value. The
+/// error's `offset` and `snippet` point at the invalid component.
+pub fn parse_translate_property(input: &str) -> Result<Option<TransformFunction>, ParseError> {
+    let Some(parts) = split_individual_property(input, "translate")? else {
+        return Ok(None);
+    };
+
+    let lengths = parts
+        .iter()
+        .map(|&(offset, part)| parse_length(part).map_err(|e| e.with_offset(offset, part)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match lengths.as_slice() {
+        [x] => Ok(Some(TransformFunction::Translate {
+            x: *x,
+            y: Length::new(0.0, LengthUnit::Px),
+        })),
+        [x, y] => Ok(Some(TransformFunction::Translate { x: *x, y: *y })),
+        [x, y, z] => Ok(Some(TransformFunction::Translate3d {
+            x: *x,
+            y: *y,
+            z: *z,
+        })),
+        _ => Err(ParseError::new(0, 0, "translate requires 1 to 3 values")),
+    }
+}
+
+/// Parse the `rotate` property value
+///
+/// Accepts an angle (rotation about the z axis), an axis keyword (`x`,
+/// `y` or `z`) with an angle, or an axis vector of three numbers with an
+/// angle. The angle may come before or after the axis. `none` yields
+/// `None`.
+///
+/// # Examples
+/// ```
+/// use css_transforms::{parse_rotate_property, TransformFunction};
+///
+/// let rotate = parse_rotate_property("45deg").unwrap();
+/// assert!(matches!(rotate, Some(TransformFunction::Rotate { .. })));
+///
+/// let rotate = parse_rotate_property("x 90deg").unwrap();
+/// assert!(matches!(rotate, Some(TransformFunction::RotateX { .. })));
+/// ```
+///
+/// # Errors
+/// Returns `ParseError` if the input is not a valid `rotate` value. The
+/// error's `offset` and `snippet` point at the invalid component.
+pub fn parse_rotate_property(input: &str) -> Result<Option<TransformFunction>, ParseError> {
+    let Some(parts) = split_individual_property(input, "rotate")? else {
+        return Ok(None);
+    };
+
+    // The angle is either the first or the last component
+    let (angle_index, (angle_offset, angle_part)) = if parse_angle(parts[0].1).is_ok() {
+        (0, parts[0])
+    } else {
+        (parts.len() - 1, parts[parts.len() - 1])
+    };
+    let angle = parse_angle(angle_part).map_err(|e| e.with_offset(angle_offset, angle_part))?;
+
+    let axis: Vec<(usize, &str)> = parts
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| index != angle_index)
+        .map(|(_, &part)| part)
+        .collect();
+
+    match axis.as_slice() {
+        [] => Ok(Some(TransformFunction::Rotate { angle })),
+        [(offset, keyword)] => match keyword.to_ascii_lowercase().as_str() {
+            "x" => Ok(Some(TransformFunction::RotateX { angle })),
+            "y" => Ok(Some(TransformFunction::RotateY { angle })),
+            "z" => Ok(Some(TransformFunction::RotateZ { angle })),
+            _ => Err(ParseError::new(0, 0, "Expected rotation axis x, y or z")
+                .with_offset(*offset, *keyword)),
+        },
+        [x, y, z] => {
+            let number = |&(offset, part): &(usize, &str)| {
+                parse_number(part).map_err(|e| e.with_offset(offset, part))
+            };
+            Ok(Some(TransformFunction::Rotate3d {
+                x: number(x)?,
+                y: number(y)?,
+                z: number(z)?,
+                angle,
+            }))
+        }
+        _ => Err(ParseError::new(
+            0,
+            0,
+            "rotate requires an angle with an optional axis",
+        )),
+    }
+}
+
+/// Parse the `scale` property value
+///
+/// One to three whitespace-separated numbers or percentages give the x, y
+/// and z factors; a missing y repeats x. `none` yields `None`.
+///
+/// # Examples
+/// ```
+/// use css_transforms::{parse_scale_property, TransformFunction};
+///
+/// let scale = parse_scale_property("2").unwrap();
+/// assert_eq!(scale, Some(TransformFunction::Scale { x: 2.0, y: 2.0 }));
+///
+/// let scale = parse_scale_property("50% 2").unwrap();
+/// assert_eq!(scale, Some(TransformFunction::Scale { x: 0.5, y: 2.0 }));
+/// ```
+///
+/// # Errors
+/// Returns `ParseError` if the input is not a valid `scale` value. The
+/// error's `offset` and `snippet` point at the invalid component.
+pub fn parse_scale_property(input: &str) -> Result<Option<TransformFunction>, ParseError> {
+    let Some(parts) = split_individual_property(input, "scale")? else {
+        return Ok(None);
+    };
+
+    let factors = parts
+        .iter()
+        .map(|&(offset, part)| {
+            let factor = match part.strip_suffix('%') {
+                Some(percent) => parse_number(percent).map(|value| value / 100.0),
+                None => parse_number(part),
+            };
+            factor.map_err(|e| e.with_offset(offset, part))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match factors.as_slice() {
+        [x] => Ok(Some(TransformFunction::Scale { x: *x, y: *x })),
+        [x, y] => Ok(Some(TransformFunction::Scale { x: *x, y: *y })),
+        [x, y, z] => Ok(Some(TransformFunction::Scale3d {
+            x: *x,
+            y: *y,
+            z: *z,
+        })),
+        _ => Err(ParseError::new(0, 0, "scale requires 1 to 3 values")),
+    }
+}
+
+/// Split an individual transform property into components with offsets
+///
+/// Returns `None` for `none`.
+fn split_individual_property<'a>(
+    input: &'a str,
+    property: &str,
+) -> Result<Option<Vec<(usize, &'a str)>>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::new(0, 0, format!("Empty {} string", property)));
+    }
+    if input.trim().eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+
+    Ok(Some(split_whitespace_with_offsets(input)))
+}
+
 /// Parse transform-origin property value
 ///
 /// Parses a CSS transform-origin string, supporting keywords (left, right, top, bottom, center),
@@ -301,29 +496,25 @@ fn parse_transform_function(name: &str, args: &str) -> Result<TransformFunction,
 /// ```
 ///
 /// # Errors
-/// Returns `ParseError` if the input is not a valid CSS transform-origin.
+/// Returns `ParseError` if the input is not a valid CSS transform-origin. The
+/// error's `offset` and `snippet` point at the invalid component.
 pub fn parse_transform_origin(input: &str) -> Result<TransformOrigin, ParseError> {
-    let input = input.trim();
-
-    if input.is_empty() {
+    if input.trim().is_empty() {
         return Err(ParseError::new(0, 0, "Empty transform-origin string"));
     }
 
-    let parts: Vec<&str> = input.split_whitespace().collect();
-
-    if parts.is_empty() {
-        return Err(ParseError::new(0, 0, "Empty transform-origin string"));
-    }
+    let parts = split_whitespace_with_offsets(input);
 
-    let x = parse_origin_component(parts[0], true)?;
-    let y = if parts.len() > 1 {
-        parse_origin_component(parts[1], false)?
+    let (x_offset, x_part) = parts[0];
+    let x = parse_origin_component(x_part, true).map_err(|e| e.with_offset(x_offset, x_part))?;
+    let y = if let Some(&(offset, part)) = parts.get(1) {
+        parse_origin_component(part, false).map_err(|e| e.with_offset(offset, part))?
     } else {
         // If only one value, y defaults to center
         Length::new(50.0, LengthUnit::Percent)
     };
-    let z = if parts.len() > 2 {
-        parse_length(parts[2])?
+    let z = if let Some(&(offset, part)) = parts.get(2) {
+        parse_length(part).map_err(|e| e.with_offset(offset, part))?
     } else {
         Length::new(0.0, LengthUnit::Px)
     };
@@ -331,6 +522,28 @@ pub fn parse_transform_origin(input: &str) -> Result<TransformOrigin, ParseError
     Ok(TransformOrigin { x, y, z })
 }
 
+/// Split on whitespace, keeping each token's byte offset within `input`
+fn split_whitespace_with_offsets(input: &str) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut start = None;
+
+    for (i, ch) in input.char_indices() {
+        match (ch.is_whitespace(), start) {
+            (true, Some(s)) => {
+                parts.push((s, &input[s..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        parts.push((s, &input[s..]));
+    }
+
+    parts
+}
+
 fn parse_origin_component(input: &str, is_x: bool) -> Result<Length, ParseError> {
     match input {
         "left" if is_x => Ok(Length::new(0.0, LengthUnit::Percent)),
@@ -344,6 +557,10 @@ fn parse_origin_component(input: &str, is_x: bool) -> Result<Length, ParseError>
 
 fn parse_length(input: &str) -> Result<Length, ParseError> {
     use css_types::CssValue;
+    // Zero lengths may be written without a unit
+    if parse_finite_f32(input).is_ok_and(|value| value == 0.0) {
+        return Ok(Length::new(0.0, LengthUnit::Px));
+    }
     Length::parse(input).map_err(|e| ParseError::new(0, 0, e.to_string()))
 }
 
@@ -352,8 +569,5 @@ fn parse_angle(input: &str) -> Result<Angle, ParseError> {
 }
 
 fn parse_number(input: &str) -> Result<f32, ParseError> {
-    input
-        .trim()
-        .parse::<f32>()
-        .map_err(|_| ParseError::new(0, 0, format!("Invalid number: {}", input)))
+    parse_finite_f32(input).map_err(|e| ParseError::new(0, 0, e.to_string()))
 }
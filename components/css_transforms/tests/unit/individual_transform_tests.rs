@@ -0,0 +1,128 @@
+//! Unit tests for the `translate`, `rotate` and `scale` properties
+
+use css_transforms::*;
+
+fn reference_box() -> Rect {
+    Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    }
+}
+
+fn assert_point_eq(actual: (f32, f32), expected: (f32, f32)) {
+    assert!(
+        (actual.0 - expected.0).abs() < 1e-4 && (actual.1 - expected.1).abs() < 1e-4,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_parse_translate_property() {
+    match parse_translate_property("10px").unwrap() {
+        Some(TransformFunction::Translate { x, y }) => {
+            assert_eq!(x.value(), 10.0);
+            assert_eq!(y.value(), 0.0);
+        }
+        other => panic!("Expected Translate, got {:?}", other),
+    }
+    assert!(matches!(
+        parse_translate_property("1px 2px 3px").unwrap(),
+        Some(TransformFunction::Translate3d { .. })
+    ));
+    assert_eq!(parse_translate_property("none").unwrap(), None);
+}
+
+#[test]
+fn test_parse_rotate_property() {
+    match parse_rotate_property("45deg").unwrap() {
+        Some(TransformFunction::Rotate { angle }) => assert_eq!(angle.value(), 45.0),
+        other => panic!("Expected Rotate, got {:?}", other),
+    }
+    assert!(matches!(
+        parse_rotate_property("90deg y").unwrap(),
+        Some(TransformFunction::RotateY { .. })
+    ));
+    match parse_rotate_property("1 0 0 30deg").unwrap() {
+        Some(TransformFunction::Rotate3d { x, y, z, angle }) => {
+            assert_eq!((x, y, z), (1.0, 0.0, 0.0));
+            assert_eq!(angle.value(), 30.0);
+        }
+        other => panic!("Expected Rotate3d, got {:?}", other),
+    }
+    assert_eq!(parse_rotate_property("none").unwrap(), None);
+}
+
+#[test]
+fn test_parse_scale_property() {
+    assert_eq!(
+        parse_scale_property("2").unwrap(),
+        Some(TransformFunction::Scale { x: 2.0, y: 2.0 })
+    );
+    assert_eq!(
+        parse_scale_property("1 2 3").unwrap(),
+        Some(TransformFunction::Scale3d {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        })
+    );
+    assert_eq!(parse_scale_property("NONE").unwrap(), None);
+}
+
+#[test]
+fn test_invalid_individual_properties() {
+    assert!(parse_translate_property("").is_err());
+    assert!(parse_translate_property("1px 2px 3px 4px").is_err());
+    assert!(parse_rotate_property("w 45deg").is_err());
+    assert!(parse_rotate_property("1 0 45deg").is_err());
+    assert!(parse_scale_property("big").is_err());
+
+    let error = parse_scale_property("2 big").unwrap_err();
+    assert_eq!(error.offset, Some(2));
+    assert_eq!(error.snippet.as_deref(), Some("big"));
+}
+
+#[test]
+fn test_rotate_property_composes_before_transform_scale() {
+    let rotate = parse_rotate_property("45deg").unwrap();
+    let transform = parse_transform("scale(2)").unwrap();
+
+    let composed = compose_individual_transforms(None, rotate.as_ref(), None, &transform);
+    assert_eq!(composed.functions.len(), 2);
+    assert!(matches!(
+        composed.functions[0],
+        TransformFunction::Rotate { .. }
+    ));
+    assert_eq!(
+        composed.functions[1],
+        TransformFunction::Scale { x: 2.0, y: 2.0 }
+    );
+
+    let matrix = compute_transform_matrix(&composed, &reference_box());
+    let diagonal = 2.0 * std::f32::consts::FRAC_1_SQRT_2;
+    assert_point_eq(matrix.transform_point(1.0, 0.0), (diagonal, diagonal));
+}
+
+#[test]
+fn test_individual_properties_compose_in_order() {
+    let translate = parse_translate_property("10px").unwrap();
+    let rotate = parse_rotate_property("90deg").unwrap();
+    let scale = parse_scale_property("2 1").unwrap();
+    let transform = parse_transform("translateX(5px)").unwrap();
+
+    let composed = compose_individual_transforms(
+        translate.as_ref(),
+        rotate.as_ref(),
+        scale.as_ref(),
+        &transform,
+    );
+    let matrix = compute_transform_matrix(&composed, &reference_box());
+
+    // (0, 0) -> translateX(5px) -> (5, 0) -> scale -> (10, 0)
+    //        -> rotate -> (0, 10) -> translate -> (10, 10)
+    assert_point_eq(matrix.transform_point(0.0, 0.0), (10.0, 10.0));
+}
@@ -0,0 +1,81 @@
+//! Unit tests for transform interpolation
+
+use css_transforms::*;
+use css_types::{Length, LengthUnit};
+
+fn transform(input: &str) -> Transform {
+    parse_transform(input).unwrap()
+}
+
+#[test]
+fn test_interpolate_scale_halfway() {
+    let result = transform("scale(1)").interpolate(&transform("scale(2)"), 0.5);
+    assert_eq!(
+        result.functions,
+        vec![TransformFunction::Scale { x: 1.5, y: 1.5 }]
+    );
+}
+
+#[test]
+fn test_interpolate_matching_lists_per_function() {
+    let from = transform("translateX(0px) rotate(0deg)");
+    let to = transform("translateX(100px) rotate(90deg)");
+    let result = from.interpolate(&to, 0.25);
+
+    assert_eq!(
+        result.functions,
+        vec![
+            TransformFunction::TranslateX {
+                value: Length::new(25.0, LengthUnit::Px)
+            },
+            TransformFunction::Rotate {
+                angle: Angle::new(22.5, AngleUnit::Deg)
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_interpolate_rotation_across_angle_units() {
+    let result = transform("rotate(0deg)").interpolate(&transform("rotate(0.5turn)"), 0.5);
+    match &result.functions[0] {
+        TransformFunction::Rotate { angle } => {
+            assert!((angle.to_radians() - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        }
+        other => panic!("Expected Rotate, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolate_from_empty_list_uses_identity() {
+    let none = Transform { functions: vec![] };
+    let result = none.interpolate(&transform("scale(3) translateY(10px)"), 0.5);
+
+    assert_eq!(
+        result.functions,
+        vec![
+            TransformFunction::Scale { x: 2.0, y: 2.0 },
+            TransformFunction::TranslateY {
+                value: Length::new(5.0, LengthUnit::Px)
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_interpolate_mismatched_functions_is_discrete() {
+    let from = transform("scale(2)");
+    let to = transform("rotate(45deg)");
+
+    assert_eq!(from.interpolate(&to, 0.4), from);
+    assert_eq!(from.interpolate(&to, 0.5), to);
+}
+
+#[test]
+fn test_interpolate_mismatched_length_units_is_discrete() {
+    let from = transform("translateX(10px)");
+    let to = transform("translateX(50%)");
+
+    assert_eq!(from.interpolate(&to, 0.25), from);
+    assert_eq!(from.interpolate(&to, 0.75), to);
+}
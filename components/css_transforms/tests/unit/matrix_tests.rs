@@ -139,3 +139,380 @@ fn test_apply_transform_origin() {
     // Should still be valid after applying origin
     assert!(matrix.matrix[0][0].is_finite());
 }
+
+#[test]
+fn test_empty_transform_is_identity() {
+    let transform = Transform { functions: vec![] };
+    assert!(transform.is_identity());
+
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    assert!(compute_transform_matrix(&transform, &rect).is_identity(1e-6));
+}
+
+#[test]
+fn test_zero_translate_is_identity() {
+    let transform = parse_transform("translate(0px, 0px) scale(1)").unwrap();
+    assert!(transform.is_identity());
+
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    assert!(compute_transform_matrix(&transform, &rect).is_identity(1e-6));
+}
+
+#[test]
+fn test_none_transform_is_identity() {
+    let rect = Rect {
+        x: 10.0,
+        y: 10.0,
+        width: 100.0,
+        height: 50.0,
+    };
+    let origin = parse_transform_origin("center center").unwrap();
+
+    for transform in [parse_transform("none").unwrap(), Transform::none()] {
+        assert!(transform.functions.is_empty());
+        assert!(transform.is_identity());
+        assert_eq!(
+            compute_transform_matrix(&transform, &rect),
+            TransformMatrix::identity()
+        );
+        assert_eq!(
+            DefaultTransformComputer.compute_transform(&transform, &origin, &rect),
+            TransformMatrix::identity()
+        );
+    }
+}
+
+#[test]
+fn test_empty_function_list_is_identity() {
+    let transform = Transform { functions: vec![] };
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    assert_eq!(
+        compute_transform_matrix(&transform, &rect),
+        TransformMatrix::identity()
+    );
+}
+
+#[test]
+fn test_unitless_zero_translate_is_identity() {
+    let transform = parse_transform("translate(0)").unwrap();
+    assert!(transform.is_identity());
+
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    assert!(compute_transform_matrix(&transform, &rect).is_identity(1e-6));
+}
+
+#[test]
+fn test_non_identity_transform() {
+    let transform = parse_transform("translate(0px, 0px) rotate(45deg)").unwrap();
+    assert!(!transform.is_identity());
+
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    assert!(!compute_transform_matrix(&transform, &rect).is_identity(1e-6));
+}
+
+#[test]
+fn test_matrix_is_identity_within_epsilon() {
+    let mut matrix = TransformMatrix::identity();
+    matrix.matrix[0][3] = 1e-7;
+    assert!(matrix.is_identity(1e-6));
+    matrix.matrix[0][3] = 0.5;
+    assert!(!matrix.is_identity(1e-6));
+}
+
+#[test]
+fn test_percentage_translate_resolves_against_reference_box() {
+    let transform = parse_transform("translate(50%, 100%)").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    let matrix = compute_transform_matrix(&transform, &rect);
+
+    assert_eq!(matrix.matrix[0][3], 100.0);
+    assert_eq!(matrix.matrix[1][3], 100.0);
+}
+
+#[test]
+fn test_default_computer_resolves_percentage_translate() {
+    let transform = parse_transform("translate(50%, 100%)").unwrap();
+    let origin = parse_transform_origin("center center").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    let matrix = DefaultTransformComputer.compute_transform(&transform, &origin, &rect);
+
+    // A pure translation is unaffected by the transform origin
+    assert_eq!(matrix.matrix[0][3], 100.0);
+    assert_eq!(matrix.matrix[1][3], 100.0);
+}
+
+// ============================================================================
+// Bounding Box Tests
+// ============================================================================
+
+fn assert_rect_near(actual: Rect, expected: Rect) {
+    for (a, e) in [
+        (actual.x, expected.x),
+        (actual.y, expected.y),
+        (actual.width, expected.width),
+        (actual.height, expected.height),
+    ] {
+        assert!((a - e).abs() < 0.01, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_rotate_45_bounds_about_top_left() {
+    let transform = parse_transform("rotate(45deg)").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    let bounds = compute_transform_matrix(&transform, &rect).transform_rect_bounds(&rect);
+
+    let diagonal = 100.0 * std::f32::consts::SQRT_2;
+    assert_rect_near(
+        bounds,
+        Rect {
+            x: -diagonal / 2.0,
+            y: 0.0,
+            width: diagonal,
+            height: diagonal,
+        },
+    );
+}
+
+#[test]
+fn test_rotate_45_bounds_about_center() {
+    let transform = parse_transform("rotate(45deg)").unwrap();
+    let origin = parse_transform_origin("50% 50%").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    let matrix = DefaultTransformComputer.compute_transform(&transform, &origin, &rect);
+    let bounds = matrix.transform_rect_bounds(&rect);
+
+    // The square's diagonal becomes its width and height, centered on (50, 50)
+    let diagonal = 100.0 * std::f32::consts::SQRT_2;
+    assert_rect_near(
+        bounds,
+        Rect {
+            x: 50.0 - diagonal / 2.0,
+            y: 50.0 - diagonal / 2.0,
+            width: diagonal,
+            height: diagonal,
+        },
+    );
+}
+
+#[test]
+fn test_translate_bounds_offsets_rect() {
+    let transform = parse_transform("translate(10px, -5px)").unwrap();
+    let rect = Rect {
+        x: 20.0,
+        y: 20.0,
+        width: 30.0,
+        height: 40.0,
+    };
+
+    let bounds = compute_transform_matrix(&transform, &rect).transform_rect_bounds(&rect);
+
+    assert_rect_near(
+        bounds,
+        Rect {
+            x: 30.0,
+            y: 15.0,
+            width: 30.0,
+            height: 40.0,
+        },
+    );
+}
+
+#[test]
+fn test_perspective_bounds_are_projected() {
+    // Moving halfway toward a 100px perspective doubles the apparent size
+    let transform = parse_transform("perspective(100px) translateZ(50px)").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 10.0,
+        height: 10.0,
+    };
+
+    let bounds = compute_transform_matrix(&transform, &rect).transform_rect_bounds(&rect);
+
+    assert_rect_near(
+        bounds,
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 20.0,
+            height: 20.0,
+        },
+    );
+}
+
+fn sample_matrix(seed: f32) -> TransformMatrix {
+    let mut matrix = TransformMatrix::identity();
+    for (i, row) in matrix.matrix.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = seed * (i * 4 + j) as f32 - 3.5;
+        }
+    }
+    matrix
+}
+
+#[test]
+fn test_multiply_assign_matches_multiply() {
+    let a = sample_matrix(0.75);
+    let b = sample_matrix(-1.25);
+
+    let mut assigned = a.clone();
+    assigned.multiply_assign(&b);
+    assert_eq!(assigned, a.multiply(&b));
+
+    // Order matters: a * b != b * a for these matrices
+    let mut reversed = b.clone();
+    reversed.multiply_assign(&a);
+    assert_eq!(reversed, b.multiply(&a));
+    assert_ne!(assigned, reversed);
+}
+
+#[test]
+fn test_multiply_matches_row_column_definition() {
+    let a = sample_matrix(0.5);
+    let b = sample_matrix(2.0);
+    let product = a.multiply(&b);
+
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected: f32 = (0..4).map(|k| a.matrix[i][k] * b.matrix[k][j]).sum();
+            assert_eq!(product.matrix[i][j], expected);
+        }
+    }
+}
+
+#[test]
+fn test_multiply_assign_by_identity() {
+    let mut matrix = sample_matrix(1.5);
+    matrix.multiply_assign(&TransformMatrix::identity());
+    assert_eq!(matrix, sample_matrix(1.5));
+}
+
+#[test]
+fn test_to_matrix_folds_functions_in_order() {
+    let transform = parse_transform(
+        "translate(10px, 5px) rotate(30deg) scale(2, 3) skewX(10deg) translateY(4px)",
+    )
+    .unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    // Compose each function on its own and multiply them up one at a time
+    let expected = transform
+        .functions
+        .iter()
+        .map(|func| {
+            compute_transform_matrix(
+                &Transform {
+                    functions: vec![func.clone()],
+                },
+                &rect,
+            )
+        })
+        .fold(TransformMatrix::identity(), |acc, m| acc.multiply(&m));
+
+    assert_eq!(transform.to_matrix(&rect), expected);
+    assert_eq!(compute_transform_matrix(&transform, &rect), expected);
+}
+
+#[test]
+fn test_translation_and_scale_factors_of_translate_scale() {
+    let transform = parse_transform("translate(30px, -10px) scale(2, 3)").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    let matrix = compute_transform_matrix(&transform, &rect);
+
+    assert_eq!(matrix.translation(), (30.0, -10.0, 0.0));
+    assert_eq!(matrix.scale_factors(), (2.0, 3.0, 1.0));
+}
+
+#[test]
+fn test_scale_factors_ignore_rotation() {
+    let transform = parse_transform("rotate(30deg) scale(2)").unwrap();
+    let rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+    let (sx, sy, sz) = compute_transform_matrix(&transform, &rect).scale_factors();
+
+    assert!((sx - 2.0).abs() < 1e-5);
+    assert!((sy - 2.0).abs() < 1e-5);
+    assert_eq!(sz, 1.0);
+    assert_eq!(TransformMatrix::identity().translation(), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_rect_converts_to_and_from_canonical_rect() {
+    let rect = Rect {
+        x: 10.0,
+        y: 20.0,
+        width: 100.0,
+        height: 50.0,
+    };
+    let canonical = css_types::Rect::from(rect);
+
+    assert_eq!(canonical, css_types::Rect::new(10.0, 20.0, 100.0, 50.0));
+    assert_eq!(canonical.area(), rect.width * rect.height);
+    assert!(canonical.contains(rect.x + rect.width, rect.y + rect.height));
+    assert_eq!(Rect::from(canonical), rect);
+}
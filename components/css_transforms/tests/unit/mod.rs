@@ -1,5 +1,7 @@
 //! Unit tests for css_transforms
 
+mod individual_transform_tests;
+mod interpolation_tests;
 mod matrix_tests;
 mod transform_origin_tests;
 mod transform_parsing_tests;
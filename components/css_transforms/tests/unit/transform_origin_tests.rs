@@ -68,3 +68,10 @@ fn test_parse_transform_origin_single_value() {
     assert_eq!(origin.x.value(), 50.0);
     assert_eq!(origin.y.value(), 50.0);
 }
+
+#[test]
+fn test_transform_origin_error_offset_points_at_bad_component() {
+    let err = parse_transform_origin("left  bogus").unwrap_err();
+    assert_eq!(err.offset, Some(6));
+    assert_eq!(err.snippet.as_deref(), Some("bogus"));
+}
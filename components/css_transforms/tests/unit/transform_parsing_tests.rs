@@ -182,3 +182,63 @@ fn test_parse_empty_transform() {
     let result = parse_transform("");
     assert!(result.is_err());
 }
+
+#[test]
+fn test_parse_error_offset_points_at_bad_function() {
+    let input = "translate(10px, 20px) rotate(45px) scale(2)";
+    let err = parse_transform(input).unwrap_err();
+
+    assert_eq!(err.offset, Some(22));
+    assert_eq!(err.snippet.as_deref(), Some("rotate(45px)"));
+    assert_eq!(
+        &input[err.offset.unwrap()..err.offset.unwrap() + 6],
+        "rotate"
+    );
+}
+
+#[test]
+fn test_parse_error_offset_accounts_for_leading_whitespace() {
+    let err = parse_transform("  scale(2) skewX(oops)").unwrap_err();
+    assert_eq!(err.offset, Some(11));
+    assert_eq!(err.snippet.as_deref(), Some("skewX(oops)"));
+}
+
+#[test]
+fn test_parse_error_offset_can_be_mapped_to_source() {
+    // The value starts at byte 11 of "transform: scale(2) nope(1)"
+    let err = parse_transform("scale(2) nope(1)")
+        .unwrap_err()
+        .offset_by(11);
+    assert_eq!(err.offset, Some(20));
+    assert!(err.to_string().contains("nope(1)"));
+}
+
+#[test]
+fn test_parse_none_is_empty_list() {
+    assert_eq!(parse_transform("none").unwrap(), Transform::none());
+    assert_eq!(parse_transform("  NONE ").unwrap(), Transform::none());
+    assert!(parse_transform("none scale(2)").is_err());
+}
+
+#[test]
+fn test_parse_translate_single_argument() {
+    let transform = parse_transform("translate(10px)").unwrap();
+    match &transform.functions[0] {
+        TransformFunction::Translate { x, y } => {
+            assert_eq!(x.value(), 10.0);
+            assert_eq!(y.value(), 0.0);
+        }
+        _ => panic!("Expected Translate variant"),
+    }
+    assert!(parse_transform("translate(1px, 2px, 3px)").is_err());
+}
+
+#[test]
+fn test_parse_unitless_zero_length() {
+    let transform = parse_transform("translateX(0)").unwrap();
+    match &transform.functions[0] {
+        TransformFunction::TranslateX { value } => assert_eq!(value.value(), 0.0),
+        _ => panic!("Expected TranslateX variant"),
+    }
+    assert!(parse_transform("translateX(5)").is_err());
+}
@@ -4,11 +4,17 @@
 //! - Transition property parsing (all, none, specific, multiple)
 //! - Duration and delay parsing (s, ms)
 //! - Timing function parsing (ease, linear, cubic-bezier, steps)
-//! - Value interpolation (length, color, number, percentage)
+//! - Value interpolation (length, color, number, percentage, transform,
+//!   box-shadow, text-shadow)
 //! - Transition state management
 
 use css_animations::StepPosition;
-use css_types::{Color, CssError, Length};
+use css_parser_core::{tokenize_value, Token};
+pub use css_parser_values::{BoxShadow, TextShadow};
+pub use css_transforms::Transform;
+use css_types::{
+    parse_finite_f64, strip_function, Color, ColorValue, CssError, Length, LengthUnit,
+};
 
 // Re-export StepPosition from css_animations
 pub use css_animations::StepPosition as AnimationStepPosition;
@@ -76,13 +82,6 @@ pub struct Transition {
     pub delay: TransitionDelay,
 }
 
-/// Placeholder Transform type (to be implemented in css_types later)
-#[derive(Debug, Clone, PartialEq)]
-pub struct Transform {
-    // Simplified placeholder
-    pub value: String,
-}
-
 /// Generic property value for transitions
 #[derive(Debug, Clone, PartialEq)]
 pub enum PropertyValue {
@@ -96,6 +95,10 @@ pub enum PropertyValue {
     Percentage(f32),
     /// Transform value
     Transform(Transform),
+    /// Box shadow layers, topmost first
+    BoxShadow(Vec<BoxShadow>),
+    /// Text shadow layers, topmost first
+    TextShadow(Vec<TextShadow>),
 }
 
 /// Active transition state
@@ -121,6 +124,10 @@ pub struct TransitionState {
 
 /// Parse transition-property value
 ///
+/// A comma-separated list keeps its source order. Whitespace around each
+/// name is ignored and names are lowercased, except custom properties,
+/// which are case-sensitive. `all` and `none` are only valid on their own.
+///
 /// # Examples
 /// ```
 /// use css_transitions::{parse_transition_property, TransitionProperty};
@@ -130,6 +137,8 @@ pub struct TransitionState {
 ///
 /// let prop = parse_transition_property("opacity").unwrap();
 /// assert_eq!(prop, TransitionProperty::Property("opacity".to_string()));
+///
+/// assert!(parse_transition_property("all, opacity").is_err());
 /// ```
 pub fn parse_transition_property(input: &str) -> Result<TransitionProperty, CssError> {
     let input = input.trim();
@@ -140,29 +149,61 @@ pub fn parse_transition_property(input: &str) -> Result<TransitionProperty, CssE
         ));
     }
 
-    match input {
-        "all" => Ok(TransitionProperty::All),
-        "none" => Ok(TransitionProperty::None),
-        _ => {
-            // Check for multiple properties (comma-separated)
-            if input.contains(',') {
-                let properties: Vec<String> = input
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-
-                if properties.is_empty() {
-                    return Err(CssError::ParseError(
-                        "No valid properties found".to_string(),
-                    ));
-                }
+    let mut properties = input
+        .split(',')
+        .map(normalize_transition_property_name)
+        .collect::<Result<Vec<String>, CssError>>()?;
+
+    if properties.len() == 1 {
+        let property = properties.remove(0);
+        return Ok(match property.as_str() {
+            "all" => TransitionProperty::All,
+            "none" => TransitionProperty::None,
+            _ => TransitionProperty::Property(property),
+        });
+    }
 
-                Ok(TransitionProperty::Multiple(properties))
-            } else {
-                Ok(TransitionProperty::Property(input.to_string()))
-            }
-        }
+    if let Some(keyword) = properties
+        .iter()
+        .find(|name| matches!(name.as_str(), "all" | "none"))
+    {
+        return Err(CssError::InvalidValue(format!(
+            "'{}' cannot be combined with other transition properties",
+            keyword
+        )));
+    }
+
+    Ok(TransitionProperty::Multiple(properties))
+}
+
+/// Trim and validate one entry of a transition-property list
+fn normalize_transition_property_name(name: &str) -> Result<String, CssError> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(CssError::ParseError(
+            "Empty entry in transition property list".to_string(),
+        ));
+    }
+
+    let valid_start = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '-' || c == '_');
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !valid_start || !valid_chars {
+        return Err(CssError::ParseError(format!(
+            "Invalid transition property name: '{}'",
+            name
+        )));
+    }
+
+    if name.starts_with("--") {
+        Ok(name.to_string())
+    } else {
+        Ok(name.to_ascii_lowercase())
     }
 }
 
@@ -188,9 +229,7 @@ pub fn parse_transition_duration(input: &str) -> Result<TransitionDuration, CssE
     // Check for milliseconds first (more specific)
     if let Some(ms_value) = input.strip_suffix("ms") {
         let ms_value = ms_value.trim();
-        let duration_ms = ms_value
-            .parse::<f64>()
-            .map_err(|_| CssError::ParseError("Invalid duration value".to_string()))?;
+        let duration_ms = parse_finite_f64(ms_value)?;
 
         if duration_ms < 0.0 {
             return Err(CssError::InvalidValue(
@@ -205,9 +244,7 @@ pub fn parse_transition_duration(input: &str) -> Result<TransitionDuration, CssE
         // Seconds
         let s_value = s_value.trim();
 
-        let duration = s_value
-            .parse::<f64>()
-            .map_err(|_| CssError::ParseError("Invalid duration value".to_string()))?;
+        let duration = parse_finite_f64(s_value)?;
 
         if duration < 0.0 {
             return Err(CssError::InvalidValue(
@@ -245,12 +282,20 @@ pub fn parse_transition_timing_function(input: &str) -> Result<TransitionTimingF
         "ease-in" => Ok(TransitionTimingFunction::EaseIn),
         "ease-out" => Ok(TransitionTimingFunction::EaseOut),
         "ease-in-out" => Ok(TransitionTimingFunction::EaseInOut),
+        "step-start" => Ok(TransitionTimingFunction::Steps {
+            count: 1,
+            position: StepPosition::Start,
+        }),
+        "step-end" => Ok(TransitionTimingFunction::Steps {
+            count: 1,
+            position: StepPosition::End,
+        }),
         _ => {
             // Try to parse cubic-bezier or steps
-            if input.starts_with("cubic-bezier(") && input.ends_with(')') {
-                parse_cubic_bezier(input)
-            } else if input.starts_with("steps(") && input.ends_with(')') {
-                parse_steps(input)
+            if let Some(arguments) = strip_function(input, "cubic-bezier") {
+                parse_cubic_bezier(arguments)
+            } else if let Some(arguments) = strip_function(input, "steps") {
+                parse_steps(arguments)
             } else {
                 Err(CssError::ParseError(format!(
                     "Unknown timing function: {}",
@@ -261,10 +306,9 @@ pub fn parse_transition_timing_function(input: &str) -> Result<TransitionTimingF
     }
 }
 
-/// Parse cubic-bezier timing function
-fn parse_cubic_bezier(input: &str) -> Result<TransitionTimingFunction, CssError> {
-    let content = &input[13..input.len() - 1]; // Remove "cubic-bezier(" and ")"
-    let parts: Vec<&str> = content.split(',').map(|s| s.trim()).collect();
+/// Parse the arguments of a cubic-bezier timing function
+fn parse_cubic_bezier(arguments: &str) -> Result<TransitionTimingFunction, CssError> {
+    let parts: Vec<&str> = arguments.split(',').map(|s| s.trim()).collect();
 
     if parts.len() != 4 {
         return Err(CssError::ParseError(
@@ -272,18 +316,10 @@ fn parse_cubic_bezier(input: &str) -> Result<TransitionTimingFunction, CssError>
         ));
     }
 
-    let x1 = parts[0]
-        .parse::<f64>()
-        .map_err(|_| CssError::ParseError("Invalid x1 value".to_string()))?;
-    let y1 = parts[1]
-        .parse::<f64>()
-        .map_err(|_| CssError::ParseError("Invalid y1 value".to_string()))?;
-    let x2 = parts[2]
-        .parse::<f64>()
-        .map_err(|_| CssError::ParseError("Invalid x2 value".to_string()))?;
-    let y2 = parts[3]
-        .parse::<f64>()
-        .map_err(|_| CssError::ParseError("Invalid y2 value".to_string()))?;
+    let x1 = parse_finite_f64(parts[0])?;
+    let y1 = parse_finite_f64(parts[1])?;
+    let x2 = parse_finite_f64(parts[2])?;
+    let y2 = parse_finite_f64(parts[3])?;
 
     // x values must be in [0, 1]
     if !(0.0..=1.0).contains(&x1) || !(0.0..=1.0).contains(&x2) {
@@ -295,10 +331,15 @@ fn parse_cubic_bezier(input: &str) -> Result<TransitionTimingFunction, CssError>
     Ok(TransitionTimingFunction::CubicBezier { x1, y1, x2, y2 })
 }
 
-/// Parse steps timing function
-fn parse_steps(input: &str) -> Result<TransitionTimingFunction, CssError> {
-    let content = &input[6..input.len() - 1]; // Remove "steps(" and ")"
-    let parts: Vec<&str> = content.split(',').map(|s| s.trim()).collect();
+/// Largest step count accepted by `steps()`
+///
+/// Beyond this, steps are finer than any practical frame rate or pixel grid
+/// and the step boundaries start to lose precision.
+pub const MAX_STEP_COUNT: u32 = 1_000_000;
+
+/// Parse the arguments of a steps timing function
+fn parse_steps(arguments: &str) -> Result<TransitionTimingFunction, CssError> {
+    let parts: Vec<&str> = arguments.split(',').map(|s| s.trim()).collect();
 
     if parts.is_empty() || parts.len() > 2 {
         return Err(CssError::ParseError(
@@ -307,12 +348,19 @@ fn parse_steps(input: &str) -> Result<TransitionTimingFunction, CssError> {
     }
 
     let count = parts[0]
-        .parse::<u32>()
+        .parse::<u64>()
         .map_err(|_| CssError::ParseError("Invalid step count".to_string()))?;
 
     if count == 0 {
         return Err(CssError::InvalidValue("Step count must be > 0".to_string()));
     }
+    if count > u64::from(MAX_STEP_COUNT) {
+        return Err(CssError::OutOfRange(format!(
+            "Step count {} exceeds the maximum of {}",
+            count, MAX_STEP_COUNT
+        )));
+    }
+    let count = count as u32;
 
     let position = if parts.len() == 2 {
         match parts[1] {
@@ -352,9 +400,7 @@ pub fn parse_transition_delay(input: &str) -> Result<TransitionDelay, CssError>
     // Check for milliseconds first (more specific)
     if let Some(ms_value) = input.strip_suffix("ms") {
         let ms_value = ms_value.trim();
-        let delay_ms = ms_value
-            .parse::<f64>()
-            .map_err(|_| CssError::ParseError("Invalid delay value".to_string()))?;
+        let delay_ms = parse_finite_f64(ms_value)?;
 
         Ok(TransitionDelay {
             delay: delay_ms / 1000.0,
@@ -362,9 +408,7 @@ pub fn parse_transition_delay(input: &str) -> Result<TransitionDelay, CssError>
     } else if let Some(s_value) = input.strip_suffix('s') {
         let s_value = s_value.trim();
 
-        let delay = s_value
-            .parse::<f64>()
-            .map_err(|_| CssError::ParseError("Invalid delay value".to_string()))?;
+        let delay = parse_finite_f64(s_value)?;
 
         Ok(TransitionDelay { delay })
     } else {
@@ -376,11 +420,17 @@ pub fn parse_transition_delay(input: &str) -> Result<TransitionDelay, CssError>
 
 /// Parse transition shorthand property
 ///
+/// Every component is optional: the property defaults to `all`, the
+/// duration and delay to `0s` and the timing function to `ease`.
+///
 /// # Examples
 /// ```
 /// use css_transitions::parse_transition;
 ///
 /// let transition = parse_transition("opacity 0.3s ease").unwrap();
+///
+/// let transition = parse_transition("opacity").unwrap();
+/// assert_eq!(transition.duration.duration, 0.0);
 /// ```
 pub fn parse_transition(input: &str) -> Result<Transition, CssError> {
     let input = input.trim();
@@ -390,7 +440,7 @@ pub fn parse_transition(input: &str) -> Result<Transition, CssError> {
     }
 
     // Extract timing functions first (they may contain spaces)
-    let (parts, timing_function) = extract_timing_function(input)?;
+    let (parts, mut timing_function) = extract_timing_function(input)?;
 
     if parts.is_empty() {
         return Err(CssError::ParseError("Empty transition".to_string()));
@@ -402,8 +452,8 @@ pub fn parse_transition(input: &str) -> Result<Transition, CssError> {
     let mut delay = None;
 
     for part in parts {
-        // Try to parse as duration/delay (must have s or ms)
-        if part.ends_with('s') || part.ends_with("ms") {
+        // Try to parse as duration/delay (a number ending in s or ms)
+        if is_time(&part) {
             if duration.is_none() {
                 duration = Some(parse_transition_duration(&part)?);
             } else if delay.is_none() {
@@ -417,10 +467,9 @@ pub fn parse_transition(input: &str) -> Result<Transition, CssError> {
         // Try to parse as timing function keyword
         else if matches!(
             part.as_str(),
-            "ease" | "linear" | "ease-in" | "ease-out" | "ease-in-out"
+            "ease" | "linear" | "ease-in" | "ease-out" | "ease-in-out" | "step-start" | "step-end"
         ) {
-            // Already handled by extract_timing_function
-            continue;
+            timing_function = Some(parse_transition_timing_function(&part)?);
         }
         // Otherwise, it's a property name
         else {
@@ -435,50 +484,356 @@ pub fn parse_transition(input: &str) -> Result<Transition, CssError> {
 
     Ok(Transition {
         property: property.unwrap_or(TransitionProperty::All),
-        duration: duration.ok_or_else(|| {
-            CssError::ParseError("Duration is required in transition".to_string())
-        })?,
+        duration: duration.unwrap_or(TransitionDuration { duration: 0.0 }),
         timing_function: timing_function.unwrap_or(TransitionTimingFunction::Ease),
         delay: delay.unwrap_or(TransitionDelay { delay: 0.0 }),
     })
 }
 
+/// Parse a comma-separated list of transitions
+///
+/// `none` on its own yields an empty list. It is not allowed as one entry of
+/// a longer list.
+///
+/// # Examples
+/// ```
+/// use css_transitions::parse_transition_list;
+///
+/// let transitions = parse_transition_list("opacity 0.3s, transform 1s ease-in").unwrap();
+/// assert_eq!(transitions.len(), 2);
+///
+/// assert!(parse_transition_list("none").unwrap().is_empty());
+/// ```
+pub fn parse_transition_list(input: &str) -> Result<Vec<Transition>, CssError> {
+    let mut entries = vec![String::new()];
+    for token in tokenize_value(input) {
+        match token {
+            Token::Delimiter(',') => entries.push(String::new()),
+            _ => {
+                let entry = entries.last_mut().expect("entries is never empty");
+                if !entry.is_empty() {
+                    entry.push(' ');
+                }
+                entry.push_str(&token.to_css_string());
+            }
+        }
+    }
+
+    let transitions = entries
+        .iter()
+        .map(|entry| parse_transition(entry))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match transitions.as_slice() {
+        [only] if only.property == TransitionProperty::None => Ok(Vec::new()),
+        _ if transitions
+            .iter()
+            .any(|transition| transition.property == TransitionProperty::None) =>
+        {
+            Err(CssError::ParseError(
+                "'none' cannot be combined with other transitions".to_string(),
+            ))
+        }
+        _ => Ok(transitions),
+    }
+}
+
+/// Check whether a shorthand component is a time value such as `0.3s`
+fn is_time(part: &str) -> bool {
+    let starts_numeric = part
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_digit() || matches!(c, '.' | '+' | '-'));
+    starts_numeric && (part.ends_with('s') || part.ends_with("ms"))
+}
+
 /// Extract timing function from transition string, handling functions with spaces
 fn extract_timing_function(
     input: &str,
 ) -> Result<(Vec<String>, Option<TransitionTimingFunction>), CssError> {
     let mut timing_function = None;
     let mut remaining_parts = Vec::new();
-    let mut current_token = String::new();
-    let mut in_function = false;
-
-    for ch in input.chars() {
-        if ch == '(' {
-            in_function = true;
-            current_token.push(ch);
-        } else if ch == ')' {
-            in_function = false;
-            current_token.push(ch);
-            // Parse the function
-            if current_token.starts_with("cubic-bezier(") || current_token.starts_with("steps(") {
-                timing_function = Some(parse_transition_timing_function(&current_token)?);
-                current_token.clear();
-            }
-        } else if ch.is_whitespace() && !in_function {
-            if !current_token.is_empty() {
-                remaining_parts.push(current_token.clone());
-                current_token.clear();
-            }
+
+    for token in tokenize_value(input) {
+        if token.is_function("cubic-bezier") || token.is_function("steps") {
+            timing_function = Some(parse_transition_timing_function(&token.to_css_string())?);
         } else {
-            current_token.push(ch);
+            remaining_parts.push(token.to_css_string());
         }
     }
 
-    if !current_token.is_empty() {
-        remaining_parts.push(current_token);
+    Ok((remaining_parts, timing_function))
+}
+
+// ============================================================================
+// Interpolation Trait
+// ============================================================================
+
+/// Types whose values can be blended for transitions and animations
+///
+/// `t` is the (already eased) progress: `0.0` gives `self`, `1.0` gives
+/// `other`. Values outside `0.0..=1.0` extrapolate where the type allows.
+///
+/// # Examples
+/// ```
+/// use css_transitions::Interpolate;
+/// use css_types::{Length, LengthUnit};
+///
+/// assert_eq!(0.0_f32.interpolate(&10.0, 0.25), 2.5);
+///
+/// let from = Length::new(10.0, LengthUnit::Px);
+/// let to = Length::new(20.0, LengthUnit::Px);
+/// assert_eq!(from.interpolate(&to, 0.5), Length::new(15.0, LengthUnit::Px));
+/// ```
+pub trait Interpolate {
+    /// Blend `self` towards `other` by `t`
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as f32
     }
+}
 
-    Ok((remaining_parts, timing_function))
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Length {
+    /// Interpolate the numeric value, keeping the unit of `self`
+    ///
+    /// Lengths in different units need converting first; see
+    /// [`interpolate_length`].
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Length::new(self.value().interpolate(&other.value(), t), self.unit())
+    }
+}
+
+/// Font and viewport sizes used to convert relative lengths to pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LengthContext {
+    /// Font size of the element in pixels (used for `em`)
+    pub font_size: f32,
+    /// Root element font size in pixels (used for `rem`)
+    pub root_font_size: f32,
+    /// Viewport width in pixels
+    pub viewport_width: f32,
+    /// Viewport height in pixels
+    pub viewport_height: f32,
+}
+
+impl LengthContext {
+    /// Create a length context
+    pub fn new(
+        font_size: f32,
+        root_font_size: f32,
+        viewport_width: f32,
+        viewport_height: f32,
+    ) -> Self {
+        Self {
+            font_size,
+            root_font_size,
+            viewport_width,
+            viewport_height,
+        }
+    }
+}
+
+/// Interpolate two lengths, converting mismatched units to pixels
+///
+/// Lengths in the same unit interpolate in that unit. Otherwise both
+/// endpoints are resolved to pixels, absolute units directly and font or
+/// viewport units against `context`, and the result is in pixels.
+/// Returns `None` when an endpoint cannot be resolved (percentages, whose
+/// basis depends on the property), so the caller can switch discretely.
+///
+/// # Examples
+/// ```
+/// use css_transitions::{interpolate_length, LengthContext};
+/// use css_types::{Length, LengthUnit};
+///
+/// let context = LengthContext::new(16.0, 16.0, 1024.0, 768.0);
+/// let start = Length::new(1.0, LengthUnit::In);
+/// let end = Length::new(0.0, LengthUnit::Px);
+/// assert_eq!(
+///     interpolate_length(&start, &end, 0.5, &context),
+///     Some(Length::new(48.0, LengthUnit::Px))
+/// );
+/// ```
+pub fn interpolate_length(
+    start: &Length,
+    end: &Length,
+    t: f64,
+    context: &LengthContext,
+) -> Option<Length> {
+    interpolate_lengths(start, end, t, Some(context))
+}
+
+/// Interpolate two lengths, resolving relative units only with a context
+fn interpolate_lengths(
+    start: &Length,
+    end: &Length,
+    t: f64,
+    context: Option<&LengthContext>,
+) -> Option<Length> {
+    if start.unit() == end.unit() {
+        return Some(start.interpolate(end, t));
+    }
+
+    let start_px = length_to_px(start, context)?;
+    let end_px = length_to_px(end, context)?;
+    Some(Length::new(
+        start_px.interpolate(&end_px, t),
+        LengthUnit::Px,
+    ))
+}
+
+/// Resolve a length to pixels, if its unit allows it
+fn length_to_px(length: &Length, context: Option<&LengthContext>) -> Option<f32> {
+    if let Some(factor) = length.unit().px_per_unit() {
+        return Some(length.value() * factor);
+    }
+
+    let context = context?;
+    let basis = match length.unit() {
+        LengthUnit::Em => context.font_size,
+        LengthUnit::Rem => context.root_font_size,
+        LengthUnit::Vw => context.viewport_width / 100.0,
+        LengthUnit::Vh => context.viewport_height / 100.0,
+        LengthUnit::Vmin => context.viewport_width.min(context.viewport_height) / 100.0,
+        LengthUnit::Vmax => context.viewport_width.max(context.viewport_height) / 100.0,
+        _ => return None,
+    };
+    Some(length.value() * basis)
+}
+
+impl Interpolate for Color {
+    /// When either endpoint is translucent the RGB channels are interpolated
+    /// in premultiplied-alpha space, so fading to `transparent` does not
+    /// darken the color on the way.
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        if self.a() < 1.0 || other.a() < 1.0 {
+            return interpolate_color_premultiplied(self, other, t);
+        }
+
+        let channel = |s: u8, e: u8| (s as f64).interpolate(&(e as f64), t) as u8;
+
+        Color::rgba(
+            channel(self.r(), other.r()),
+            channel(self.g(), other.g()),
+            channel(self.b(), other.b()),
+            self.a().interpolate(&other.a(), t),
+        )
+    }
+}
+
+impl Interpolate for Transform {
+    /// See [`Transform::interpolate`] for how function lists are matched
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Transform::interpolate(self, other, t as f32)
+    }
+}
+
+impl Interpolate for BoxShadow {
+    /// Interpolate offsets, blur, spread and color, keeping the `inset` flag
+    /// of `self`
+    ///
+    /// A `currentcolor` endpoint cannot be mixed before the cascade resolves
+    /// it, so such colors switch discretely at the midpoint.
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        BoxShadow::new(
+            self.inset(),
+            self.offset_x().interpolate(&other.offset_x(), t),
+            self.offset_y().interpolate(&other.offset_y(), t),
+            self.blur().interpolate(&other.blur(), t),
+            self.spread().interpolate(&other.spread(), t),
+            interpolate_shadow_color(self.color(), other.color(), t),
+        )
+    }
+}
+
+impl Interpolate for TextShadow {
+    /// Interpolate offsets, blur and color; colors behave as for
+    /// [`BoxShadow`]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        TextShadow::new(
+            self.offset_x().interpolate(&other.offset_x(), t),
+            self.offset_y().interpolate(&other.offset_y(), t),
+            self.blur().interpolate(&other.blur(), t),
+            interpolate_shadow_color(self.color(), other.color(), t),
+        )
+    }
+}
+
+/// Interpolate a shadow color, switching `currentcolor` at the midpoint
+fn interpolate_shadow_color(start: ColorValue, end: ColorValue, t: f64) -> ColorValue {
+    match (start, end) {
+        (ColorValue::Rgba(s), ColorValue::Rgba(e)) => ColorValue::Rgba(s.interpolate(&e, t)),
+        (s, _) if t < 0.5 => s,
+        (_, e) => e,
+    }
+}
+
+/// Transparent color used to pad the shorter of two shadow lists
+fn transparent_shadow_color() -> ColorValue {
+    ColorValue::Rgba(Color::rgba(0, 0, 0, 0.0))
+}
+
+/// Interpolate two box-shadow lists layer by layer
+///
+/// The shorter list is padded with transparent zero-length shadows. Returns
+/// `None` if a pair of layers disagrees on `inset`, in which case the
+/// value switches discretely at the midpoint.
+fn interpolate_box_shadows(
+    start: &[BoxShadow],
+    end: &[BoxShadow],
+    t: f64,
+) -> Option<Vec<BoxShadow>> {
+    let zero_shadow = |like: &BoxShadow| {
+        let zero = Length::new(0.0, LengthUnit::Px);
+        BoxShadow::new(
+            like.inset(),
+            zero,
+            zero,
+            zero,
+            zero,
+            transparent_shadow_color(),
+        )
+    };
+
+    (0..start.len().max(end.len()))
+        .map(|i| {
+            let s = start
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| zero_shadow(&end[i]));
+            let e = end
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| zero_shadow(&start[i]));
+            (s.inset() == e.inset()).then(|| s.interpolate(&e, t))
+        })
+        .collect()
+}
+
+/// Interpolate two text-shadow lists layer by layer
+///
+/// Layers are matched pairwise and the shorter list is padded with
+/// transparent zero-length shadows.
+fn interpolate_text_shadows(start: &[TextShadow], end: &[TextShadow], t: f64) -> Vec<TextShadow> {
+    let zero = Length::new(0.0, LengthUnit::Px);
+    let zero_shadow = TextShadow::new(zero, zero, zero, transparent_shadow_color());
+
+    (0..start.len().max(end.len()))
+        .map(|i| {
+            let s = start.get(i).unwrap_or(&zero_shadow);
+            let e = end.get(i).unwrap_or(&zero_shadow);
+            s.interpolate(e, t)
+        })
+        .collect()
 }
 
 // ============================================================================
@@ -507,42 +862,112 @@ pub fn interpolate_value(
 
     match (start, end) {
         (PropertyValue::Number(s), PropertyValue::Number(e)) => {
-            PropertyValue::Number(s + (e - s) * eased_progress)
+            PropertyValue::Number(s.interpolate(e, eased_progress))
         }
         (PropertyValue::Percentage(s), PropertyValue::Percentage(e)) => {
-            PropertyValue::Percentage(s + (e - s) * eased_progress as f32)
+            PropertyValue::Percentage(s.interpolate(e, eased_progress))
         }
         (PropertyValue::Length(s), PropertyValue::Length(e)) => {
-            // Interpolate lengths (assuming same unit)
-            PropertyValue::Length(interpolate_length(s, e, eased_progress))
+            // Without a context only absolute units can be converted
+            match interpolate_lengths(s, e, eased_progress, None) {
+                Some(length) => PropertyValue::Length(length),
+                None if eased_progress < 0.5 => start.clone(),
+                None => end.clone(),
+            }
         }
         (PropertyValue::Color(s), PropertyValue::Color(e)) => {
-            PropertyValue::Color(interpolate_color(s, e, eased_progress))
+            PropertyValue::Color(s.interpolate(e, eased_progress))
+        }
+        (PropertyValue::Transform(s), PropertyValue::Transform(e)) => {
+            PropertyValue::Transform(Interpolate::interpolate(s, e, eased_progress))
+        }
+        (PropertyValue::BoxShadow(s), PropertyValue::BoxShadow(e)) => {
+            match interpolate_box_shadows(s, e, eased_progress) {
+                Some(shadows) => PropertyValue::BoxShadow(shadows),
+                None if eased_progress < 0.5 => start.clone(),
+                None => end.clone(),
+            }
+        }
+        (PropertyValue::TextShadow(s), PropertyValue::TextShadow(e)) => {
+            PropertyValue::TextShadow(interpolate_text_shadows(s, e, eased_progress))
         }
         // If types don't match, return end value (discrete transition)
         _ => end.clone(),
     }
 }
 
-/// Interpolate between two lengths
-fn interpolate_length(start: &Length, end: &Length, progress: f64) -> Length {
-    // For simplicity, just interpolate the value
-    // In a real implementation, we'd need to handle unit conversion
-    let start_val = start.value();
-    let end_val = end.value();
-    let interpolated = start_val + (end_val - start_val) * progress as f32;
+/// Recover the interpolation progress that produced a value
+///
+/// This is the inverse of the linear interpolation in [`interpolate_value`]:
+/// given the endpoints and an intermediate value, it returns the progress
+/// `p` such that `start + (end - start) * p == value`. The timing function
+/// is not inverted, so the result is the eased progress. Values outside the
+/// endpoints give progress outside `0.0..=1.0`.
+///
+/// Returns `None` for non-numeric values, lengths with mismatched units,
+/// mismatched value types, and equal endpoints (where every progress
+/// produces the same value).
+///
+/// # Examples
+/// ```
+/// use css_transitions::{interpolate_value_inverse, PropertyValue};
+///
+/// let start = PropertyValue::Number(0.0);
+/// let end = PropertyValue::Number(100.0);
+/// let value = PropertyValue::Number(50.0);
+/// assert_eq!(interpolate_value_inverse(&start, &end, &value), Some(0.5));
+/// ```
+pub fn interpolate_value_inverse(
+    start: &PropertyValue,
+    end: &PropertyValue,
+    value: &PropertyValue,
+) -> Option<f64> {
+    let (s, e, v) = match (start, end, value) {
+        (PropertyValue::Number(s), PropertyValue::Number(e), PropertyValue::Number(v)) => {
+            (*s, *e, *v)
+        }
+        (
+            PropertyValue::Percentage(s),
+            PropertyValue::Percentage(e),
+            PropertyValue::Percentage(v),
+        ) => (*s as f64, *e as f64, *v as f64),
+        (PropertyValue::Length(s), PropertyValue::Length(e), PropertyValue::Length(v))
+            if s.unit() == e.unit() && e.unit() == v.unit() =>
+        {
+            (s.value() as f64, e.value() as f64, v.value() as f64)
+        }
+        _ => return None,
+    };
+
+    if s == e {
+        return None;
+    }
 
-    Length::new(interpolated, start.unit())
+    Some((v - s) / (e - s))
 }
 
-/// Interpolate between two colors
-fn interpolate_color(start: &Color, end: &Color, progress: f64) -> Color {
-    let r = (start.r() as f64 + (end.r() as f64 - start.r() as f64) * progress) as u8;
-    let g = (start.g() as f64 + (end.g() as f64 - start.g() as f64) * progress) as u8;
-    let b = (start.b() as f64 + (end.b() as f64 - start.b() as f64) * progress) as u8;
-    let a = start.a() + (end.a() - start.a()) * progress as f32;
+/// Interpolate between two colors with premultiplied alpha
+fn interpolate_color_premultiplied(start: &Color, end: &Color, progress: f64) -> Color {
+    let start_a = start.a() as f64;
+    let end_a = end.a() as f64;
+    let a = start_a + (end_a - start_a) * progress;
 
-    Color::rgba(r, g, b, a)
+    if a <= 0.0 {
+        return Color::rgba(0, 0, 0, 0.0);
+    }
+
+    let channel = |s: u8, e: u8| {
+        let s = s as f64 * start_a;
+        let e = e as f64 * end_a;
+        ((s + (e - s) * progress) / a).round().clamp(0.0, 255.0) as u8
+    };
+
+    Color::rgba(
+        channel(start.r(), end.r()),
+        channel(start.g(), end.g()),
+        channel(start.b(), end.b()),
+        a as f32,
+    )
 }
 
 // ============================================================================
@@ -639,16 +1064,33 @@ fn evaluate_steps(count: u32, position: StepPosition, progress: f64) -> f64 {
         return 0.0;
     }
 
+    // `progress * steps` can round across a step boundary, so the step
+    // index is corrected by comparing the boundary itself with `progress`
     let steps = count as f64;
-    match position {
-        StepPosition::Start => ((progress * steps).ceil() / steps).min(1.0),
+    let step = match position {
+        StepPosition::Start => {
+            // Smallest step whose boundary is at or after progress
+            let mut step = (progress * steps).ceil();
+            if step / steps < progress {
+                step += 1.0;
+            } else if step > 1.0 && (step - 1.0) / steps >= progress {
+                step -= 1.0;
+            }
+            step
+        }
         StepPosition::End => {
-            // For "end", boundaries belong to the previous interval
-            // Subtract tiny epsilon to handle exact boundary cases
-            let adjusted = (progress * steps - 1e-10).max(0.0);
-            (adjusted.floor() / steps).min(1.0)
+            // Largest step whose boundary is before progress; for "end",
+            // boundaries belong to the previous interval
+            let mut step = (progress * steps).floor();
+            if step / steps >= progress {
+                step -= 1.0;
+            } else if (step + 1.0) / steps < progress {
+                step += 1.0;
+            }
+            step.max(0.0)
         }
-    }
+    };
+    (step / steps).min(1.0)
 }
 
 // ============================================================================
@@ -728,6 +1170,7 @@ impl TransitionEngine for DefaultTransitionEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use css_parser_values::{parse_box_shadow, parse_text_shadow};
 
     // ========================================================================
     // Transition Property Parsing Tests
@@ -766,6 +1209,44 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_transition_property_trims_and_normalizes() {
+        let result =
+            parse_transition_property("  margin-top ,margin-left ,  Opacity , --Custom ").unwrap();
+        assert_eq!(
+            result,
+            TransitionProperty::Multiple(vec![
+                "margin-top".to_string(),
+                "margin-left".to_string(),
+                "opacity".to_string(),
+                "--Custom".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_transition_property_keyword_in_list_is_error() {
+        assert!(matches!(
+            parse_transition_property("all, opacity"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            parse_transition_property("opacity, none"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert_eq!(
+            parse_transition_property(" ALL ").unwrap(),
+            TransitionProperty::All
+        );
+    }
+
+    #[test]
+    fn test_parse_transition_property_rejects_malformed_entries() {
+        assert!(parse_transition_property("opacity,").is_err());
+        assert!(parse_transition_property("opacity,,transform").is_err());
+        assert!(parse_transition_property("margin top").is_err());
+    }
+
     // ========================================================================
     // Duration Parsing Tests
     // ========================================================================
@@ -800,6 +1281,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_duration_non_finite() {
+        for input in ["NaNs", "infs", "1e40s", "NaNms", "-infms"] {
+            assert!(
+                matches!(
+                    parse_transition_duration(input),
+                    Err(CssError::InvalidValue(_))
+                ),
+                "{} should be rejected",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_cubic_bezier_non_finite() {
+        assert!(matches!(
+            parse_transition_timing_function("cubic-bezier(0.1, NaN, 0.2, 1.0)"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            parse_transition_timing_function("cubic-bezier(0.1, 1e40, 0.2, 1.0)"),
+            Err(CssError::InvalidValue(_))
+        ));
+    }
+
     // ========================================================================
     // Timing Function Parsing Tests
     // ========================================================================
@@ -854,6 +1361,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_timing_function_space_before_paren() {
+        assert_eq!(
+            parse_transition_timing_function("steps (4, end)").unwrap(),
+            TransitionTimingFunction::Steps {
+                count: 4,
+                position: StepPosition::End
+            }
+        );
+        assert!(matches!(
+            parse_transition_timing_function("cubic-bezier\t(0.4, 0, 0.2, 1)"),
+            Ok(TransitionTimingFunction::CubicBezier { .. })
+        ));
+        assert!(parse_transition_timing_function("steps(4) steps(2)").is_err());
+    }
+
+    #[test]
+    fn test_parse_timing_function_step_start() {
+        let result = parse_transition_timing_function("step-start").unwrap();
+        assert_eq!(
+            result,
+            TransitionTimingFunction::Steps {
+                count: 1,
+                position: StepPosition::Start
+            }
+        );
+
+        // Jumps to the end value as soon as the transition starts
+        assert_eq!(evaluate_timing_function(&result, 0.01), 1.0);
+        assert_eq!(evaluate_timing_function(&result, 0.5), 1.0);
+    }
+
+    #[test]
+    fn test_parse_timing_function_step_end() {
+        let result = parse_transition_timing_function("step-end").unwrap();
+        assert_eq!(
+            result,
+            TransitionTimingFunction::Steps {
+                count: 1,
+                position: StepPosition::End
+            }
+        );
+
+        // Holds the start value until the transition ends
+        assert_eq!(evaluate_timing_function(&result, 0.5), 0.0);
+        assert_eq!(evaluate_timing_function(&result, 0.99), 0.0);
+        assert_eq!(evaluate_timing_function(&result, 1.0), 1.0);
+    }
+
     // ========================================================================
     // Delay Parsing Tests
     // ========================================================================
@@ -876,6 +1432,13 @@ mod tests {
         assert_eq!(result.delay, -1.0);
     }
 
+    #[test]
+    fn test_parse_delay_non_finite() {
+        assert!(parse_transition_delay("NaNs").is_err());
+        assert!(parse_transition_delay("infms").is_err());
+        assert!(parse_transition_delay("1e40s").is_err());
+    }
+
     // ========================================================================
     // Transition Shorthand Parsing Tests
     // ========================================================================
@@ -909,6 +1472,39 @@ mod tests {
         assert_eq!(result.delay.delay, 0.5);
     }
 
+    #[test]
+    fn test_parse_transition_step_keyword() {
+        let result = parse_transition("opacity 1s step-end").unwrap();
+        assert_eq!(
+            result.property,
+            TransitionProperty::Property("opacity".to_string())
+        );
+        assert_eq!(
+            result.timing_function,
+            TransitionTimingFunction::Steps {
+                count: 1,
+                position: StepPosition::End
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_transition_steps_function_with_spaces() {
+        let result = parse_transition("width  2s   steps(4, start) 250ms").unwrap();
+        assert_eq!(
+            result.property,
+            TransitionProperty::Property("width".to_string())
+        );
+        assert_eq!(
+            result.timing_function,
+            TransitionTimingFunction::Steps {
+                count: 4,
+                position: StepPosition::Start
+            }
+        );
+        assert_eq!(result.delay.delay, 0.25);
+    }
+
     #[test]
     fn test_parse_transition_minimal() {
         let result = parse_transition("0.3s").unwrap();
@@ -916,6 +1512,127 @@ mod tests {
         assert_eq!(result.duration.duration, 0.3);
     }
 
+    #[test]
+    fn test_parse_transition_none() {
+        let result = parse_transition("none").unwrap();
+        assert_eq!(result.property, TransitionProperty::None);
+        assert_eq!(result.duration.duration, 0.0);
+
+        assert_eq!(parse_transition_list("none").unwrap(), vec![]);
+        assert_eq!(parse_transition_list(" none ").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_transition_all_without_duration() {
+        let result = parse_transition("all").unwrap();
+        assert_eq!(result.property, TransitionProperty::All);
+        assert_eq!(result.duration.duration, 0.0);
+        assert_eq!(result.delay.delay, 0.0);
+
+        let result = parse_transition("all 0s").unwrap();
+        assert_eq!(result.duration.duration, 0.0);
+    }
+
+    #[test]
+    fn test_parse_transition_property_without_duration() {
+        let result = parse_transition("opacity").unwrap();
+        assert_eq!(
+            result.property,
+            TransitionProperty::Property("opacity".to_string())
+        );
+        assert_eq!(result.duration.duration, 0.0);
+        assert_eq!(result.timing_function, TransitionTimingFunction::Ease);
+
+        // Property names ending in 's' are not mistaken for times
+        let result = parse_transition("columns ease-in").unwrap();
+        assert_eq!(
+            result.property,
+            TransitionProperty::Property("columns".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_transition_list() {
+        let result =
+            parse_transition_list("opacity 0.3s, transform 1s cubic-bezier(0.4, 0, 0.2, 1) 50ms")
+                .unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].property,
+            TransitionProperty::Property("opacity".to_string())
+        );
+        assert_eq!(
+            result[1].property,
+            TransitionProperty::Property("transform".to_string())
+        );
+        assert_eq!(result[1].delay.delay, 0.05);
+        assert!(matches!(
+            result[1].timing_function,
+            TransitionTimingFunction::CubicBezier { .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_transition_list_rejects_none_in_list() {
+        assert!(parse_transition_list("none, opacity 1s").is_err());
+        assert!(parse_transition_list("opacity 1s,").is_err());
+    }
+
+    // ========================================================================
+    // Interpolate Trait Tests
+    // ========================================================================
+
+    #[test]
+    fn test_interpolate_trait_numbers() {
+        assert_eq!(10.0_f32.interpolate(&20.0, 0.5), 15.0);
+        assert_eq!(10.0_f64.interpolate(&20.0, 0.25), 12.5);
+        // Extrapolation past the end point
+        assert_eq!(0.0_f32.interpolate(&10.0, 1.5), 15.0);
+    }
+
+    #[test]
+    fn test_interpolate_trait_length() {
+        let from = Length::new(0.0, LengthUnit::Em);
+        let to = Length::new(4.0, LengthUnit::Em);
+        assert_eq!(
+            from.interpolate(&to, 0.75),
+            Length::new(3.0, LengthUnit::Em)
+        );
+    }
+
+    #[test]
+    fn test_interpolate_trait_color() {
+        let from = Color::rgb(0, 100, 200);
+        let to = Color::rgb(100, 200, 0);
+        assert_eq!(from.interpolate(&to, 0.5), Color::rgb(50, 150, 100));
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 1.0), to);
+    }
+
+    #[test]
+    fn test_interpolate_trait_transform() {
+        let from = css_transforms::parse_transform("translateX(0px)").unwrap();
+        let to = css_transforms::parse_transform("translateX(100px)").unwrap();
+        let halfway = Interpolate::interpolate(&from, &to, 0.5);
+        assert_eq!(
+            halfway,
+            css_transforms::parse_transform("translateX(50px)").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_interpolate_value_matches_trait() {
+        let from = Color::rgba(255, 0, 0, 0.5);
+        let to = Color::rgba(0, 0, 255, 1.0);
+        let result = interpolate_value(
+            &PropertyValue::Color(from),
+            &PropertyValue::Color(to),
+            0.3,
+            &TransitionTimingFunction::Linear,
+        );
+        assert_eq!(result, PropertyValue::Color(from.interpolate(&to, 0.3)));
+    }
+
     // ========================================================================
     // Value Interpolation Tests
     // ========================================================================
@@ -961,6 +1678,243 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_interpolate_color_to_transparent_is_premultiplied() {
+        let start = PropertyValue::Color(Color::rgb(255, 0, 0));
+        let end = PropertyValue::Color(Color::rgba(0, 0, 0, 0.0));
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        match result {
+            PropertyValue::Color(color) => {
+                // Stays fully red instead of passing through dark red
+                assert_eq!(color.r(), 255);
+                assert_eq!(color.g(), 0);
+                assert_eq!(color.b(), 0);
+                assert!((color.a() - 0.5).abs() < 0.001);
+            }
+            _ => panic!("Expected Color"),
+        }
+    }
+
+    #[test]
+    fn test_interpolate_color_fully_transparent_endpoints() {
+        let start = PropertyValue::Color(Color::rgba(255, 0, 0, 0.0));
+        let end = PropertyValue::Color(Color::rgba(0, 0, 255, 0.0));
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        assert_eq!(result, PropertyValue::Color(Color::rgba(0, 0, 0, 0.0)));
+    }
+
+    #[test]
+    fn test_interpolate_transform_scale() {
+        use css_transforms::{parse_transform, TransformFunction};
+
+        let start = PropertyValue::Transform(parse_transform("scale(1)").unwrap());
+        let end = PropertyValue::Transform(parse_transform("scale(2)").unwrap());
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        assert_eq!(
+            result,
+            PropertyValue::Transform(Transform {
+                functions: vec![TransformFunction::Scale { x: 1.5, y: 1.5 }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_interpolate_transform_mismatched_functions_is_discrete() {
+        use css_transforms::parse_transform;
+
+        let start = PropertyValue::Transform(parse_transform("scale(2)").unwrap());
+        let end = PropertyValue::Transform(parse_transform("rotate(90deg)").unwrap());
+
+        let early = interpolate_value(&start, &end, 0.25, &TransitionTimingFunction::Linear);
+        let late = interpolate_value(&start, &end, 0.75, &TransitionTimingFunction::Linear);
+        assert_eq!(early, start);
+        assert_eq!(late, end);
+    }
+
+    #[test]
+    fn test_interpolate_box_shadow_blur_and_offset() {
+        let start = PropertyValue::BoxShadow(parse_box_shadow("0 0 0 black").unwrap());
+        let end = PropertyValue::BoxShadow(parse_box_shadow("10px 4px 8px black").unwrap());
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        assert_eq!(
+            result,
+            PropertyValue::BoxShadow(parse_box_shadow("5px 2px 4px black").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_interpolate_box_shadow_pads_missing_layers() {
+        let start = PropertyValue::BoxShadow(parse_box_shadow("2px 2px 4px red").unwrap());
+        let end = PropertyValue::BoxShadow(
+            parse_box_shadow("4px 4px 4px red, 10px 10px 10px rgba(0, 0, 255, 1)").unwrap(),
+        );
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        let PropertyValue::BoxShadow(shadows) = result else {
+            panic!("Expected box-shadow value");
+        };
+        assert_eq!(shadows.len(), 2);
+        assert_eq!(shadows[0].offset_x(), Length::new(3.0, LengthUnit::Px));
+        assert_eq!(shadows[1].blur(), Length::new(5.0, LengthUnit::Px));
+        // Fading in from transparent keeps the color channels
+        assert_eq!(
+            shadows[1].color(),
+            ColorValue::Rgba(Color::rgba(0, 0, 255, 0.5))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_box_shadow_mismatched_inset_is_discrete() {
+        let start = PropertyValue::BoxShadow(parse_box_shadow("2px 2px red").unwrap());
+        let end = PropertyValue::BoxShadow(parse_box_shadow("inset 2px 2px red").unwrap());
+
+        let early = interpolate_value(&start, &end, 0.25, &TransitionTimingFunction::Linear);
+        let late = interpolate_value(&start, &end, 0.75, &TransitionTimingFunction::Linear);
+        assert_eq!(early, start);
+        assert_eq!(late, end);
+    }
+
+    #[test]
+    fn test_interpolate_text_shadow_blur_at_midpoint() {
+        let start = PropertyValue::TextShadow(parse_text_shadow("1px 1px 2px black").unwrap());
+        let end = PropertyValue::TextShadow(parse_text_shadow("1px 1px 10px black").unwrap());
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        assert_eq!(
+            result,
+            PropertyValue::TextShadow(parse_text_shadow("1px 1px 6px black").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_interpolate_text_shadow_matches_layers_pairwise() {
+        let start =
+            PropertyValue::TextShadow(parse_text_shadow("0 0 4px red, 2px 2px blue").unwrap());
+        let end = PropertyValue::TextShadow(parse_text_shadow("0 0 8px red").unwrap());
+        let result = interpolate_value(&start, &end, 0.5, &TransitionTimingFunction::Linear);
+
+        let PropertyValue::TextShadow(shadows) = result else {
+            panic!("Expected text-shadow value");
+        };
+        assert_eq!(shadows.len(), 2);
+        assert_eq!(shadows[0].blur(), Length::new(6.0, LengthUnit::Px));
+        assert_eq!(shadows[1].offset_x(), Length::new(1.0, LengthUnit::Px));
+        assert_eq!(
+            shadows[1].color(),
+            ColorValue::Rgba(Color::rgba(0, 0, 255, 0.5))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_length_converts_absolute_units() {
+        let context = LengthContext::new(16.0, 16.0, 1000.0, 500.0);
+        let inch = Length::new(1.0, LengthUnit::In);
+        let zero = Length::new(0.0, LengthUnit::Px);
+
+        assert_eq!(
+            interpolate_length(&inch, &zero, 0.5, &context),
+            Some(Length::new(48.0, LengthUnit::Px))
+        );
+        assert_eq!(
+            interpolate_value(
+                &PropertyValue::Length(inch),
+                &PropertyValue::Length(zero),
+                0.25,
+                &TransitionTimingFunction::Linear
+            ),
+            PropertyValue::Length(Length::new(72.0, LengthUnit::Px))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_length_resolves_font_and_viewport_units() {
+        let context = LengthContext::new(20.0, 10.0, 1000.0, 500.0);
+        let zero = Length::new(0.0, LengthUnit::Px);
+
+        assert_eq!(
+            interpolate_length(&Length::new(2.0, LengthUnit::Em), &zero, 0.5, &context),
+            Some(Length::new(20.0, LengthUnit::Px))
+        );
+        assert_eq!(
+            interpolate_length(
+                &Length::new(2.0, LengthUnit::Rem),
+                &Length::new(10.0, LengthUnit::Vmin),
+                0.5,
+                &context
+            ),
+            Some(Length::new(35.0, LengthUnit::Px))
+        );
+        // Same units keep their unit
+        assert_eq!(
+            interpolate_length(
+                &Length::new(2.0, LengthUnit::Em),
+                &Length::new(4.0, LengthUnit::Em),
+                0.5,
+                &context
+            ),
+            Some(Length::new(3.0, LengthUnit::Em))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_length_incompatible_units_are_discrete() {
+        let context = LengthContext::new(16.0, 16.0, 1000.0, 500.0);
+        let percent = Length::new(50.0, LengthUnit::Percent);
+        let px = Length::new(10.0, LengthUnit::Px);
+        assert_eq!(interpolate_length(&percent, &px, 0.5, &context), None);
+
+        // Without a context, relative units switch at the midpoint
+        let start = PropertyValue::Length(Length::new(2.0, LengthUnit::Em));
+        let end = PropertyValue::Length(px);
+        let linear = TransitionTimingFunction::Linear;
+        assert_eq!(interpolate_value(&start, &end, 0.25, &linear), start);
+        assert_eq!(interpolate_value(&start, &end, 0.75, &linear), end);
+
+        let number = PropertyValue::Number(1.0);
+        assert_eq!(interpolate_value(&number, &end, 0.25, &linear), end);
+    }
+
+    #[test]
+    fn test_interpolate_value_inverse_number() {
+        let start = PropertyValue::Number(0.0);
+        let end = PropertyValue::Number(100.0);
+        let value = PropertyValue::Number(50.0);
+
+        assert_eq!(interpolate_value_inverse(&start, &end, &value), Some(0.5));
+    }
+
+    #[test]
+    fn test_interpolate_value_inverse_round_trips_length_and_percentage() {
+        let start = PropertyValue::Length(Length::new(10.0, LengthUnit::Px));
+        let end = PropertyValue::Length(Length::new(30.0, LengthUnit::Px));
+        let value = interpolate_value(&start, &end, 0.25, &TransitionTimingFunction::Linear);
+        assert_eq!(interpolate_value_inverse(&start, &end, &value), Some(0.25));
+
+        let start = PropertyValue::Percentage(100.0);
+        let end = PropertyValue::Percentage(0.0);
+        let value = PropertyValue::Percentage(25.0);
+        assert_eq!(interpolate_value_inverse(&start, &end, &value), Some(0.75));
+    }
+
+    #[test]
+    fn test_interpolate_value_inverse_unsupported() {
+        let red = PropertyValue::Color(Color::rgb(255, 0, 0));
+        let blue = PropertyValue::Color(Color::rgb(0, 0, 255));
+        assert_eq!(interpolate_value_inverse(&red, &blue, &red), None);
+
+        // Mismatched units and equal endpoints have no single answer
+        let px = PropertyValue::Length(Length::new(10.0, LengthUnit::Px));
+        let em = PropertyValue::Length(Length::new(10.0, LengthUnit::Em));
+        assert_eq!(interpolate_value_inverse(&px, &em, &px), None);
+
+        let number = PropertyValue::Number(1.0);
+        assert_eq!(interpolate_value_inverse(&number, &number, &number), None);
+    }
+
     // ========================================================================
     // Timing Function Evaluation Tests
     // ========================================================================
@@ -1007,6 +1961,57 @@ mod tests {
         assert_eq!(evaluate_timing_function(&timing, 1.0), 1.0);
     }
 
+    #[test]
+    fn test_parse_steps_rejects_huge_count() {
+        assert!(matches!(
+            parse_transition_timing_function("steps(4000000000)"),
+            Err(CssError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            parse_transition_timing_function("steps(1000001, start)"),
+            Err(CssError::OutOfRange(_))
+        ));
+        assert!(parse_transition_timing_function("steps(99999999999999999999999)").is_err());
+        assert!(parse_transition_timing_function("steps(1000000)").is_ok());
+    }
+
+    #[test]
+    fn test_evaluate_steps_mid_range_count_is_exact() {
+        let count = 1000;
+        for (position, offset) in [(StepPosition::End, 0.0), (StepPosition::Start, 1.0)] {
+            let timing = TransitionTimingFunction::Steps { count, position };
+            for k in 1..count {
+                let boundary = k as f64 / count as f64;
+                // Boundaries belong to the previous interval
+                assert_eq!(
+                    evaluate_timing_function(&timing, boundary),
+                    (k as f64 - 1.0 + offset) / count as f64,
+                    "{:?} at step {}",
+                    position,
+                    k
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_steps_max_count_boundaries() {
+        let count = MAX_STEP_COUNT as f64;
+        let timing = TransitionTimingFunction::Steps {
+            count: MAX_STEP_COUNT,
+            position: StepPosition::End,
+        };
+
+        let k = 999_999.0;
+        assert_eq!(
+            evaluate_timing_function(&timing, k / count),
+            (k - 1.0) / count
+        );
+        let just_after = (k + 0.5) / count;
+        assert_eq!(evaluate_timing_function(&timing, just_after), k / count);
+        assert!(evaluate_timing_function(&timing, 0.5) < 0.5);
+    }
+
     // ========================================================================
     // TransitionEngine Tests
     // ========================================================================
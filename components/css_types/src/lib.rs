@@ -2,10 +2,14 @@
 //!
 //! This module provides core CSS types including:
 //! - Color (RGB/RGBA)
-//! - Length (with units: px, em, rem, %, vw, vh)
+//! - Length (with units: px, em, rem, %, vw, vh, vmin, vmax, pt, cm, mm, in)
 //! - Specificity (selector specificity calculation)
 //! - CssError (error handling)
-//! - CssValue trait (parsing and serialization)
+//! - CssValue trait (parsing and serialization) and a round-trip check
+//! - Finite number parsing shared by value parsers
+//! - Function notation argument extraction (strip_function)
+//! - Keyword enum parsing (KeywordEnum)
+//! - Rect (axis-aligned rectangle shared by layout and transforms)
 
 use std::cmp::Ordering;
 use std::fmt;
@@ -50,6 +54,208 @@ pub trait CssValue: Sized {
     fn serialize(&self) -> String;
 }
 
+/// Check that a value survives a serialize/parse round trip
+///
+/// Returns `true` when `input` parses and re-parsing its serialization
+/// yields an equal value. Intended for tests that guard against parser and
+/// serializer drifting apart.
+///
+/// # Examples
+/// ```
+/// use css_types::{roundtrip, Color, Length};
+///
+/// assert!(roundtrip::<Length>("1.5em"));
+/// assert!(roundtrip::<Color>("rgb(255,0,0)"));
+/// assert!(!roundtrip::<Length>("not a length"));
+/// ```
+pub fn roundtrip<T: CssValue + PartialEq>(input: &str) -> bool {
+    match T::parse(input) {
+        Ok(value) => T::parse(&value.serialize()).is_ok_and(|reparsed| reparsed == value),
+        Err(_) => false,
+    }
+}
+
+/// Format a number the way CSS serializes it
+///
+/// Integral values have no fractional part (`10`, not `10.0`), no exponent
+/// notation is used, and negative zero is written as `0`.
+fn serialize_number(value: f32) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    // `Display` for floats already omits a trailing `.0` and never uses
+    // exponent notation
+    value.to_string()
+}
+
+// ============================================================================
+// Number Parsing
+// ============================================================================
+
+/// Parse a finite `f32`
+///
+/// `str::parse` accepts `NaN`, `inf` and values that overflow to infinity
+/// (such as `1e40`). Those are rejected with [`CssError::InvalidValue`] so
+/// they cannot poison layout and interpolation math; malformed numbers are a
+/// [`CssError::ParseError`].
+///
+/// # Examples
+/// ```
+/// use css_types::{parse_finite_f32, CssError};
+///
+/// assert_eq!(parse_finite_f32(" 1.5 "), Ok(1.5));
+/// assert!(matches!(parse_finite_f32("NaN"), Err(CssError::InvalidValue(_))));
+/// assert!(matches!(parse_finite_f32("1e40"), Err(CssError::InvalidValue(_))));
+/// assert!(matches!(parse_finite_f32("abc"), Err(CssError::ParseError(_))));
+/// ```
+pub fn parse_finite_f32(input: &str) -> Result<f32, CssError> {
+    let input = input.trim();
+    let value = input
+        .parse::<f32>()
+        .map_err(|_| CssError::ParseError(format!("Invalid number: {}", input)))?;
+
+    if !value.is_finite() {
+        return Err(CssError::InvalidValue(format!(
+            "Number must be finite: {}",
+            input
+        )));
+    }
+
+    Ok(value)
+}
+
+/// Parse a finite `f64` that also fits in an `f32`
+///
+/// Like [`parse_finite_f32`], but for parsers that keep double precision.
+/// Values outside the `f32` range are rejected too, since they become
+/// infinite as soon as they reach single-precision layout code.
+pub fn parse_finite_f64(input: &str) -> Result<f64, CssError> {
+    let input = input.trim();
+    let value = input
+        .parse::<f64>()
+        .map_err(|_| CssError::ParseError(format!("Invalid number: {}", input)))?;
+
+    if !value.is_finite() || value.abs() > f32::MAX as f64 {
+        return Err(CssError::InvalidValue(format!(
+            "Number must be finite: {}",
+            input
+        )));
+    }
+
+    Ok(value)
+}
+
+// ============================================================================
+// Function Notation
+// ============================================================================
+
+/// Split a functional notation such as `calc(...)` into its name and arguments
+///
+/// `input` must be exactly one function call. The name is the text before
+/// the first `(` with surrounding whitespace trimmed, so `calc (1px)` is
+/// accepted, but a name containing whitespace such as `cal c(1px)` is
+/// rejected. The opening parenthesis must be closed by the final character,
+/// so `calc(1px) + calc(2px)` is not a single call; parentheses inside
+/// quoted strings are not counted. The arguments are returned trimmed.
+///
+/// # Examples
+/// ```
+/// use css_types::split_function;
+///
+/// assert_eq!(split_function("calc (100% - 20px)"), Ok(("calc", "100% - 20px")));
+/// assert!(split_function("cal c(1px)").is_err());
+/// assert!(split_function("calc(1px) + calc(2px)").is_err());
+/// ```
+///
+/// # Errors
+/// Returns `CssError::ParseError` if the input has no parentheses, the name
+/// is empty or contains whitespace, the parentheses are unbalanced, or a
+/// quoted string is not closed.
+pub fn split_function(input: &str) -> Result<(&str, &str), CssError> {
+    let input = input.trim();
+    let paren = input.find('(').ok_or_else(|| {
+        CssError::ParseError(format!("Expected a function call, found '{}'", input))
+    })?;
+
+    let name = input[..paren].trim_end();
+    if name.is_empty() {
+        return Err(CssError::ParseError(format!(
+            "Missing function name in '{}'",
+            input
+        )));
+    }
+    if name.contains(char::is_whitespace) {
+        return Err(CssError::ParseError(format!(
+            "Function name '{}' must not contain whitespace",
+            name
+        )));
+    }
+
+    let arguments = input[paren + 1..].strip_suffix(')').ok_or_else(|| {
+        CssError::ParseError(format!("Missing closing parenthesis in '{}'", input))
+    })?;
+
+    // The opening parenthesis must not be closed before the final one
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for ch in arguments.chars() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (ch, quote) {
+            ('\\', _) => escaped = true,
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(ch),
+            ('(', None) => depth += 1,
+            (')', None) => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    CssError::ParseError(format!("Unbalanced parentheses in '{}'", input))
+                })?
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(CssError::ParseError(format!(
+            "Unbalanced parentheses in '{}'",
+            input
+        )));
+    }
+    if quote.is_some() {
+        return Err(CssError::ParseError(format!(
+            "Unclosed string in '{}'",
+            input
+        )));
+    }
+
+    Ok((name, arguments.trim()))
+}
+
+/// Get the arguments of a functional notation such as `calc(...)`
+///
+/// Returns the arguments when [`split_function`] accepts `input` and the
+/// function name matches `name` ASCII case-insensitively, and `None`
+/// otherwise.
+///
+/// # Examples
+/// ```
+/// use css_types::strip_function;
+///
+/// assert_eq!(strip_function("calc(100% - 20px)", "calc"), Some("100% - 20px"));
+/// assert_eq!(strip_function(" steps (4, end) ", "steps"), Some("4, end"));
+/// assert_eq!(strip_function("calc(1px) + calc(2px)", "calc"), None);
+/// assert_eq!(strip_function("calculate(1px)", "calc"), None);
+/// ```
+pub fn strip_function<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    match split_function(input) {
+        Ok((found, arguments)) if found.eq_ignore_ascii_case(name) => Some(arguments),
+        _ => None,
+    }
+}
+
 // ============================================================================
 // Color Type
 // ============================================================================
@@ -65,12 +271,12 @@ pub struct Color {
 
 impl Color {
     /// Create a new RGB color (alpha defaults to 1.0)
-    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
         Self { r, g, b, a: 1.0 }
     }
 
     /// Create a new RGBA color
-    pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
+    pub const fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
         Self { r, g, b, a }
     }
 
@@ -94,6 +300,45 @@ impl Color {
         self.a
     }
 
+    /// WCAG relative luminance of the color
+    ///
+    /// Channels are linearized from sRGB and weighted per WCAG 2.x, giving
+    /// 0.0 for black and 1.0 for white. Alpha is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// WCAG contrast ratio between two colors
+    ///
+    /// The result ranges from 1.0 (identical luminance) to 21.0 (black on
+    /// white) and does not depend on argument order. Alpha is ignored, so
+    /// translucent colors should be composited first.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::Color;
+    ///
+    /// let black = Color::rgb(0, 0, 0);
+    /// let white = Color::rgb(255, 255, 255);
+    /// assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        let (lighter, darker) = if a >= b { (a, b) } else { (b, a) };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
     /// Parse a hex color string (#RGB or #RRGGBB)
     fn parse_hex(input: &str) -> Result<Self, CssError> {
         if !input.starts_with('#') {
@@ -159,9 +404,7 @@ impl Color {
             let r = parse_color_component(parts[0])?;
             let g = parse_color_component(parts[1])?;
             let b = parse_color_component(parts[2])?;
-            let a = parts[3]
-                .parse::<f32>()
-                .map_err(|_| CssError::ParseError("Invalid alpha value".to_string()))?;
+            let a = parse_finite_f32(parts[3])?;
 
             if !(0.0..=1.0).contains(&a) {
                 return Err(CssError::OutOfRange(
@@ -220,19 +463,95 @@ impl CssValue for Color {
 
     fn serialize(&self) -> String {
         if self.a < 1.0 {
-            format!("rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+            format!(
+                "rgba({}, {}, {}, {})",
+                self.r,
+                self.g,
+                self.b,
+                serialize_number(self.a)
+            )
         } else {
             format!("rgb({}, {}, {})", self.r, self.g, self.b)
         }
     }
 }
 
+/// A specified color that may refer to the element's `color` property
+///
+/// `currentColor` cannot be turned into RGBA at parse time; it is kept as a
+/// sentinel until the cascade knows the element's computed `color`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorValue {
+    /// A concrete color
+    Rgba(Color),
+    /// The `currentColor` keyword
+    CurrentColor,
+}
+
+impl ColorValue {
+    /// Check whether this is the `currentColor` keyword
+    pub fn is_current_color(&self) -> bool {
+        matches!(self, ColorValue::CurrentColor)
+    }
+
+    /// Resolve to a concrete color given the element's computed `color`
+    pub fn resolve(&self, current_color: Color) -> Color {
+        match self {
+            ColorValue::Rgba(color) => *color,
+            ColorValue::CurrentColor => current_color,
+        }
+    }
+
+    /// Parse `currentColor`, or hand anything else to `parse_color`
+    ///
+    /// Lets richer color parsers (named colors, `hsl()`, ...) share the
+    /// keyword handling of [`ColorValue::parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::{Color, ColorValue, CssValue};
+    ///
+    /// let value = ColorValue::parse_with(" currentColor ", Color::parse).unwrap();
+    /// assert!(value.is_current_color());
+    /// ```
+    pub fn parse_with(
+        input: &str,
+        parse_color: impl FnOnce(&str) -> Result<Color, CssError>,
+    ) -> Result<Self, CssError> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("currentcolor") {
+            Ok(ColorValue::CurrentColor)
+        } else {
+            parse_color(input).map(ColorValue::Rgba)
+        }
+    }
+}
+
+impl From<Color> for ColorValue {
+    fn from(color: Color) -> Self {
+        ColorValue::Rgba(color)
+    }
+}
+
+impl CssValue for ColorValue {
+    fn parse(input: &str) -> Result<Self, CssError> {
+        ColorValue::parse_with(input, Color::parse)
+    }
+
+    fn serialize(&self) -> String {
+        match self {
+            ColorValue::Rgba(color) => color.serialize(),
+            ColorValue::CurrentColor => "currentcolor".to_string(),
+        }
+    }
+}
+
 // ============================================================================
 // Length Types
 // ============================================================================
 
 /// CSS length units
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LengthUnit {
     /// Pixels
     Px,
@@ -246,6 +565,18 @@ pub enum LengthUnit {
     Vw,
     /// Viewport height
     Vh,
+    /// Smaller of the viewport width and height
+    Vmin,
+    /// Larger of the viewport width and height
+    Vmax,
+    /// Points (1/72 of an inch)
+    Pt,
+    /// Centimeters
+    Cm,
+    /// Millimeters
+    Mm,
+    /// Inches (96 CSS pixels)
+    In,
 }
 
 impl LengthUnit {
@@ -258,12 +589,18 @@ impl LengthUnit {
             "%" => Ok(LengthUnit::Percent),
             "vw" => Ok(LengthUnit::Vw),
             "vh" => Ok(LengthUnit::Vh),
+            "vmin" => Ok(LengthUnit::Vmin),
+            "vmax" => Ok(LengthUnit::Vmax),
+            "pt" => Ok(LengthUnit::Pt),
+            "cm" => Ok(LengthUnit::Cm),
+            "mm" => Ok(LengthUnit::Mm),
+            "in" => Ok(LengthUnit::In),
             _ => Err(CssError::ParseError(format!("Unknown unit: {}", s))),
         }
     }
 
-    /// Convert unit to string
-    fn to_str(self) -> &'static str {
+    /// Convert unit to string (e.g. `"px"`, `"%"`)
+    pub fn to_str(self) -> &'static str {
         match self {
             LengthUnit::Px => "px",
             LengthUnit::Em => "em",
@@ -271,6 +608,36 @@ impl LengthUnit {
             LengthUnit::Percent => "%",
             LengthUnit::Vw => "vw",
             LengthUnit::Vh => "vh",
+            LengthUnit::Vmin => "vmin",
+            LengthUnit::Vmax => "vmax",
+            LengthUnit::Pt => "pt",
+            LengthUnit::Cm => "cm",
+            LengthUnit::Mm => "mm",
+            LengthUnit::In => "in",
+        }
+    }
+
+    /// Number of CSS pixels in one unit, for absolute units
+    ///
+    /// Returns `None` for units that need a font size, viewport or
+    /// percentage basis to resolve.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::LengthUnit;
+    ///
+    /// assert_eq!(LengthUnit::In.px_per_unit(), Some(96.0));
+    /// assert_eq!(LengthUnit::Pt.px_per_unit(), Some(96.0 / 72.0));
+    /// assert_eq!(LengthUnit::Em.px_per_unit(), None);
+    /// ```
+    pub fn px_per_unit(self) -> Option<f32> {
+        match self {
+            LengthUnit::Px => Some(1.0),
+            LengthUnit::Pt => Some(96.0 / 72.0),
+            LengthUnit::Cm => Some(96.0 / 2.54),
+            LengthUnit::Mm => Some(96.0 / 25.4),
+            LengthUnit::In => Some(96.0),
+            _ => None,
         }
     }
 }
@@ -284,7 +651,7 @@ pub struct Length {
 
 impl Length {
     /// Create a new length
-    pub fn new(value: f32, unit: LengthUnit) -> Self {
+    pub const fn new(value: f32, unit: LengthUnit) -> Self {
         Self { value, unit }
     }
 
@@ -297,6 +664,22 @@ impl Length {
     pub fn unit(&self) -> LengthUnit {
         self.unit
     }
+
+    /// Convert an absolute length to pixels
+    ///
+    /// Returns `None` for units that need a font size, viewport or
+    /// percentage basis; see [`LengthUnit::px_per_unit`].
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::{Length, LengthUnit};
+    ///
+    /// assert_eq!(Length::new(1.0, LengthUnit::In).to_px(), Some(96.0));
+    /// assert_eq!(Length::new(2.0, LengthUnit::Em).to_px(), None);
+    /// ```
+    pub fn to_px(&self) -> Option<f32> {
+        self.unit.px_per_unit().map(|px| self.value * px)
+    }
 }
 
 impl CssValue for Length {
@@ -330,9 +713,7 @@ impl CssValue for Length {
             return Err(CssError::ParseError("Length must have a unit".to_string()));
         }
 
-        let value = value_str
-            .parse::<f32>()
-            .map_err(|_| CssError::ParseError("Invalid number".to_string()))?;
+        let value = parse_finite_f32(value_str)?;
 
         let unit = LengthUnit::parse(unit_str)?;
 
@@ -340,10 +721,322 @@ impl CssValue for Length {
     }
 
     fn serialize(&self) -> String {
-        format!("{}{}", self.value, self.unit.to_str())
+        format!("{}{}", serialize_number(self.value), self.unit.to_str())
+    }
+}
+
+// ============================================================================
+// Keyword Enums
+// ============================================================================
+
+/// Enum whose values are written as plain CSS keywords
+///
+/// Implementors list each keyword once, in lowercase, and get
+/// case-insensitive parsing with a uniform error plus serialization back
+/// to the keyword.
+///
+/// # Examples
+/// ```
+/// use css_types::{CssError, KeywordEnum};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Visibility {
+///     Visible,
+///     Hidden,
+/// }
+///
+/// impl KeywordEnum for Visibility {
+///     const KIND: &'static str = "visibility";
+///     const KEYWORDS: &'static [(&'static str, Self)] =
+///         &[("visible", Visibility::Visible), ("hidden", Visibility::Hidden)];
+/// }
+///
+/// assert_eq!(Visibility::from_keyword(" HIDDEN "), Ok(Visibility::Hidden));
+/// assert_eq!(Visibility::Visible.to_keyword(), "visible");
+/// assert_eq!(
+///     Visibility::from_keyword("collapse"),
+///     Err(CssError::ParseError("Unknown visibility: collapse".to_string()))
+/// );
+/// ```
+pub trait KeywordEnum: Copy + PartialEq + 'static {
+    /// Human-readable name of the value kind, used in error messages
+    const KIND: &'static str;
+
+    /// Lowercase keywords and the values they map to
+    const KEYWORDS: &'static [(&'static str, Self)];
+
+    /// Parse a keyword, ignoring ASCII case and surrounding whitespace
+    ///
+    /// # Errors
+    /// Returns `CssError::ParseError("Unknown <kind>: <input>")` for any
+    /// keyword not in [`KEYWORDS`](Self::KEYWORDS).
+    fn from_keyword(input: &str) -> Result<Self, CssError> {
+        let input = input.trim();
+        Self::KEYWORDS
+            .iter()
+            .find(|(keyword, _)| keyword.eq_ignore_ascii_case(input))
+            .map(|(_, value)| *value)
+            .ok_or_else(|| CssError::ParseError(format!("Unknown {}: {}", Self::KIND, input)))
+    }
+
+    /// Serialize the value as its keyword
+    fn to_keyword(&self) -> &'static str {
+        Self::KEYWORDS
+            .iter()
+            .find(|(_, value)| value == self)
+            .map(|(keyword, _)| *keyword)
+            .expect("every keyword enum value is listed in KEYWORDS")
+    }
+}
+
+// ============================================================================
+// Border Style Type
+// ============================================================================
+
+/// Line style used by borders, outlines, and column rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// No border
+    None,
+    /// Solid line
+    Solid,
+    /// Dashed line
+    Dashed,
+    /// Dotted line
+    Dotted,
+    /// Double line
+    Double,
+    /// 3D groove
+    Groove,
+    /// 3D ridge
+    Ridge,
+    /// 3D inset
+    Inset,
+    /// 3D outset
+    Outset,
+}
+
+impl KeywordEnum for BorderStyle {
+    const KIND: &'static str = "border style";
+    const KEYWORDS: &'static [(&'static str, Self)] = &[
+        ("none", BorderStyle::None),
+        ("solid", BorderStyle::Solid),
+        ("dashed", BorderStyle::Dashed),
+        ("dotted", BorderStyle::Dotted),
+        ("double", BorderStyle::Double),
+        ("groove", BorderStyle::Groove),
+        ("ridge", BorderStyle::Ridge),
+        ("inset", BorderStyle::Inset),
+        ("outset", BorderStyle::Outset),
+    ];
+}
+
+impl BorderStyle {
+    /// Parse a border style keyword (case-insensitive)
+    pub fn parse(input: &str) -> Result<Self, CssError> {
+        Self::from_keyword(input)
+    }
+}
+
+// ============================================================================
+// Gap Type
+// ============================================================================
+
+/// Value of the `gap`, `row-gap` and `column-gap` properties
+///
+/// `normal` is kept as a keyword because its size depends on the layout
+/// mode: flex and grid containers use 0, multi-column containers use 1em.
+/// Consumers resolve it with [`Gap::resolve`].
+///
+/// # Examples
+/// ```
+/// use css_types::{parse_gap, Gap};
+///
+/// let gap = parse_gap("normal").unwrap();
+/// assert_eq!(gap, Gap::Normal);
+/// assert_eq!(gap.resolve(0.0), Some(0.0));
+/// assert_eq!(gap.resolve(16.0), Some(16.0));
+/// assert_eq!(parse_gap("8px").unwrap().resolve(16.0), Some(8.0));
+/// assert_eq!(parse_gap("1em").unwrap().resolve(16.0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Gap {
+    /// The `normal` keyword, sized by the layout mode
+    #[default]
+    Normal,
+    /// Explicit gap size
+    Length(Length),
+}
+
+impl Gap {
+    /// Get the gap size in pixels, using `normal` as the size of [`Gap::Normal`]
+    ///
+    /// Returns `None` for a length that cannot be converted to pixels
+    /// without a font size, viewport or percentage basis.
+    pub fn resolve(self, normal: f32) -> Option<f32> {
+        match self {
+            Gap::Normal => Some(normal),
+            Gap::Length(length) => length.to_px(),
+        }
+    }
+}
+
+impl CssValue for Gap {
+    fn parse(input: &str) -> Result<Self, CssError> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("normal") {
+            return Ok(Gap::Normal);
+        }
+
+        let length = Length::parse(input)?;
+        if length.value() < 0.0 {
+            return Err(CssError::InvalidValue(format!(
+                "Gap must not be negative: {}",
+                input
+            )));
+        }
+        Ok(Gap::Length(length))
+    }
+
+    fn serialize(&self) -> String {
+        match self {
+            Gap::Normal => "normal".to_string(),
+            Gap::Length(length) => length.serialize(),
+        }
     }
 }
 
+/// Parse a `gap`, `row-gap` or `column-gap` value
+///
+/// # Errors
+/// Returns `CssError::ParseError` for anything other than `normal` or a
+/// length, and `CssError::InvalidValue` for a negative length.
+pub fn parse_gap(input: &str) -> Result<Gap, CssError> {
+    Gap::parse(input)
+}
+
+// ============================================================================
+// Rect Type
+// ============================================================================
+
+/// Axis-aligned rectangle in CSS pixels
+///
+/// This is the canonical rectangle shared by the layout and transform
+/// crates.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl Rect {
+    /// Create a new rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::Rect;
+    ///
+    /// let rect = Rect::new(10.0, 20.0, 100.0, 50.0);
+    /// assert_eq!(rect.width(), 100.0);
+    /// assert_eq!(rect.area(), 5000.0);
+    /// ```
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Get the x coordinate
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Get the y coordinate
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Get the width
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Get the height
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Calculate the area of the rectangle
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
+    /// Check if a point is contained within the rectangle, edges included
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    /// Smallest rectangle containing both rectangles
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::Rect;
+    ///
+    /// let union = Rect::new(0.0, 0.0, 10.0, 10.0).union(&Rect::new(5.0, -5.0, 20.0, 10.0));
+    /// assert_eq!(union, Rect::new(0.0, -5.0, 25.0, 15.0));
+    /// ```
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Rect::new(x, y, right - x, bottom - y)
+    }
+
+    /// Smallest rectangle containing every rectangle in `rects`
+    ///
+    /// An empty iterator gives an empty rectangle at the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_types::Rect;
+    ///
+    /// let rects = [Rect::new(0.0, 0.0, 10.0, 10.0), Rect::new(20.0, 5.0, 5.0, 10.0)];
+    /// assert_eq!(Rect::union_all(rects), Rect::new(0.0, 0.0, 25.0, 15.0));
+    /// assert_eq!(Rect::union_all([]), Rect::default());
+    /// ```
+    pub fn union_all(rects: impl IntoIterator<Item = Rect>) -> Rect {
+        rects
+            .into_iter()
+            .reduce(|bounds, rect| bounds.union(&rect))
+            .unwrap_or_default()
+    }
+}
+
+/// Snap a span to the device-pixel grid and return the snapped (start, size)
+///
+/// Both edges are rounded to the nearest device pixel and the size is taken
+/// from the rounded edges, so spans that shared an edge before snapping still
+/// share it afterwards.
+///
+/// # Examples
+/// ```
+/// use css_types::snap_span;
+///
+/// assert_eq!(snap_span(0.3, 10.4, 1.0), (0.0, 11.0));
+/// assert_eq!(snap_span(0.3, 10.4, 2.0), (0.5, 10.0));
+/// ```
+pub fn snap_span(start: f32, size: f32, device_pixel_ratio: f32) -> (f32, f32) {
+    let snap = |edge: f32| (edge * device_pixel_ratio).round() / device_pixel_ratio;
+    let snapped_start = snap(start);
+    (snapped_start, snap(start + size) - snapped_start)
+}
+
 // ============================================================================
 // Specificity Type
 // ============================================================================
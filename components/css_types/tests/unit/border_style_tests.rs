@@ -0,0 +1,60 @@
+use css_types::{BorderStyle, CssError, KeywordEnum};
+
+#[cfg(test)]
+mod border_style_parsing_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all_border_styles() {
+        let cases = [
+            ("none", BorderStyle::None),
+            ("solid", BorderStyle::Solid),
+            ("dashed", BorderStyle::Dashed),
+            ("dotted", BorderStyle::Dotted),
+            ("double", BorderStyle::Double),
+            ("groove", BorderStyle::Groove),
+            ("ridge", BorderStyle::Ridge),
+            ("inset", BorderStyle::Inset),
+            ("outset", BorderStyle::Outset),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(BorderStyle::parse(input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_border_style_case_and_whitespace() {
+        assert_eq!(
+            BorderStyle::parse("  DaShEd ").unwrap(),
+            BorderStyle::Dashed
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_border_style() {
+        let result = BorderStyle::parse("wavy");
+        assert!(matches!(result, Err(CssError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_invalid_border_style_error_message() {
+        assert_eq!(
+            BorderStyle::parse(" wavy "),
+            Err(CssError::ParseError(
+                "Unknown border style: wavy".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_border_style_keyword_round_trip() {
+        for (keyword, style) in BorderStyle::KEYWORDS {
+            assert_eq!(style.to_keyword(), *keyword);
+            assert_eq!(
+                BorderStyle::from_keyword(&keyword.to_uppercase()),
+                Ok(*style)
+            );
+        }
+    }
+}
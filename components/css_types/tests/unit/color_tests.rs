@@ -1,4 +1,4 @@
-use css_types::{Color, CssError, CssValue};
+use css_types::{roundtrip, Color, ColorValue, CssError, CssValue};
 
 #[cfg(test)]
 mod color_parsing_tests {
@@ -189,3 +189,107 @@ mod color_construction_tests {
         assert_ne!(color1, color2);
     }
 }
+
+#[cfg(test)]
+mod color_value_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_current_color_case_insensitive() {
+        assert_eq!(
+            ColorValue::parse("currentColor").unwrap(),
+            ColorValue::CurrentColor
+        );
+        assert_eq!(
+            ColorValue::parse(" CURRENTCOLOR ").unwrap(),
+            ColorValue::CurrentColor
+        );
+    }
+
+    #[test]
+    fn test_parse_concrete_color_value() {
+        let value = ColorValue::parse("#FF0000").unwrap();
+        assert_eq!(value, ColorValue::Rgba(Color::rgb(255, 0, 0)));
+        assert!(!value.is_current_color());
+    }
+
+    #[test]
+    fn test_resolve_current_color() {
+        let current = Color::rgb(0, 128, 0);
+        assert_eq!(ColorValue::CurrentColor.resolve(current), current);
+        assert_eq!(
+            ColorValue::from(Color::rgb(1, 2, 3)).resolve(current),
+            Color::rgb(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn test_serialize_color_value() {
+        assert_eq!(ColorValue::CurrentColor.serialize(), "currentcolor");
+        assert_eq!(
+            ColorValue::Rgba(Color::rgb(1, 2, 3)).serialize(),
+            "rgb(1, 2, 3)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod color_contrast_tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_luminance_extremes() {
+        assert_eq!(Color::rgb(0, 0, 0).relative_luminance(), 0.0);
+        assert!((Color::rgb(255, 255, 255).relative_luminance() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_relative_luminance_weights_green_highest() {
+        let red = Color::rgb(255, 0, 0).relative_luminance();
+        let green = Color::rgb(0, 255, 0).relative_luminance();
+        let blue = Color::rgb(0, 0, 255).relative_luminance();
+
+        assert!((red - 0.2126).abs() < 1e-4);
+        assert!((green - 0.7152).abs() < 1e-4);
+        assert!((blue - 0.0722).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_contrast_ratio_black_on_white() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_contrast_ratio_identical_colors() {
+        let gray = Color::rgb(119, 119, 119);
+        assert_eq!(gray.contrast_ratio(&gray), 1.0);
+    }
+
+    #[test]
+    fn test_contrast_ratio_mid_gray_on_white() {
+        // #777 on white is the classic just-below-AA example (about 4.48:1)
+        let ratio = Color::rgb(119, 119, 119).contrast_ratio(&Color::rgb(255, 255, 255));
+        assert!((ratio - 4.48).abs() < 0.01);
+    }
+}
+
+#[cfg(test)]
+mod color_roundtrip_tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_roundtrip() {
+        assert!(roundtrip::<Color>("rgb(255,0,0)"));
+        assert!(roundtrip::<Color>("rgba(0, 128, 255, 0.5)"));
+        assert!(roundtrip::<Color>("#336699"));
+    }
+
+    #[test]
+    fn test_rgba_serializes_integral_alpha_without_fraction() {
+        assert_eq!(Color::rgba(1, 2, 3, 0.0).serialize(), "rgba(1, 2, 3, 0)");
+    }
+}
@@ -0,0 +1,87 @@
+use css_types::{split_function, strip_function, CssError};
+
+#[cfg(test)]
+mod strip_function_tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_function_returns_arguments() {
+        assert_eq!(
+            strip_function("cubic-bezier(0.1, 0.7, 1.0, 0.1)", "cubic-bezier"),
+            Some("0.1, 0.7, 1.0, 0.1")
+        );
+        assert_eq!(strip_function("var(--a)", "var"), Some("--a"));
+        assert_eq!(strip_function("calc()", "calc"), Some(""));
+    }
+
+    #[test]
+    fn test_strip_function_ignores_whitespace() {
+        assert_eq!(
+            strip_function("  calc( 1px + 2px )  ", "calc"),
+            Some("1px + 2px")
+        );
+        assert_eq!(strip_function("steps (4)", "steps"), Some("4"));
+        assert_eq!(strip_function("steps\t\n(4)", "steps"), Some("4"));
+    }
+
+    #[test]
+    fn test_strip_function_name_is_case_insensitive() {
+        assert_eq!(strip_function("CALC(1px)", "calc"), Some("1px"));
+    }
+
+    #[test]
+    fn test_strip_function_requires_exact_call() {
+        assert_eq!(strip_function("calc(1px", "calc"), None);
+        assert_eq!(strip_function("calc 1px)", "calc"), None);
+        assert_eq!(strip_function("calculate(1px)", "calc"), None);
+        assert_eq!(strip_function("var(--a)", "calc"), None);
+        assert_eq!(strip_function("ca", "calc"), None);
+        assert_eq!(strip_function("calc(1px) + calc(2px)", "calc"), None);
+    }
+
+    #[test]
+    fn test_strip_function_handles_nesting_and_strings() {
+        assert_eq!(
+            strip_function("calc((1px + 2px) * 2)", "calc"),
+            Some("(1px + 2px) * 2")
+        );
+        assert_eq!(strip_function("var(--a, \")\")", "var"), Some("--a, \")\""));
+    }
+
+    #[test]
+    fn test_split_function_trims_name() {
+        assert_eq!(split_function("calc(1px)"), Ok(("calc", "1px")));
+        assert_eq!(split_function(" calc  ( 1px ) "), Ok(("calc", "1px")));
+    }
+
+    #[test]
+    fn test_split_function_rejects_whitespace_in_name() {
+        match split_function("cal c(1px)") {
+            Err(CssError::ParseError(message)) => assert!(message.contains("whitespace")),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert_eq!(strip_function("cal c(1px)", "calc"), None);
+    }
+
+    #[test]
+    fn test_split_function_rejects_malformed_calls() {
+        assert!(split_function("calc").is_err());
+        assert!(split_function("(1px)").is_err());
+        assert!(split_function("calc(1px").is_err());
+        assert!(split_function("calc(1px) + calc(2px)").is_err());
+    }
+
+    #[test]
+    fn test_split_function_rejects_unclosed_inner_parenthesis() {
+        assert!(split_function("calc((1px)").is_err());
+        assert!(split_function("calc(min(1px, 2px)").is_err());
+        assert_eq!(strip_function("calc((1px)", "calc"), None);
+    }
+
+    #[test]
+    fn test_split_function_rejects_unclosed_string() {
+        assert!(split_function("var(--a, \")").is_err());
+        assert!(split_function("url('a.png)").is_err());
+        assert_eq!(strip_function("url('a.png)", "url"), None);
+    }
+}
@@ -0,0 +1,56 @@
+use css_types::{parse_gap, roundtrip, CssError, Gap, Length, LengthUnit};
+
+#[cfg(test)]
+mod gap_value_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gap_keeps_normal() {
+        assert_eq!(parse_gap("normal"), Ok(Gap::Normal));
+        assert_eq!(parse_gap(" NORMAL "), Ok(Gap::Normal));
+        assert_eq!(Gap::default(), Gap::Normal);
+    }
+
+    #[test]
+    fn test_parse_gap_length() {
+        assert_eq!(
+            parse_gap("1.5em"),
+            Ok(Gap::Length(Length::new(1.5, LengthUnit::Em)))
+        );
+        assert!(matches!(parse_gap("-1px"), Err(CssError::InvalidValue(_))));
+        assert!(matches!(parse_gap("wide"), Err(CssError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_gap_resolve_depends_on_layout_mode() {
+        // Flex and grid resolve `normal` to 0, multi-column to 1em
+        assert_eq!(Gap::Normal.resolve(0.0), Some(0.0));
+        assert_eq!(Gap::Normal.resolve(16.0), Some(16.0));
+
+        let explicit = Gap::Length(Length::new(4.0, LengthUnit::Px));
+        assert_eq!(explicit.resolve(0.0), Some(4.0));
+        assert_eq!(explicit.resolve(16.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_gap_resolve_keeps_units() {
+        assert_eq!(
+            Gap::Length(Length::new(1.0, LengthUnit::In)).resolve(0.0),
+            Some(96.0)
+        );
+        assert_eq!(
+            Gap::Length(Length::new(2.0, LengthUnit::Em)).resolve(0.0),
+            None
+        );
+        assert_eq!(
+            Gap::Length(Length::new(10.0, LengthUnit::Percent)).resolve(0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gap_roundtrip() {
+        assert!(roundtrip::<Gap>("normal"));
+        assert!(roundtrip::<Gap>("10px"));
+    }
+}
@@ -1,4 +1,4 @@
-use css_types::{CssError, CssValue, Length, LengthUnit};
+use css_types::{roundtrip, CssError, CssValue, Length, LengthUnit};
 
 #[cfg(test)]
 mod length_parsing_tests {
@@ -58,6 +58,49 @@ mod length_parsing_tests {
         assert_eq!(length.unit(), LengthUnit::Vh);
     }
 
+    #[test]
+    fn test_parse_vmin_vmax_length() {
+        assert_eq!(
+            Length::parse("10vmin").unwrap(),
+            Length::new(10.0, LengthUnit::Vmin)
+        );
+        assert_eq!(
+            Length::parse("10vmax").unwrap(),
+            Length::new(10.0, LengthUnit::Vmax)
+        );
+        assert_eq!(Length::new(2.5, LengthUnit::Vmin).serialize(), "2.5vmin");
+    }
+
+    #[test]
+    fn test_parse_absolute_lengths() {
+        assert_eq!(
+            Length::parse("1in").unwrap(),
+            Length::new(1.0, LengthUnit::In)
+        );
+        assert_eq!(
+            Length::parse("12pt").unwrap(),
+            Length::new(12.0, LengthUnit::Pt)
+        );
+        assert_eq!(
+            Length::parse("2.54cm").unwrap(),
+            Length::new(2.54, LengthUnit::Cm)
+        );
+        assert_eq!(Length::new(5.0, LengthUnit::Mm).serialize(), "5mm");
+    }
+
+    #[test]
+    fn test_absolute_units_convert_to_px() {
+        let px = |value: f32, unit: LengthUnit| value * unit.px_per_unit().unwrap();
+
+        assert_eq!(px(1.0, LengthUnit::In), 96.0);
+        assert_eq!(px(72.0, LengthUnit::Pt), 96.0);
+        assert!((px(2.54, LengthUnit::Cm) - 96.0).abs() < 1e-4);
+        assert!((px(25.4, LengthUnit::Mm) - 96.0).abs() < 1e-4);
+        assert_eq!(LengthUnit::Px.px_per_unit(), Some(1.0));
+        assert_eq!(LengthUnit::Percent.px_per_unit(), None);
+        assert_eq!(LengthUnit::Vw.px_per_unit(), None);
+    }
+
     #[test]
     fn test_parse_negative_length() {
         let result = Length::parse("-10px");
@@ -152,6 +195,26 @@ mod length_serialization_tests {
         assert_eq!(length.value(), reparsed.value());
         assert_eq!(length.unit(), reparsed.unit());
     }
+
+    #[test]
+    fn test_serialize_integral_value_has_no_fraction() {
+        assert_eq!(Length::parse("10.0px").unwrap().serialize(), "10px");
+        assert_eq!(Length::new(100.0, LengthUnit::Percent).serialize(), "100%");
+    }
+
+    #[test]
+    fn test_serialize_negative_zero() {
+        assert_eq!(Length::new(-0.0, LengthUnit::Px).serialize(), "0px");
+    }
+
+    #[test]
+    fn test_roundtrip_helper() {
+        assert!(roundtrip::<Length>("10px"));
+        assert!(roundtrip::<Length>("1.5em"));
+        assert!(roundtrip::<Length>("-0px"));
+        assert!(roundtrip::<Length>("0.001vh"));
+        assert!(!roundtrip::<Length>("10"));
+    }
 }
 
 #[cfg(test)]
@@ -185,3 +248,48 @@ mod length_unit_tests {
         assert_ne!(l1, l2);
     }
 }
+
+#[cfg(test)]
+mod length_non_finite_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_nan_and_infinity() {
+        assert!(Length::parse("NaN").is_err());
+        assert!(Length::parse("NaNpx").is_err());
+        assert!(Length::parse("inf").is_err());
+        assert!(Length::parse("infpx").is_err());
+        assert!(Length::parse("1e40").is_err());
+        assert!(Length::parse("1e40px").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_overflow_as_invalid_value() {
+        let huge = format!("1{}px", "0".repeat(40));
+        assert!(matches!(
+            Length::parse(&huge),
+            Err(CssError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_finite_helpers() {
+        assert_eq!(css_types::parse_finite_f32("2.5"), Ok(2.5));
+        assert!(matches!(
+            css_types::parse_finite_f32("inf"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            css_types::parse_finite_f64("1e40"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            css_types::parse_finite_f64("-NaN"),
+            Err(CssError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            css_types::parse_finite_f64("twelve"),
+            Err(CssError::ParseError(_))
+        ));
+    }
+}
@@ -1,3 +1,7 @@
+mod border_style_tests;
 mod color_tests;
+mod function_notation_tests;
+mod gap_tests;
 mod length_tests;
+mod rect_tests;
 mod specificity_tests;
@@ -0,0 +1,56 @@
+use css_types::Rect;
+
+#[cfg(test)]
+mod rect_geometry_tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_getters() {
+        let rect = Rect::new(10.0, 20.0, 100.0, 50.0);
+        assert_eq!(rect.x(), 10.0);
+        assert_eq!(rect.y(), 20.0);
+        assert_eq!(rect.width(), 100.0);
+        assert_eq!(rect.height(), 50.0);
+        assert_eq!(Rect::default(), Rect::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_rect_area() {
+        assert_eq!(Rect::new(10.0, 20.0, 100.0, 50.0).area(), 5000.0);
+        assert_eq!(Rect::new(0.0, 0.0, 0.0, 50.0).area(), 0.0);
+    }
+
+    #[test]
+    fn test_rect_contains_includes_edges() {
+        let rect = Rect::new(10.0, 20.0, 100.0, 50.0);
+        assert!(rect.contains(50.0, 40.0));
+        assert!(rect.contains(10.0, 20.0));
+        assert!(rect.contains(110.0, 70.0));
+        assert!(!rect.contains(9.9, 40.0));
+        assert!(!rect.contains(50.0, 70.1));
+    }
+
+    #[test]
+    fn test_rect_union() {
+        let a = Rect::new(10.0, 20.0, 100.0, 50.0);
+        let b = Rect::new(50.0, 0.0, 100.0, 30.0);
+        assert_eq!(a.union(&b), Rect::new(10.0, 0.0, 140.0, 70.0));
+        assert_eq!(a.union(&b), b.union(&a));
+
+        // A rectangle inside another adds nothing
+        assert_eq!(a.union(&Rect::new(20.0, 30.0, 10.0, 10.0)), a);
+    }
+
+    #[test]
+    fn test_rect_union_all() {
+        let a = Rect::new(10.0, 20.0, 100.0, 50.0);
+        let b = Rect::new(50.0, 0.0, 100.0, 30.0);
+        let c = Rect::new(-5.0, 60.0, 10.0, 40.0);
+        assert_eq!(
+            Rect::union_all([a, b, c]),
+            Rect::new(-5.0, 0.0, 155.0, 100.0)
+        );
+        assert_eq!(Rect::union_all([a]), a);
+        assert_eq!(Rect::union_all(Vec::new()), Rect::default());
+    }
+}